@@ -0,0 +1,3 @@
+ALTER TABLE jobs DROP COLUMN referrer;
+ALTER TABLE jobs DROP COLUMN source_id;
+DROP TABLE sources;
@@ -0,0 +1,11 @@
+-- This table holds all unique application sources (ie. "REFERRAL", "LINKEDIN",
+-- "RECRUITER INBOUND").
+CREATE TABLE sources (
+    id INTEGER NOT NULL PRIMARY KEY AUTOINCREMENT,
+    name TEXT NOT NULL UNIQUE
+);
+
+-- The channel through which each job application was submitted and the contact who referred
+-- you, if any.
+ALTER TABLE jobs ADD COLUMN source_id INTEGER REFERENCES sources (id);
+ALTER TABLE jobs ADD COLUMN referrer TEXT;
@@ -0,0 +1,15 @@
+-- This table holds all unique tags (ie. "dream-company", "fintech", "needs-visa").
+CREATE TABLE tags (
+    id INTEGER NOT NULL PRIMARY KEY AUTOINCREMENT,
+    name TEXT NOT NULL UNIQUE
+);
+
+-- This table maps job applications to their tags.
+CREATE TABLE job_tags (
+    job_id INTEGER NOT NULL,
+    tag_id INTEGER NOT NULL,
+    PRIMARY KEY (job_id, tag_id),
+    FOREIGN KEY (job_id) REFERENCES jobs (id) ON DELETE CASCADE,
+    FOREIGN KEY (tag_id) REFERENCES tags (id) ON DELETE CASCADE
+);
+CREATE INDEX idx_job_tags_tag_id ON job_tags (tag_id);
@@ -0,0 +1,20 @@
+-- This table holds all user-defined custom field definitions (ie. "visa sponsorship", "team
+-- name", "hiring manager"). `field_type` is one of "TEXT", "NUMBER", "DATE", "BOOL", or "ENUM".
+-- `options` holds the comma-separated choices for "ENUM" fields.
+CREATE TABLE custom_fields (
+    id INTEGER NOT NULL PRIMARY KEY AUTOINCREMENT,
+    name TEXT NOT NULL UNIQUE,
+    field_type TEXT NOT NULL,
+    options TEXT
+);
+
+-- This table holds the custom field values for each job application.
+CREATE TABLE custom_field_values (
+    job_id INTEGER NOT NULL,
+    field_id INTEGER NOT NULL,
+    value TEXT NOT NULL,
+    PRIMARY KEY (job_id, field_id),
+    FOREIGN KEY (job_id) REFERENCES jobs (id) ON DELETE CASCADE,
+    FOREIGN KEY (field_id) REFERENCES custom_fields (id) ON DELETE CASCADE
+);
+CREATE INDEX idx_custom_field_values_field_id ON custom_field_values (field_id);
@@ -0,0 +1,8 @@
+-- This table holds a snapshot of the full job posting text for each job application. Postings are
+-- often taken down before interviews, so the text is stored separately from the link.
+CREATE TABLE job_descriptions (
+    job_id INTEGER NOT NULL PRIMARY KEY,
+    content TEXT NOT NULL,
+    captured TEXT NOT NULL,
+    FOREIGN KEY (job_id) REFERENCES jobs (id) ON DELETE CASCADE
+);
@@ -0,0 +1,3 @@
+ALTER TABLE jobs DROP COLUMN cover_letter_id;
+ALTER TABLE jobs DROP COLUMN resume_id;
+DROP TABLE documents;
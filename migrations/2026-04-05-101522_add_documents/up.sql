@@ -0,0 +1,15 @@
+-- This table holds every version of your resumes and cover letters. `kind` is either "RESUME" or
+-- "COVER LETTER". The checksum is the SHA-256 hash of the file when it was added.
+CREATE TABLE documents (
+    id INTEGER NOT NULL PRIMARY KEY AUTOINCREMENT,
+    kind TEXT NOT NULL,
+    label TEXT NOT NULL,
+    file_path TEXT NOT NULL,
+    checksum TEXT NOT NULL,
+    created TEXT NOT NULL,
+    UNIQUE (kind, label)
+);
+
+-- The resume and cover letter versions submitted with each job application, if any.
+ALTER TABLE jobs ADD COLUMN resume_id INTEGER REFERENCES documents (id);
+ALTER TABLE jobs ADD COLUMN cover_letter_id INTEGER REFERENCES documents (id);
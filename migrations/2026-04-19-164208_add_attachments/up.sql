@@ -0,0 +1,10 @@
+-- This table holds the files attached to each job application (ie. offer letters, take-home
+-- assignments, or screenshots). Attached files are copied into the fetters data directory, so
+-- `file_path` points to the copy rather than the original file.
+CREATE TABLE attachments (
+    id INTEGER NOT NULL PRIMARY KEY AUTOINCREMENT,
+    job_id INTEGER NOT NULL REFERENCES jobs (id),
+    file_name TEXT NOT NULL,
+    file_path TEXT NOT NULL,
+    added TEXT NOT NULL
+);
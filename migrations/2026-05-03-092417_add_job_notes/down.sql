@@ -0,0 +1,11 @@
+ALTER TABLE jobs ADD COLUMN notes TEXT;
+
+-- Only the latest note for each job application can be kept.
+UPDATE jobs SET notes = (
+    SELECT content FROM job_notes
+    WHERE job_notes.job_id = jobs.id
+    ORDER BY job_notes.created DESC, job_notes.id DESC
+    LIMIT 1
+);
+
+DROP TABLE IF EXISTS job_notes;
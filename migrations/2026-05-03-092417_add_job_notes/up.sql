@@ -0,0 +1,13 @@
+-- This table holds an append-only log of notes for each job application.
+CREATE TABLE job_notes (
+    id INTEGER NOT NULL PRIMARY KEY AUTOINCREMENT,
+    job_id INTEGER NOT NULL REFERENCES jobs (id),
+    content TEXT NOT NULL,
+    created TEXT NOT NULL
+);
+
+-- Existing notes become the first entry in each job's log.
+INSERT INTO job_notes (job_id, content, created)
+SELECT id, notes, created FROM jobs WHERE notes IS NOT NULL AND TRIM(notes) != '';
+
+ALTER TABLE jobs DROP COLUMN notes;
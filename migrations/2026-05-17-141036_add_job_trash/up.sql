@@ -0,0 +1,6 @@
+-- Deleted job applications are moved to the trash by setting `deleted_at` instead of removing the
+-- row. Trashed jobs are excluded from all queries until they are restored, and they are
+-- permanently deleted when the trash is emptied or purged.
+ALTER TABLE jobs ADD COLUMN deleted_at TEXT;
+
+CREATE INDEX idx_jobs_deleted_at ON jobs (deleted_at);
@@ -0,0 +1,21 @@
+-- This table holds every command that changed job applications, interview stages, or sprints so
+-- that it can be inspected with `fetters log` and reversed with `fetters undo`.
+CREATE TABLE operations (
+    id INTEGER NOT NULL PRIMARY KEY AUTOINCREMENT,
+    performed TEXT NOT NULL,
+    command TEXT NOT NULL,
+    undone_at TEXT
+);
+
+-- Each change holds JSON snapshots of a row before and after it was modified. `before_snapshot` is
+-- NULL for inserted rows and `after_snapshot` is NULL for deleted rows.
+CREATE TABLE operation_changes (
+    id INTEGER NOT NULL PRIMARY KEY AUTOINCREMENT,
+    operation_id INTEGER NOT NULL REFERENCES operations (id),
+    table_name TEXT NOT NULL,
+    row_id INTEGER NOT NULL,
+    before_snapshot TEXT,
+    after_snapshot TEXT
+);
+
+CREATE INDEX idx_operation_changes_operation_id ON operation_changes (operation_id);
@@ -0,0 +1,15 @@
+DROP TRIGGER IF EXISTS jobs_search_after_insert;
+DROP TRIGGER IF EXISTS jobs_search_after_update;
+DROP TRIGGER IF EXISTS jobs_search_after_delete;
+DROP TRIGGER IF EXISTS job_notes_search_after_insert;
+DROP TRIGGER IF EXISTS job_notes_search_after_update;
+DROP TRIGGER IF EXISTS job_notes_search_after_delete;
+DROP TRIGGER IF EXISTS interview_stages_search_after_insert;
+DROP TRIGGER IF EXISTS interview_stages_search_after_update;
+DROP TRIGGER IF EXISTS interview_stages_search_after_delete;
+DROP TRIGGER IF EXISTS job_descriptions_search_after_insert;
+DROP TRIGGER IF EXISTS job_descriptions_search_after_update;
+DROP TRIGGER IF EXISTS job_descriptions_search_after_delete;
+DROP TRIGGER IF EXISTS titles_search_after_update;
+DROP VIEW IF EXISTS job_search_documents;
+DROP TABLE IF EXISTS job_search;
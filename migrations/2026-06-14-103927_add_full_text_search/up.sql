@@ -0,0 +1,142 @@
+-- `job_search` is an FTS5 index with one row per job application whose rowid is the job ID. It
+-- covers the company name, job title, every logged note, every interview stage note, and the job
+-- description so `fetters search` can rank matches across all of them.
+CREATE VIRTUAL TABLE job_search USING fts5 (
+    company_name,
+    title,
+    notes,
+    stage_notes,
+    description
+);
+
+-- This view builds the searchable text for each job application. The triggers below rebuild a
+-- job's row in `job_search` from this view whenever anything it covers changes.
+CREATE VIEW job_search_documents AS
+SELECT
+    jobs.id AS job_id,
+    jobs.company_name AS company_name,
+    titles.name AS title,
+    (SELECT GROUP_CONCAT(content, char(10)) FROM job_notes WHERE job_notes.job_id = jobs.id) AS notes,
+    (SELECT GROUP_CONCAT(notes, char(10)) FROM interview_stages WHERE interview_stages.job_id = jobs.id) AS stage_notes,
+    (SELECT content FROM job_descriptions WHERE job_descriptions.job_id = jobs.id) AS description
+FROM jobs
+LEFT JOIN titles ON titles.id = jobs.title_id;
+
+INSERT INTO job_search (rowid, company_name, title, notes, stage_notes, description)
+SELECT job_id, company_name, title, notes, stage_notes, description
+FROM job_search_documents;
+
+-- A job's row is always deleted before it is inserted again. Undoing a change replaces rows, which
+-- fires the insert triggers without the delete triggers.
+CREATE TRIGGER jobs_search_after_insert AFTER INSERT ON jobs
+BEGIN
+    DELETE FROM job_search WHERE rowid = NEW.id;
+    INSERT INTO job_search (rowid, company_name, title, notes, stage_notes, description)
+    SELECT job_id, company_name, title, notes, stage_notes, description
+    FROM job_search_documents
+    WHERE job_id = NEW.id;
+END;
+
+CREATE TRIGGER jobs_search_after_update AFTER UPDATE ON jobs
+BEGIN
+    DELETE FROM job_search WHERE rowid = NEW.id;
+    INSERT INTO job_search (rowid, company_name, title, notes, stage_notes, description)
+    SELECT job_id, company_name, title, notes, stage_notes, description
+    FROM job_search_documents
+    WHERE job_id = NEW.id;
+END;
+
+CREATE TRIGGER jobs_search_after_delete AFTER DELETE ON jobs
+BEGIN
+    DELETE FROM job_search WHERE rowid = OLD.id;
+END;
+
+CREATE TRIGGER job_notes_search_after_insert AFTER INSERT ON job_notes
+BEGIN
+    DELETE FROM job_search WHERE rowid = NEW.job_id;
+    INSERT INTO job_search (rowid, company_name, title, notes, stage_notes, description)
+    SELECT job_id, company_name, title, notes, stage_notes, description
+    FROM job_search_documents
+    WHERE job_id = NEW.job_id;
+END;
+
+CREATE TRIGGER job_notes_search_after_update AFTER UPDATE ON job_notes
+BEGIN
+    DELETE FROM job_search WHERE rowid = NEW.job_id;
+    INSERT INTO job_search (rowid, company_name, title, notes, stage_notes, description)
+    SELECT job_id, company_name, title, notes, stage_notes, description
+    FROM job_search_documents
+    WHERE job_id = NEW.job_id;
+END;
+
+CREATE TRIGGER job_notes_search_after_delete AFTER DELETE ON job_notes
+BEGIN
+    DELETE FROM job_search WHERE rowid = OLD.job_id;
+    INSERT INTO job_search (rowid, company_name, title, notes, stage_notes, description)
+    SELECT job_id, company_name, title, notes, stage_notes, description
+    FROM job_search_documents
+    WHERE job_id = OLD.job_id;
+END;
+
+CREATE TRIGGER interview_stages_search_after_insert AFTER INSERT ON interview_stages
+BEGIN
+    DELETE FROM job_search WHERE rowid = NEW.job_id;
+    INSERT INTO job_search (rowid, company_name, title, notes, stage_notes, description)
+    SELECT job_id, company_name, title, notes, stage_notes, description
+    FROM job_search_documents
+    WHERE job_id = NEW.job_id;
+END;
+
+CREATE TRIGGER interview_stages_search_after_update AFTER UPDATE ON interview_stages
+BEGIN
+    DELETE FROM job_search WHERE rowid = NEW.job_id;
+    INSERT INTO job_search (rowid, company_name, title, notes, stage_notes, description)
+    SELECT job_id, company_name, title, notes, stage_notes, description
+    FROM job_search_documents
+    WHERE job_id = NEW.job_id;
+END;
+
+CREATE TRIGGER interview_stages_search_after_delete AFTER DELETE ON interview_stages
+BEGIN
+    DELETE FROM job_search WHERE rowid = OLD.job_id;
+    INSERT INTO job_search (rowid, company_name, title, notes, stage_notes, description)
+    SELECT job_id, company_name, title, notes, stage_notes, description
+    FROM job_search_documents
+    WHERE job_id = OLD.job_id;
+END;
+
+CREATE TRIGGER job_descriptions_search_after_insert AFTER INSERT ON job_descriptions
+BEGIN
+    DELETE FROM job_search WHERE rowid = NEW.job_id;
+    INSERT INTO job_search (rowid, company_name, title, notes, stage_notes, description)
+    SELECT job_id, company_name, title, notes, stage_notes, description
+    FROM job_search_documents
+    WHERE job_id = NEW.job_id;
+END;
+
+CREATE TRIGGER job_descriptions_search_after_update AFTER UPDATE ON job_descriptions
+BEGIN
+    DELETE FROM job_search WHERE rowid = NEW.job_id;
+    INSERT INTO job_search (rowid, company_name, title, notes, stage_notes, description)
+    SELECT job_id, company_name, title, notes, stage_notes, description
+    FROM job_search_documents
+    WHERE job_id = NEW.job_id;
+END;
+
+CREATE TRIGGER job_descriptions_search_after_delete AFTER DELETE ON job_descriptions
+BEGIN
+    DELETE FROM job_search WHERE rowid = OLD.job_id;
+    INSERT INTO job_search (rowid, company_name, title, notes, stage_notes, description)
+    SELECT job_id, company_name, title, notes, stage_notes, description
+    FROM job_search_documents
+    WHERE job_id = OLD.job_id;
+END;
+
+CREATE TRIGGER titles_search_after_update AFTER UPDATE OF name ON titles
+BEGIN
+    DELETE FROM job_search WHERE rowid IN (SELECT id FROM jobs WHERE title_id = NEW.id);
+    INSERT INTO job_search (rowid, company_name, title, notes, stage_notes, description)
+    SELECT job_id, company_name, title, notes, stage_notes, description
+    FROM job_search_documents
+    WHERE job_id IN (SELECT id FROM jobs WHERE title_id = NEW.id);
+END;
@@ -0,0 +1,309 @@
+//! Contains the local HTTP JSON API started with `fetters serve`, which lets scripts and browser
+//! bookmarklets read and change job applications without running the CLI.
+
+pub mod routes;
+
+use std::io::Read;
+
+use chrono::Local;
+use diesel::SqliteConnection;
+use owo_colors::OwoColorize;
+use serde_json::{Value, json};
+use sha2::{Digest, Sha256};
+use tiny_http::{Header, Method, Request, Response, Server};
+
+use crate::{errors::FettersError, models::sprint::QueriedSprint};
+
+/// The largest request body that is read. Job applications and interview stages are small, so
+/// anything larger is rejected.
+const MAX_BODY_BYTES: usize = 1024 * 1024;
+
+/// A request read from the socket.
+#[derive(Debug)]
+pub struct ApiRequest {
+    /// The HTTP method, ie. `GET`.
+    pub method: String,
+    /// The path without the query string, ie. `/jobs/3`.
+    pub path: String,
+    /// The decoded query string parameters in order.
+    pub query: Vec<(String, String)>,
+    /// The token sent in the `Authorization: Bearer <token>` header.
+    pub token: Option<String>,
+    /// The request body.
+    pub body: String,
+}
+
+/// A JSON response.
+#[derive(Debug)]
+pub struct ApiResponse {
+    /// The HTTP status code.
+    pub status: u16,
+    /// The response body.
+    pub body: Value,
+}
+
+/// Answer requests until the process is stopped.
+pub fn serve(
+    server: &Server,
+    connection: &mut SqliteConnection,
+    token: &str,
+    current_sprint: &QueriedSprint,
+) -> Result<(), FettersError> {
+    for request in server.incoming_requests() {
+        if let Err(error) = answer(request, connection, token, current_sprint) {
+            println!("{}", error.red().bold());
+        }
+    }
+
+    Ok(())
+}
+
+/// Check the token sent with a request against the API token. Both are hashed and the digests
+/// are compared in constant time, so response times do not reveal how much of a guessed token is
+/// correct or how long the API token is.
+pub fn token_matches(sent: Option<&str>, token: &str) -> bool {
+    let Some(sent) = sent else {
+        return false;
+    };
+
+    Sha256::digest(sent)
+        .iter()
+        .zip(Sha256::digest(token).iter())
+        .fold(0, |difference, (left, right)| difference | (left ^ right))
+        == 0
+}
+
+/// Read a single request, handle it, and send the response.
+pub fn answer(
+    mut request: Request,
+    connection: &mut SqliteConnection,
+    token: &str,
+    current_sprint: &QueriedSprint,
+) -> Result<(), FettersError> {
+    // Browsers send a preflight request without credentials before cross-origin requests that
+    // carry the token, ie. from a bookmarklet.
+    if *request.method() == Method::Options {
+        return Ok(request.respond(with_cors_headers(Response::empty(204)))?);
+    }
+
+    let (path, query) = request.url().split_once('?').unwrap_or((request.url(), ""));
+    let token_header = request
+        .headers()
+        .iter()
+        .find(|header| header.field.equiv("Authorization"))
+        .and_then(|header| {
+            header
+                .value
+                .as_str()
+                .strip_prefix("Bearer ")
+                .map(|token| token.trim().to_string())
+        });
+
+    let mut api_request = ApiRequest {
+        method: request.method().to_string(),
+        path: path.to_string(),
+        query: form_urlencoded::parse(query.as_bytes())
+            .into_owned()
+            .collect(),
+        token: token_header,
+        body: String::new(),
+    };
+
+    let response = if !token_matches(api_request.token.as_deref(), token) {
+        // Requests without the token are rejected before their body is read.
+        routes::handle_request(connection, token, current_sprint, &api_request)
+    } else if request
+        .body_length()
+        .is_some_and(|length| length > MAX_BODY_BYTES)
+    {
+        payload_too_large()
+    } else {
+        request
+            .as_reader()
+            .take(MAX_BODY_BYTES as u64 + 1)
+            .read_to_string(&mut api_request.body)?;
+
+        if api_request.body.len() > MAX_BODY_BYTES {
+            payload_too_large()
+        } else {
+            routes::handle_request(connection, token, current_sprint, &api_request)
+        }
+    };
+
+    let status = if response.status < 400 {
+        response.status.green().to_string()
+    } else {
+        response.status.red().to_string()
+    };
+    println!(
+        "{} {} {} {}",
+        Local::now().format("%Y-%m-%d %H:%M:%S").dimmed(),
+        api_request.method.bold(),
+        api_request.path,
+        status
+    );
+
+    Ok(request.respond(with_cors_headers(
+        Response::from_string(response.body.to_string())
+            .with_status_code(response.status)
+            .with_header(header("Content-Type", "application/json")),
+    ))?)
+}
+
+/// The response sent when a request body is larger than `MAX_BODY_BYTES`.
+fn payload_too_large() -> ApiResponse {
+    ApiResponse {
+        status: 413,
+        body: json!({ "error": format!("Request bodies may not exceed {MAX_BODY_BYTES} bytes.") }),
+    }
+}
+
+/// Allow pages in the browser to call the API. Requests still need the token.
+fn with_cors_headers<R: Read>(response: Response<R>) -> Response<R> {
+    response
+        .with_header(header("Access-Control-Allow-Origin", "*"))
+        .with_header(header(
+            "Access-Control-Allow-Methods",
+            "GET, POST, PATCH, DELETE, OPTIONS",
+        ))
+        .with_header(header(
+            "Access-Control-Allow-Headers",
+            "Authorization, Content-Type",
+        ))
+}
+
+/// Create a response header.
+fn header(field: &str, value: &str) -> Header {
+    Header::from_bytes(field.as_bytes(), value.as_bytes())
+        .expect("Header fields and values are valid ASCII")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Write;
+    use std::net::TcpStream;
+    use std::thread;
+
+    use diesel::Connection;
+
+    use crate::models::sprint::NewSprint;
+    use crate::repositories::{sprint::SprintRepository, statuses::StatusRepository};
+
+    /// Start a server on a free port that answers `requests` requests against an in-memory
+    /// database, and return its port.
+    fn start_test_server(requests: usize) -> (u16, thread::JoinHandle<()>) {
+        let server = Server::http("127.0.0.1:0").unwrap();
+        let port = server.server_addr().to_ip().unwrap().port();
+
+        let handle = thread::spawn(move || {
+            let mut connection = SqliteConnection::establish(":memory:")
+                .expect("Failed to create in-memory database");
+            crate::utils::migrations::run_migrations(&mut connection)
+                .expect("Failed to run migrations");
+            let mut status_repo = StatusRepository {
+                connection: &mut connection,
+            };
+            status_repo
+                .seed_statuses()
+                .expect("Failed to seed statuses");
+            let mut sprint_repo = SprintRepository {
+                connection: &mut connection,
+            };
+            let sprint = sprint_repo
+                .add_job_sprint(NewSprint {
+                    name: "2025-01-01",
+                    start_date: "2025-01-01",
+                    end_date: None,
+                    num_jobs: &0,
+                })
+                .unwrap();
+
+            for _ in 0..requests {
+                let request = server.recv().unwrap();
+                answer(request, &mut connection, "secret", &sprint).unwrap();
+            }
+        });
+
+        (port, handle)
+    }
+
+    /// Send a raw HTTP request and return the response.
+    fn send(port: u16, request: &str) -> String {
+        let mut stream = TcpStream::connect(("127.0.0.1", port)).unwrap();
+        stream.write_all(request.as_bytes()).unwrap();
+
+        let mut response = String::new();
+        stream.read_to_string(&mut response).unwrap();
+
+        response
+    }
+
+    #[test]
+    fn test_answer_over_http() {
+        let (port, handle) = start_test_server(3);
+
+        let body = r#"{"company":"Initech","title":"Backend Engineer"}"#;
+        let response = send(
+            port,
+            &format!(
+                "POST /jobs HTTP/1.1\r\nHost: localhost\r\nAuthorization: Bearer secret\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                body.len()
+            ),
+        );
+        assert!(response.starts_with("HTTP/1.1 201"));
+        assert!(response.contains("Content-Type: application/json"));
+        assert!(response.contains(r#""company_name":"Initech""#));
+
+        let response = send(
+            port,
+            "GET /jobs?company=init HTTP/1.1\r\nHost: localhost\r\nAuthorization: Bearer secret\r\nConnection: close\r\n\r\n",
+        );
+        assert!(response.starts_with("HTTP/1.1 200"));
+        assert!(response.contains(r#""company_name":"Initech""#));
+
+        let response = send(
+            port,
+            "GET /jobs HTTP/1.1\r\nHost: localhost\r\nConnection: close\r\n\r\n",
+        );
+        assert!(response.starts_with("HTTP/1.1 401"));
+        assert!(response.contains("Access-Control-Allow-Origin: *"));
+
+        handle.join().unwrap();
+    }
+
+    #[test]
+    fn test_answer_rejects_before_reading_large_bodies() {
+        let (port, handle) = start_test_server(2);
+
+        // Neither body is sent, so the server would wait forever if it tried to read them.
+        let response = send(
+            port,
+            &format!(
+                "POST /jobs HTTP/1.1\r\nHost: localhost\r\nContent-Length: {}\r\nConnection: close\r\n\r\n",
+                MAX_BODY_BYTES
+            ),
+        );
+        assert!(response.starts_with("HTTP/1.1 401"));
+
+        let response = send(
+            port,
+            &format!(
+                "POST /jobs HTTP/1.1\r\nHost: localhost\r\nAuthorization: Bearer secret\r\nContent-Length: {}\r\nConnection: close\r\n\r\n",
+                MAX_BODY_BYTES + 1
+            ),
+        );
+        assert!(response.starts_with("HTTP/1.1 413"));
+
+        handle.join().unwrap();
+    }
+
+    #[test]
+    fn test_token_matches() {
+        assert!(token_matches(Some("secret"), "secret"));
+        assert!(!token_matches(Some("secreT"), "secret"));
+        assert!(!token_matches(Some("secret-but-longer"), "secret"));
+        assert!(!token_matches(Some(""), "secret"));
+        assert!(!token_matches(None, "secret"));
+    }
+}
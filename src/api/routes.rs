@@ -0,0 +1,970 @@
+//! Contains the endpoints of the local HTTP API. Requests are handled by the same repositories
+//! used by the CLI, so every change is recorded in the operation log and can be undone.
+
+use chrono::{Local, NaiveDate};
+use clap::{CommandFactory, Parser};
+use diesel::{Connection, SqliteConnection};
+use serde::Deserialize;
+use serde::de::DeserializeOwned;
+use serde_json::json;
+
+use crate::{
+    api::{ApiRequest, ApiResponse, token_matches},
+    cli::QueryArgs,
+    errors::FettersError,
+    models::{
+        job::{JobUpdate, NewJob},
+        note::NewJobNote,
+        sprint::QueriedSprint,
+        stage::{InterviewStageUpdate, NewInterviewStage, QueriedInterviewStage, StageStatus},
+        status::QueriedStatus,
+        title::NewTitle,
+    },
+    repositories::{
+        job::JobRepository, note::NoteRepository, operation::OperationRepository,
+        sprint::SprintRepository, stage::StageRepository, statuses::StatusRepository,
+        title::TitleRepository,
+    },
+};
+
+/// An error returned to the client as `{"error": "..."}` with an HTTP status code.
+#[derive(Debug)]
+struct ApiError {
+    /// The HTTP status code.
+    status: u16,
+    /// The error message.
+    message: String,
+}
+
+impl ApiError {
+    /// The request is malformed, ie. the body is not valid JSON or a status does not exist.
+    fn bad_request(message: impl Into<String>) -> ApiError {
+        ApiError {
+            status: 400,
+            message: message.into(),
+        }
+    }
+
+    /// The requested resource does not exist.
+    fn not_found(message: impl Into<String>) -> ApiError {
+        ApiError {
+            status: 404,
+            message: message.into(),
+        }
+    }
+}
+
+impl From<diesel::result::Error> for ApiError {
+    fn from(error: diesel::result::Error) -> Self {
+        FettersError::from(error).into()
+    }
+}
+
+impl From<FettersError> for ApiError {
+    fn from(error: FettersError) -> Self {
+        let status = match error {
+            FettersError::JobNotFound(_)
+            | FettersError::DieselResultError(diesel::result::Error::NotFound) => 404,
+            FettersError::InvalidCustomFieldValue(_)
+            | FettersError::InvalidSearchQuery(_)
+            | FettersError::UnknownCustomField(_) => 400,
+            _ => 500,
+        };
+
+        ApiError {
+            status,
+            message: error.to_string(),
+        }
+    }
+}
+
+/// The body of `POST /jobs` and `PATCH /jobs/{id}`. Every field is optional when updating a job
+/// application.
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct JobBody {
+    /// The name of the company.
+    company: Option<String>,
+    /// The job title. The title is created if it has not been used before.
+    title: Option<String>,
+    /// The application status, ie. `PENDING`. New job applications are `PENDING` by default.
+    status: Option<String>,
+    /// The link to the job application.
+    link: Option<String>,
+    /// The contact who referred you for the job application.
+    referrer: Option<String>,
+    /// A note logged for the job application.
+    note: Option<String>,
+}
+
+/// The body of `POST /jobs/{id}/stages` and `PATCH /stages/{id}`.
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct StageBody {
+    /// The name of the stage, ie. "Phone Screen".
+    name: Option<String>,
+    /// The stage status, ie. `SCHEDULED`. New stages are `SCHEDULED` by default.
+    status: Option<String>,
+    /// The date of the stage formatted as `YYYY-MM-DD`. New stages are dated today by default.
+    date: Option<String>,
+    /// Notes about the stage.
+    notes: Option<String>,
+}
+
+/// Handle a request that has already been read from the socket. Every request must carry the
+/// API token in an `Authorization: Bearer <token>` header.
+pub fn handle_request(
+    connection: &mut SqliteConnection,
+    token: &str,
+    current_sprint: &QueriedSprint,
+    request: &ApiRequest,
+) -> ApiResponse {
+    if !token_matches(request.token.as_deref(), token) {
+        return error_response(ApiError {
+            status: 401,
+            message: "Missing or invalid API token.".to_string(),
+        });
+    }
+
+    route(connection, current_sprint, request).unwrap_or_else(error_response)
+}
+
+/// Call the endpoint matching the method and path of a request.
+fn route(
+    connection: &mut SqliteConnection,
+    current_sprint: &QueriedSprint,
+    request: &ApiRequest,
+) -> Result<ApiResponse, ApiError> {
+    let segments = request
+        .path
+        .split('/')
+        .filter(|segment| !segment.is_empty())
+        .collect::<Vec<&str>>();
+
+    match (request.method.as_str(), segments.as_slice()) {
+        ("GET", ["jobs"]) => list_jobs(connection, current_sprint, &request.query),
+        ("POST", ["jobs"]) => {
+            let body = parse_body(&request.body)?;
+            record_operation(connection, request)?;
+            connection.transaction::<_, ApiError, _>(|connection| {
+                add_job(connection, current_sprint, body)
+            })
+        }
+        ("GET", ["jobs", id]) => show_job(connection, parse_id(id)?),
+        ("PATCH", ["jobs", id]) => {
+            let body = parse_body(&request.body)?;
+            let job_id = parse_id(id)?;
+            record_operation(connection, request)?;
+            connection
+                .transaction::<_, ApiError, _>(|connection| update_job(connection, job_id, body))
+        }
+        ("DELETE", ["jobs", id]) => {
+            let job_id = parse_id(id)?;
+            record_operation(connection, request)?;
+            connection.transaction::<_, ApiError, _>(|connection| trash_job(connection, job_id))
+        }
+        ("GET", ["jobs", id, "stages"]) => list_stages(connection, parse_id(id)?),
+        ("POST", ["jobs", id, "stages"]) => {
+            let body = parse_body(&request.body)?;
+            let job_id = parse_id(id)?;
+            record_operation(connection, request)?;
+            connection
+                .transaction::<_, ApiError, _>(|connection| add_stage(connection, job_id, body))
+        }
+        ("PATCH", ["stages", id]) => {
+            let body = parse_body(&request.body)?;
+            let stage_id = parse_id(id)?;
+            record_operation(connection, request)?;
+            connection.transaction::<_, ApiError, _>(|connection| {
+                update_stage(connection, stage_id, body)
+            })
+        }
+        ("DELETE", ["stages", id]) => {
+            let stage_id = parse_id(id)?;
+            record_operation(connection, request)?;
+            connection
+                .transaction::<_, ApiError, _>(|connection| delete_stage(connection, stage_id))
+        }
+        ("GET", ["sprints"]) => {
+            let mut sprint_repo = SprintRepository { connection };
+            to_response(200, sprint_repo.get_all_sprints()?)
+        }
+        ("GET", ["sprints", "current"]) => {
+            let mut sprint_repo = SprintRepository { connection };
+            to_response(200, sprint_repo.get_sprint(current_sprint.id)?)
+        }
+        ("GET", ["statuses"]) => {
+            let mut status_repo = StatusRepository { connection };
+            to_response(200, status_repo.get_all_statuses()?)
+        }
+        ("GET", ["titles"]) => {
+            let mut title_repo = TitleRepository { connection };
+            to_response(200, title_repo.get_all_titles()?)
+        }
+        (
+            _,
+            ["jobs"]
+            | ["jobs", _]
+            | ["jobs", _, "stages"]
+            | ["stages", _]
+            | ["sprints"]
+            | ["sprints", "current"]
+            | ["statuses"]
+            | ["titles"],
+        ) => Err(ApiError {
+            status: 405,
+            message: format!("{} is not supported for {}.", request.method, request.path),
+        }),
+        _ => Err(ApiError::not_found(format!(
+            "There is no endpoint at {}.",
+            request.path
+        ))),
+    }
+}
+
+/// Record the changes made by a request in their own operation, so they can be undone with
+/// `fetters undo`.
+fn record_operation(
+    connection: &mut SqliteConnection,
+    request: &ApiRequest,
+) -> Result<(), ApiError> {
+    let mut operation_repo = OperationRepository { connection };
+    operation_repo.start_operation(&format!(
+        "fetters serve ({} {})",
+        request.method, request.path
+    ))?;
+
+    Ok(())
+}
+
+/// `GET /jobs`: list job applications. The query string accepts the same options as `fetters
+/// list`, ie. `?status=PENDING&tag=remote&all_sprints`.
+fn list_jobs(
+    connection: &mut SqliteConnection,
+    current_sprint: &QueriedSprint,
+    query: &[(String, String)],
+) -> Result<ApiResponse, ApiError> {
+    let query_args = parse_query_args(query)?;
+
+    let mut job_repo = JobRepository { connection };
+    to_response(200, job_repo.list_jobs(&query_args, current_sprint)?)
+}
+
+/// `GET /jobs/{id}`: show everything stored about a job application.
+fn show_job(connection: &mut SqliteConnection, job_id: i32) -> Result<ApiResponse, ApiError> {
+    let mut job_repo = JobRepository { connection };
+    let job = job_repo.get_tabled_job(job_id)?;
+
+    to_response(200, job_repo.get_job_details(&job)?)
+}
+
+/// `POST /jobs`: track a new job application in the current sprint.
+fn add_job(
+    connection: &mut SqliteConnection,
+    current_sprint: &QueriedSprint,
+    body: JobBody,
+) -> Result<ApiResponse, ApiError> {
+    let company_name = required(body.company.as_deref(), "company")?;
+    let title_name = required(body.title.as_deref(), "title")?;
+    let status = find_status(connection, body.status.as_deref().unwrap_or("PENDING"))?;
+
+    let mut title_repo = TitleRepository { connection };
+    let title = title_repo.add_title(NewTitle { name: title_name })?;
+
+    let mut job_repo = JobRepository { connection };
+    let queried_job = job_repo.add_job(NewJob {
+        company_name,
+        created: Local::now().format("%Y-%m-%d %H:%M:%S").to_string(),
+        title_id: title.id,
+        status_id: status.id,
+        link: body.link.as_deref(),
+        sprint_id: current_sprint.id,
+        source_id: None,
+        referrer: body.referrer.as_deref(),
+        resume_id: None,
+        cover_letter_id: None,
+    })?;
+
+    add_note(connection, queried_job.id, body.note.as_deref())?;
+
+    let mut response = show_job(connection, queried_job.id)?;
+    response.status = 201;
+
+    Ok(response)
+}
+
+/// `PATCH /jobs/{id}`: update a job application. Only the fields provided are changed.
+fn update_job(
+    connection: &mut SqliteConnection,
+    job_id: i32,
+    body: JobBody,
+) -> Result<ApiResponse, ApiError> {
+    let mut job_repo = JobRepository { connection };
+    job_repo.get_tabled_job(job_id)?;
+
+    let status_id = match body.status.as_deref() {
+        Some(status) => Some(find_status(connection, status)?.id),
+        None => None,
+    };
+    let title_id = match body.title.as_deref() {
+        Some(title) => {
+            let mut title_repo = TitleRepository { connection };
+            Some(title_repo.add_title(NewTitle { name: title })?.id)
+        }
+        None => None,
+    };
+
+    let changes = JobUpdate {
+        company_name: body.company.as_deref(),
+        title_id,
+        status_id,
+        link: body.link.as_deref(),
+        referrer: body.referrer.as_deref(),
+        ..Default::default()
+    };
+    // Diesel refuses to run an update without any changes.
+    if changes.company_name.is_some()
+        || changes.title_id.is_some()
+        || changes.status_id.is_some()
+        || changes.link.is_some()
+        || changes.referrer.is_some()
+    {
+        let mut job_repo = JobRepository { connection };
+        job_repo.update_job(job_id, changes)?;
+    }
+
+    add_note(connection, job_id, body.note.as_deref())?;
+
+    show_job(connection, job_id)
+}
+
+/// `DELETE /jobs/{id}`: move a job application to the trash.
+fn trash_job(connection: &mut SqliteConnection, job_id: i32) -> Result<ApiResponse, ApiError> {
+    let mut job_repo = JobRepository { connection };
+    let trashed_job = job_repo.trash_job(
+        job_id,
+        &Local::now().format("%Y-%m-%d %H:%M:%S").to_string(),
+    )?;
+
+    to_response(200, trashed_job)
+}
+
+/// `GET /jobs/{id}/stages`: list the interview stages of a job application.
+fn list_stages(connection: &mut SqliteConnection, job_id: i32) -> Result<ApiResponse, ApiError> {
+    let mut job_repo = JobRepository { connection };
+    job_repo.get_tabled_job(job_id)?;
+
+    let mut stage_repo = StageRepository { connection };
+    to_response(200, stage_repo.get_stages_for_job(job_id)?)
+}
+
+/// `POST /jobs/{id}/stages`: add an interview stage to a job application.
+fn add_stage(
+    connection: &mut SqliteConnection,
+    job_id: i32,
+    body: StageBody,
+) -> Result<ApiResponse, ApiError> {
+    let mut job_repo = JobRepository { connection };
+    job_repo.get_tabled_job(job_id)?;
+
+    let status = match body.status.as_deref() {
+        Some(status) => parse_stage_status(status)?,
+        None => StageStatus::Scheduled,
+    };
+    let date = match body.date.as_deref() {
+        Some(date) => parse_stage_date(date)?,
+        None => Local::now().date_naive().format("%Y/%m/%d").to_string(),
+    };
+
+    let mut stage_repo = StageRepository { connection };
+    let stage_number = stage_repo.get_next_stage_number(job_id)?;
+    let stage = stage_repo.add_stage(NewInterviewStage {
+        job_id,
+        stage_number,
+        name: body.name.filter(|name| !name.trim().is_empty()),
+        status: status.to_string(),
+        scheduled_date: date,
+        notes: body.notes.filter(|notes| !notes.trim().is_empty()),
+        created: Local::now().format("%Y-%m-%d %H:%M:%S").to_string(),
+    })?;
+
+    to_response(201, stage)
+}
+
+/// `PATCH /stages/{id}`: update an interview stage. Only the fields provided are changed.
+fn update_stage(
+    connection: &mut SqliteConnection,
+    stage_id: i32,
+    body: StageBody,
+) -> Result<ApiResponse, ApiError> {
+    let stage = find_stage(connection, stage_id)?;
+
+    let changes = InterviewStageUpdate {
+        name: body.name,
+        status: body
+            .status
+            .as_deref()
+            .map(parse_stage_status)
+            .transpose()?
+            .map(|status| status.to_string()),
+        scheduled_date: body.date.as_deref().map(parse_stage_date).transpose()?,
+        notes: body.notes,
+    };
+    if changes.name.is_none()
+        && changes.status.is_none()
+        && changes.scheduled_date.is_none()
+        && changes.notes.is_none()
+    {
+        return to_response(200, stage);
+    }
+
+    let mut stage_repo = StageRepository { connection };
+    to_response(200, stage_repo.update_stage(stage_id, changes)?)
+}
+
+/// `DELETE /stages/{id}`: delete an interview stage. The remaining stages of the job application
+/// are renumbered.
+fn delete_stage(connection: &mut SqliteConnection, stage_id: i32) -> Result<ApiResponse, ApiError> {
+    find_stage(connection, stage_id)?;
+
+    let mut stage_repo = StageRepository { connection };
+    let deleted = stage_repo.delete_stage(stage_id)?;
+    stage_repo.renumber_stages(deleted.job_id)?;
+
+    to_response(200, deleted)
+}
+
+/// Log a note for a job application if one was provided.
+fn add_note(
+    connection: &mut SqliteConnection,
+    job_id: i32,
+    note: Option<&str>,
+) -> Result<(), ApiError> {
+    if let Some(content) = note.map(str::trim).filter(|note| !note.is_empty()) {
+        let mut note_repo = NoteRepository { connection };
+        note_repo.add_note(NewJobNote {
+            job_id,
+            content,
+            created: &Local::now().format("%Y-%m-%d %H:%M:%S").to_string(),
+        })?;
+    }
+
+    Ok(())
+}
+
+/// Find an interview stage by ID. Stages of job applications in the trash are not found, just like
+/// the job applications themselves.
+fn find_stage(
+    connection: &mut SqliteConnection,
+    stage_id: i32,
+) -> Result<QueriedInterviewStage, ApiError> {
+    let mut stage_repo = StageRepository { connection };
+    let stage = stage_repo
+        .get_stage(stage_id)
+        .map_err(|error| stage_error(error, stage_id))?;
+
+    let mut job_repo = JobRepository { connection };
+    job_repo
+        .get_tabled_job(stage.job_id)
+        .map_err(|error| stage_error(error, stage_id))?;
+
+    Ok(stage)
+}
+
+/// Find an application status by name, ignoring case.
+fn find_status(connection: &mut SqliteConnection, name: &str) -> Result<QueriedStatus, ApiError> {
+    let mut status_repo = StatusRepository { connection };
+
+    status_repo
+        .get_all_statuses()?
+        .into_iter()
+        .find(|status| status.name.eq_ignore_ascii_case(name.trim()))
+        .ok_or_else(|| ApiError::bad_request(format!("There is no status named {name}.")))
+}
+
+/// Convert the query string of `GET /jobs` into query options by parsing them like `fetters list`
+/// flags, so both accept exactly the same options and values. Flags without values, ie.
+/// `all_sprints`, may be passed without a value or as `true`.
+fn parse_query_args(query: &[(String, String)]) -> Result<QueryArgs, ApiError> {
+    let command = QueryArgs::command();
+    let mut args = vec!["fetters".to_string()];
+
+    for (key, value) in query {
+        let long = key.replace('_', "-");
+        let argument = command
+            .get_arguments()
+            .find(|argument| argument.get_long() == Some(long.as_str()))
+            .ok_or_else(|| ApiError::bad_request(format!("Unknown query parameter {key}.")))?;
+
+        if value.is_empty() || (!argument.get_action().takes_values() && value == "true") {
+            args.push(format!("--{long}"));
+        } else if argument.get_action().takes_values() {
+            args.push(format!("--{long}={value}"));
+        } else if value != "false" {
+            return Err(ApiError::bad_request(format!(
+                "The query parameter {key} must be true or false."
+            )));
+        }
+    }
+
+    QueryArgs::try_parse_from(args).map_err(|error| {
+        ApiError::bad_request(
+            error
+                .to_string()
+                .lines()
+                .next()
+                .unwrap_or_default()
+                .trim_start_matches("error: "),
+        )
+    })
+}
+
+/// Deserialize a JSON request body. An empty body is treated as an empty object.
+fn parse_body<T: DeserializeOwned + Default>(body: &str) -> Result<T, ApiError> {
+    if body.trim().is_empty() {
+        return Ok(T::default());
+    }
+
+    serde_json::from_str(body)
+        .map_err(|error| ApiError::bad_request(format!("Invalid request body: {error}")))
+}
+
+/// Parse the ID in a request path.
+fn parse_id(id: &str) -> Result<i32, ApiError> {
+    id.parse()
+        .map_err(|_| ApiError::bad_request(format!("{id} is not a valid ID.")))
+}
+
+/// Parse the status of an interview stage.
+fn parse_stage_status(status: &str) -> Result<StageStatus, ApiError> {
+    status.parse().map_err(ApiError::bad_request)
+}
+
+/// Convert a `YYYY-MM-DD` date into the `YYYY/MM/DD` format interview stage dates are stored in.
+fn parse_stage_date(date: &str) -> Result<String, ApiError> {
+    NaiveDate::parse_from_str(date, "%Y-%m-%d")
+        .map(|date| date.format("%Y/%m/%d").to_string())
+        .map_err(|_| ApiError::bad_request(format!("{date} is not a valid YYYY-MM-DD date.")))
+}
+
+/// Get a field that is required when creating a job application.
+fn required<'a>(value: Option<&'a str>, field: &str) -> Result<&'a str, ApiError> {
+    value
+        .map(str::trim)
+        .filter(|value| !value.is_empty())
+        .ok_or_else(|| ApiError::bad_request(format!("The {field} field is required.")))
+}
+
+/// Report a missing interview stage with a readable message.
+fn stage_error(error: FettersError, stage_id: i32) -> ApiError {
+    match error {
+        FettersError::DieselResultError(diesel::result::Error::NotFound)
+        | FettersError::JobNotFound(_) => {
+            ApiError::not_found(format!("There is no interview stage with ID {stage_id}."))
+        }
+        error => error.into(),
+    }
+}
+
+/// Serialize a value into a response.
+fn to_response<T: serde::Serialize>(status: u16, value: T) -> Result<ApiResponse, ApiError> {
+    Ok(ApiResponse {
+        status,
+        body: serde_json::to_value(value).map_err(FettersError::from)?,
+    })
+}
+
+/// Convert an error into a response.
+fn error_response(error: ApiError) -> ApiResponse {
+    ApiResponse {
+        status: error.status,
+        body: json!({ "error": error.message }),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::Value;
+
+    use crate::models::sprint::NewSprint;
+
+    const TOKEN: &str = "secret";
+
+    fn setup_test_db() -> (SqliteConnection, QueriedSprint) {
+        let mut connection =
+            SqliteConnection::establish(":memory:").expect("Failed to create in-memory database");
+        crate::utils::migrations::run_migrations(&mut connection)
+            .expect("Failed to run migrations");
+
+        let mut status_repo = StatusRepository {
+            connection: &mut connection,
+        };
+        status_repo
+            .seed_statuses()
+            .expect("Failed to seed statuses");
+
+        let mut sprint_repo = SprintRepository {
+            connection: &mut connection,
+        };
+        let sprint = sprint_repo
+            .add_job_sprint(NewSprint {
+                name: "2025-01-01",
+                start_date: "2025-01-01",
+                end_date: None,
+                num_jobs: &0,
+            })
+            .unwrap();
+
+        (connection, sprint)
+    }
+
+    fn send(
+        connection: &mut SqliteConnection,
+        sprint: &QueriedSprint,
+        method: &str,
+        url: &str,
+        body: Value,
+    ) -> ApiResponse {
+        let (path, query) = url.split_once('?').unwrap_or((url, ""));
+        let request = ApiRequest {
+            method: method.to_string(),
+            path: path.to_string(),
+            query: form_urlencoded::parse(query.as_bytes())
+                .into_owned()
+                .collect(),
+            token: Some(TOKEN.to_string()),
+            body: if body.is_null() {
+                String::new()
+            } else {
+                body.to_string()
+            },
+        };
+
+        handle_request(connection, TOKEN, sprint, &request)
+    }
+
+    fn create_job(connection: &mut SqliteConnection, sprint: &QueriedSprint, company: &str) -> i64 {
+        let response = send(
+            connection,
+            sprint,
+            "POST",
+            "/jobs",
+            json!({ "company": company, "title": "Backend Engineer", "note": "Applied online" }),
+        );
+        assert_eq!(response.status, 201, "{}", response.body);
+
+        response.body["id"].as_i64().unwrap()
+    }
+
+    #[test]
+    fn test_rejects_missing_token() {
+        let (mut connection, sprint) = setup_test_db();
+        let request = ApiRequest {
+            method: "GET".to_string(),
+            path: "/jobs".to_string(),
+            query: Vec::new(),
+            token: Some("wrong".to_string()),
+            body: String::new(),
+        };
+
+        let response = handle_request(&mut connection, TOKEN, &sprint, &request);
+        assert_eq!(response.status, 401);
+        assert_eq!(response.body["error"], "Missing or invalid API token.");
+    }
+
+    #[test]
+    fn test_add_and_show_job() {
+        let (mut connection, sprint) = setup_test_db();
+
+        let response = send(
+            &mut connection,
+            &sprint,
+            "POST",
+            "/jobs",
+            json!({ "company": "Initech", "title": "Backend Engineer", "status": "in progress", "link": "https://initech.example.com", "note": "Referred by Peter" }),
+        );
+        assert_eq!(response.status, 201);
+        assert_eq!(response.body["company_name"], "Initech");
+        assert_eq!(response.body["status"], "IN PROGRESS");
+        assert_eq!(response.body["sprint"], "2025-01-01");
+        assert_eq!(response.body["notes"][0]["content"], "Referred by Peter");
+
+        let id = response.body["id"].as_i64().unwrap();
+        let response = send(
+            &mut connection,
+            &sprint,
+            "GET",
+            &format!("/jobs/{id}"),
+            Value::Null,
+        );
+        assert_eq!(response.status, 200);
+        assert_eq!(response.body["link"], "https://initech.example.com");
+
+        let response = send(&mut connection, &sprint, "GET", "/titles", Value::Null);
+        assert_eq!(response.body[0]["name"], "Backend Engineer");
+
+        let response = send(
+            &mut connection,
+            &sprint,
+            "GET",
+            "/sprints/current",
+            Value::Null,
+        );
+        assert_eq!(response.body["num_jobs"], 1);
+    }
+
+    #[test]
+    fn test_add_job_validates_body() {
+        let (mut connection, sprint) = setup_test_db();
+
+        let response = send(
+            &mut connection,
+            &sprint,
+            "POST",
+            "/jobs",
+            json!({ "title": "Backend Engineer" }),
+        );
+        assert_eq!(response.status, 400);
+        assert_eq!(response.body["error"], "The company field is required.");
+
+        let response = send(
+            &mut connection,
+            &sprint,
+            "POST",
+            "/jobs",
+            json!({ "company": "Initech", "title": "Backend Engineer", "status": "HIRING" }),
+        );
+        assert_eq!(response.status, 400);
+        assert_eq!(response.body["error"], "There is no status named HIRING.");
+
+        let response = send(
+            &mut connection,
+            &sprint,
+            "POST",
+            "/jobs",
+            json!({ "company": "Initech", "salary": 1 }),
+        );
+        assert_eq!(response.status, 400);
+    }
+
+    #[test]
+    fn test_list_jobs_with_query() {
+        let (mut connection, sprint) = setup_test_db();
+        create_job(&mut connection, &sprint, "Initech");
+        let globex_id = create_job(&mut connection, &sprint, "Globex");
+        send(
+            &mut connection,
+            &sprint,
+            "PATCH",
+            &format!("/jobs/{globex_id}"),
+            json!({ "status": "REJECTED" }),
+        );
+
+        let response = send(&mut connection, &sprint, "GET", "/jobs", Value::Null);
+        assert_eq!(response.status, 200);
+        assert_eq!(response.body.as_array().unwrap().len(), 2);
+
+        let response = send(
+            &mut connection,
+            &sprint,
+            "GET",
+            "/jobs?status=rejected&all_sprints",
+            Value::Null,
+        );
+        assert_eq!(response.status, 200);
+        let jobs = response.body.as_array().unwrap();
+        assert_eq!(jobs.len(), 1);
+        assert_eq!(jobs[0]["company_name"], "Globex");
+
+        let response = send(
+            &mut connection,
+            &sprint,
+            "GET",
+            "/jobs?salary=1",
+            Value::Null,
+        );
+        assert_eq!(response.status, 400);
+        assert_eq!(response.body["error"], "Unknown query parameter salary.");
+
+        let response = send(
+            &mut connection,
+            &sprint,
+            "GET",
+            "/jobs?limit=many",
+            Value::Null,
+        );
+        assert_eq!(response.status, 400);
+    }
+
+    #[test]
+    fn test_update_and_delete_job() {
+        let (mut connection, sprint) = setup_test_db();
+        let id = create_job(&mut connection, &sprint, "Initech");
+
+        let response = send(
+            &mut connection,
+            &sprint,
+            "PATCH",
+            &format!("/jobs/{id}"),
+            json!({ "company": "Initrode", "title": "Staff Engineer", "note": "Called back" }),
+        );
+        assert_eq!(response.status, 200);
+        assert_eq!(response.body["company_name"], "Initrode");
+        assert_eq!(response.body["title"], "Staff Engineer");
+        assert_eq!(response.body["notes"].as_array().unwrap().len(), 2);
+
+        let response = send(
+            &mut connection,
+            &sprint,
+            "DELETE",
+            &format!("/jobs/{id}"),
+            Value::Null,
+        );
+        assert_eq!(response.status, 200);
+        assert!(response.body["deleted_at"].is_string());
+
+        let response = send(
+            &mut connection,
+            &sprint,
+            "GET",
+            &format!("/jobs/{id}"),
+            Value::Null,
+        );
+        assert_eq!(response.status, 404);
+
+        let mut operation_repo = OperationRepository {
+            connection: &mut connection,
+        };
+        let operation = operation_repo.get_current_operation().unwrap().unwrap();
+        assert_eq!(
+            operation.command,
+            format!("fetters serve (DELETE /jobs/{id})")
+        );
+    }
+
+    #[test]
+    fn test_manage_stages() {
+        let (mut connection, sprint) = setup_test_db();
+        let id = create_job(&mut connection, &sprint, "Initech");
+        let stages_url = format!("/jobs/{id}/stages");
+
+        let response = send(
+            &mut connection,
+            &sprint,
+            "POST",
+            &stages_url,
+            json!({ "name": "Phone Screen", "date": "2025-01-20" }),
+        );
+        assert_eq!(response.status, 201);
+        assert_eq!(response.body["status"], "SCHEDULED");
+        assert_eq!(response.body["scheduled_date"], "2025/01/20");
+        let first_id = response.body["id"].as_i64().unwrap();
+
+        let response = send(
+            &mut connection,
+            &sprint,
+            "POST",
+            &stages_url,
+            json!({ "name": "Onsite", "status": "passed" }),
+        );
+        assert_eq!(response.body["stage_number"], 2);
+
+        let response = send(
+            &mut connection,
+            &sprint,
+            "PATCH",
+            &format!("/stages/{first_id}"),
+            json!({ "status": "PASSED", "date": "2025/01/21" }),
+        );
+        assert_eq!(response.status, 400);
+
+        let response = send(
+            &mut connection,
+            &sprint,
+            "PATCH",
+            &format!("/stages/{first_id}"),
+            json!({ "status": "PASSED" }),
+        );
+        assert_eq!(response.status, 200);
+        assert_eq!(response.body["status"], "PASSED");
+
+        let response = send(
+            &mut connection,
+            &sprint,
+            "DELETE",
+            &format!("/stages/{first_id}"),
+            Value::Null,
+        );
+        assert_eq!(response.status, 200);
+
+        let response = send(&mut connection, &sprint, "GET", &stages_url, Value::Null);
+        let stages = response.body.as_array().unwrap();
+        assert_eq!(stages.len(), 1);
+        assert_eq!(stages[0]["name"], "Onsite");
+        assert_eq!(stages[0]["stage_number"], 1);
+
+        let response = send(
+            &mut connection,
+            &sprint,
+            "DELETE",
+            "/stages/999",
+            Value::Null,
+        );
+        assert_eq!(response.status, 404);
+        assert_eq!(
+            response.body["error"],
+            "There is no interview stage with ID 999."
+        );
+
+        let onsite_id = stages[0]["id"].as_i64().unwrap();
+        send(
+            &mut connection,
+            &sprint,
+            "DELETE",
+            &format!("/jobs/{id}"),
+            Value::Null,
+        );
+
+        let response = send(&mut connection, &sprint, "GET", &stages_url, Value::Null);
+        assert_eq!(response.status, 404);
+
+        let response = send(
+            &mut connection,
+            &sprint,
+            "PATCH",
+            &format!("/stages/{onsite_id}"),
+            json!({ "status": "PASSED" }),
+        );
+        assert_eq!(response.status, 404);
+        assert_eq!(
+            response.body["error"],
+            format!("There is no interview stage with ID {onsite_id}.")
+        );
+    }
+
+    #[test]
+    fn test_unknown_routes() {
+        let (mut connection, sprint) = setup_test_db();
+
+        let response = send(&mut connection, &sprint, "GET", "/companies", Value::Null);
+        assert_eq!(response.status, 404);
+
+        let response = send(&mut connection, &sprint, "PUT", "/statuses", Value::Null);
+        assert_eq!(response.status, 405);
+
+        let response = send(&mut connection, &sprint, "GET", "/jobs/abc", Value::Null);
+        assert_eq!(response.status, 400);
+
+        let response = send(&mut connection, &sprint, "GET", "/statuses", Value::Null);
+        assert_eq!(response.status, 200);
+        assert!(
+            response
+                .body
+                .as_array()
+                .unwrap()
+                .iter()
+                .any(|status| status["name"] == "PENDING")
+        );
+    }
+}
@@ -1,6 +1,15 @@
 //! Contains all CLI options.
 
-use clap::{Parser, Subcommand};
+use std::fmt::{self, Display, Formatter};
+use std::str::FromStr;
+
+use chrono::{Duration, NaiveDate};
+use clap::{Parser, Subcommand, ValueEnum};
+use serde::de::Error as DeError;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use strum::{Display as StrumDisplay, EnumIter, EnumString, IntoEnumIterator};
+
+use crate::utils::filter::{WhereClause, WhereField};
 
 /// Contains all CLI options for `fetters`.
 #[derive(Debug, Parser)]
@@ -16,33 +25,130 @@ pub struct Cli {
 #[derive(Debug, Subcommand)]
 pub enum Command {
     /// Track a new job application.
-    Add {
-        /// The name of the company.
-        company: String,
+    Add(AddArgs),
+    /// Attach a file (ie. an offer letter or a take-home assignment) to a job application. The
+    /// file is copied into the fetters data directory.
+    Attach {
+        /// The ID of the job application.
+        #[arg(value_name = "ID")]
+        job_id: i32,
+        /// The path to the file.
+        #[arg(value_name = "FILE")]
+        file: String,
     },
     /// Display the ASCII art.
     Banner,
+    /// Display the job applications in the current sprint as a board with a column per status.
+    Board {
+        /// Open the board full-screen to move job applications between status columns.
+        #[arg(short, long)]
+        interactive: bool,
+    },
     /// Configure `fetters` by opening its config file.
     #[command(subcommand)]
     Config(ConfigOption),
-    /// Delete a tracked job application.
+    /// Move a tracked job application to the trash.
     Delete(QueryArgs),
-    /// Export all tracked job applications from a job sprint to a spreadsheet.
+    /// Manage resume and cover letter versions.
+    #[command(subcommand)]
+    Doc(DocOption),
+    /// Edit a job application's attributes, notes, and interview stages as a TOML document in your
+    /// `$EDITOR`.
+    Edit {
+        /// The ID of the job application.
+        #[arg(value_name = "ID")]
+        job_id: i32,
+    },
+    /// Export all tracked job applications from a job sprint to a spreadsheet or Markdown notes.
     Export(ExportArgs),
+    /// Manage user-defined custom fields for job applications.
+    #[command(subcommand)]
+    Field(FieldOption),
     /// Show job application inslghts.
     Insights,
     /// List job applications. All applications are listed if no query arguments are provided.
-    List(QueryArgs),
-    /// Open the web link in your default browser or the local file associated with a job application.
+    List(ListArgs),
+    /// Display the most recent operations that changed job applications, interview stages, or
+    /// sprints.
+    Log {
+        /// The maximum number of operations to display.
+        #[arg(long, default_value_t = 20)]
+        limit: i64,
+    },
+    /// Run the native messaging host that lets the fetters browser extension track job
+    /// applications, or print its manifest.
+    NativeHost(NativeHostArgs),
+    /// Manage the notes logged for a job application.
+    #[command(subcommand)]
+    Note(NoteOption),
+    /// Open the web link in your default browser or a file attached to a job application.
     Open(QueryArgs),
+    /// Generate reports to share job applications with people who don't use `fetters`.
+    #[command(subcommand)]
+    Report(ReportOption),
+    /// Search company names, job titles, notes, interview stage notes, and job descriptions across
+    /// all sprints. Results are ranked by relevance.
+    Search(SearchArgs),
+    /// Serve a JSON API on localhost for scripts and browser bookmarklets. Requests must send the
+    /// `api_token` from the config file.
+    Serve {
+        /// The port to listen on.
+        #[arg(short, long, default_value_t = 8741)]
+        port: u16,
+    },
+    /// Start an interactive shell to run several commands in a row, with history and tab
+    /// completion.
+    Shell,
     /// Configuration options for job sprints.
     #[command(subcommand)]
     Sprint(SprintOption),
+    /// Show everything stored about a tracked job application.
+    Show(ShowArgs),
     /// Manage interview stages for a particular job application.
     #[command(subcommand)]
     Stage(StageOption),
+    /// Manage tags for job applications.
+    #[command(subcommand)]
+    Tag(TagOption),
+    /// Restore or permanently delete job applications in the trash.
+    #[command(subcommand)]
+    Trash(TrashOption),
+    /// Open a full-screen dashboard to browse, filter, and update job applications.
+    Tui,
+    /// Undo the most recent operations that changed job applications, interview stages, or
+    /// sprints.
+    Undo {
+        /// The number of operations to undo.
+        #[arg(long, default_value_t = 1)]
+        steps: usize,
+    },
     /// Update a tracked job application.
-    Update(QueryArgs),
+    Update(UpdateArgs),
+    /// Save and run named queries. Saved views are stored in the configuration file.
+    #[command(subcommand)]
+    View(ViewOption),
+}
+
+/// All flags you can use when adding a job.
+#[derive(Debug, Default, Parser)]
+pub struct AddArgs {
+    #[arg(
+        required_unless_present = "from_html",
+        help = "The name of the company. Defaults to the hiring organization when using --from-html."
+    )]
+    pub company: Option<String>,
+    #[arg(
+        long,
+        value_name = "FILE",
+        help = "Read the full text of the job posting from a file. Use '-' to read from stdin."
+    )]
+    pub description_file: Option<String>,
+    #[arg(
+        long,
+        value_name = "FILE",
+        help = "Prefill the prompts with the job details from a saved job posting HTML page."
+    )]
+    pub from_html: Option<String>,
 }
 
 /// All subcommands for interacting with the configuration file for `fetters`.
@@ -56,6 +162,20 @@ pub enum ConfigOption {
     Show,
 }
 
+/// All subcommands for managing resume and cover letter versions.
+#[derive(Debug, Subcommand)]
+pub enum DocOption {
+    /// Add a new resume or cover letter version.
+    Add {
+        /// The path to the document.
+        file: String,
+    },
+    /// Display all resume and cover letter versions.
+    List,
+    /// Open a resume or cover letter version with the default application.
+    Open,
+}
+
 /// All subcommands for exporting tracked jobs.
 #[derive(Debug, Parser)]
 pub struct ExportArgs {
@@ -79,45 +199,187 @@ pub struct ExportArgs {
         help = "Select a sprint to export from. Defaults to the current sprint."
     )]
     pub sprint: Option<String>,
+
+    #[arg(
+        long,
+        conflicts_with = "filename",
+        help = "Export Markdown notes with YAML frontmatter, ie. into an Obsidian vault, instead of a spreadsheet. Notes that were exported before are updated in place."
+    )]
+    pub markdown: bool,
+
+    #[arg(
+        long,
+        value_enum,
+        default_value_t = MarkdownLayout::Job,
+        requires = "markdown",
+        help = "Write one Markdown note per job application or one per sprint."
+    )]
+    pub per: MarkdownLayout,
+
+    #[arg(
+        long,
+        conflicts_with = "sprint",
+        requires = "markdown",
+        help = "Export the Markdown notes of all sprints instead of a single sprint."
+    )]
+    pub all_sprints: bool,
+}
+
+/// The ways job applications may be split into Markdown notes.
+#[derive(Clone, Copy, Debug, PartialEq, ValueEnum)]
+pub enum MarkdownLayout {
+    /// One note per job application.
+    Job,
+    /// One note per sprint, with a section for each job application.
+    Sprint,
+}
+
+/// All subcommands for managing user-defined custom fields.
+#[derive(Debug, Subcommand)]
+pub enum FieldOption {
+    /// Define a new custom field. You will be prompted for the field type.
+    Add {
+        /// The name of the custom field.
+        name: String,
+    },
+    /// Delete a custom field and every value stored for it.
+    Delete,
+    /// Display all custom fields.
+    List,
 }
 
-/// All flags you can use to query jobs.
+/// All flags you can use with the native messaging host.
 #[derive(Debug, Default, Parser)]
+pub struct NativeHostArgs {
+    #[arg(
+        long,
+        value_enum,
+        value_name = "BROWSER",
+        requires = "extension_id",
+        help = "Print the native messaging host manifest for this browser instead of running the host."
+    )]
+    pub manifest: Option<Browser>,
+    #[arg(
+        long,
+        value_name = "ID",
+        requires = "manifest",
+        help = "The ID of the browser extension allowed to connect to the host."
+    )]
+    pub extension_id: Option<String>,
+    #[arg(
+        hide = true,
+        trailing_var_arg = true,
+        allow_hyphen_values = true,
+        help = "The arguments browsers pass to native messaging hosts, ie. the extension's origin. These are ignored."
+    )]
+    pub browser_args: Vec<String>,
+}
+
+/// The browsers a native messaging host manifest may be printed for.
+#[derive(Clone, Copy, Debug, PartialEq, ValueEnum)]
+pub enum Browser {
+    /// Firefox and other Gecko-based browsers.
+    Firefox,
+    /// Chrome, Chromium, and other Chromium-based browsers.
+    Chromium,
+}
+
+/// All subcommands for managing the notes logged for a job application.
+#[derive(Debug, Subcommand)]
+pub enum NoteOption {
+    /// Write a new note in your `$EDITOR` and add it to a job application's notes.
+    Add {
+        /// The ID of the job application.
+        #[arg(value_name = "ID")]
+        job_id: i32,
+    },
+    /// Display all notes for a job application in chronological order.
+    List {
+        /// The ID of the job application.
+        #[arg(value_name = "ID")]
+        job_id: i32,
+    },
+}
+
+/// All flags you can use to query jobs. Query options are also stored in saved views, where
+/// options that were not provided are left out.
+#[derive(Clone, Debug, Default, Deserialize, Parser, PartialEq, Serialize)]
+#[serde(default)]
 pub struct QueryArgs {
     #[arg(
         short,
         long,
         help = "Filter results by company name. Supports searching with partial text."
     )]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub company: Option<String>,
+    #[arg(
+        long,
+        help = "Filter results by the stored job description. Supports searching with partial text."
+    )]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[arg(
+        long = "field",
+        value_name = "NAME=VALUE",
+        value_parser = parse_field_filter,
+        help = "Filter results by a custom field value. Repeat this flag to filter by multiple fields. Text fields support searching with partial text."
+    )]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub fields: Vec<(String, String)>,
     #[arg(
         short,
         long,
         help = "Filter results by links. Supports searching with partial text."
     )]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub link: Option<String>,
     #[arg(
         short,
         long,
-        help = "Filter results by notes. Supports searching with partial text."
+        help = "Filter results by notes. Searches all notes logged for each job. Supports searching with partial text."
     )]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub notes: Option<String>,
+    #[arg(
+        long,
+        help = "Filter results by application source (ie. referral, job board). Supports searching with partial text."
+    )]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub source: Option<String>,
     #[arg(
         long,
         help = "Filter results by sprint name. Supports searching with partial text."
     )]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub sprint: Option<String>,
     #[arg(
         short,
         long,
         help = "Filter results by application status. Supports searching with partial text."
     )]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub status: Option<String>,
+    #[arg(
+        long = "tag",
+        value_name = "TAG",
+        help = "Filter results by tag. Repeat this flag to filter by multiple tags. Jobs with any of the given tags are shown unless --all-tags is provided."
+    )]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub tags: Vec<String>,
+    #[arg(
+        long,
+        requires = "tags",
+        help = "Only show jobs that have all of the tags provided with --tag."
+    )]
+    #[serde(skip_serializing_if = "is_false")]
+    pub all_tags: bool,
     #[arg(
         short,
         long,
         help = "Filter results by job title. Supports searching with partial text."
     )]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub title: Option<String>,
     #[arg(
         long,
@@ -125,7 +387,557 @@ pub struct QueryArgs {
         default_missing_value = "0",
         help = "Filter by number of interview stages. Without a value, shows jobs with any stages. With a number, shows jobs with that exact count."
     )]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub stages: Option<i32>,
+    #[arg(
+        long,
+        help = "Only show the job application with this ID. All sprints are searched unless --sprint is provided."
+    )]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<i32>,
+    #[arg(
+        long,
+        value_name = "DATE",
+        help = "Only show job applications created on or after this date. Accepts YYYY-MM-DD or a relative date like 7d or 2w."
+    )]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub since: Option<DateBound>,
+    #[arg(
+        long,
+        value_name = "DATE",
+        help = "Only show job applications created on or before this date. Accepts YYYY-MM-DD or a relative date like 7d or 2w."
+    )]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub until: Option<DateBound>,
+    #[arg(
+        long,
+        conflicts_with = "sprint",
+        help = "Search job applications in all sprints instead of the current sprint."
+    )]
+    #[serde(skip_serializing_if = "is_false")]
+    pub all_sprints: bool,
+    #[arg(
+        long,
+        value_name = "FIELD[:desc]",
+        help = "Sort results by id, created, company, title, status, source, or sprint. Append ':desc' to sort in descending order."
+    )]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sort: Option<JobSort>,
+    #[arg(long, help = "The maximum number of results to show.")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<i64>,
+    #[arg(
+        long = "where",
+        value_name = "EXPRESSION",
+        help = "Filter results with an expression, ie. 'status:(REJECTED|GHOSTED) AND title:backend AND created>2026-01-01 AND NOT company:acme'. Combined with all other query options."
+    )]
+    #[serde(skip_serializing_if = "Option::is_none", rename = "where")]
+    pub where_clause: Option<WhereClause>,
+}
+
+impl QueryArgs {
+    /// Whether this query searches job applications in every sprint. This is the case if
+    /// `--all-sprints` is provided, or if a job is targeted by ID or the `--where` expression
+    /// filters by sprint without a `--sprint` filter.
+    pub fn searches_all_sprints(&self) -> bool {
+        let where_filters_sprint = self
+            .where_clause
+            .as_ref()
+            .is_some_and(|where_clause| where_clause.expr.uses_field(WhereField::Sprint));
+
+        self.all_sprints || (self.sprint.is_none() && (self.id.is_some() || where_filters_sprint))
+    }
+
+    /// Apply the options provided in `overrides` on top of this query, ie. when refining a saved
+    /// view with additional options on the command line. Repeatable options are combined.
+    pub fn with_overrides(mut self, overrides: QueryArgs) -> QueryArgs {
+        macro_rules! override_options {
+            ($($field:ident),*) => {
+                $(
+                    if overrides.$field.is_some() {
+                        self.$field = overrides.$field;
+                    }
+                )*
+            };
+        }
+
+        override_options!(
+            company,
+            description,
+            link,
+            notes,
+            source,
+            status,
+            title,
+            stages,
+            id,
+            since,
+            until,
+            sort,
+            limit
+        );
+
+        // A saved expression is narrowed down by the one provided alongside it.
+        self.where_clause = match (self.where_clause, overrides.where_clause) {
+            (Some(saved), Some(extra)) => Some(saved.and(extra)),
+            (saved, extra) => extra.or(saved),
+        };
+
+        self.fields.extend(overrides.fields);
+        self.tags.extend(overrides.tags);
+        self.all_tags |= overrides.all_tags;
+
+        // `--sprint` and `--all-sprints` conflict, so providing either replaces the other.
+        if overrides.sprint.is_some() {
+            self.sprint = overrides.sprint;
+            self.all_sprints = false;
+        } else if overrides.all_sprints {
+            self.sprint = None;
+            self.all_sprints = true;
+        }
+
+        self
+    }
+
+    /// The name of the sprint searched by this query, used in table headers and error messages.
+    pub fn sprint_label(&self, current_sprint_name: &str) -> String {
+        if self.searches_all_sprints() {
+            "ALL".to_string()
+        } else {
+            self.sprint
+                .clone()
+                .unwrap_or(current_sprint_name.to_string())
+        }
+    }
+}
+
+/// A date bound for filtering job applications by their created timestamp. Dates are either
+/// absolute (`YYYY-MM-DD`) or relative to today (`7d`, `2w`).
+#[derive(Clone, Debug, PartialEq)]
+pub enum DateBound {
+    /// A specific date.
+    Absolute(NaiveDate),
+    /// A number of days before today.
+    DaysAgo(i64),
+}
+
+impl DateBound {
+    /// Resolve this bound to a date, counting relative bounds back from `today`.
+    pub fn resolve(&self, today: NaiveDate) -> NaiveDate {
+        match self {
+            DateBound::Absolute(date) => *date,
+            DateBound::DaysAgo(days) => today - Duration::days(*days),
+        }
+    }
+}
+
+impl FromStr for DateBound {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let value = value.trim();
+        let invalid = || {
+            format!("invalid date \"{value}\", expected YYYY-MM-DD or a relative date like 7d or 2w")
+        };
+
+        if let Some(days) = value.strip_suffix('d') {
+            return days
+                .parse::<u32>()
+                .map(|days| DateBound::DaysAgo(days as i64))
+                .map_err(|_| invalid());
+        }
+        if let Some(weeks) = value.strip_suffix('w') {
+            return weeks
+                .parse::<u32>()
+                .map(|weeks| DateBound::DaysAgo(weeks as i64 * 7))
+                .map_err(|_| invalid());
+        }
+
+        NaiveDate::parse_from_str(value, "%Y-%m-%d")
+            .map(DateBound::Absolute)
+            .map_err(|_| invalid())
+    }
+}
+
+impl Display for DateBound {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            DateBound::Absolute(date) => write!(f, "{}", date.format("%Y-%m-%d")),
+            DateBound::DaysAgo(days) => write!(f, "{days}d"),
+        }
+    }
+}
+
+/// Date bounds are saved as they are written on the command line.
+impl Serialize for DateBound {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for DateBound {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(DeError::custom)
+    }
+}
+
+/// The fields job applications may be sorted by.
+#[derive(Clone, Copy, Debug, EnumIter, EnumString, PartialEq, StrumDisplay)]
+#[strum(serialize_all = "lowercase", ascii_case_insensitive)]
+pub enum SortField {
+    /// The SQLite ID.
+    Id,
+    /// The timestamp at which the job application was created.
+    Created,
+    /// The name of the company.
+    Company,
+    /// The job title.
+    Title,
+    /// The application status.
+    Status,
+    /// The application source.
+    Source,
+    /// The sprint name.
+    Sprint,
+}
+
+/// The order in which job applications are sorted, written as `FIELD[:desc]`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct JobSort {
+    /// The field to sort by.
+    pub field: SortField,
+    /// Whether to sort in descending order.
+    pub descending: bool,
+}
+
+impl FromStr for JobSort {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let (field, direction) = match value.trim().split_once(':') {
+            Some((field, direction)) => (field, Some(direction)),
+            None => (value.trim(), None),
+        };
+
+        let field = SortField::from_str(field).map_err(|_| {
+            format!(
+                "invalid sort field \"{field}\", expected one of: {}",
+                SortField::iter()
+                    .map(|field| field.to_string())
+                    .collect::<Vec<String>>()
+                    .join(", ")
+            )
+        })?;
+        let descending = match direction.map(|direction| direction.to_lowercase()).as_deref() {
+            None | Some("asc") => false,
+            Some("desc") => true,
+            Some(direction) => {
+                return Err(format!(
+                    "invalid sort direction \"{direction}\", expected asc or desc"
+                ));
+            }
+        };
+
+        Ok(JobSort { field, descending })
+    }
+}
+
+impl Display for JobSort {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        if self.descending {
+            write!(f, "{}:desc", self.field)
+        } else {
+            write!(f, "{}", self.field)
+        }
+    }
+}
+
+/// Sort orders are saved as they are written on the command line.
+impl Serialize for JobSort {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for JobSort {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(DeError::custom)
+    }
+}
+
+/// The columns that may be displayed in job application tables. Any other name refers to a
+/// custom field.
+#[derive(Clone, Debug, EnumIter, EnumString, PartialEq, StrumDisplay)]
+#[strum(serialize_all = "lowercase", ascii_case_insensitive)]
+pub enum JobColumn {
+    /// The SQLite ID.
+    Id,
+    /// The timestamp at which the job application was created.
+    Created,
+    /// The name of the company.
+    Company,
+    /// The job title.
+    Title,
+    /// The application status.
+    Status,
+    /// The application source.
+    Source,
+    /// The number of interview stages.
+    Stages,
+    /// The tags applied to the job application.
+    Tags,
+    /// The link to the job application.
+    Link,
+    /// The most recent note.
+    Notes,
+    /// The number of days since the job application was created.
+    Age,
+    /// The date of the most recent note, interview stage, or change to the job application.
+    #[strum(to_string = "activity", serialize = "last_activity")]
+    Activity,
+    /// The value of a user-defined custom field.
+    #[strum(default)]
+    Field(String),
+}
+
+impl JobColumn {
+    /// The columns displayed when no columns are configured. Custom fields are appended after
+    /// these columns.
+    pub fn defaults() -> Vec<JobColumn> {
+        vec![
+            JobColumn::Id,
+            JobColumn::Created,
+            JobColumn::Company,
+            JobColumn::Title,
+            JobColumn::Status,
+            JobColumn::Source,
+            JobColumn::Stages,
+            JobColumn::Tags,
+            JobColumn::Link,
+            JobColumn::Notes,
+        ]
+    }
+
+    /// The column header displayed in job tables.
+    pub fn header(&self) -> String {
+        match self {
+            JobColumn::Id => "ID".to_string(),
+            JobColumn::Created => "Created".to_string(),
+            JobColumn::Company => "Company Name".to_string(),
+            JobColumn::Title => "Title".to_string(),
+            JobColumn::Status => "Status".to_string(),
+            JobColumn::Source => "Source".to_string(),
+            JobColumn::Stages => "Num Stages".to_string(),
+            JobColumn::Tags => "Tags".to_string(),
+            JobColumn::Link => "Link".to_string(),
+            JobColumn::Notes => "Notes".to_string(),
+            JobColumn::Age => "Age (Days)".to_string(),
+            JobColumn::Activity => "Last Activity".to_string(),
+            JobColumn::Field(name) => name.clone(),
+        }
+    }
+}
+
+/// Columns are saved as they are written on the command line.
+impl Serialize for JobColumn {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for JobColumn {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(DeError::custom)
+    }
+}
+
+/// Parse a column name, trimming surrounding whitespace.
+fn parse_job_column(column: &str) -> Result<JobColumn, String> {
+    match column.trim() {
+        "" => Err("column names must not be empty".to_string()),
+        column => JobColumn::from_str(column).map_err(|error| error.to_string()),
+    }
+}
+
+/// Used to leave flags that were not provided out of saved views.
+fn is_false(value: &bool) -> bool {
+    !*value
+}
+
+/// All flags you can use when listing jobs.
+#[derive(Debug, Default, Parser)]
+pub struct ListArgs {
+    #[arg(
+        value_name = "@VIEW",
+        value_parser = parse_view_reference,
+        help = "Run a saved view, ie. '@stale'. Query options provided alongside the view refine the saved query."
+    )]
+    pub view: Option<String>,
+    #[arg(
+        long,
+        value_enum,
+        help = "The output format. Defaults to the saved view's format, or text."
+    )]
+    pub format: Option<OutputFormat>,
+    #[arg(
+        long,
+        value_name = "COLUMNS",
+        value_delimiter = ',',
+        value_parser = parse_job_column,
+        help = "The comma-separated columns to display, ie. 'id,company,status,age'. Accepts id, created, company, title, status, source, stages, tags, link, notes, age, activity, or a custom field name."
+    )]
+    pub columns: Vec<JobColumn>,
+    #[command(flatten)]
+    pub query_args: QueryArgs,
+}
+
+/// All flags you can use when updating a job.
+#[derive(Debug, Default, Parser)]
+pub struct UpdateArgs {
+    #[command(flatten)]
+    pub query_args: QueryArgs,
+    #[arg(
+        long,
+        help = "Select multiple job applications matching the query and change their status, sprint, or tags at once."
+    )]
+    pub bulk: bool,
+    #[arg(
+        long,
+        value_name = "IDS",
+        value_delimiter = ',',
+        help = "Change the status, sprint, or tags of the job applications with these comma-separated IDs at once."
+    )]
+    pub ids: Vec<i32>,
+    #[arg(
+        long,
+        value_name = "FILE",
+        conflicts_with_all = ["bulk", "ids"],
+        help = "Replace the stored job description with the contents of a file. Use '-' to read from stdin."
+    )]
+    pub description_file: Option<String>,
+}
+
+/// All flags you can use when searching jobs.
+#[derive(Debug, Default, Parser)]
+pub struct SearchArgs {
+    #[arg(
+        value_name = "QUERY",
+        help = "The search query. Supports phrases (\"phone screen\"), prefixes (recruit*), AND/OR/NOT, and column filters (company_name:, title:, notes:, stage_notes:, description:)."
+    )]
+    pub query: String,
+    #[arg(
+        long,
+        help = "Only search job applications in sprints matching this name. Supports searching with partial text."
+    )]
+    pub sprint: Option<String>,
+    #[arg(
+        long,
+        default_value_t = 25,
+        help = "The maximum number of results to display."
+    )]
+    pub limit: i64,
+}
+
+/// All flags you can use when showing a job. `--description` prints only the stored job
+/// description, so the job description filter is renamed to `--description-contains`.
+#[derive(Debug, Default, Parser)]
+#[command(mut_arg("description", |arg| arg.long("description-contains")))]
+pub struct ShowArgs {
+    #[arg(
+        value_name = "ID",
+        help = "The ID of the job application. Select a job matching the query options if this is not provided."
+    )]
+    pub job_id: Option<i32>,
+    #[arg(
+        long,
+        value_enum,
+        default_value_t = OutputFormat::Text,
+        help = "The output format."
+    )]
+    pub format: OutputFormat,
+    #[arg(
+        id = "description_only",
+        long = "description",
+        help = "Only print the stored job description."
+    )]
+    pub description_only: bool,
+    #[command(flatten)]
+    pub query_args: QueryArgs,
+}
+
+/// The formats job applications may be printed in.
+#[derive(
+    Clone, Copy, Debug, Default, Deserialize, PartialEq, Serialize, StrumDisplay, ValueEnum,
+)]
+#[serde(rename_all = "lowercase")]
+#[strum(serialize_all = "lowercase")]
+pub enum OutputFormat {
+    /// Human-readable tables and trees.
+    #[default]
+    Text,
+    /// Pretty-printed JSON.
+    Json,
+}
+
+/// Parse a reference to a saved view, written as `@NAME`.
+fn parse_view_reference(reference: &str) -> Result<String, String> {
+    match reference.strip_prefix('@') {
+        Some(name) => parse_view_name(name),
+        None => Err(format!(
+            "expected a saved view like @{reference}. Use query options such as --company to filter jobs"
+        )),
+    }
+}
+
+/// Parse the name of a saved view. A leading `@` is allowed so views can be named the way they
+/// are referenced.
+fn parse_view_name(name: &str) -> Result<String, String> {
+    let name = name.strip_prefix('@').unwrap_or(name);
+
+    if name.is_empty() || name.chars().any(char::is_whitespace) {
+        Err("view names must not be empty or contain whitespace".to_string())
+    } else {
+        Ok(name.to_string())
+    }
+}
+
+/// Parse a `NAME=VALUE` custom field filter.
+fn parse_field_filter(filter: &str) -> Result<(String, String), String> {
+    match filter.split_once('=') {
+        Some((name, value)) if !name.trim().is_empty() => {
+            Ok((name.trim().to_string(), value.trim().to_string()))
+        }
+        _ => Err(format!(
+            "invalid custom field filter \"{filter}\", expected NAME=VALUE"
+        )),
+    }
+}
+
+/// All subcommands for generating reports.
+#[derive(Debug, Subcommand)]
+pub enum ReportOption {
+    /// Write a self-contained HTML page per sprint with the job applications, status counts,
+    /// interview stage trees, and charts.
+    Html {
+        #[arg(
+            long,
+            value_name = "DIR",
+            help = "The directory the pages are written to. It is created if it does not exist."
+        )]
+        out: String,
+        #[arg(
+            short,
+            long,
+            help = "Only write the page for the sprint with this name. Pages for all sprints are written if this is not provided."
+        )]
+        sprint: Option<String>,
+    },
 }
 
 /// All subcommands for managing job sprints.
@@ -158,6 +970,65 @@ pub enum StageOption {
     Update(QueryArgs),
 }
 
+/// All subcommands for managing tags on job applications.
+#[derive(Debug, Subcommand)]
+pub enum TagOption {
+    /// Apply existing or new tags to an application.
+    Add(QueryArgs),
+    /// Display all tags and the number of applications they are applied to.
+    List,
+    /// Remove tags from an application.
+    Remove(QueryArgs),
+}
+
+/// All subcommands for managing deleted job applications in the trash.
+#[derive(Debug, Subcommand)]
+pub enum TrashOption {
+    /// Permanently delete every job application in the trash.
+    Empty,
+    /// List all job applications in the trash.
+    List,
+    /// Restore a job application from the trash.
+    Restore {
+        /// The ID of the job application. Select a job in the trash if this is not provided.
+        #[arg(value_name = "ID")]
+        job_id: Option<i32>,
+    },
+}
+
+/// All subcommands for managing saved views.
+#[derive(Debug, Subcommand)]
+pub enum ViewOption {
+    /// Delete a saved view.
+    Delete {
+        /// The name of the view.
+        #[arg(value_parser = parse_view_name)]
+        name: String,
+    },
+    /// Display all saved views.
+    List,
+    /// List the job applications matching a saved view. This is the same as `fetters list @NAME`.
+    Run {
+        /// The name of the view.
+        #[arg(value_parser = parse_view_name)]
+        name: String,
+    },
+    /// Save the query options as a named view. An existing view with the same name is replaced.
+    Save {
+        /// The name of the view.
+        #[arg(value_parser = parse_view_name)]
+        name: String,
+        /// The output format used when running the view.
+        #[arg(long, value_enum, default_value_t = OutputFormat::Text)]
+        format: OutputFormat,
+        /// The comma-separated columns displayed when running the view.
+        #[arg(long, value_delimiter = ',', value_parser = parse_job_column)]
+        columns: Vec<JobColumn>,
+        #[command(flatten)]
+        query_args: Box<QueryArgs>,
+    },
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -167,7 +1038,11 @@ mod tests {
     fn test_parse_add_command() {
         let cli = Cli::try_parse_from(["fetters", "add", "Google"]).unwrap();
         match cli.command {
-            Command::Add { company } => assert_eq!(company, "Google"),
+            Command::Add(args) => {
+                assert_eq!(args.company.as_deref(), Some("Google"));
+                assert!(args.description_file.is_none());
+                assert!(args.from_html.is_none());
+            }
             _ => panic!("Expected Add command"),
         }
     }
@@ -182,7 +1057,7 @@ mod tests {
     fn test_parse_list_command_with_no_args() {
         let cli = Cli::try_parse_from(["fetters", "list"]).unwrap();
         match cli.command {
-            Command::List(args) => {
+            Command::List(ListArgs { query_args: args, .. }) => {
                 assert!(args.company.is_none());
                 assert!(args.status.is_none());
                 assert!(args.title.is_none());
@@ -198,7 +1073,7 @@ mod tests {
         ])
         .unwrap();
         match cli.command {
-            Command::List(args) => {
+            Command::List(ListArgs { query_args: args, .. }) => {
                 assert_eq!(args.company.as_deref(), Some("Google"));
                 assert_eq!(args.status.as_deref(), Some("PENDING"));
                 assert_eq!(args.title.as_deref(), Some("SWE"));
@@ -207,6 +1082,15 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_parse_list_command_with_source_filter() {
+        let cli = Cli::try_parse_from(["fetters", "list", "--source", "referral"]).unwrap();
+        match cli.command {
+            Command::List(ListArgs { query_args: args, .. }) => assert_eq!(args.source.as_deref(), Some("referral")),
+            _ => panic!("Expected List command"),
+        }
+    }
+
     #[test]
     fn test_parse_delete_command() {
         let cli =
@@ -217,12 +1101,96 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_parse_board_command() {
+        let cli = Cli::try_parse_from(["fetters", "board"]).unwrap();
+        assert!(matches!(cli.command, Command::Board { interactive: false }));
+
+        let cli = Cli::try_parse_from(["fetters", "board", "-i"]).unwrap();
+        assert!(matches!(cli.command, Command::Board { interactive: true }));
+    }
+
+    #[test]
+    fn test_parse_native_host_command() {
+        let cli = Cli::try_parse_from([
+            "fetters",
+            "native-host",
+            "/home/user/.mozilla/native-messaging-hosts/fetters.json",
+            "fetters@example.com",
+        ])
+        .unwrap();
+        match cli.command {
+            Command::NativeHost(args) => {
+                assert_eq!(args.manifest, None);
+                assert_eq!(args.browser_args.len(), 2);
+            }
+            _ => panic!("Expected NativeHost command"),
+        }
+
+        let cli = Cli::try_parse_from([
+            "fetters",
+            "native-host",
+            "--manifest",
+            "chromium",
+            "--extension-id",
+            "abcdefghijklmnop",
+        ])
+        .unwrap();
+        match cli.command {
+            Command::NativeHost(args) => {
+                assert_eq!(args.manifest, Some(Browser::Chromium));
+                assert_eq!(args.extension_id.as_deref(), Some("abcdefghijklmnop"));
+            }
+            _ => panic!("Expected NativeHost command"),
+        }
+
+        assert!(Cli::try_parse_from(["fetters", "native-host", "--manifest", "firefox"]).is_err());
+    }
+
+    #[test]
+    fn test_parse_report_html_command() {
+        let cli = Cli::try_parse_from(["fetters", "report", "html", "--out", "reports/"]).unwrap();
+        match cli.command {
+            Command::Report(ReportOption::Html { out, sprint }) => {
+                assert_eq!(out, "reports/");
+                assert_eq!(sprint, None);
+            }
+            _ => panic!("Expected Report command"),
+        }
+
+        assert!(Cli::try_parse_from(["fetters", "report", "html"]).is_err());
+    }
+
+    #[test]
+    fn test_parse_serve_command() {
+        let cli = Cli::try_parse_from(["fetters", "serve"]).unwrap();
+        assert!(matches!(cli.command, Command::Serve { port: 8741 }));
+
+        let cli = Cli::try_parse_from(["fetters", "serve", "--port", "9000"]).unwrap();
+        assert!(matches!(cli.command, Command::Serve { port: 9000 }));
+    }
+
+    #[test]
+    fn test_parse_shell_command() {
+        let cli = Cli::try_parse_from(["fetters", "shell"]).unwrap();
+        assert!(matches!(cli.command, Command::Shell));
+    }
+
+    #[test]
+    fn test_parse_tui_command() {
+        let cli = Cli::try_parse_from(["fetters", "tui"]).unwrap();
+        assert!(matches!(cli.command, Command::Tui));
+    }
+
     #[test]
     fn test_parse_update_command() {
         let cli =
             Cli::try_parse_from(["fetters", "update", "--company", "Apple"]).unwrap();
         match cli.command {
-            Command::Update(args) => assert_eq!(args.company.as_deref(), Some("Apple")),
+            Command::Update(args) => {
+                assert_eq!(args.query_args.company.as_deref(), Some("Apple"));
+                assert!(args.description_file.is_none());
+            }
             _ => panic!("Expected Update command"),
         }
     }
@@ -262,6 +1230,68 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_parse_export_markdown() {
+        let cli = Cli::try_parse_from(["fetters", "export", "-d", "/tmp/vault"]).unwrap();
+        match cli.command {
+            Command::Export(args) => {
+                assert!(!args.markdown);
+                assert_eq!(args.per, MarkdownLayout::Job);
+            }
+            _ => panic!("Expected Export command"),
+        }
+
+        let cli = Cli::try_parse_from([
+            "fetters",
+            "export",
+            "--markdown",
+            "--per",
+            "sprint",
+            "--all-sprints",
+        ])
+        .unwrap();
+        match cli.command {
+            Command::Export(args) => {
+                assert!(args.markdown);
+                assert!(args.all_sprints);
+                assert_eq!(args.per, MarkdownLayout::Sprint);
+            }
+            _ => panic!("Expected Export command"),
+        }
+
+        assert!(Cli::try_parse_from(["fetters", "export", "--per", "sprint"]).is_err());
+        assert!(
+            Cli::try_parse_from(["fetters", "export", "--markdown", "-f", "jobs.xlsx"]).is_err()
+        );
+        assert!(
+            Cli::try_parse_from(["fetters", "export", "--markdown", "--all-sprints", "-s", "x"])
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_parse_show_description() {
+        let cli = Cli::try_parse_from(["fetters", "show", "7", "--description"]).unwrap();
+        match cli.command {
+            Command::Show(args) => {
+                assert_eq!(args.job_id, Some(7));
+                assert!(args.description_only);
+                assert!(args.query_args.description.is_none());
+            }
+            _ => panic!("Expected Show command"),
+        }
+
+        let cli = Cli::try_parse_from(["fetters", "show", "--description-contains", "rust"])
+            .unwrap();
+        match cli.command {
+            Command::Show(args) => {
+                assert!(!args.description_only);
+                assert_eq!(args.query_args.description.as_deref(), Some("rust"));
+            }
+            _ => panic!("Expected Show command"),
+        }
+    }
+
     #[test]
     fn test_parse_sprint_current() {
         let cli = Cli::try_parse_from(["fetters", "sprint", "current"]).unwrap();
@@ -371,7 +1401,7 @@ mod tests {
     fn test_parse_list_with_stages_flag_no_value() {
         let cli = Cli::try_parse_from(["fetters", "list", "--stages"]).unwrap();
         match cli.command {
-            Command::List(args) => assert_eq!(args.stages, Some(0)),
+            Command::List(ListArgs { query_args: args, .. }) => assert_eq!(args.stages, Some(0)),
             _ => panic!("Expected List command"),
         }
     }
@@ -380,7 +1410,7 @@ mod tests {
     fn test_parse_list_with_stages_flag_with_value() {
         let cli = Cli::try_parse_from(["fetters", "list", "--stages", "3"]).unwrap();
         match cli.command {
-            Command::List(args) => assert_eq!(args.stages, Some(3)),
+            Command::List(ListArgs { query_args: args, .. }) => assert_eq!(args.stages, Some(3)),
             _ => panic!("Expected List command"),
         }
     }
@@ -401,9 +1431,594 @@ mod tests {
         assert!(args.company.is_none());
         assert!(args.link.is_none());
         assert!(args.notes.is_none());
+        assert!(args.source.is_none());
         assert!(args.sprint.is_none());
         assert!(args.status.is_none());
         assert!(args.title.is_none());
         assert!(args.stages.is_none());
+        assert!(args.tags.is_empty());
+        assert!(!args.all_tags);
+    }
+
+    #[test]
+    fn test_parse_list_with_repeated_tag_flags() {
+        let cli = Cli::try_parse_from([
+            "fetters", "list", "--tag", "fintech", "--tag", "remote", "--all-tags",
+        ])
+        .unwrap();
+        match cli.command {
+            Command::List(ListArgs { query_args: args, .. }) => {
+                assert_eq!(args.tags, vec!["fintech", "remote"]);
+                assert!(args.all_tags);
+            }
+            _ => panic!("Expected List command"),
+        }
+    }
+
+    #[test]
+    fn test_parse_all_tags_requires_tag() {
+        assert!(Cli::try_parse_from(["fetters", "list", "--all-tags"]).is_err());
+    }
+
+    #[test]
+    fn test_parse_tag_add() {
+        let cli = Cli::try_parse_from(["fetters", "tag", "add", "--company", "Stripe"]).unwrap();
+        match cli.command {
+            Command::Tag(TagOption::Add(args)) => {
+                assert_eq!(args.company.as_deref(), Some("Stripe"));
+            }
+            _ => panic!("Expected Tag Add"),
+        }
+    }
+
+    #[test]
+    fn test_parse_tag_remove_and_list() {
+        let cli = Cli::try_parse_from(["fetters", "tag", "remove"]).unwrap();
+        assert!(matches!(cli.command, Command::Tag(TagOption::Remove(_))));
+
+        let cli = Cli::try_parse_from(["fetters", "tag", "list"]).unwrap();
+        assert!(matches!(cli.command, Command::Tag(TagOption::List)));
+    }
+
+    #[test]
+    fn test_parse_list_with_field_filters() {
+        let cli = Cli::try_parse_from([
+            "fetters",
+            "list",
+            "--field",
+            "Team=Payments",
+            "--field",
+            "Visa Sponsorship = yes",
+        ])
+        .unwrap();
+        match cli.command {
+            Command::List(ListArgs { query_args: args, .. }) => {
+                assert_eq!(
+                    args.fields,
+                    vec![
+                        ("Team".to_string(), "Payments".to_string()),
+                        ("Visa Sponsorship".to_string(), "yes".to_string()),
+                    ]
+                );
+            }
+            _ => panic!("Expected List command"),
+        }
+    }
+
+    #[test]
+    fn test_parse_field_filter_without_equals_fails() {
+        assert!(Cli::try_parse_from(["fetters", "list", "--field", "Team"]).is_err());
+        assert!(Cli::try_parse_from(["fetters", "list", "--field", "=Payments"]).is_err());
+    }
+
+    #[test]
+    fn test_parse_field_subcommands() {
+        let cli = Cli::try_parse_from(["fetters", "field", "add", "Hiring Manager"]).unwrap();
+        match cli.command {
+            Command::Field(FieldOption::Add { name }) => assert_eq!(name, "Hiring Manager"),
+            _ => panic!("Expected Field Add"),
+        }
+
+        let cli = Cli::try_parse_from(["fetters", "field", "list"]).unwrap();
+        assert!(matches!(cli.command, Command::Field(FieldOption::List)));
+
+        let cli = Cli::try_parse_from(["fetters", "field", "delete"]).unwrap();
+        assert!(matches!(cli.command, Command::Field(FieldOption::Delete)));
+    }
+
+    #[test]
+    fn test_parse_add_with_description_file() {
+        let cli = Cli::try_parse_from([
+            "fetters",
+            "add",
+            "Stripe",
+            "--description-file",
+            "posting.txt",
+        ])
+        .unwrap();
+        match cli.command {
+            Command::Add(args) => {
+                assert_eq!(args.description_file.as_deref(), Some("posting.txt"))
+            }
+            _ => panic!("Expected Add command"),
+        }
+    }
+
+    #[test]
+    fn test_parse_add_from_html_without_company() {
+        let cli = Cli::try_parse_from(["fetters", "add", "--from-html", "posting.html"]).unwrap();
+        match cli.command {
+            Command::Add(args) => {
+                assert!(args.company.is_none());
+                assert_eq!(args.from_html.as_deref(), Some("posting.html"));
+            }
+            _ => panic!("Expected Add command"),
+        }
+    }
+
+    #[test]
+    fn test_parse_update_with_description_file_from_stdin() {
+        let cli = Cli::try_parse_from(["fetters", "update", "--description-file", "-"]).unwrap();
+        match cli.command {
+            Command::Update(args) => assert_eq!(args.description_file.as_deref(), Some("-")),
+            _ => panic!("Expected Update command"),
+        }
+    }
+
+    #[test]
+    fn test_parse_update_bulk() {
+        let cli =
+            Cli::try_parse_from(["fetters", "update", "--bulk", "--status", "pending"]).unwrap();
+        match cli.command {
+            Command::Update(args) => {
+                assert!(args.bulk);
+                assert!(args.ids.is_empty());
+                assert_eq!(args.query_args.status.as_deref(), Some("pending"));
+            }
+            _ => panic!("Expected Update command"),
+        }
+
+        let cli = Cli::try_parse_from(["fetters", "update", "--ids", "3,5,9"]).unwrap();
+        match cli.command {
+            Command::Update(args) => assert_eq!(args.ids, vec![3, 5, 9]),
+            _ => panic!("Expected Update command"),
+        }
+
+        assert!(Cli::try_parse_from(["fetters", "update", "--ids", "3,five"]).is_err());
+        assert!(
+            Cli::try_parse_from(["fetters", "update", "--bulk", "--description-file", "-"])
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_parse_list_with_description_filter() {
+        let cli = Cli::try_parse_from(["fetters", "list", "--description", "kubernetes"]).unwrap();
+        match cli.command {
+            Command::List(ListArgs { query_args: args, .. }) => assert_eq!(args.description.as_deref(), Some("kubernetes")),
+            _ => panic!("Expected List command"),
+        }
+    }
+
+    #[test]
+    fn test_parse_show_command() {
+        let cli = Cli::try_parse_from(["fetters", "show", "7", "--format", "json"]).unwrap();
+        match cli.command {
+            Command::Show(args) => {
+                assert_eq!(args.job_id, Some(7));
+                assert_eq!(args.format, OutputFormat::Json);
+            }
+            _ => panic!("Expected Show command"),
+        }
+
+        let cli = Cli::try_parse_from(["fetters", "show", "--company", "Stripe"]).unwrap();
+        match cli.command {
+            Command::Show(args) => {
+                assert_eq!(args.job_id, None);
+                assert_eq!(args.format, OutputFormat::Text);
+                assert_eq!(args.query_args.company.as_deref(), Some("Stripe"));
+            }
+            _ => panic!("Expected Show command"),
+        }
+
+        assert!(Cli::try_parse_from(["fetters", "show", "abc"]).is_err());
+        assert!(Cli::try_parse_from(["fetters", "show", "7", "--format", "yaml"]).is_err());
+    }
+
+    #[test]
+    fn test_parse_doc_subcommands() {
+        let cli = Cli::try_parse_from(["fetters", "doc", "add", "resume.pdf"]).unwrap();
+        match cli.command {
+            Command::Doc(DocOption::Add { file }) => assert_eq!(file, "resume.pdf"),
+            _ => panic!("Expected Doc Add"),
+        }
+
+        let cli = Cli::try_parse_from(["fetters", "doc", "list"]).unwrap();
+        assert!(matches!(cli.command, Command::Doc(DocOption::List)));
+
+        let cli = Cli::try_parse_from(["fetters", "doc", "open"]).unwrap();
+        assert!(matches!(cli.command, Command::Doc(DocOption::Open)));
+    }
+
+    #[test]
+    fn test_parse_note_subcommands() {
+        let cli = Cli::try_parse_from(["fetters", "note", "add", "3"]).unwrap();
+        assert!(matches!(
+            cli.command,
+            Command::Note(NoteOption::Add { job_id: 3 })
+        ));
+
+        let cli = Cli::try_parse_from(["fetters", "note", "list", "3"]).unwrap();
+        assert!(matches!(
+            cli.command,
+            Command::Note(NoteOption::List { job_id: 3 })
+        ));
+
+        assert!(Cli::try_parse_from(["fetters", "note", "add"]).is_err());
+    }
+
+    #[test]
+    fn test_parse_attach_command() {
+        let cli = Cli::try_parse_from(["fetters", "attach", "7", "offer.pdf"]).unwrap();
+        match cli.command {
+            Command::Attach { job_id, file } => {
+                assert_eq!(job_id, 7);
+                assert_eq!(file, "offer.pdf");
+            }
+            _ => panic!("Expected Attach command"),
+        }
+
+        assert!(Cli::try_parse_from(["fetters", "attach", "7"]).is_err());
+    }
+
+    #[test]
+    fn test_parse_trash_commands() {
+        let cli = Cli::try_parse_from(["fetters", "trash", "list"]).unwrap();
+        assert!(matches!(cli.command, Command::Trash(TrashOption::List)));
+
+        let cli = Cli::try_parse_from(["fetters", "trash", "empty"]).unwrap();
+        assert!(matches!(cli.command, Command::Trash(TrashOption::Empty)));
+
+        let cli = Cli::try_parse_from(["fetters", "trash", "restore", "4"]).unwrap();
+        assert!(matches!(
+            cli.command,
+            Command::Trash(TrashOption::Restore { job_id: Some(4) })
+        ));
+
+        let cli = Cli::try_parse_from(["fetters", "trash", "restore"]).unwrap();
+        assert!(matches!(
+            cli.command,
+            Command::Trash(TrashOption::Restore { job_id: None })
+        ));
+    }
+
+    #[test]
+    fn test_parse_edit_command() {
+        let cli = Cli::try_parse_from(["fetters", "edit", "12"]).unwrap();
+        assert!(matches!(cli.command, Command::Edit { job_id: 12 }));
+
+        assert!(Cli::try_parse_from(["fetters", "edit"]).is_err());
+    }
+
+    #[test]
+    fn test_parse_undo_and_log_commands() {
+        let cli = Cli::try_parse_from(["fetters", "undo"]).unwrap();
+        assert!(matches!(cli.command, Command::Undo { steps: 1 }));
+
+        let cli = Cli::try_parse_from(["fetters", "undo", "--steps", "3"]).unwrap();
+        assert!(matches!(cli.command, Command::Undo { steps: 3 }));
+
+        let cli = Cli::try_parse_from(["fetters", "log"]).unwrap();
+        assert!(matches!(cli.command, Command::Log { limit: 20 }));
+
+        let cli = Cli::try_parse_from(["fetters", "log", "--limit", "5"]).unwrap();
+        assert!(matches!(cli.command, Command::Log { limit: 5 }));
+    }
+
+    #[test]
+    fn test_parse_search_command() {
+        let cli = Cli::try_parse_from(["fetters", "search", "phone screen"]).unwrap();
+        match cli.command {
+            Command::Search(args) => {
+                assert_eq!(args.query, "phone screen");
+                assert!(args.sprint.is_none());
+                assert_eq!(args.limit, 25);
+            }
+            _ => panic!("Expected Search command"),
+        }
+
+        let cli = Cli::try_parse_from([
+            "fetters", "search", "recruit*", "--sprint", "2026", "--limit", "5",
+        ])
+        .unwrap();
+        match cli.command {
+            Command::Search(args) => {
+                assert_eq!(args.sprint.as_deref(), Some("2026"));
+                assert_eq!(args.limit, 5);
+            }
+            _ => panic!("Expected Search command"),
+        }
+
+        assert!(Cli::try_parse_from(["fetters", "search"]).is_err());
+    }
+
+    #[test]
+    fn test_parse_query_range_sort_and_limit() {
+        let cli = Cli::try_parse_from([
+            "fetters",
+            "list",
+            "--id",
+            "4",
+            "--since",
+            "2w",
+            "--until",
+            "2026-01-31",
+            "--all-sprints",
+            "--sort",
+            "company:desc",
+            "--limit",
+            "10",
+        ])
+        .unwrap();
+        match cli.command {
+            Command::List(ListArgs { query_args: args, .. }) => {
+                assert_eq!(args.id, Some(4));
+                assert_eq!(args.since, Some(DateBound::DaysAgo(14)));
+                assert_eq!(
+                    args.until,
+                    Some(DateBound::Absolute(
+                        NaiveDate::from_ymd_opt(2026, 1, 31).unwrap()
+                    ))
+                );
+                assert!(args.all_sprints);
+                assert_eq!(
+                    args.sort,
+                    Some(JobSort {
+                        field: SortField::Company,
+                        descending: true
+                    })
+                );
+                assert_eq!(args.limit, Some(10));
+            }
+            _ => panic!("Expected List command"),
+        }
+
+        assert!(
+            Cli::try_parse_from(["fetters", "list", "--all-sprints", "--sprint", "2026"]).is_err()
+        );
+        assert!(Cli::try_parse_from(["fetters", "list", "--since", "last week"]).is_err());
+        assert!(Cli::try_parse_from(["fetters", "list", "--sort", "salary"]).is_err());
+    }
+
+    #[test]
+    fn test_date_bound_resolve_and_display() {
+        let today = NaiveDate::from_ymd_opt(2026, 3, 10).unwrap();
+
+        let bound: DateBound = "7d".parse().unwrap();
+        assert_eq!(bound.resolve(today), NaiveDate::from_ymd_opt(2026, 3, 3).unwrap());
+        assert_eq!(bound.to_string(), "7d");
+
+        let bound: DateBound = "2026-01-05".parse().unwrap();
+        assert_eq!(bound.resolve(today), NaiveDate::from_ymd_opt(2026, 1, 5).unwrap());
+        assert_eq!(bound.to_string(), "2026-01-05");
+
+        assert!("xd".parse::<DateBound>().is_err());
+        assert!("2026-13-01".parse::<DateBound>().is_err());
+    }
+
+    #[test]
+    fn test_parse_job_sort() {
+        assert_eq!(
+            "created".parse::<JobSort>().unwrap(),
+            JobSort {
+                field: SortField::Created,
+                descending: false
+            }
+        );
+        assert_eq!(
+            "Status:DESC".parse::<JobSort>().unwrap().to_string(),
+            "status:desc"
+        );
+        assert!("created:sideways".parse::<JobSort>().is_err());
+    }
+
+    #[test]
+    fn test_sprint_label() {
+        let args = QueryArgs::default();
+        assert_eq!(args.sprint_label("2026-01-01"), "2026-01-01");
+
+        let args = QueryArgs {
+            id: Some(3),
+            ..Default::default()
+        };
+        assert_eq!(args.sprint_label("2026-01-01"), "ALL");
+
+        let args = QueryArgs {
+            id: Some(3),
+            sprint: Some("2025".to_string()),
+            ..Default::default()
+        };
+        assert_eq!(args.sprint_label("2026-01-01"), "2025");
+    }
+
+    #[test]
+    fn test_parse_where_clause() {
+        let cli = Cli::try_parse_from([
+            "fetters",
+            "list",
+            "--where",
+            "status:(REJECTED|GHOSTED) AND title:backend",
+        ])
+        .unwrap();
+        match cli.command {
+            Command::List(ListArgs { query_args: args, .. }) => {
+                let where_clause = args.where_clause.unwrap();
+                assert_eq!(
+                    where_clause.to_string(),
+                    "status:(REJECTED|GHOSTED) AND title:backend"
+                );
+            }
+            _ => panic!("Expected List command"),
+        }
+
+        assert!(Cli::try_parse_from(["fetters", "list", "--where", "salary>100"]).is_err());
+    }
+
+    #[test]
+    fn test_where_clause_on_sprint_searches_all_sprints() {
+        let args = QueryArgs {
+            where_clause: Some("sprint:2025".parse().unwrap()),
+            ..Default::default()
+        };
+        assert!(args.searches_all_sprints());
+
+        let args = QueryArgs {
+            where_clause: Some("status:PENDING".parse().unwrap()),
+            ..Default::default()
+        };
+        assert!(!args.searches_all_sprints());
+    }
+
+    #[test]
+    fn test_parse_list_with_view() {
+        let cli = Cli::try_parse_from([
+            "fetters", "list", "@stale", "--status", "PENDING", "--format", "json",
+        ])
+        .unwrap();
+        match cli.command {
+            Command::List(args) => {
+                assert_eq!(args.view.as_deref(), Some("stale"));
+                assert_eq!(args.format, Some(OutputFormat::Json));
+                assert_eq!(args.query_args.status.as_deref(), Some("PENDING"));
+            }
+            _ => panic!("Expected List command"),
+        }
+
+        let cli = Cli::try_parse_from(["fetters", "list"]).unwrap();
+        match cli.command {
+            Command::List(args) => {
+                assert!(args.view.is_none());
+                assert!(args.format.is_none());
+            }
+            _ => panic!("Expected List command"),
+        }
+
+        assert!(Cli::try_parse_from(["fetters", "list", "stale"]).is_err());
+        assert!(Cli::try_parse_from(["fetters", "list", "@"]).is_err());
+    }
+
+    #[test]
+    fn test_parse_view_commands() {
+        let cli = Cli::try_parse_from([
+            "fetters",
+            "view",
+            "save",
+            "@stale",
+            "--status",
+            "PENDING",
+            "--until",
+            "2w",
+            "--sort",
+            "created",
+        ])
+        .unwrap();
+        match cli.command {
+            Command::View(ViewOption::Save {
+                name,
+                format,
+                columns,
+                query_args,
+            }) => {
+                assert_eq!(name, "stale");
+                assert_eq!(format, OutputFormat::Text);
+                assert!(columns.is_empty());
+                assert_eq!(query_args.status.as_deref(), Some("PENDING"));
+                assert_eq!(query_args.until, Some(DateBound::DaysAgo(14)));
+            }
+            _ => panic!("Expected View Save command"),
+        }
+
+        let cli = Cli::try_parse_from(["fetters", "view", "run", "stale"]).unwrap();
+        match cli.command {
+            Command::View(ViewOption::Run { name }) => assert_eq!(name, "stale"),
+            _ => panic!("Expected View Run command"),
+        }
+
+        assert!(Cli::try_parse_from(["fetters", "view", "save", "two words"]).is_err());
+    }
+
+    #[test]
+    fn test_query_args_with_overrides() {
+        let saved = QueryArgs {
+            status: Some("PENDING".to_string()),
+            sprint: Some("2025-01".to_string()),
+            tags: vec!["remote".to_string()],
+            sort: Some("created:desc".parse().unwrap()),
+            ..Default::default()
+        };
+        let overrides = QueryArgs {
+            status: Some("REJECTED".to_string()),
+            tags: vec!["onsite".to_string()],
+            all_sprints: true,
+            ..Default::default()
+        };
+
+        let merged = saved.clone().with_overrides(overrides);
+        assert_eq!(merged.status.as_deref(), Some("REJECTED"));
+        assert_eq!(merged.tags, vec!["remote", "onsite"]);
+        assert_eq!(merged.sort, saved.sort);
+        assert!(merged.sprint.is_none());
+        assert!(merged.all_sprints);
+
+        assert_eq!(saved.clone().with_overrides(QueryArgs::default()), saved);
+    }
+
+    #[test]
+    fn test_query_args_with_overrides_combines_where_clauses() {
+        let saved = QueryArgs {
+            where_clause: Some("status=PENDING".parse().unwrap()),
+            ..Default::default()
+        };
+        let overrides = QueryArgs {
+            where_clause: Some("tag:remote OR tag:hybrid".parse().unwrap()),
+            ..Default::default()
+        };
+
+        let merged = saved.clone().with_overrides(overrides.clone());
+        assert_eq!(
+            merged.where_clause,
+            Some("(status=PENDING) AND (tag:remote OR tag:hybrid)".parse().unwrap())
+        );
+
+        let merged = QueryArgs::default().with_overrides(overrides.clone());
+        assert_eq!(merged.where_clause, overrides.where_clause);
+    }
+
+    #[test]
+    fn test_parse_list_with_columns() {
+        let cli = Cli::try_parse_from([
+            "fetters",
+            "list",
+            "--columns",
+            "id, Company,Salary,last_activity,age",
+        ])
+        .unwrap();
+        match cli.command {
+            Command::List(args) => assert_eq!(
+                args.columns,
+                vec![
+                    JobColumn::Id,
+                    JobColumn::Company,
+                    JobColumn::Field("Salary".to_string()),
+                    JobColumn::Activity,
+                    JobColumn::Age,
+                ]
+            ),
+            _ => panic!("Expected List command"),
+        }
+
+        assert!(Cli::try_parse_from(["fetters", "list", "--columns", "id,,status"]).is_err());
+        assert_eq!(JobColumn::Activity.to_string(), "activity");
+        assert_eq!(JobColumn::Field("Salary".to_string()).to_string(), "Salary");
     }
 }
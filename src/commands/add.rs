@@ -5,32 +5,66 @@ use diesel::sqlite::SqliteConnection;
 use inquire::{Confirm, Select, Text};
 use owo_colors::OwoColorize;
 
+use crate::cli::AddArgs;
+use crate::utils::posting::{JobPosting, read_job_posting};
 use crate::utils::prompt::get_inquire_config;
 use crate::{errors::FettersError, utils::display::display_single_job};
 use crate::{
     models::{
+        description::NewJobDescription,
+        document::DocumentKind,
         job::{NewJob, TabledJob},
+        note::NewJobNote,
+        source::NewSource,
         sprint::QueriedSprint,
         status::QueriedStatus,
         title::NewTitle,
     },
-    utils::titles::create_or_use_title,
+    utils::{
+        custom_fields::input_custom_field_values,
+        description::input_description,
+        documents::select_document,
+        sources::{SourceType, create_or_use_source},
+        titles::create_or_use_title,
+    },
 };
 use crate::{
-    repositories::{job::JobRepository, statuses::StatusRepository, title::TitleRepository},
+    repositories::{
+        custom_field::CustomFieldRepository, description::DescriptionRepository,
+        job::JobRepository, note::NoteRepository, source::SourceRepository,
+        statuses::StatusRepository, title::TitleRepository,
+    },
     utils::titles::TitleType,
 };
 
 /// Run the inquire menu to track a new job application.
 pub fn add_job(
     connection: &mut SqliteConnection,
-    company_name: &str,
+    add_args: &AddArgs,
     current_sprint: &QueriedSprint,
 ) -> Result<(), FettersError> {
-    let title_type = create_or_use_title(connection)?;
+    let posting = match &add_args.from_html {
+        Some(path) => read_job_posting(path)?,
+        None => JobPosting::default(),
+    };
+
+    let company_name = match &add_args.company {
+        Some(company_name) => company_name.to_string(),
+        None => input_company_name(posting.company.as_deref())?,
+    };
+    let title_type = create_or_use_title(connection, posting.title.as_deref())?;
     let status = select_status(connection)?;
-    let link = input_link()?;
-    let notes = input_notes()?;
+    let source_type = create_or_use_source(connection)?;
+    let referrer = input_referrer()?;
+    let resume = select_document(connection, DocumentKind::Resume)?;
+    let cover_letter = select_document(connection, DocumentKind::CoverLetter)?;
+    let link = input_link(posting.url.as_deref())?;
+    let notes = input_notes(posting.summary_notes().as_deref())?;
+    let custom_field_values = input_custom_field_values(connection)?;
+    let description = input_description(
+        add_args.description_file.as_deref(),
+        posting.description.as_deref(),
+    )?;
 
     let created = Local::now().format("%Y-%m-%d %H:%M:%S").to_string();
 
@@ -38,15 +72,22 @@ pub fn add_job(
         // NOTE: The ID is set to an arbitrary value to satisfy struct requirements.
         id: 0,
         created: created.clone(),
-        company_name: company_name.to_string(),
+        company_name: company_name.clone(),
         title: Some(match title_type {
             TitleType::NewTitle(ref title) => title.to_string(),
             TitleType::QueriedTitle(ref queried_title) => queried_title.name.to_string(),
         }),
         status: Some(status.name),
+        source: source_type.as_ref().map(|source| source.name().to_string()),
         stages: None,
+        tags: None,
         link: link.clone(),
         notes: notes.clone(),
+        last_activity: created.clone(),
+        custom_fields: custom_field_values
+            .iter()
+            .map(|(field, value)| (field.name.clone(), value.clone()))
+            .collect(),
     };
 
     display_single_job(&tabled_job);
@@ -65,18 +106,52 @@ pub fn add_job(
                     }
                     TitleType::QueriedTitle(queried_title) => queried_title.id,
                 };
+                let source_id = match source_type {
+                    Some(SourceType::NewSource(new_source)) => {
+                        let mut source_repo = SourceRepository { connection };
+                        Some(source_repo.add_source(NewSource { name: &new_source })?.id)
+                    }
+                    Some(SourceType::QueriedSource(queried_source)) => Some(queried_source.id),
+                    None => None,
+                };
                 let new_job = NewJob {
-                    company_name,
+                    company_name: &company_name,
                     created,
                     title_id,
                     status_id: status.id,
                     link: link.as_deref(),
-                    notes: notes.as_deref(),
                     sprint_id: current_sprint.id,
+                    source_id,
+                    referrer: referrer.as_deref(),
+                    resume_id: resume.as_ref().map(|resume| resume.id),
+                    cover_letter_id: cover_letter.as_ref().map(|cover_letter| cover_letter.id),
                 };
 
                 let mut job_repo = JobRepository { connection };
-                job_repo.add_job(new_job)?;
+                let queried_job = job_repo.add_job(new_job)?;
+
+                let mut field_repo = CustomFieldRepository { connection };
+                for (field, value) in &custom_field_values {
+                    field_repo.set_value(queried_job.id, field.id, value)?;
+                }
+
+                if let Some(content) = notes.as_deref().map(str::trim).filter(|n| !n.is_empty()) {
+                    let mut note_repo = NoteRepository { connection };
+                    note_repo.add_note(NewJobNote {
+                        job_id: queried_job.id,
+                        content,
+                        created: &queried_job.created,
+                    })?;
+                }
+
+                if let Some(content) = &description {
+                    let mut description_repo = DescriptionRepository { connection };
+                    description_repo.set_description(NewJobDescription {
+                        job_id: queried_job.id,
+                        content,
+                        captured: &queried_job.created,
+                    })?;
+                }
 
                 println!(
                     "{}",
@@ -116,19 +191,51 @@ fn select_status(connection: &mut SqliteConnection) -> Result<QueriedStatus, Fet
     }
 }
 
+/// Input an optional contact who referred you for the job application.
+fn input_referrer() -> Result<Option<String>, FettersError> {
+    Ok(
+        Text::new("[OPTIONAL] Enter the name of the person who referred you:")
+            .with_render_config(get_inquire_config())
+            .prompt_skippable()?
+            .filter(|referrer| !referrer.trim().is_empty()),
+    )
+}
+
+/// Input the company name. This is only prompted when the company name was not provided as an
+/// argument, in which case it is prefilled with the hiring organization from the job posting.
+fn input_company_name(suggested_name: Option<&str>) -> Result<String, FettersError> {
+    loop {
+        match Text::new("Enter the company name:")
+            .with_initial_value(suggested_name.unwrap_or(""))
+            .with_render_config(get_inquire_config())
+            .prompt_skippable()?
+        {
+            Some(name) if !name.trim().is_empty() => return Ok(name.trim().to_string()),
+            Some(_) => println!("{}", "Please enter a company name!".red().bold()),
+            None => {
+                return Err(FettersError::UnknownError(
+                    "No company name was provided.".to_string(),
+                ));
+            }
+        }
+    }
+}
+
 /// Input an optional link to the job application.
-fn input_link() -> Result<Option<String>, FettersError> {
+fn input_link(suggested_link: Option<&str>) -> Result<Option<String>, FettersError> {
     Ok(
         Text::new("[OPTIONAL] Enter a link to this job application:")
+            .with_initial_value(suggested_link.unwrap_or(""))
             .with_render_config(get_inquire_config())
             .prompt_skippable()?,
     )
 }
 
-/// Input optional notes for the job application.
-fn input_notes() -> Result<Option<String>, FettersError> {
+/// Input an optional first note for the job application.
+fn input_notes(suggested_notes: Option<&str>) -> Result<Option<String>, FettersError> {
     Ok(
-        Text::new("[OPTIONAL] Enter any notes for this job application:")
+        Text::new("[OPTIONAL] Enter a note for this job application:")
+            .with_initial_value(suggested_notes.unwrap_or(""))
             .with_render_config(get_inquire_config())
             .prompt_skippable()?,
     )
@@ -0,0 +1,48 @@
+//! Contains a function called by the CLI when attaching a file to a job application.
+
+use std::path::Path;
+
+use chrono::Local;
+use diesel::SqliteConnection;
+use owo_colors::OwoColorize;
+
+use crate::{
+    errors::FettersError,
+    models::attachment::NewAttachment,
+    repositories::{attachment::AttachmentRepository, job::JobRepository},
+    utils::attachments::{copy_attachment, get_attachment_dir},
+};
+
+/// Attach a file to a job application. The file is copied into the fetters data directory so it
+/// is still available if the original is moved or deleted.
+pub fn attach_file(
+    connection: &mut SqliteConnection,
+    job_id: i32,
+    file_path: &str,
+) -> Result<(), FettersError> {
+    let mut job_repo = JobRepository { connection };
+    let job = job_repo.get_tabled_job(job_id)?;
+
+    let copy = copy_attachment(Path::new(file_path), &get_attachment_dir(job_id)?)?;
+    let file_name = copy
+        .file_name()
+        .map(|name| name.to_string_lossy().to_string())
+        .unwrap_or_default();
+
+    let mut attachment_repo = AttachmentRepository { connection };
+    attachment_repo.add_attachment(NewAttachment {
+        job_id,
+        file_name: &file_name,
+        file_path: &copy.to_string_lossy(),
+        added: &Local::now().format("%Y-%m-%d %H:%M:%S").to_string(),
+    })?;
+
+    println!(
+        "{}",
+        format!("\nAttached {file_name} to {}!\n", job.company_name)
+            .green()
+            .bold()
+    );
+
+    Ok(())
+}
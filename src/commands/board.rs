@@ -0,0 +1,43 @@
+//! Contains a function called by the CLI when displaying the job board.
+
+use diesel::SqliteConnection;
+
+use crate::{
+    cli::QueryArgs,
+    errors::FettersError,
+    models::sprint::QueriedSprint,
+    repositories::{job::JobRepository, statuses::StatusRepository},
+    tui::{board::Board, run_screen},
+    utils::display::display_board,
+};
+
+/// Display the job applications in the current sprint as a board with a column per status. The
+/// interactive board allows moving job applications between columns to change their status.
+pub fn show_board(
+    connection: &mut SqliteConnection,
+    interactive: bool,
+    current_sprint: &QueriedSprint,
+) -> Result<(), FettersError> {
+    if interactive {
+        let mut board = Board::new(connection, current_sprint)?;
+        return run_screen(connection, &mut board);
+    }
+
+    let mut status_repo = StatusRepository { connection };
+    let statuses = status_repo
+        .get_all_statuses()?
+        .into_iter()
+        .map(|status| status.name)
+        .collect::<Vec<String>>();
+
+    let mut job_repo = JobRepository { connection };
+    let all_jobs = job_repo.list_jobs(&QueryArgs::default(), current_sprint)?;
+
+    if all_jobs.is_empty() {
+        return Err(FettersError::NoJobsAvailable(current_sprint.name.clone()));
+    }
+
+    display_board(&statuses, &all_jobs, &current_sprint.name);
+
+    Ok(())
+}
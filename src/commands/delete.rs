@@ -1,5 +1,6 @@
 //! Contains a function called by the CLI when deleting a job.
 
+use chrono::Local;
 use diesel::SqliteConnection;
 use inquire::{Confirm, Select};
 use owo_colors::OwoColorize;
@@ -12,36 +13,26 @@ use crate::{
     utils::{display::display_jobs, prompt::get_inquire_config},
 };
 
-/// Delete a tracked job application.
+/// Move a tracked job application to the trash. It can be restored with `fetters trash restore`
+/// until the trash is emptied or purged.
 pub fn delete_job(
     connection: &mut SqliteConnection,
     query_args: &mut QueryArgs,
     current_sprint: &QueriedSprint,
 ) -> Result<(), FettersError> {
-    let default_sprint = Some(current_sprint.name.clone());
-
-    // Search the default sprint if no sprint filter was specified.
-    if query_args.sprint.is_none() {
-        query_args.sprint = default_sprint;
-    }
-
     let mut job_repo = JobRepository { connection };
     let matched_jobs = job_repo.list_jobs(query_args, current_sprint)?;
 
     if matched_jobs.is_empty() {
         return Err(FettersError::NoJobsAvailable(
-            query_args
-                .sprint
-                .clone()
-                .as_ref()
-                .unwrap_or(&current_sprint.name.clone())
-                .to_string(),
+            query_args.sprint_label(&current_sprint.name),
         ));
     }
 
     display_jobs(
         &matched_jobs,
-        query_args.sprint.as_ref().unwrap_or(&current_sprint.name),
+        &query_args.sprint_label(&current_sprint.name),
+        &[],
     );
 
     if let Some(job) = Select::new("Select the job you want to delete:", matched_jobs)
@@ -55,13 +46,19 @@ pub fn delete_job(
         {
             Some(true) => {
                 let mut job_repo = JobRepository { connection };
-                job_repo.delete_job(job.id)?;
+                job_repo.trash_job(
+                    job.id,
+                    &Local::now().format("%Y-%m-%d %H:%M:%S").to_string(),
+                )?;
 
                 println!(
                     "{}",
-                    format!("\nDeleted entry for sprint [{}]!\n", current_sprint.name)
-                        .green()
-                        .bold()
+                    format!(
+                        "\nMoved {} to the trash! Run `fetters trash restore {}` to restore it.\n",
+                        job.company_name, job.id
+                    )
+                    .green()
+                    .bold()
                 );
 
                 return Ok(());
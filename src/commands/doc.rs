@@ -0,0 +1,152 @@
+//! Contains functions called by the CLI when managing resume and cover letter versions.
+
+use std::path::Path;
+
+use chrono::Local;
+use diesel::SqliteConnection;
+use inquire::{Select, Text};
+use owo_colors::OwoColorize;
+use strum::IntoEnumIterator;
+
+use crate::{
+    errors::FettersError,
+    models::document::{DocumentKind, NewDocument},
+    repositories::document::DocumentRepository,
+    utils::{display::display_documents, documents::compute_checksum, prompt::get_inquire_config},
+};
+
+/// Add a new resume or cover letter version.
+pub fn add_document(
+    connection: &mut SqliteConnection,
+    file_path: &str,
+) -> Result<(), FettersError> {
+    let absolute_path = Path::new(file_path).canonicalize()?;
+    let absolute_path = absolute_path.to_string_lossy().to_string();
+    let checksum = compute_checksum(&absolute_path)?;
+
+    let mut document_repo = DocumentRepository { connection };
+    if let Some(existing_document) = document_repo.get_document_by_checksum(&checksum)? {
+        println!(
+            "{}",
+            format!(
+                "This file has already been added as {} [{}]!",
+                existing_document.label, existing_document.kind
+            )
+            .yellow()
+            .bold()
+        );
+        return Ok(());
+    }
+
+    let kind = match Select::new(
+        "Select the kind of document:",
+        DocumentKind::iter().collect(),
+    )
+    .with_render_config(get_inquire_config())
+    .prompt_skippable()?
+    {
+        Some(kind) => kind,
+        None => return Ok(()),
+    };
+
+    let default_label = Path::new(&absolute_path)
+        .file_stem()
+        .map(|stem| stem.to_string_lossy().to_string())
+        .unwrap_or_default();
+    let label = loop {
+        match Text::new("Enter a label for this version:")
+            .with_initial_value(&default_label)
+            .with_render_config(get_inquire_config())
+            .prompt_skippable()?
+        {
+            Some(label) if !label.trim().is_empty() => break label.trim().to_string(),
+            Some(_) => println!("{}", "Please enter a label!".red().bold()),
+            None => return Ok(()),
+        }
+    };
+
+    let queried_document = document_repo.add_document(NewDocument {
+        kind: &kind.to_string(),
+        label: &label,
+        file_path: &absolute_path,
+        checksum: &checksum,
+        created: &Local::now().format("%Y-%m-%d %H:%M:%S").to_string(),
+    })?;
+
+    println!(
+        "{}",
+        format!(
+            "\nAdded {} version {}!\n",
+            queried_document.kind.to_lowercase(),
+            queried_document.label
+        )
+        .green()
+        .bold()
+    );
+
+    Ok(())
+}
+
+/// Display all resume and cover letter versions.
+pub fn list_documents(connection: &mut SqliteConnection) -> Result<(), FettersError> {
+    let mut document_repo = DocumentRepository { connection };
+    let all_documents = document_repo.get_all_documents(None)?;
+
+    if all_documents.is_empty() {
+        println!(
+            "{}",
+            "There are currently no resumes or cover letters!"
+                .yellow()
+                .bold()
+        );
+        return Ok(());
+    }
+
+    display_documents(&all_documents);
+
+    Ok(())
+}
+
+/// Open a resume or cover letter version with the default application. A warning is shown if the
+/// file has changed since it was added.
+pub fn open_document(connection: &mut SqliteConnection) -> Result<(), FettersError> {
+    let mut document_repo = DocumentRepository { connection };
+    let all_documents = document_repo.get_all_documents(None)?;
+
+    if all_documents.is_empty() {
+        println!(
+            "{}",
+            "There are currently no resumes or cover letters!"
+                .yellow()
+                .bold()
+        );
+        return Ok(());
+    }
+
+    display_documents(&all_documents);
+
+    if let Some(document) = Select::new("Select the document you want to open:", all_documents)
+        .with_render_config(get_inquire_config())
+        .prompt_skippable()?
+    {
+        if !Path::new(&document.file_path).is_file() {
+            return Err(FettersError::DocumentNotFound(document.file_path));
+        }
+
+        if compute_checksum(&document.file_path)? != document.checksum {
+            println!(
+                "{}",
+                format!(
+                    "{} has been modified since it was added. Consider adding it as a new version.",
+                    document.file_path
+                )
+                .yellow()
+                .bold()
+            );
+        }
+
+        open::that(&document.file_path)?;
+    }
+
+    Ok(())
+}
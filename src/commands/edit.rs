@@ -0,0 +1,402 @@
+//! Contains a function called by the CLI when editing a job application in `$EDITOR`.
+
+use std::collections::HashMap;
+
+use chrono::Local;
+use diesel::{Connection, SqliteConnection};
+use inquire::Confirm;
+use owo_colors::OwoColorize;
+
+use crate::{
+    errors::FettersError,
+    models::{
+        job::JobUpdate,
+        note::NewJobNote,
+        stage::{InterviewStageUpdate, NewInterviewStage},
+        title::NewTitle,
+    },
+    repositories::{
+        job::JobRepository, note::NoteRepository, sprint::SprintRepository, stage::StageRepository,
+        statuses::StatusRepository, title::TitleRepository,
+    },
+    utils::{
+        editor::{EditableJob, diff_jobs, parse_toml_document, to_toml_document},
+        prompt::get_inquire_config,
+    },
+};
+
+/// Edit a job application's attributes, notes, and interview stages as a TOML document in
+/// `$EDITOR`. The changes are previewed before they are applied in a single transaction.
+pub fn edit_job(connection: &mut SqliteConnection, job_id: i32) -> Result<(), FettersError> {
+    let mut job_repo = JobRepository { connection };
+    let job = job_repo.get_tabled_job(job_id)?;
+    let details = job_repo.get_job_details(&job)?;
+
+    let mut status_repo = StatusRepository { connection };
+    let statuses = status_repo.get_all_statuses()?;
+    let status_names: Vec<String> = statuses.iter().map(|status| status.name.clone()).collect();
+
+    let mut sprint_repo = SprintRepository { connection };
+    let sprints = sprint_repo.get_all_sprints()?;
+    let sprint_names: Vec<String> = sprints.iter().map(|sprint| sprint.name.clone()).collect();
+
+    let stage_ids: Vec<i32> = details.stages.iter().map(|stage| stage.id).collect();
+
+    let before = EditableJob::from_details(&details);
+    let mut document = to_toml_document(&before, &details.notes)?;
+
+    // Reopen the edited document until it is valid so that the user does not lose their changes.
+    let after = loop {
+        document = edit::edit_with_builder(&document, edit::Builder::new().suffix(".toml"))?;
+
+        let result = parse_toml_document(&document).and_then(|mut edited| {
+            edited.normalize_and_validate(&status_names, &sprint_names, &stage_ids)?;
+            Ok(edited)
+        });
+
+        match result {
+            Ok(edited) => break edited,
+            Err(error) => {
+                println!("{}", error.to_string().red().bold());
+
+                let edit_again = Confirm::new("Edit again?")
+                    .with_default(true)
+                    .with_render_config(get_inquire_config())
+                    .prompt_skippable()?;
+                if edit_again != Some(true) {
+                    println!("{}", "Discarded all changes.".yellow().bold());
+                    return Ok(());
+                }
+            }
+        }
+    };
+
+    let changes = diff_jobs(&before, &after);
+    if changes.is_empty() {
+        println!(
+            "{}",
+            "No changes were made to this job application."
+                .yellow()
+                .bold()
+        );
+        return Ok(());
+    }
+
+    println!("\n{}\n", format!("Changes to {}:", job.company_name).bold());
+    for change in &changes {
+        println!("  {change}");
+    }
+    println!();
+
+    let apply_changes = Confirm::new("Apply changes?")
+        .with_default(true)
+        .with_render_config(get_inquire_config())
+        .prompt_skippable()?;
+    if apply_changes != Some(true) {
+        println!("{}", "Discarded all changes.".yellow().bold());
+        return Ok(());
+    }
+
+    connection.transaction::<_, FettersError, _>(|connection| {
+        let title_id = if after.title != before.title {
+            let mut title_repo = TitleRepository { connection };
+            Some(title_repo.add_title(NewTitle { name: &after.title })?.id)
+        } else {
+            None
+        };
+        let status_id = (after.status != before.status)
+            .then(|| statuses.iter().find(|status| status.name == after.status))
+            .flatten()
+            .map(|status| status.id);
+        let sprint_id = (after.sprint != before.sprint)
+            .then(|| sprints.iter().find(|sprint| sprint.name == after.sprint))
+            .flatten()
+            .map(|sprint| sprint.id);
+
+        let job_update = JobUpdate {
+            company_name: (after.company_name != before.company_name)
+                .then_some(after.company_name.as_str()),
+            title_id,
+            status_id,
+            link: (after.link != before.link).then_some(after.link.as_str()),
+            sprint_id,
+            ..Default::default()
+        };
+
+        // Diesel refuses to run an update without any changes.
+        if job_update.company_name.is_some()
+            || job_update.title_id.is_some()
+            || job_update.status_id.is_some()
+            || job_update.link.is_some()
+            || job_update.sprint_id.is_some()
+        {
+            let mut job_repo = JobRepository { connection };
+            job_repo.update_job(job_id, job_update)?;
+        }
+
+        if !after.new_note.is_empty() {
+            let mut note_repo = NoteRepository { connection };
+            note_repo.add_note(NewJobNote {
+                job_id,
+                content: &after.new_note,
+                created: &Local::now().format("%Y-%m-%d %H:%M:%S").to_string(),
+            })?;
+        }
+
+        apply_stage_changes(connection, job_id, &before, &after)
+    })?;
+
+    println!(
+        "{}",
+        format!("\nUpdated {}!\n", after.company_name)
+            .green()
+            .bold()
+    );
+
+    Ok(())
+}
+
+/// Delete removed stages, update modified stages, add new stages, and number all stages by their
+/// position in the edited document.
+fn apply_stage_changes(
+    connection: &mut SqliteConnection,
+    job_id: i32,
+    before: &EditableJob,
+    after: &EditableJob,
+) -> Result<(), FettersError> {
+    let mut stage_repo = StageRepository { connection };
+
+    for stage in &before.stages {
+        if let Some(stage_id) = stage.id
+            && !after
+                .stages
+                .iter()
+                .any(|new_stage| new_stage.id == Some(stage_id))
+        {
+            stage_repo.delete_stage(stage_id)?;
+        }
+    }
+
+    // Only existing stages whose position changed are renumbered. Stage numbers are unique per
+    // job, so those stages are moved out of the way first.
+    let current_numbers: HashMap<i32, i32> = stage_repo
+        .get_stages_for_job(job_id)?
+        .into_iter()
+        .map(|stage| (stage.id, stage.stage_number))
+        .collect();
+    let moved_stage_ids: Vec<i32> = after
+        .stages
+        .iter()
+        .enumerate()
+        .filter_map(|(index, stage)| {
+            stage
+                .id
+                .filter(|stage_id| current_numbers.get(stage_id) != Some(&(index as i32 + 1)))
+        })
+        .collect();
+    for &stage_id in &moved_stage_ids {
+        stage_repo.set_stage_number(stage_id, -stage_id)?;
+    }
+
+    for (index, stage) in after.stages.iter().enumerate() {
+        let stage_number = (index + 1) as i32;
+
+        match stage.id {
+            Some(stage_id) => {
+                let old_stage = before
+                    .stages
+                    .iter()
+                    .find(|old_stage| old_stage.id == Some(stage_id));
+
+                if old_stage != Some(stage) {
+                    stage_repo.update_stage(
+                        stage_id,
+                        InterviewStageUpdate {
+                            name: Some(stage.name.clone()),
+                            status: Some(stage.status.clone()),
+                            scheduled_date: Some(stage.date.clone()),
+                            notes: Some(stage.notes.clone()),
+                        },
+                    )?;
+                }
+
+                if moved_stage_ids.contains(&stage_id) {
+                    stage_repo.set_stage_number(stage_id, stage_number)?;
+                }
+            }
+            None => {
+                stage_repo.add_stage(NewInterviewStage {
+                    job_id,
+                    stage_number,
+                    name: (!stage.name.is_empty()).then(|| stage.name.clone()),
+                    status: stage.status.clone(),
+                    scheduled_date: stage.date.clone(),
+                    notes: (!stage.notes.is_empty()).then(|| stage.notes.clone()),
+                    created: Local::now().format("%Y-%m-%d %H:%M:%S").to_string(),
+                })?;
+            }
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::{
+        models::{job::NewJob, operation::NewOperation, sprint::NewSprint},
+        repositories::operation::OperationRepository,
+        utils::editor::EditableStage,
+    };
+
+    fn setup_test_db() -> (SqliteConnection, i32) {
+        let mut connection =
+            SqliteConnection::establish(":memory:").expect("Failed to create in-memory database");
+        crate::utils::migrations::run_migrations(&mut connection)
+            .expect("Failed to run migrations");
+
+        let mut status_repo = StatusRepository {
+            connection: &mut connection,
+        };
+        status_repo
+            .seed_statuses()
+            .expect("Failed to seed statuses");
+        let status_id = status_repo.get_all_statuses().unwrap()[0].id;
+
+        let mut sprint_repo = SprintRepository {
+            connection: &mut connection,
+        };
+        let sprint = sprint_repo
+            .add_job_sprint(NewSprint {
+                name: "test-sprint",
+                start_date: "2025-01-01",
+                end_date: None,
+                num_jobs: &0,
+            })
+            .unwrap();
+
+        let mut title_repo = TitleRepository {
+            connection: &mut connection,
+        };
+        let title = title_repo.add_title(NewTitle { name: "SWE" }).unwrap();
+
+        let mut job_repo = JobRepository {
+            connection: &mut connection,
+        };
+        let job = job_repo
+            .add_job(NewJob {
+                company_name: "Initech",
+                created: "2025-01-15 10:00:00".to_string(),
+                title_id: title.id,
+                status_id,
+                link: None,
+                sprint_id: sprint.id,
+                source_id: None,
+                referrer: None,
+                resume_id: None,
+                cover_letter_id: None,
+            })
+            .unwrap();
+
+        (connection, job.id)
+    }
+
+    fn editable_stage(id: Option<i32>, name: &str) -> EditableStage {
+        EditableStage {
+            id,
+            name: name.to_string(),
+            status: "SCHEDULED".to_string(),
+            date: "2025/01/20".to_string(),
+            notes: String::new(),
+        }
+    }
+
+    fn editable_job(stages: Vec<EditableStage>) -> EditableJob {
+        EditableJob {
+            company_name: "Initech".to_string(),
+            title: "SWE".to_string(),
+            status: "PENDING".to_string(),
+            sprint: "test-sprint".to_string(),
+            link: String::new(),
+            new_note: String::new(),
+            stages,
+        }
+    }
+
+    #[test]
+    fn test_apply_stage_changes_only_renumbers_moved_stages() {
+        let (mut connection, job_id) = setup_test_db();
+
+        let mut stage_repo = StageRepository {
+            connection: &mut connection,
+        };
+        let stage_ids: Vec<i32> = ["Phone Screen", "Onsite", "Offer Call"]
+            .into_iter()
+            .enumerate()
+            .map(|(index, name)| {
+                stage_repo
+                    .add_stage(NewInterviewStage {
+                        job_id,
+                        stage_number: index as i32 + 1,
+                        name: Some(name.to_string()),
+                        status: "SCHEDULED".to_string(),
+                        scheduled_date: "2025/01/20".to_string(),
+                        notes: None,
+                        created: "2025-01-15 10:00:00".to_string(),
+                    })
+                    .unwrap()
+                    .id
+            })
+            .collect();
+
+        let before = editable_job(vec![
+            editable_stage(Some(stage_ids[0]), "Phone Screen"),
+            editable_stage(Some(stage_ids[1]), "Onsite"),
+            editable_stage(Some(stage_ids[2]), "Offer Call"),
+        ]);
+        let after = editable_job(vec![
+            editable_stage(Some(stage_ids[0]), "Phone Screen"),
+            editable_stage(Some(stage_ids[2]), "Offer Call"),
+            editable_stage(Some(stage_ids[1]), "Onsite"),
+            editable_stage(None, "Team Lunch"),
+        ]);
+
+        let mut operation_repo = OperationRepository {
+            connection: &mut connection,
+        };
+        let operation_id = operation_repo
+            .begin_operation(NewOperation {
+                performed: "2025-01-16 10:00:00",
+                command: "fetters edit",
+            })
+            .unwrap()
+            .id;
+
+        apply_stage_changes(&mut connection, job_id, &before, &after).unwrap();
+
+        let mut stage_repo = StageRepository {
+            connection: &mut connection,
+        };
+        let stages = stage_repo.get_stages_for_job(job_id).unwrap();
+        let names: Vec<Option<&str>> = stages.iter().map(|stage| stage.name.as_deref()).collect();
+        assert_eq!(
+            names,
+            vec![
+                Some("Phone Screen"),
+                Some("Offer Call"),
+                Some("Onsite"),
+                Some("Team Lunch")
+            ]
+        );
+
+        let mut operation_repo = OperationRepository {
+            connection: &mut connection,
+        };
+        let changes = operation_repo.get_changes(operation_id).unwrap();
+        assert!(!changes.iter().any(|change| {
+            change.table_name == "interview_stages" && change.row_id == stage_ids[0]
+        }));
+    }
+}
@@ -1,17 +1,30 @@
-//! Contains a function called by the CLI when exporting jobs from SQLite.
+//! Contains functions called by the CLI when exporting jobs from SQLite.
 
-use std::{env, path::Path};
+use std::{
+    env,
+    fs::{create_dir_all, read_to_string, write},
+    path::Path,
+};
 
 use chrono::Local;
 use diesel::SqliteConnection;
 use owo_colors::OwoColorize;
 
 use crate::{
-    cli::{ExportArgs, QueryArgs},
+    cli::{ExportArgs, MarkdownLayout, QueryArgs},
     errors::FettersError,
     models::sprint::QueriedSprint,
-    repositories::job::JobRepository,
-    utils::spreadsheet::{create_spreadsheet, write_jobs},
+    repositories::{
+        job::JobRepository, note::NoteRepository, sprint::SprintRepository,
+        stage::StageRepository, tag::TagRepository,
+    },
+    utils::{
+        markdown::{
+            MarkdownJob, find_exported_notes, job_note_filename, job_note_id, merge_user_content,
+            render_job_note, render_sprint_note, sprint_note_filename, sprint_note_id,
+        },
+        spreadsheet::{create_spreadsheet, write_jobs},
+    },
 };
 
 /// Export all jobs tracked for a given sprint.
@@ -20,33 +33,31 @@ pub fn export_jobs(
     export_args: &mut ExportArgs,
     current_sprint: &QueriedSprint,
 ) -> Result<(), FettersError> {
-    let target_sprint = if export_args.sprint.is_none() {
-        Some(current_sprint.name.clone())
-    } else {
-        export_args.sprint.clone()
-    };
-
-    let mut job_repo = JobRepository { connection };
+    if export_args.markdown {
+        return export_markdown(connection, export_args, current_sprint);
+    }
 
-    let query_args = QueryArgs {
-        sprint: target_sprint.clone(),
-        ..Default::default()
+    let target_sprint = match &export_args.sprint {
+        Some(sprint_name) => {
+            let mut sprint_repo = SprintRepository { connection };
+            sprint_repo
+                .get_all_sprints()?
+                .into_iter()
+                .find(|sprint| sprint.name == *sprint_name)
+                .ok_or(FettersError::UnknownSprint(sprint_name.clone()))?
+        }
+        None => current_sprint.clone(),
     };
 
-    let matched_jobs = job_repo.list_jobs(&query_args, current_sprint)?;
+    // Without a sprint filter, only the job applications in the given sprint are listed.
+    let mut job_repo = JobRepository { connection };
+    let matched_jobs = job_repo.list_jobs(&QueryArgs::default(), &target_sprint)?;
 
     if matched_jobs.is_empty() {
-        return Err(FettersError::NoJobsAvailable(
-            query_args
-                .sprint
-                .clone()
-                .as_ref()
-                .unwrap_or(&current_sprint.name.clone())
-                .to_string(),
-        ));
+        return Err(FettersError::NoJobsAvailable(target_sprint.name));
     }
 
-    let (mut spreadsheet, sheet_name) = create_spreadsheet(&target_sprint)?;
+    let (mut spreadsheet, sheet_name) = create_spreadsheet(&Some(target_sprint.name.clone()))?;
     write_jobs(&mut spreadsheet, &sheet_name, matched_jobs);
 
     let filename = if let Some(filename) = export_args.filename.clone() {
@@ -59,7 +70,7 @@ pub fn export_jobs(
         format!(
             "{}-fetters-export-sprint-{}.xlsx",
             Local::now().format("%Y-%m-%d"),
-            target_sprint.clone().unwrap_or("unknown".to_string())
+            target_sprint.name
         )
     };
 
@@ -77,7 +88,7 @@ pub fn export_jobs(
         "{}",
         format!(
             "Successfully exported all jobs for sprint {} to path: {}!",
-            target_sprint.unwrap_or("unknown".to_string()),
+            target_sprint.name,
             export_path.to_string_lossy()
         )
         .green()
@@ -86,3 +97,238 @@ pub fn export_jobs(
 
     Ok(())
 }
+
+/// Export job applications as Markdown notes, either one per job application or one per sprint.
+/// Notes that were exported to the directory before are overwritten in place, keeping anything
+/// written below their marker line.
+pub fn export_markdown(
+    connection: &mut SqliteConnection,
+    export_args: &ExportArgs,
+    current_sprint: &QueriedSprint,
+) -> Result<(), FettersError> {
+    let mut sprint_repo = SprintRepository { connection };
+    let all_sprints = sprint_repo.get_all_sprints()?;
+
+    let target_sprints = if export_args.all_sprints {
+        all_sprints
+    } else {
+        let sprint_name = export_args
+            .sprint
+            .clone()
+            .unwrap_or(current_sprint.name.clone());
+        vec![
+            all_sprints
+                .into_iter()
+                .find(|sprint| sprint.name == sprint_name)
+                .ok_or(FettersError::UnknownSprint(sprint_name))?,
+        ]
+    };
+
+    let export_dir = Path::new(
+        &export_args
+            .directory
+            .clone()
+            .unwrap_or(env::current_dir()?.to_string_lossy().to_string()),
+    )
+    .to_path_buf();
+    create_dir_all(&export_dir)?;
+    let exported_notes = find_exported_notes(&export_dir)?;
+
+    let mut num_jobs = 0;
+    let mut num_files = 0;
+    for sprint in &target_sprints {
+        let markdown_jobs = get_markdown_jobs(connection, sprint)?;
+        if markdown_jobs.is_empty() {
+            if export_args.all_sprints {
+                continue;
+            }
+
+            return Err(FettersError::NoJobsAvailable(sprint.name.clone()));
+        }
+        num_jobs += markdown_jobs.len();
+
+        let notes = match export_args.per {
+            MarkdownLayout::Job => markdown_jobs
+                .iter()
+                .map(|markdown_job| {
+                    (
+                        job_note_id(markdown_job.job.id),
+                        job_note_filename(&markdown_job.job),
+                        render_job_note(markdown_job),
+                    )
+                })
+                .collect(),
+            MarkdownLayout::Sprint => vec![(
+                sprint_note_id(&sprint.name),
+                sprint_note_filename(&sprint.name),
+                render_sprint_note(sprint, &markdown_jobs),
+            )],
+        };
+
+        for (id, filename, content) in notes {
+            let path = exported_notes
+                .get(&id)
+                .cloned()
+                .unwrap_or(export_dir.join(filename));
+            let existing = read_to_string(&path).ok();
+            write(&path, merge_user_content(&content, existing.as_deref()))?;
+            num_files += 1;
+        }
+    }
+
+    println!(
+        "{}",
+        format!(
+            "Successfully exported {num_jobs} job(s) to {num_files} Markdown note(s) in path: {}!",
+            export_dir.to_string_lossy()
+        )
+        .green()
+        .bold()
+    );
+
+    Ok(())
+}
+
+/// Get the job applications in a sprint along with their tags, notes, and interview stages.
+fn get_markdown_jobs(
+    connection: &mut SqliteConnection,
+    sprint: &QueriedSprint,
+) -> Result<Vec<MarkdownJob>, FettersError> {
+    let mut job_repo = JobRepository { connection };
+    // Without a sprint filter, only the job applications in the given sprint are listed. The
+    // sprint filter matches partial names, which would include other sprints, ie. `fall-2` for
+    // `fall`.
+    let jobs = job_repo.list_jobs(&QueryArgs::default(), sprint)?;
+
+    let mut markdown_jobs = Vec::new();
+    for job in jobs {
+        let tags = TagRepository { connection }
+            .get_tags_for_job(job.id)?
+            .into_iter()
+            .map(|tag| tag.name)
+            .collect();
+        let notes = NoteRepository { connection }.get_notes_for_job(job.id)?;
+        let stages = StageRepository { connection }.get_stages_for_job(job.id)?;
+
+        markdown_jobs.push(MarkdownJob {
+            job,
+            sprint: sprint.name.clone(),
+            tags,
+            notes,
+            stages,
+        });
+    }
+
+    Ok(markdown_jobs)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use clap::Parser;
+    use diesel::Connection;
+
+    use crate::models::{job::NewJob, sprint::NewSprint, title::NewTitle};
+    use crate::repositories::{statuses::StatusRepository, title::TitleRepository};
+
+    fn setup_test_db() -> (SqliteConnection, Vec<QueriedSprint>) {
+        let mut connection =
+            SqliteConnection::establish(":memory:").expect("Failed to create in-memory database");
+        crate::utils::migrations::run_migrations(&mut connection)
+            .expect("Failed to run migrations");
+        let mut status_repo = StatusRepository {
+            connection: &mut connection,
+        };
+        status_repo
+            .seed_statuses()
+            .expect("Failed to seed statuses");
+        let status_id = status_repo
+            .get_all_statuses()
+            .unwrap()
+            .into_iter()
+            .find(|status| status.name == "PENDING")
+            .unwrap()
+            .id;
+
+        let mut title_repo = TitleRepository {
+            connection: &mut connection,
+        };
+        let title = title_repo.add_title(NewTitle { name: "SWE" }).unwrap();
+
+        let mut sprints = Vec::new();
+        for (name, company) in [("fall", "Initech"), ("fall-2", "Globex")] {
+            let mut sprint_repo = SprintRepository {
+                connection: &mut connection,
+            };
+            let sprint = sprint_repo
+                .add_job_sprint(NewSprint {
+                    name,
+                    start_date: "2025-09-01",
+                    end_date: None,
+                    num_jobs: &0,
+                })
+                .unwrap();
+
+            let mut job_repo = JobRepository {
+                connection: &mut connection,
+            };
+            job_repo
+                .add_job(NewJob {
+                    company_name: company,
+                    created: "2025-09-01 10:00:00".to_string(),
+                    title_id: title.id,
+                    status_id,
+                    link: None,
+                    sprint_id: sprint.id,
+                    source_id: None,
+                    referrer: None,
+                    resume_id: None,
+                    cover_letter_id: None,
+                })
+                .unwrap();
+            sprints.push(sprint);
+        }
+
+        (connection, sprints)
+    }
+
+    #[test]
+    fn test_get_markdown_jobs_only_includes_the_given_sprint() {
+        let (mut connection, sprints) = setup_test_db();
+
+        let markdown_jobs = get_markdown_jobs(&mut connection, &sprints[0]).unwrap();
+        assert_eq!(markdown_jobs.len(), 1);
+        assert_eq!(markdown_jobs[0].job.company_name, "Initech");
+        assert_eq!(markdown_jobs[0].sprint, "fall");
+    }
+
+    #[test]
+    fn test_export_jobs_only_includes_the_given_sprint() {
+        let (mut connection, sprints) = setup_test_db();
+        let dir = tempfile::tempdir().unwrap();
+        let mut export_args = ExportArgs::try_parse_from([
+            "export",
+            "-d",
+            &dir.path().to_string_lossy(),
+            "-f",
+            "fall",
+            "-s",
+            "fall",
+        ])
+        .unwrap();
+
+        export_jobs(&mut connection, &mut export_args, &sprints[1]).unwrap();
+
+        let spreadsheet =
+            umya_spreadsheet::reader::xlsx::read(dir.path().join("fall.xlsx")).unwrap();
+        let worksheet = spreadsheet.get_sheet_by_name("Sprint: fall").unwrap();
+        assert_eq!(worksheet.get_value((2, 2)), "Initech");
+        assert_eq!(worksheet.get_value((2, 3)), "");
+
+        export_args.sprint = Some("fal".to_string());
+        assert!(matches!(
+            export_jobs(&mut connection, &mut export_args, &sprints[1]),
+            Err(FettersError::UnknownSprint(name)) if name == "fal"
+        ));
+    }
+}
@@ -0,0 +1,159 @@
+//! Contains functions called by the CLI when managing custom fields.
+
+use diesel::SqliteConnection;
+use inquire::{Confirm, Select, Text};
+use owo_colors::OwoColorize;
+
+use crate::{
+    errors::FettersError,
+    models::custom_field::{CustomFieldType, NewCustomField},
+    repositories::custom_field::CustomFieldRepository,
+    utils::{display::display_custom_fields, prompt::get_inquire_config},
+};
+
+/// Define a new custom field that can be set on every job application.
+pub fn add_field(connection: &mut SqliteConnection, name: &str) -> Result<(), FettersError> {
+    let name = name.trim();
+    if name.is_empty() {
+        return Err(FettersError::EmptyCustomFieldName);
+    }
+
+    let mut field_repo = CustomFieldRepository { connection };
+
+    if field_repo.get_field_by_name(name)?.is_some() {
+        println!(
+            "{}",
+            format!("A custom field named {name} already exists!")
+                .yellow()
+                .bold()
+        );
+        return Ok(());
+    }
+
+    let field_type = match Select::new(
+        "Select the type of value this field holds:",
+        CustomFieldType::variants(),
+    )
+    .with_render_config(get_inquire_config())
+    .prompt_skippable()?
+    {
+        Some(field_type) => field_type,
+        None => return Ok(()),
+    };
+
+    let options = if field_type == CustomFieldType::Enum {
+        Some(input_enum_options()?)
+    } else {
+        None
+    };
+
+    let queried_field = field_repo.add_field(NewCustomField {
+        name,
+        field_type: field_type.as_str(),
+        options: options.as_deref(),
+    })?;
+
+    println!(
+        "{}",
+        format!("\nCreated custom field {queried_field}!\n")
+            .green()
+            .bold()
+    );
+
+    Ok(())
+}
+
+/// Input the comma-separated options for an "ENUM" field. At least one option is required.
+fn input_enum_options() -> Result<String, FettersError> {
+    loop {
+        match Text::new("Enter the options for this field separated by commas:")
+            .with_render_config(get_inquire_config())
+            .prompt_skippable()?
+        {
+            Some(input) => {
+                let options: Vec<&str> = input
+                    .split(',')
+                    .map(|option| option.trim())
+                    .filter(|option| !option.is_empty())
+                    .collect();
+
+                if !options.is_empty() {
+                    return Ok(options.join(","));
+                }
+
+                println!("{}", "Please enter at least one option!".red().bold());
+            }
+            None => {
+                return Err(FettersError::UnknownError(
+                    "No options were provided.".to_string(),
+                ));
+            }
+        }
+    }
+}
+
+/// Display all custom field definitions.
+pub fn list_fields(connection: &mut SqliteConnection) -> Result<(), FettersError> {
+    let mut field_repo = CustomFieldRepository { connection };
+    let all_fields = field_repo.get_all_fields()?;
+
+    if all_fields.is_empty() {
+        println!(
+            "{}",
+            "There are currently no custom fields!".yellow().bold()
+        );
+        return Ok(());
+    }
+
+    display_custom_fields(&all_fields);
+
+    Ok(())
+}
+
+/// Delete a custom field definition and every value stored for it.
+pub fn delete_field(connection: &mut SqliteConnection) -> Result<(), FettersError> {
+    let mut field_repo = CustomFieldRepository { connection };
+    let all_fields = field_repo.get_all_fields()?;
+
+    if all_fields.is_empty() {
+        println!(
+            "{}",
+            "There are currently no custom fields!".yellow().bold()
+        );
+        return Ok(());
+    }
+
+    let field = match Select::new("Select the custom field to delete:", all_fields)
+        .with_render_config(get_inquire_config())
+        .prompt_skippable()?
+    {
+        Some(field) => field,
+        None => return Ok(()),
+    };
+
+    match Confirm::new(&format!(
+        "Delete {}? Its value will be removed from every job application.",
+        field.name
+    ))
+    .with_default(false)
+    .with_render_config(get_inquire_config())
+    .prompt_skippable()?
+    {
+        Some(true) => {
+            let deleted_field = field_repo.delete_field(field.id)?;
+
+            println!(
+                "{}",
+                format!("\nDeleted custom field {}!\n", deleted_field.name)
+                    .green()
+                    .bold()
+            );
+        }
+        Some(false) => {
+            println!("{}", "Cancelled.".red().bold());
+        }
+        None => println!("{}", "Invalid input, try again".red().bold()),
+    }
+
+    Ok(())
+}
@@ -3,7 +3,9 @@
 use diesel::SqliteConnection;
 
 use crate::{
-    errors::FettersError, models::sprint::QueriedSprint, repositories::job::JobRepository,
+    errors::FettersError,
+    models::sprint::QueriedSprint,
+    repositories::{document::DocumentRepository, job::JobRepository},
     utils::display::display_insights,
 };
 
@@ -15,10 +17,16 @@ pub fn show_insights(
     let mut job_repo = JobRepository { connection };
     let jobs_per_status = job_repo.count_jobs_per_status(current_sprint)?;
     let jobs_per_sprint = job_repo.count_jobs_per_sprint(current_sprint)?;
+    let rates_per_source = job_repo.count_rates_per_source(current_sprint)?;
+
+    let mut document_repo = DocumentRepository { connection };
+    let rates_per_resume = document_repo.count_rates_per_resume(current_sprint)?;
 
     if !jobs_per_status.is_empty() && !jobs_per_sprint.is_empty() {
         display_insights(jobs_per_status, "JOBS PER STATUS", false);
         display_insights(jobs_per_sprint, "JOBS PER SPRINT", true);
+        display_insights(rates_per_source, "RATES PER SOURCE (ALL SPRINTS)", false);
+        display_insights(rates_per_resume, "RATES PER RESUME (ALL SPRINTS)", false);
     } else {
         return Err(FettersError::NoJobsAvailable(current_sprint.name.clone()));
     }
@@ -3,33 +3,53 @@
 use diesel::SqliteConnection;
 
 use crate::{
-    cli::QueryArgs, errors::FettersError, models::sprint::QueriedSprint,
-    repositories::job::JobRepository, utils::display::display_jobs,
+    cli::{JobColumn, OutputFormat, QueryArgs},
+    errors::FettersError,
+    models::sprint::QueriedSprint,
+    repositories::{custom_field::CustomFieldRepository, job::JobRepository},
+    utils::display::display_jobs,
 };
 
-/// List all job applications stored in the `jobs` SQLite table.
+/// List all job applications stored in the `jobs` SQLite table. Tables display the given columns,
+/// or the default columns if none are provided.
 pub fn list_jobs(
     connection: &mut SqliteConnection,
     query_args: &QueryArgs,
+    format: OutputFormat,
+    columns: &[JobColumn],
     current_sprint: &QueriedSprint,
 ) -> Result<(), FettersError> {
+    // Any column that is not built in has to name a custom field.
+    let mut field_repo = CustomFieldRepository { connection };
+    let all_fields = field_repo.get_all_fields()?;
+    for column in columns {
+        if let JobColumn::Field(name) = column
+            && !all_fields
+                .iter()
+                .any(|field| field.name.eq_ignore_ascii_case(name))
+        {
+            return Err(FettersError::UnknownColumn(name.clone()));
+        }
+    }
+
     let mut job_repo = JobRepository { connection };
     let all_jobs = job_repo.list_jobs(query_args, current_sprint)?;
 
+    if format == OutputFormat::Json {
+        println!("{}", serde_json::to_string_pretty(&all_jobs)?);
+        return Ok(());
+    }
+
     if all_jobs.is_empty() {
         return Err(FettersError::NoJobsAvailable(
-            query_args
-                .sprint
-                .clone()
-                .as_ref()
-                .unwrap_or(&current_sprint.name.clone())
-                .to_string(),
+            query_args.sprint_label(&current_sprint.name),
         ));
     }
 
     display_jobs(
         &all_jobs,
-        query_args.sprint.as_ref().unwrap_or(&current_sprint.name),
+        &query_args.sprint_label(&current_sprint.name),
+        columns,
     );
 
     Ok(())
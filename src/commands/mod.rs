@@ -1,12 +1,29 @@
 //! Contains functions for use with the CLI.
 
 pub mod add;
+pub mod attach;
+pub mod board;
 pub mod config;
 pub mod delete;
+pub mod doc;
+pub mod edit;
 pub mod export;
+pub mod field;
 pub mod insights;
 pub mod list;
+pub mod native_host;
+pub mod note;
 pub mod open;
+pub mod report;
+pub mod search;
+pub mod serve;
+pub mod shell;
+pub mod show;
 pub mod sprint;
 pub mod stage;
+pub mod tag;
+pub mod trash;
+pub mod tui;
+pub mod undo;
 pub mod update;
+pub mod view;
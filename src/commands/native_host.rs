@@ -0,0 +1,573 @@
+//! Contains the native messaging host started by the fetters browser extension, which tracks the
+//! job posting open in the current tab with a single click.
+//!
+//! Browsers exchange messages with the host over stdin and stdout. Every message is JSON prefixed
+//! with its length as a 32-bit integer in native byte order, so nothing else may be printed to
+//! stdout while the host is running.
+
+use std::fs::{self, create_dir_all};
+use std::io::{self, ErrorKind, Read, Write, stdin, stdout};
+use std::path::{Path, PathBuf};
+
+use chrono::Local;
+use diesel::SqliteConnection;
+use serde::Deserialize;
+use serde_json::{Value, json};
+
+use crate::{
+    cli::{Browser, NativeHostArgs, QueryArgs},
+    config::configuration::Config,
+    errors::FettersError,
+    models::{
+        job::{JobDetails, NewJob},
+        note::NewJobNote,
+        sprint::QueriedSprint,
+        title::NewTitle,
+    },
+    repositories::{
+        job::JobRepository, note::NoteRepository, operation::OperationRepository,
+        statuses::StatusRepository, title::TitleRepository,
+    },
+    utils::posting::parse_page_title,
+};
+
+/// The name browsers use to find the host's manifest.
+const HOST_NAME: &str = "fetters";
+
+/// The largest message that is read from the browser. Messages only carry a tab's URL, title, and
+/// selected text, so anything larger is rejected.
+const MAX_MESSAGE_BYTES: usize = 1024 * 1024;
+
+/// A message sent by the browser extension.
+#[derive(Debug, Deserialize)]
+#[serde(tag = "action", rename_all = "snake_case")]
+enum Message {
+    /// Check that the host is installed.
+    Ping,
+    /// Track the job posting open in the current tab.
+    AddJob {
+        /// The URL of the tab.
+        url: String,
+        /// The title of the tab, which the company name and job title are read from.
+        title: Option<String>,
+        /// The text selected in the tab, which is logged as a note.
+        selection: Option<String>,
+    },
+}
+
+/// Print the host manifest for a browser, or answer messages from the browser extension until it
+/// disconnects.
+pub fn run_native_host(
+    connection: &mut SqliteConnection,
+    native_host_args: &NativeHostArgs,
+    current_sprint: &QueriedSprint,
+) -> Result<(), FettersError> {
+    if let (Some(browser), Some(extension_id)) =
+        (native_host_args.manifest, &native_host_args.extension_id)
+    {
+        return print_manifest(browser, extension_id);
+    }
+
+    let mut input = stdin().lock();
+    let mut output = stdout().lock();
+
+    // Anything other than a message written to stdout would corrupt the framing, so errors are
+    // reported to the extension instead.
+    if let Err(error) = answer_messages(connection, current_sprint, &mut input, &mut output) {
+        write_message(
+            &mut output,
+            &json!({ "ok": false, "error": error.to_string() }),
+        )?;
+    }
+
+    Ok(())
+}
+
+/// Answer messages until the browser closes `input`.
+fn answer_messages<R: Read, W: Write>(
+    connection: &mut SqliteConnection,
+    current_sprint: &QueriedSprint,
+    input: &mut R,
+    output: &mut W,
+) -> Result<(), FettersError> {
+    loop {
+        let response = match read_message(input) {
+            Ok(Some(message)) => handle_message(connection, current_sprint, &message),
+            Ok(None) => return Ok(()),
+            Err(error @ FettersError::NativeMessageTooLarge(_)) => {
+                json!({ "ok": false, "error": error.to_string() })
+            }
+            Err(error) => return Err(error),
+        };
+
+        write_message(output, &response)?;
+    }
+}
+
+/// Read a single length-prefixed message. Returns `None` once the browser closes stdin. Messages
+/// larger than `MAX_MESSAGE_BYTES` are skipped without being stored.
+fn read_message<R: Read>(reader: &mut R) -> Result<Option<Vec<u8>>, FettersError> {
+    let mut length = [0; 4];
+    match reader.read_exact(&mut length) {
+        Ok(()) => {}
+        Err(error) if error.kind() == ErrorKind::UnexpectedEof => return Ok(None),
+        Err(error) => return Err(error.into()),
+    }
+
+    let length = u32::from_ne_bytes(length) as usize;
+    if length > MAX_MESSAGE_BYTES {
+        io::copy(&mut reader.take(length as u64), &mut io::sink())?;
+        return Err(FettersError::NativeMessageTooLarge(length));
+    }
+
+    let mut message = vec![0; length];
+    reader.read_exact(&mut message)?;
+
+    Ok(Some(message))
+}
+
+/// Write a single length-prefixed message.
+fn write_message<W: Write>(writer: &mut W, message: &Value) -> Result<(), FettersError> {
+    let message = serde_json::to_vec(message)?;
+
+    writer.write_all(&(message.len() as u32).to_ne_bytes())?;
+    writer.write_all(&message)?;
+    writer.flush()?;
+
+    Ok(())
+}
+
+/// Handle a message and build the response. Errors are sent to the extension as
+/// `{"ok": false, "error": "..."}` so the host keeps running.
+fn handle_message(
+    connection: &mut SqliteConnection,
+    current_sprint: &QueriedSprint,
+    message: &[u8],
+) -> Value {
+    let result = serde_json::from_slice::<Message>(message)
+        .map_err(FettersError::from)
+        .and_then(|message| match message {
+            Message::Ping => Ok(json!({ "ok": true, "version": env!("CARGO_PKG_VERSION") })),
+            Message::AddJob {
+                url,
+                title,
+                selection,
+            } => add_job(
+                connection,
+                current_sprint,
+                &url,
+                title.as_deref(),
+                selection.as_deref(),
+            )
+            .map(|(created, job)| json!({ "ok": true, "created": created, "job": job })),
+        });
+
+    result.unwrap_or_else(|error| json!({ "ok": false, "error": error.to_string() }))
+}
+
+/// Track a job posting as a `PENDING` job application in the current sprint. The company name
+/// and job title are read from the page title, and the company name falls back to the website's
+/// domain. Postings that are already tracked are returned instead of being added twice. Returns
+/// whether a job application was created along with its details.
+fn add_job(
+    connection: &mut SqliteConnection,
+    current_sprint: &QueriedSprint,
+    url: &str,
+    page_title: Option<&str>,
+    selection: Option<&str>,
+) -> Result<(bool, JobDetails), FettersError> {
+    let url = url.trim();
+    if url.is_empty() {
+        return Err(FettersError::UnknownError(
+            "The message does not contain a URL.".to_string(),
+        ));
+    }
+
+    let mut job_repo = JobRepository { connection };
+    let query_args = QueryArgs {
+        link: Some(url.to_string()),
+        all_sprints: true,
+        ..Default::default()
+    };
+    if let Some(job) = job_repo
+        .list_jobs(&query_args, current_sprint)?
+        .into_iter()
+        .find(|job| job.link.as_deref() == Some(url))
+    {
+        return Ok((false, job_repo.get_job_details(&job)?));
+    }
+
+    let posting = parse_page_title(page_title.unwrap_or_default());
+    let company_name = posting.company.or_else(|| url_host(url)).ok_or_else(|| {
+        FettersError::UnknownError("Could not find a company name for this page.".to_string())
+    })?;
+    let title_name = posting.title.ok_or_else(|| {
+        FettersError::UnknownError("The message does not contain a page title.".to_string())
+    })?;
+
+    let mut status_repo = StatusRepository { connection };
+    let status = status_repo
+        .get_all_statuses()?
+        .into_iter()
+        .find(|status| status.name == "PENDING")
+        .ok_or_else(|| FettersError::UnknownError("The PENDING status is missing.".to_string()))?;
+
+    let mut operation_repo = OperationRepository { connection };
+    operation_repo.start_operation(&format!("fetters native-host (add job from {url})"))?;
+
+    let mut title_repo = TitleRepository { connection };
+    let title = title_repo.add_title(NewTitle { name: &title_name })?;
+
+    let created = Local::now().format("%Y-%m-%d %H:%M:%S").to_string();
+    let mut job_repo = JobRepository { connection };
+    let queried_job = job_repo.add_job(NewJob {
+        company_name: &company_name,
+        created: created.clone(),
+        title_id: title.id,
+        status_id: status.id,
+        link: Some(url),
+        sprint_id: current_sprint.id,
+        source_id: None,
+        referrer: None,
+        resume_id: None,
+        cover_letter_id: None,
+    })?;
+
+    if let Some(content) = selection.map(str::trim).filter(|text| !text.is_empty()) {
+        let mut note_repo = NoteRepository { connection };
+        note_repo.add_note(NewJobNote {
+            job_id: queried_job.id,
+            content,
+            created: &created,
+        })?;
+    }
+
+    let mut job_repo = JobRepository { connection };
+    let job = job_repo.get_tabled_job(queried_job.id)?;
+
+    Ok((true, job_repo.get_job_details(&job)?))
+}
+
+/// The domain of a URL without a leading `www.`, ie. `initech.com`.
+fn url_host(url: &str) -> Option<String> {
+    let without_scheme = url.split_once("://").map_or(url, |(_, rest)| rest);
+    let host = without_scheme
+        .split(['/', '?', '#'])
+        .next()?
+        .rsplit('@')
+        .next()?
+        .split(':')
+        .next()?;
+
+    Some(host.trim_start_matches("www.").to_string()).filter(|host| !host.is_empty())
+}
+
+/// Write the launcher script browsers start the host with and print the host manifest pointing
+/// to it. Browsers pass their own arguments to the host, so the manifest cannot point to the
+/// `fetters` binary directly.
+fn print_manifest(browser: Browser, extension_id: &str) -> Result<(), FettersError> {
+    let launcher_path = write_launcher()?;
+
+    println!(
+        "{}",
+        serde_json::to_string_pretty(&host_manifest(browser, extension_id, &launcher_path))?
+    );
+    eprintln!(
+        "Wrote the host launcher to {}. Save the manifest above as {HOST_NAME}.json in your browser's native messaging hosts directory.",
+        launcher_path.display()
+    );
+
+    Ok(())
+}
+
+/// Build the native messaging host manifest for a browser. Firefox identifies extensions by ID,
+/// while Chromium-based browsers identify them by origin.
+fn host_manifest(browser: Browser, extension_id: &str, launcher_path: &Path) -> Value {
+    let mut manifest = json!({
+        "name": HOST_NAME,
+        "description": "Track job applications with fetters",
+        "path": launcher_path,
+        "type": "stdio",
+    });
+
+    match browser {
+        Browser::Firefox => manifest["allowed_extensions"] = json!([extension_id]),
+        Browser::Chromium => {
+            manifest["allowed_origins"] = json!([format!("chrome-extension://{extension_id}/")])
+        }
+    }
+
+    manifest
+}
+
+/// Write the launcher script into the fetters data directory.
+fn write_launcher() -> Result<PathBuf, FettersError> {
+    let data_dir = Config::get_data_dir_path()?;
+    create_dir_all(&data_dir)?;
+
+    let executable = std::env::current_exe()?;
+    let launcher_path = if cfg!(windows) {
+        data_dir.join("fetters-native-host.bat")
+    } else {
+        data_dir.join("fetters-native-host")
+    };
+    fs::write(&launcher_path, launcher_script(&executable))?;
+
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+
+        fs::set_permissions(&launcher_path, fs::Permissions::from_mode(0o755))?;
+    }
+
+    Ok(launcher_path)
+}
+
+/// A script that starts `fetters native-host` with the arguments passed by the browser.
+fn launcher_script(executable: &Path) -> String {
+    let executable = executable.display().to_string();
+
+    if cfg!(windows) {
+        format!("@echo off\r\n\"{executable}\" native-host %*\r\n")
+    } else {
+        format!(
+            "#!/bin/sh\nexec '{}' native-host \"$@\"\n",
+            executable.replace('\'', r"'\''")
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Cursor;
+
+    use diesel::Connection;
+
+    use crate::models::sprint::NewSprint;
+    use crate::repositories::sprint::SprintRepository;
+
+    fn setup_test_db() -> (SqliteConnection, QueriedSprint) {
+        let mut connection =
+            SqliteConnection::establish(":memory:").expect("Failed to create in-memory database");
+        crate::utils::migrations::run_migrations(&mut connection)
+            .expect("Failed to run migrations");
+
+        let mut status_repo = StatusRepository {
+            connection: &mut connection,
+        };
+        status_repo
+            .seed_statuses()
+            .expect("Failed to seed statuses");
+
+        let mut sprint_repo = SprintRepository {
+            connection: &mut connection,
+        };
+        let sprint = sprint_repo
+            .add_job_sprint(NewSprint {
+                name: "2025-01-01",
+                start_date: "2025-01-01",
+                end_date: None,
+                num_jobs: &0,
+            })
+            .unwrap();
+
+        (connection, sprint)
+    }
+
+    fn frame(message: &Value) -> Vec<u8> {
+        let mut framed = Vec::new();
+        write_message(&mut framed, message).unwrap();
+        framed
+    }
+
+    #[test]
+    fn test_read_and_write_messages() {
+        let first = json!({ "action": "ping" });
+        let second = json!({ "action": "add_job", "url": "https://initech.example.com" });
+
+        let mut framed = frame(&first);
+        assert_eq!(
+            &framed[..4],
+            &(first.to_string().len() as u32).to_ne_bytes()
+        );
+        framed.extend(frame(&second));
+
+        let mut reader = Cursor::new(framed);
+        let message = read_message(&mut reader).unwrap().unwrap();
+        assert_eq!(serde_json::from_slice::<Value>(&message).unwrap(), first);
+        let message = read_message(&mut reader).unwrap().unwrap();
+        assert_eq!(serde_json::from_slice::<Value>(&message).unwrap(), second);
+        assert!(read_message(&mut reader).unwrap().is_none());
+    }
+
+    #[test]
+    fn test_answer_messages_rejects_large_messages() {
+        let (mut connection, sprint) = setup_test_db();
+
+        let mut input = ((MAX_MESSAGE_BYTES + 1) as u32).to_ne_bytes().to_vec();
+        input.extend(vec![b' '; MAX_MESSAGE_BYTES + 1]);
+        input.extend(frame(&json!({ "action": "ping" })));
+
+        let mut output = Vec::new();
+        answer_messages(
+            &mut connection,
+            &sprint,
+            &mut Cursor::new(input),
+            &mut output,
+        )
+        .unwrap();
+
+        let mut reader = Cursor::new(output);
+        let response = read_message(&mut reader).unwrap().unwrap();
+        let response = serde_json::from_slice::<Value>(&response).unwrap();
+        assert_eq!(response["ok"], false);
+        assert!(
+            response["error"]
+                .as_str()
+                .unwrap()
+                .contains(&(MAX_MESSAGE_BYTES + 1).to_string())
+        );
+
+        let response = read_message(&mut reader).unwrap().unwrap();
+        assert_eq!(
+            serde_json::from_slice::<Value>(&response).unwrap()["ok"],
+            true
+        );
+        assert!(read_message(&mut reader).unwrap().is_none());
+    }
+
+    #[test]
+    fn test_add_job_message() {
+        let (mut connection, sprint) = setup_test_db();
+        let message = json!({
+            "action": "add_job",
+            "url": "https://boards.example.com/initech/42",
+            "title": "Backend Engineer at Initech | Example Boards",
+            "selection": "  Remote friendly, Rust and SQLite  ",
+        })
+        .to_string();
+
+        let response = handle_message(&mut connection, &sprint, message.as_bytes());
+        assert_eq!(response["ok"], true);
+        assert_eq!(response["created"], true);
+        assert_eq!(response["job"]["company_name"], "Initech");
+        assert_eq!(response["job"]["title"], "Backend Engineer");
+        assert_eq!(response["job"]["status"], "PENDING");
+        assert_eq!(response["job"]["sprint"], "2025-01-01");
+        assert_eq!(
+            response["job"]["notes"][0]["content"],
+            "Remote friendly, Rust and SQLite"
+        );
+
+        // Capturing the same posting again returns the tracked job application.
+        let response = handle_message(&mut connection, &sprint, message.as_bytes());
+        assert_eq!(response["created"], false);
+        let mut job_repo = JobRepository {
+            connection: &mut connection,
+        };
+        assert_eq!(
+            job_repo
+                .list_jobs(&QueryArgs::default(), &sprint)
+                .unwrap()
+                .len(),
+            1
+        );
+
+        let mut operation_repo = OperationRepository {
+            connection: &mut connection,
+        };
+        assert_eq!(
+            operation_repo
+                .get_current_operation()
+                .unwrap()
+                .unwrap()
+                .command,
+            "fetters native-host (add job from https://boards.example.com/initech/42)"
+        );
+    }
+
+    #[test]
+    fn test_add_job_message_uses_domain_as_company() {
+        let (mut connection, sprint) = setup_test_db();
+        let message = json!({
+            "action": "add_job",
+            "url": "https://www.globex.com/careers/7?ref=feed",
+            "title": "Staff Engineer",
+        })
+        .to_string();
+
+        let response = handle_message(&mut connection, &sprint, message.as_bytes());
+        assert_eq!(response["job"]["company_name"], "globex.com");
+        assert_eq!(response["job"]["title"], "Staff Engineer");
+    }
+
+    #[test]
+    fn test_invalid_messages() {
+        let (mut connection, sprint) = setup_test_db();
+
+        let response = handle_message(&mut connection, &sprint, b"not json");
+        assert_eq!(response["ok"], false);
+
+        let response = handle_message(&mut connection, &sprint, br#"{"action": "delete_job"}"#);
+        assert_eq!(response["ok"], false);
+
+        let response = handle_message(
+            &mut connection,
+            &sprint,
+            br#"{"action": "add_job", "url": "https://initech.example.com", "title": ""}"#,
+        );
+        assert_eq!(response["ok"], false);
+        assert_eq!(
+            response["error"],
+            "The message does not contain a page title."
+        );
+
+        let response = handle_message(&mut connection, &sprint, br#"{"action": "ping"}"#);
+        assert_eq!(response["ok"], true);
+    }
+
+    #[test]
+    fn test_url_host() {
+        assert_eq!(
+            url_host("https://www.initech.com/jobs/1").as_deref(),
+            Some("initech.com")
+        );
+        assert_eq!(
+            url_host("http://user@jobs.globex.com:8080?id=1").as_deref(),
+            Some("jobs.globex.com")
+        );
+        assert_eq!(url_host(""), None);
+    }
+
+    #[test]
+    fn test_host_manifest() {
+        let path = Path::new("/home/user/.local/share/fetters/fetters-native-host");
+
+        let manifest = host_manifest(Browser::Firefox, "fetters@example.com", path);
+        assert_eq!(manifest["name"], "fetters");
+        assert_eq!(manifest["type"], "stdio");
+        assert_eq!(
+            manifest["path"],
+            "/home/user/.local/share/fetters/fetters-native-host"
+        );
+        assert_eq!(manifest["allowed_extensions"][0], "fetters@example.com");
+        assert!(manifest.get("allowed_origins").is_none());
+
+        let manifest = host_manifest(Browser::Chromium, "abcdefghijklmnop", path);
+        assert_eq!(
+            manifest["allowed_origins"][0],
+            "chrome-extension://abcdefghijklmnop/"
+        );
+        assert!(manifest.get("allowed_extensions").is_none());
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_launcher_script() {
+        assert_eq!(
+            launcher_script(Path::new("/opt/it's/fetters")),
+            "#!/bin/sh\nexec '/opt/it'\\''s/fetters' native-host \"$@\"\n"
+        );
+    }
+}
@@ -0,0 +1,71 @@
+//! Contains functions called by the CLI when managing the notes logged for a job application.
+
+use chrono::Local;
+use diesel::SqliteConnection;
+use owo_colors::OwoColorize;
+
+use crate::{
+    errors::FettersError,
+    models::note::NewJobNote,
+    repositories::{job::JobRepository, note::NoteRepository},
+    utils::display::display_notes,
+};
+
+/// Write a new note for a job application in `$EDITOR` and append it to the job's notes log.
+pub fn add_note(connection: &mut SqliteConnection, job_id: i32) -> Result<(), FettersError> {
+    let mut job_repo = JobRepository { connection };
+    let job = job_repo.get_tabled_job(job_id)?;
+
+    let content = edit::edit("")?;
+    let content = content.trim();
+
+    if content.is_empty() {
+        println!(
+            "{}",
+            "The note is empty. Nothing was added.".yellow().bold()
+        );
+        return Ok(());
+    }
+
+    let mut note_repo = NoteRepository { connection };
+    note_repo.add_note(NewJobNote {
+        job_id,
+        content,
+        created: &Local::now().format("%Y-%m-%d %H:%M:%S").to_string(),
+    })?;
+
+    println!(
+        "{}",
+        format!("\nAdded a note to {}!\n", job.company_name)
+            .green()
+            .bold()
+    );
+
+    Ok(())
+}
+
+/// Display all notes logged for a job application in chronological order.
+pub fn list_notes(connection: &mut SqliteConnection, job_id: i32) -> Result<(), FettersError> {
+    let mut job_repo = JobRepository { connection };
+    let job = job_repo.get_tabled_job(job_id)?;
+
+    let mut note_repo = NoteRepository { connection };
+    let notes = note_repo.get_notes_for_job(job_id)?;
+
+    if notes.is_empty() {
+        println!(
+            "{}",
+            format!(
+                "There are no notes for {}. Run `fetters note add {job_id}` to add one.",
+                job.company_name
+            )
+            .yellow()
+            .bold()
+        );
+        return Ok(());
+    }
+
+    display_notes(&notes);
+
+    Ok(())
+}
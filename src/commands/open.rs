@@ -1,5 +1,7 @@
 //! Contains a function called by the CLI when opening a job application in the browser.
 
+use std::fmt::{self, Display, Formatter};
+
 use diesel::SqliteConnection;
 use inquire::MultiSelect;
 use owo_colors::OwoColorize;
@@ -7,41 +9,49 @@ use owo_colors::OwoColorize;
 use crate::{
     cli::QueryArgs,
     errors::FettersError,
-    models::sprint::QueriedSprint,
-    repositories::job::JobRepository,
+    models::{attachment::QueriedAttachment, sprint::QueriedSprint},
+    repositories::{attachment::AttachmentRepository, job::JobRepository},
     utils::{display::display_jobs, prompt::get_inquire_config},
 };
 
-/// Open the link associated with a job application in the browser.
+/// Something associated with a job application that can be opened.
+enum OpenTarget {
+    /// The job application's link.
+    Link(String),
+    /// A file attached to the job application.
+    Attachment(QueriedAttachment),
+}
+
+/// Implementing `Display` allows this enum to be displayed in `MultiSelect` Inquire menus.
+impl Display for OpenTarget {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            OpenTarget::Link(link) => write!(f, "Link: {link}"),
+            OpenTarget::Attachment(attachment) => write!(f, "Attachment: {attachment}"),
+        }
+    }
+}
+
+/// Open the link associated with a job application in the browser. If the job has attachments,
+/// you will be prompted to select the link and/or the attachments you want to open.
 pub fn open_application(
     connection: &mut SqliteConnection,
     query_args: &mut QueryArgs,
     current_sprint: &QueriedSprint,
 ) -> Result<(), FettersError> {
-    let default_sprint = Some(current_sprint.name.clone());
-
-    // Search the default sprint if no sprint filter was specified.
-    if query_args.sprint.is_none() {
-        query_args.sprint = default_sprint;
-    }
-
     let mut job_repo = JobRepository { connection };
     let matched_jobs = job_repo.list_jobs(query_args, current_sprint)?;
 
     if matched_jobs.is_empty() {
         return Err(FettersError::NoJobsAvailable(
-            query_args
-                .sprint
-                .clone()
-                .as_ref()
-                .unwrap_or(&current_sprint.name.clone())
-                .to_string(),
+            query_args.sprint_label(&current_sprint.name),
         ));
     }
 
     display_jobs(
         &matched_jobs,
-        query_args.sprint.as_ref().unwrap_or(&current_sprint.name),
+        &query_args.sprint_label(&current_sprint.name),
+        &[],
     );
 
     if let Some(selected_jobs) = MultiSelect::new(
@@ -52,17 +62,48 @@ pub fn open_application(
     .prompt_skippable()?
     {
         for job in selected_jobs {
-            if let Some(link) = job.link {
-                open::that(link)?;
-                continue;
-            }
+            let mut attachment_repo = AttachmentRepository { connection };
+            let attachments = attachment_repo.get_attachments(job.id)?;
 
-            println!(
-                "{}",
-                "Job with ID {id} has no associated link. Cannot open link in browser!"
+            if attachments.is_empty() {
+                if let Some(link) = job.link {
+                    open::that(link)?;
+                    continue;
+                }
+
+                println!(
+                    "{}",
+                    format!(
+                        "Job with ID {} has no associated link or attachments. Nothing to open!",
+                        job.id
+                    )
                     .red()
                     .bold()
-            );
+                );
+                continue;
+            }
+
+            let targets: Vec<OpenTarget> = job
+                .link
+                .into_iter()
+                .map(OpenTarget::Link)
+                .chain(attachments.into_iter().map(OpenTarget::Attachment))
+                .collect();
+
+            if let Some(selected_targets) = MultiSelect::new(
+                &format!("Select what you want to open for {}:", job.company_name),
+                targets,
+            )
+            .with_render_config(get_inquire_config())
+            .prompt_skippable()?
+            {
+                for target in selected_targets {
+                    match target {
+                        OpenTarget::Link(link) => open::that(link)?,
+                        OpenTarget::Attachment(attachment) => open::that(attachment.file_path)?,
+                    }
+                }
+            }
         }
     }
 
@@ -0,0 +1,112 @@
+//! Contains a function called by the CLI when generating reports.
+
+use std::collections::HashMap;
+use std::fs::{create_dir_all, write};
+use std::path::Path;
+
+use chrono::Local;
+use diesel::SqliteConnection;
+use owo_colors::OwoColorize;
+
+use crate::{
+    cli::QueryArgs,
+    errors::FettersError,
+    models::{insight::CountAndPercentage, sprint::QueriedSprint},
+    repositories::{job::JobRepository, sprint::SprintRepository, stage::StageRepository},
+    utils::html::{SprintReport, render_index_page, render_sprint_page, report_filename},
+};
+
+/// Write a self-contained HTML report page for every sprint, or only for the sprint named
+/// `sprint_name`, along with an index page linking to all of them.
+pub fn write_html_report(
+    connection: &mut SqliteConnection,
+    out: &str,
+    sprint_name: Option<&str>,
+    current_sprint: &QueriedSprint,
+) -> Result<(), FettersError> {
+    let mut sprint_repo = SprintRepository { connection };
+    let all_sprints = sprint_repo.get_all_sprints()?;
+
+    let target_sprints = match sprint_name {
+        Some(name) => vec![
+            all_sprints
+                .iter()
+                .find(|sprint| sprint.name == name)
+                .cloned()
+                .ok_or_else(|| FettersError::UnknownSprint(name.to_string()))?,
+        ],
+        None => all_sprints.clone(),
+    };
+
+    let mut job_repo = JobRepository { connection };
+    let counts = job_repo.count_jobs_per_sprint(current_sprint)?;
+    // Sprints without any job applications are not counted, but still get a report page.
+    let sprint_counts = all_sprints
+        .iter()
+        .map(|sprint| {
+            let count = counts
+                .iter()
+                .find(|count| count.label == sprint.name)
+                .map(|count| CountAndPercentage {
+                    label: count.label.clone(),
+                    count: count.count,
+                    sprint_percentage: count.sprint_percentage.clone(),
+                    overall_percentage: count.overall_percentage.clone(),
+                })
+                .unwrap_or(CountAndPercentage {
+                    label: sprint.name.clone(),
+                    count: 0,
+                    sprint_percentage: "0.00%".to_string(),
+                    overall_percentage: "0.00%".to_string(),
+                });
+
+            (sprint.id, count)
+        })
+        .collect::<Vec<(i32, CountAndPercentage)>>();
+
+    let out_dir = Path::new(out);
+    create_dir_all(out_dir)?;
+    let generated = Local::now().format("%Y-%m-%d %H:%M").to_string();
+
+    for sprint in target_sprints.iter().cloned() {
+        let mut job_repo = JobRepository { connection };
+        // Without a sprint filter, only the job applications in the given sprint are listed.
+        let jobs = job_repo.list_jobs(&QueryArgs::default(), &sprint)?;
+        let status_counts = job_repo.count_jobs_per_status(&sprint)?;
+
+        let mut stage_repo = StageRepository { connection };
+        let mut stages = HashMap::new();
+        for job in jobs.iter().filter(|job| job.stages.unwrap_or_default() > 0) {
+            stages.insert(job.id, stage_repo.get_stages_for_job(job.id)?);
+        }
+
+        let report = SprintReport {
+            sprint,
+            jobs,
+            stages,
+            status_counts,
+        };
+        write(
+            out_dir.join(report_filename(report.sprint.id, &report.sprint.name)),
+            render_sprint_page(&report, &sprint_counts, &generated),
+        )?;
+    }
+
+    write(
+        out_dir.join("index.html"),
+        render_index_page(&sprint_counts, &generated),
+    )?;
+
+    println!(
+        "{}",
+        format!(
+            "Wrote the report pages of {} sprint(s) and index.html to {}!",
+            target_sprints.len(),
+            out_dir.display()
+        )
+        .green()
+        .bold()
+    );
+
+    Ok(())
+}
@@ -0,0 +1,36 @@
+//! Contains a function called by the CLI when searching job applications.
+
+use diesel::SqliteConnection;
+use owo_colors::OwoColorize;
+
+use crate::{
+    cli::SearchArgs, errors::FettersError, repositories::search::SearchRepository,
+    utils::display::display_search_results,
+};
+
+/// Search job applications with a full-text query and display the matches ranked by relevance.
+pub fn search_jobs(
+    connection: &mut SqliteConnection,
+    search_args: &SearchArgs,
+) -> Result<(), FettersError> {
+    let mut search_repo = SearchRepository { connection };
+    let results = search_repo.search_jobs(
+        &search_args.query,
+        search_args.sprint.as_deref(),
+        search_args.limit,
+    )?;
+
+    if results.is_empty() {
+        println!(
+            "{}",
+            format!("No job applications match \"{}\".", search_args.query)
+                .yellow()
+                .bold()
+        );
+        return Ok(());
+    }
+
+    display_search_results(&results, &search_args.query);
+
+    Ok(())
+}
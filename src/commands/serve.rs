@@ -0,0 +1,70 @@
+//! Contains a function called by the CLI when starting the local HTTP API.
+
+use diesel::SqliteConnection;
+use owo_colors::OwoColorize;
+use tiny_http::Server;
+
+use crate::{
+    api::serve, config::configuration::Config, errors::FettersError, models::sprint::QueriedSprint,
+};
+
+/// Serve the local HTTP API on `127.0.0.1:port` until the process is stopped. An API token is
+/// generated and saved to the config file the first time the API is started.
+pub fn start_server(
+    connection: &mut SqliteConnection,
+    config: Config,
+    current_sprint: &QueriedSprint,
+    port: u16,
+) -> Result<(), FettersError> {
+    let mut config = config;
+    let token = match config.api_token.clone() {
+        Some(token) => token,
+        None => {
+            let token = generate_token()?;
+            config.api_token = Some(token.clone());
+            config.save_to_file()?;
+
+            token
+        }
+    };
+
+    let server = Server::http(("127.0.0.1", port))
+        .map_err(|error| FettersError::ServerError(error.to_string()))?;
+
+    println!(
+        "{}",
+        format!("Serving the fetters API at http://127.0.0.1:{port}. Press Ctrl-C to stop.")
+            .green()
+            .bold()
+    );
+    println!(
+        "{}",
+        format!("Send the header `Authorization: Bearer {token}` with every request.").cyan()
+    );
+
+    serve(&server, connection, &token, current_sprint)
+}
+
+/// Generate a random 32 character token from the operating system's secure random number
+/// generator.
+fn generate_token() -> Result<String, FettersError> {
+    let mut bytes = [0u8; 16];
+    getrandom::fill(&mut bytes).map_err(|error| {
+        FettersError::ServerError(format!("Could not generate an API token: {error}"))
+    })?;
+
+    Ok(bytes.iter().map(|byte| format!("{byte:02x}")).collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_generate_token() {
+        let token = generate_token().unwrap();
+        assert_eq!(token.len(), 32);
+        assert!(token.chars().all(|character| character.is_ascii_hexdigit()));
+        assert_ne!(token, generate_token().unwrap());
+    }
+}
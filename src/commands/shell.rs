@@ -0,0 +1,322 @@
+//! Contains the interactive shell started with `fetters shell`.
+
+use std::fs::create_dir_all;
+
+use clap::{CommandFactory, Parser};
+use diesel::SqliteConnection;
+use owo_colors::OwoColorize;
+use rustyline::completion::Completer;
+use rustyline::error::ReadlineError;
+use rustyline::highlight::Highlighter;
+use rustyline::hint::Hinter;
+use rustyline::history::DefaultHistory;
+use rustyline::validate::Validator;
+use rustyline::{CompletionType, Context, Editor, Helper};
+
+use crate::{
+    cli::{Cli, Command},
+    config::configuration::Config,
+    errors::FettersError,
+    models::sprint::QueriedSprint,
+    repositories::{
+        job::JobRepository, operation::OperationRepository, sprint::SprintRepository,
+        statuses::StatusRepository,
+    },
+    run_command,
+};
+
+/// Lines that close the shell.
+const EXIT_COMMANDS: [&str; 2] = ["exit", "quit"];
+
+/// Completes subcommands, flags, company names, and status names in the shell.
+#[derive(Default)]
+pub struct ShellHelper {
+    /// The company names of all job applications.
+    companies: Vec<String>,
+    /// The names of all application statuses.
+    statuses: Vec<String>,
+}
+
+impl ShellHelper {
+    /// Query the company and status names again, ie. after a command added a job application.
+    fn refresh(&mut self, connection: &mut SqliteConnection) -> Result<(), FettersError> {
+        let mut job_repo = JobRepository { connection };
+        self.companies = job_repo.get_company_names()?;
+
+        let mut status_repo = StatusRepository { connection };
+        self.statuses = status_repo
+            .get_all_statuses()?
+            .into_iter()
+            .map(|status| status.name)
+            .collect();
+
+        Ok(())
+    }
+
+    /// Find completions for the word ending at `pos` in `line`. Returns the position the word
+    /// starts at and the candidates that may replace it.
+    fn complete_word(&self, line: &str, pos: usize) -> (usize, Vec<String>) {
+        let before = &line[..pos];
+
+        // A word with an unclosed quote starts at the quote, so values containing spaces can be
+        // completed.
+        let start = if before.matches('"').count() % 2 == 1 {
+            before.rfind('"').unwrap_or_default()
+        } else {
+            before
+                .rfind(char::is_whitespace)
+                .map(|index| index + 1)
+                .unwrap_or_default()
+        };
+        let word = before[start..].trim_start_matches('"').to_lowercase();
+        let previous_words = shlex::split(&before[..start]).unwrap_or_default();
+
+        // Find the subcommand being typed, ie. `stage add` in `stage add --company `.
+        let root = Cli::command();
+        let mut command = &root;
+        for previous_word in &previous_words {
+            match command.find_subcommand(previous_word) {
+                Some(subcommand) => command = subcommand,
+                None => break,
+            }
+        }
+
+        let flag_value = previous_words.last().and_then(|flag| {
+            command
+                .get_arguments()
+                .find(|argument| {
+                    argument
+                        .get_long()
+                        .is_some_and(|long| *flag == format!("--{long}"))
+                        || argument
+                            .get_short()
+                            .is_some_and(|short| *flag == format!("-{short}"))
+                })
+                .map(|argument| argument.get_id().as_str())
+        });
+
+        let candidates = match flag_value {
+            Some("company") => self.companies.clone(),
+            Some("status") => self.statuses.clone(),
+            _ if word.starts_with('-') => command
+                .get_arguments()
+                .filter(|argument| !argument.is_hide_set())
+                .filter_map(|argument| argument.get_long().map(|long| format!("--{long}")))
+                .chain(std::iter::once("--help".to_string()))
+                .collect(),
+            _ => command
+                .get_subcommands()
+                .filter(|subcommand| !subcommand.is_hide_set())
+                .map(|subcommand| subcommand.get_name().to_string())
+                .chain(
+                    previous_words
+                        .is_empty()
+                        .then(|| {
+                            EXIT_COMMANDS
+                                .iter()
+                                .chain(["help"].iter())
+                                .map(|name| name.to_string())
+                        })
+                        .into_iter()
+                        .flatten(),
+                )
+                .collect(),
+        };
+
+        let mut matches = candidates
+            .into_iter()
+            .filter(|candidate| candidate.to_lowercase().starts_with(&word))
+            .map(|candidate| {
+                if candidate.contains(char::is_whitespace) {
+                    format!("\"{candidate}\"")
+                } else {
+                    candidate
+                }
+            })
+            .collect::<Vec<String>>();
+        matches.sort();
+        matches.dedup();
+
+        (start, matches)
+    }
+}
+
+impl Completer for ShellHelper {
+    type Candidate = String;
+
+    fn complete(
+        &self,
+        line: &str,
+        pos: usize,
+        _ctx: &Context<'_>,
+    ) -> rustyline::Result<(usize, Vec<String>)> {
+        Ok(self.complete_word(line, pos))
+    }
+}
+
+impl Hinter for ShellHelper {
+    type Hint = String;
+}
+
+impl Highlighter for ShellHelper {}
+
+impl Validator for ShellHelper {}
+
+impl Helper for ShellHelper {}
+
+/// Start an interactive shell that runs `fetters` commands without starting a new process for
+/// each one. The database connection is shared by every command, and the configuration and current
+/// sprint are only loaded again after commands that may change them.
+pub fn start_shell(
+    connection: &mut SqliteConnection,
+    config: Config,
+    current_sprint: &QueriedSprint,
+) -> Result<(), FettersError> {
+    let mut config = config;
+    let mut current_sprint = current_sprint.clone();
+
+    let mut helper = ShellHelper::default();
+    helper.refresh(connection)?;
+
+    let mut editor = Editor::<ShellHelper, DefaultHistory>::with_config(
+        rustyline::Config::builder()
+            .history_ignore_dups(true)?
+            .completion_type(CompletionType::List)
+            .build(),
+    )?;
+    editor.set_helper(Some(helper));
+
+    let history_path = Config::get_data_dir_path()?.join("shell_history.txt");
+    if history_path.exists() {
+        editor.load_history(&history_path)?;
+    }
+
+    println!(
+        "{}",
+        "Enter commands without the `fetters` prefix, ie. `list --status pending`. Press Tab to complete and type `exit` to leave the shell."
+            .cyan()
+    );
+
+    loop {
+        let line = match editor.readline(&format!("fetters [{}]> ", current_sprint.name)) {
+            Ok(line) => line,
+            // Ctrl-C discards the line being typed, Ctrl-D leaves the shell.
+            Err(ReadlineError::Interrupted) => continue,
+            Err(ReadlineError::Eof) => break,
+            Err(error) => return Err(error.into()),
+        };
+        let line = line.trim();
+        if line.is_empty() {
+            continue;
+        }
+        editor.add_history_entry(line)?;
+
+        if EXIT_COMMANDS.contains(&line) {
+            break;
+        }
+
+        let Some(words) = shlex::split(line) else {
+            println!("{}", "The command contains an unclosed quote.".red().bold());
+            continue;
+        };
+        let command = match Cli::try_parse_from(std::iter::once("fetters".to_string()).chain(words))
+        {
+            Ok(cli) => cli.command,
+            Err(error) => {
+                error.print()?;
+                continue;
+            }
+        };
+
+        if matches!(command, Command::Shell) {
+            println!(
+                "{}",
+                "You are already in the fetters shell.".yellow().bold()
+            );
+            continue;
+        }
+        let reload_config = matches!(
+            command,
+            Command::Config(_) | Command::Sprint(_) | Command::View(_)
+        );
+
+        // Every command is recorded in its own operation so it can be undone on its own.
+        let mut operation_repo = OperationRepository { connection };
+        operation_repo.start_operation(&format!("fetters {line}"))?;
+
+        run_command(command, connection, config.clone(), &current_sprint);
+
+        if reload_config {
+            config = Config::load_or_create()?;
+            let mut sprint_repo = SprintRepository { connection };
+            current_sprint = sprint_repo.get_current_sprint(&config.current_sprint)?;
+        }
+        if let Some(helper) = editor.helper_mut() {
+            helper.refresh(connection)?;
+        }
+    }
+
+    if let Some(parent) = history_path.parent() {
+        create_dir_all(parent)?;
+    }
+    editor.save_history(&history_path)?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn helper() -> ShellHelper {
+        ShellHelper {
+            companies: vec!["Globex".to_string(), "Initech Systems".to_string()],
+            statuses: vec!["IN PROGRESS".to_string(), "PENDING".to_string()],
+        }
+    }
+
+    fn complete(line: &str) -> (usize, Vec<String>) {
+        helper().complete_word(line, line.len())
+    }
+
+    #[test]
+    fn test_complete_subcommands() {
+        assert_eq!(complete("li"), (0, vec!["list".to_string()]));
+        assert_eq!(
+            complete("ex"),
+            (0, vec!["exit".to_string(), "export".to_string()])
+        );
+        assert_eq!(complete("stage u"), (6, vec!["update".to_string()]));
+    }
+
+    #[test]
+    fn test_complete_flags() {
+        let (start, candidates) = complete("list --com");
+        assert_eq!(start, 5);
+        assert_eq!(candidates, vec!["--company".to_string()]);
+    }
+
+    #[test]
+    fn test_complete_company_names() {
+        assert_eq!(
+            complete("update --company ini"),
+            (17, vec!["\"Initech Systems\"".to_string()])
+        );
+        assert_eq!(
+            complete("list -c \"Init"),
+            (8, vec!["\"Initech Systems\"".to_string()])
+        );
+    }
+
+    #[test]
+    fn test_complete_status_names() {
+        assert_eq!(
+            complete("list --status "),
+            (
+                14,
+                vec!["\"IN PROGRESS\"".to_string(), "PENDING".to_string()]
+            )
+        );
+        assert_eq!(complete("list -s pe"), (8, vec!["PENDING".to_string()]));
+    }
+}
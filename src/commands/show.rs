@@ -0,0 +1,91 @@
+//! Contains a function called by the CLI when showing a single job application.
+
+use diesel::SqliteConnection;
+use owo_colors::OwoColorize;
+
+use crate::{
+    cli::{OutputFormat, ShowArgs},
+    commands::stage::{HighlightColor, build_stage_tree},
+    errors::FettersError,
+    models::sprint::QueriedSprint,
+    repositories::job::JobRepository,
+    utils::{
+        display::{display_attachments, display_job_details, display_notes},
+        select::select_job,
+    },
+};
+
+/// Show everything stored about a job application: its attributes, notes, interview stages,
+/// attachments, and job description. The job is looked up by ID if one was provided. Otherwise,
+/// you will be prompted to select a job matching the query. Only the job description is printed
+/// if `--description` is set.
+pub fn show_job(
+    connection: &mut SqliteConnection,
+    show_args: &mut ShowArgs,
+    current_sprint: &QueriedSprint,
+) -> Result<(), FettersError> {
+    let job = match show_args.job_id {
+        Some(job_id) => {
+            let mut job_repo = JobRepository { connection };
+            job_repo.get_tabled_job(job_id)?
+        }
+        None => match select_job(connection, &mut show_args.query_args, current_sprint)? {
+            Some(job) => job,
+            None => return Ok(()),
+        },
+    };
+
+    let mut job_repo = JobRepository { connection };
+    let details = job_repo.get_job_details(&job)?;
+
+    if show_args.description_only {
+        match &details.description {
+            Some(description) if show_args.format == OutputFormat::Json => {
+                println!("{}", serde_json::to_string_pretty(description)?)
+            }
+            Some(description) => println!("{}", description.content),
+            None => println!(
+                "{}",
+                format!(
+                    "No job description stored for {}. Run `fetters update --description-file <FILE>` to store one.",
+                    job.company_name
+                )
+                .yellow()
+                .bold()
+            ),
+        }
+
+        return Ok(());
+    }
+
+    if show_args.format == OutputFormat::Json {
+        println!("{}", serde_json::to_string_pretty(&details)?);
+        return Ok(());
+    }
+
+    display_job_details(&details);
+
+    if !details.notes.is_empty() {
+        display_notes(&details.notes);
+    }
+
+    if !details.stages.is_empty() {
+        build_stage_tree(&job, &details.stages, None, HighlightColor::Green);
+    }
+
+    if !details.attachments.is_empty() {
+        display_attachments(&details.attachments);
+    }
+
+    if let Some(description) = &details.description {
+        println!(
+            "{}\n",
+            format!("JOB DESCRIPTION [CAPTURED {}]", description.captured)
+                .green()
+                .bold()
+        );
+        println!("{}\n", description.content);
+    }
+
+    Ok(())
+}
@@ -4,7 +4,8 @@ use chrono::{Local, NaiveDate};
 use diesel::SqliteConnection;
 use inquire::{Confirm, DateSelect, MultiSelect, Select, Text};
 use owo_colors::OwoColorize;
-use ptree::{TreeBuilder, print_tree};
+use ptree::print_config::StyleWhen;
+use ptree::{PrintConfig, TreeBuilder, print_tree, write_tree_with};
 
 use crate::{
     cli::QueryArgs,
@@ -16,48 +17,12 @@ use crate::{
             InterviewStageUpdate, NewInterviewStage, QueriedInterviewStage, StageStatus,
         },
     },
-    repositories::{job::JobRepository, stage::StageRepository},
-    utils::{display::display_jobs, prompt::get_inquire_config},
+    repositories::stage::StageRepository,
+    utils::{prompt::get_inquire_config, select::select_job},
 };
 
-/// Shared helper to select a job from query results.
-fn select_job(
-    connection: &mut SqliteConnection,
-    query_args: &mut QueryArgs,
-    current_sprint: &QueriedSprint,
-) -> Result<Option<TabledJob>, FettersError> {
-    let default_sprint = Some(current_sprint.name.clone());
-
-    if query_args.sprint.is_none() {
-        query_args.sprint = default_sprint;
-    }
-
-    let mut job_repo = JobRepository { connection };
-    let matched_jobs = job_repo.list_jobs(query_args, current_sprint)?;
-
-    if matched_jobs.is_empty() {
-        return Err(FettersError::NoJobsAvailable(
-            query_args
-                .sprint
-                .clone()
-                .as_ref()
-                .unwrap_or(&current_sprint.name)
-                .to_string(),
-        ));
-    }
-
-    display_jobs(
-        &matched_jobs,
-        query_args.sprint.as_ref().unwrap_or(&current_sprint.name),
-    );
-
-    Ok(Select::new("Select a job application:", matched_jobs)
-        .with_render_config(get_inquire_config())
-        .prompt_skippable()?)
-}
-
 /// Build and print a ptree for a job's interview stages.
-fn build_stage_tree(
+pub fn build_stage_tree(
     job: &TabledJob,
     stages: &[QueriedInterviewStage],
     highlight_stage_id: Option<i32>,
@@ -139,8 +104,46 @@ fn build_stage_tree(
     println!();
 }
 
+/// Render a job's interview stages as the lines of an uncolored tree. This is used where the tree
+/// is drawn by hand rather than printed, ie. in the terminal UI.
+pub fn stage_tree_lines(job: &TabledJob, stages: &[QueriedInterviewStage]) -> Vec<String> {
+    let mut builder = TreeBuilder::new(format!(
+        "{} - {}",
+        job.company_name,
+        job.title.as_deref().unwrap_or("N/A")
+    ));
+
+    for stage in stages {
+        match stage.name.as_deref() {
+            Some(name) if !name.is_empty() => {
+                builder.begin_child(format!("Stage {}: {}", stage.stage_number, name))
+            }
+            _ => builder.begin_child(format!("Stage {}", stage.stage_number)),
+        };
+        builder.add_empty_child(format!("[{}] {}", stage.status, stage.scheduled_date));
+        if let Some(notes) = stage.notes.as_deref().filter(|notes| !notes.is_empty()) {
+            builder.add_empty_child(notes.to_string());
+        }
+        builder.end_child();
+    }
+
+    let config = PrintConfig {
+        styled: StyleWhen::Never,
+        ..PrintConfig::default()
+    };
+    let mut output = Vec::new();
+    if write_tree_with(&builder.build(), &mut output, &config).is_err() {
+        return Vec::new();
+    }
+
+    String::from_utf8_lossy(&output)
+        .lines()
+        .map(|line| line.to_string())
+        .collect()
+}
+
 /// Color used for highlighting a stage in the tree preview.
-enum HighlightColor {
+pub enum HighlightColor {
     /// Green highlight for new or updated stages.
     Green,
     /// Red highlight for stages being deleted.
@@ -0,0 +1,162 @@
+//! Contains functions called by the CLI when managing tags.
+
+use diesel::SqliteConnection;
+use inquire::{Confirm, MultiSelect, Text};
+use owo_colors::OwoColorize;
+
+use crate::{
+    cli::QueryArgs,
+    errors::FettersError,
+    models::{
+        sprint::QueriedSprint,
+        tag::{NewTag, normalize_tag_name},
+    },
+    repositories::tag::TagRepository,
+    utils::{display::display_tags, prompt::get_inquire_config, select::select_job},
+};
+
+/// Apply existing or new tags to a job application.
+pub fn add_tags(
+    connection: &mut SqliteConnection,
+    query_args: &mut QueryArgs,
+    current_sprint: &QueriedSprint,
+) -> Result<(), FettersError> {
+    let job = match select_job(connection, query_args, current_sprint)? {
+        Some(job) => job,
+        None => return Ok(()),
+    };
+
+    let mut tag_repo = TagRepository { connection };
+    let applied_tags = tag_repo.get_tags_for_job(job.id)?;
+    let available_tags: Vec<_> = tag_repo
+        .get_all_tags()?
+        .into_iter()
+        .filter(|tag| !applied_tags.iter().any(|applied| applied.id == tag.id))
+        .collect();
+
+    let mut tag_names: Vec<String> = Vec::new();
+
+    if !available_tags.is_empty()
+        && let Some(selected_tags) =
+            MultiSelect::new("Select existing tags to apply:", available_tags)
+                .with_render_config(get_inquire_config())
+                .prompt_skippable()?
+    {
+        tag_names.extend(selected_tags.into_iter().map(|tag| tag.name));
+    }
+
+    if let Some(new_tags) = Text::new("[OPTIONAL] Enter new tags separated by commas:")
+        .with_render_config(get_inquire_config())
+        .prompt_skippable()?
+    {
+        for new_tag in new_tags.split(',').map(normalize_tag_name) {
+            if !new_tag.is_empty() && !tag_names.contains(&new_tag) {
+                tag_names.push(new_tag);
+            }
+        }
+    }
+
+    if tag_names.is_empty() {
+        println!("{}", "No tags were selected.".yellow().bold());
+        return Ok(());
+    }
+
+    for tag_name in &tag_names {
+        let tag = tag_repo.add_tag(NewTag { name: tag_name })?;
+        tag_repo.tag_job(job.id, tag.id)?;
+    }
+
+    println!(
+        "{}",
+        format!(
+            "\nTagged {} with [{}]!\n",
+            job.company_name,
+            tag_names.join(", ")
+        )
+        .green()
+        .bold()
+    );
+
+    Ok(())
+}
+
+/// Remove tags from a job application.
+pub fn remove_tags(
+    connection: &mut SqliteConnection,
+    query_args: &mut QueryArgs,
+    current_sprint: &QueriedSprint,
+) -> Result<(), FettersError> {
+    let job = match select_job(connection, query_args, current_sprint)? {
+        Some(job) => job,
+        None => return Ok(()),
+    };
+
+    let mut tag_repo = TagRepository { connection };
+    let applied_tags = tag_repo.get_tags_for_job(job.id)?;
+
+    if applied_tags.is_empty() {
+        println!(
+            "{}",
+            format!("\nNo tags applied to {}.\n", job.company_name)
+                .yellow()
+                .bold()
+        );
+        return Ok(());
+    }
+
+    let selected_tags = match MultiSelect::new("Select the tags to remove:", applied_tags)
+        .with_render_config(get_inquire_config())
+        .prompt_skippable()?
+    {
+        Some(tags) if !tags.is_empty() => tags,
+        _ => return Ok(()),
+    };
+
+    match Confirm::new("Confirm removal?")
+        .with_default(true)
+        .with_render_config(get_inquire_config())
+        .prompt_skippable()?
+    {
+        Some(true) => {
+            for tag in &selected_tags {
+                tag_repo.untag_job(job.id, tag.id)?;
+            }
+
+            println!(
+                "{}",
+                format!(
+                    "\nRemoved [{}] from {}!\n",
+                    selected_tags
+                        .iter()
+                        .map(|tag| tag.name.as_str())
+                        .collect::<Vec<&str>>()
+                        .join(", "),
+                    job.company_name
+                )
+                .green()
+                .bold()
+            );
+        }
+        Some(false) => {
+            println!("{}", "Cancelled.".red().bold());
+        }
+        None => println!("{}", "Invalid input, try again".red().bold()),
+    }
+
+    Ok(())
+}
+
+/// Display all tags and the number of job applications they are applied to.
+pub fn list_tags(connection: &mut SqliteConnection) -> Result<(), FettersError> {
+    let mut tag_repo = TagRepository { connection };
+    let all_tags = tag_repo.count_jobs_per_tag()?;
+
+    if all_tags.is_empty() {
+        println!("{}", "There are currently no tags!".yellow().bold());
+        return Ok(());
+    }
+
+    display_tags(&all_tags);
+
+    Ok(())
+}
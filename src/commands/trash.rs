@@ -0,0 +1,147 @@
+//! Contains functions called by the CLI when managing deleted job applications in the trash.
+
+use chrono::{Duration, Local};
+use diesel::{Connection, SqliteConnection};
+use inquire::{Confirm, Select};
+use owo_colors::OwoColorize;
+
+use crate::{
+    errors::FettersError,
+    repositories::job::JobRepository,
+    utils::{
+        attachments::remove_attachment_dir, display::display_trashed_jobs,
+        prompt::get_inquire_config,
+    },
+};
+
+/// List all job applications in the trash.
+pub fn list_trash(connection: &mut SqliteConnection) -> Result<(), FettersError> {
+    let mut job_repo = JobRepository { connection };
+    let trashed_jobs = job_repo.get_trashed_jobs()?;
+
+    if trashed_jobs.is_empty() {
+        println!("{}", "The trash is empty.".yellow().bold());
+        return Ok(());
+    }
+
+    display_trashed_jobs(&trashed_jobs);
+
+    Ok(())
+}
+
+/// Restore a job application from the trash. You will be prompted to select a job in the trash if
+/// no ID was provided.
+pub fn restore_from_trash(
+    connection: &mut SqliteConnection,
+    job_id: Option<i32>,
+) -> Result<(), FettersError> {
+    let mut job_repo = JobRepository { connection };
+
+    let job_id = match job_id {
+        Some(job_id) => job_id,
+        None => {
+            let trashed_jobs = job_repo.get_trashed_jobs()?;
+
+            if trashed_jobs.is_empty() {
+                println!("{}", "The trash is empty.".yellow().bold());
+                return Ok(());
+            }
+
+            display_trashed_jobs(&trashed_jobs);
+
+            match Select::new("Select the job you want to restore:", trashed_jobs)
+                .with_render_config(get_inquire_config())
+                .prompt_skippable()?
+            {
+                Some(job) => job.id,
+                None => return Ok(()),
+            }
+        }
+    };
+
+    let restored_job = job_repo.restore_job(job_id)?;
+
+    println!(
+        "{}",
+        format!("\nRestored {} from the trash!\n", restored_job.company_name)
+            .green()
+            .bold()
+    );
+
+    Ok(())
+}
+
+/// Permanently delete every job application in the trash.
+pub fn empty_trash(connection: &mut SqliteConnection) -> Result<(), FettersError> {
+    let mut job_repo = JobRepository { connection };
+    let trashed_jobs = job_repo.get_trashed_jobs()?;
+
+    if trashed_jobs.is_empty() {
+        println!("{}", "The trash is already empty.".yellow().bold());
+        return Ok(());
+    }
+
+    display_trashed_jobs(&trashed_jobs);
+
+    match Confirm::new(&format!(
+        "Permanently delete {} job applications? This cannot be undone.",
+        trashed_jobs.len()
+    ))
+    .with_default(false)
+    .with_render_config(get_inquire_config())
+    .prompt_skippable()?
+    {
+        Some(true) => {
+            let deleted_job_ids = purge_trash(connection, None)?;
+
+            println!(
+                "{}",
+                format!(
+                    "\nPermanently deleted {} job applications!\n",
+                    deleted_job_ids.len()
+                )
+                .green()
+                .bold()
+            );
+        }
+        _ => println!("{}", "Cancelled.".red().bold()),
+    }
+
+    Ok(())
+}
+
+/// Permanently delete job applications that have been in the trash for longer than the configured
+/// number of days. Nothing is deleted if `purge_days` is 0.
+pub fn purge_expired_trash(
+    connection: &mut SqliteConnection,
+    purge_days: u32,
+) -> Result<(), FettersError> {
+    if purge_days == 0 {
+        return Ok(());
+    }
+
+    let cutoff = (Local::now() - Duration::days(purge_days as i64))
+        .format("%Y-%m-%d %H:%M:%S")
+        .to_string();
+    purge_trash(connection, Some(&cutoff))?;
+
+    Ok(())
+}
+
+/// Permanently delete job applications in the trash along with their attached files. Returns the
+/// IDs of the deleted jobs.
+fn purge_trash(
+    connection: &mut SqliteConnection,
+    cutoff: Option<&str>,
+) -> Result<Vec<i32>, FettersError> {
+    let deleted_job_ids = connection.transaction::<_, FettersError, _>(|connection| {
+        let mut job_repo = JobRepository { connection };
+        job_repo.purge_trashed_jobs(cutoff)
+    })?;
+
+    for job_id in &deleted_job_ids {
+        remove_attachment_dir(*job_id)?;
+    }
+
+    Ok(deleted_job_ids)
+}
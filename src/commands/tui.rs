@@ -0,0 +1,19 @@
+//! Contains a function called by the CLI when opening the full-screen dashboard.
+
+use diesel::SqliteConnection;
+
+use crate::{
+    errors::FettersError,
+    models::sprint::QueriedSprint,
+    tui::{dashboard::Dashboard, run_screen},
+};
+
+/// Open the full-screen dashboard on the current sprint.
+pub fn open_dashboard(
+    connection: &mut SqliteConnection,
+    current_sprint: &QueriedSprint,
+) -> Result<(), FettersError> {
+    let mut dashboard = Dashboard::new(connection, current_sprint)?;
+
+    run_screen(connection, &mut dashboard)
+}
@@ -0,0 +1,98 @@
+//! Contains functions called by the CLI when undoing or inspecting recorded operations.
+
+use chrono::Local;
+use diesel::{Connection, SqliteConnection};
+use inquire::Confirm;
+use owo_colors::OwoColorize;
+
+use crate::{
+    errors::FettersError,
+    models::operation::TabledOperation,
+    repositories::operation::OperationRepository,
+    utils::{
+        attachments::remove_attachment_dir, display::display_operations, prompt::get_inquire_config,
+    },
+};
+
+/// Undo the most recent operations that have not been undone yet. Each undone operation restores
+/// the job applications, interview stages, and sprints it changed to their previous state.
+pub fn undo(connection: &mut SqliteConnection, steps: usize) -> Result<(), FettersError> {
+    let mut operation_repo = OperationRepository { connection };
+
+    // Changes made while running this command are recorded in the current operation, which should
+    // not be undone.
+    let current_operation_id = operation_repo
+        .get_current_operation()?
+        .map_or(i32::MAX, |operation| operation.id);
+    let operations = operation_repo.get_undoable_operations(current_operation_id, steps as i64)?;
+
+    if operations.is_empty() {
+        println!("{}", "There is nothing to undo.".yellow().bold());
+        return Ok(());
+    }
+
+    let tabled_operations: Vec<TabledOperation> = operations
+        .iter()
+        .map(|(operation, changes)| TabledOperation::new(operation.clone(), changes))
+        .collect();
+    display_operations(&tabled_operations, "OPERATIONS TO UNDO");
+
+    match Confirm::new(&format!("Undo {} operations?", operations.len()))
+        .with_default(true)
+        .with_render_config(get_inquire_config())
+        .prompt_skippable()?
+    {
+        Some(true) => {
+            let timestamp = Local::now().format("%Y-%m-%d %H:%M:%S").to_string();
+
+            let removed_job_ids = connection.transaction::<_, FettersError, _>(|connection| {
+                let mut operation_repo = OperationRepository { connection };
+
+                let mut removed_job_ids = Vec::new();
+                for (operation, _) in &operations {
+                    removed_job_ids
+                        .extend(operation_repo.undo_operation(operation.id, &timestamp)?);
+                }
+
+                Ok(removed_job_ids)
+            })?;
+
+            // Jobs that no longer exist because adding them was undone may have attached files.
+            for job_id in removed_job_ids {
+                remove_attachment_dir(job_id)?;
+            }
+
+            println!(
+                "{}",
+                format!("\nUndid {} operations!\n", operations.len())
+                    .green()
+                    .bold()
+            );
+        }
+        _ => println!("{}", "Cancelled.".red().bold()),
+    }
+
+    Ok(())
+}
+
+/// Display the most recent operations, including operations that were undone.
+pub fn show_log(connection: &mut SqliteConnection, limit: i64) -> Result<(), FettersError> {
+    let mut operation_repo = OperationRepository { connection };
+    let operations = operation_repo.get_operations(limit)?;
+
+    if operations.is_empty() {
+        println!(
+            "{}",
+            "No operations have been recorded yet.".yellow().bold()
+        );
+        return Ok(());
+    }
+
+    let tabled_operations: Vec<TabledOperation> = operations
+        .into_iter()
+        .map(|(operation, changes)| TabledOperation::new(operation, &changes))
+        .collect();
+    display_operations(&tabled_operations, "OPERATION LOG");
+
+    Ok(())
+}
@@ -1,6 +1,7 @@
 //! Contains a function called by the CLI when updating a job.
 
-use diesel::SqliteConnection;
+use chrono::Local;
+use diesel::{Connection, SqliteConnection};
 use inquire::{Confirm, MultiSelect, Select, Text};
 use owo_colors::OwoColorize;
 use strum::{Display, EnumIter, IntoEnumIterator};
@@ -8,14 +9,30 @@ use strum::{Display, EnumIter, IntoEnumIterator};
 use crate::{
     cli::QueryArgs,
     errors::FettersError,
-    models::{job::JobUpdate, sprint::QueriedSprint, title::NewTitle},
+    models::{
+        custom_field::QueriedCustomField,
+        description::NewJobDescription,
+        document::DocumentKind,
+        job::{JobUpdate, TabledJob, TabledJobChange},
+        note::NewJobNote,
+        source::NewSource,
+        sprint::QueriedSprint,
+        tag::{NewTag, QueriedTag, normalize_tag_name},
+        title::NewTitle,
+    },
     repositories::{
-        job::JobRepository, sprint::SprintRepository, statuses::StatusRepository,
+        custom_field::CustomFieldRepository, description::DescriptionRepository,
+        job::JobRepository, note::NoteRepository, source::SourceRepository,
+        sprint::SprintRepository, statuses::StatusRepository, tag::TagRepository,
         title::TitleRepository,
     },
     utils::{
-        display::display_jobs,
+        custom_fields::input_custom_field_value,
+        description::{edit_description, read_description_file},
+        display::{display_job_changes, display_jobs},
+        documents::select_document,
         prompt::get_inquire_config,
+        sources::{SourceType, create_or_use_source},
         titles::{TitleType, create_or_use_title},
     },
 };
@@ -24,30 +41,29 @@ use crate::{
 pub fn update_job(
     connection: &mut SqliteConnection,
     query_args: &mut QueryArgs,
+    description_file: Option<&str>,
     current_sprint: &QueriedSprint,
 ) -> Result<(), FettersError> {
-    let default_sprint = Some(current_sprint.name.clone());
-
-    // Search the default sprint if no sprint filter was specified.
-    if query_args.sprint.is_none() {
-        query_args.sprint = default_sprint;
-    }
+    // `None` leaves the stored job description untouched while `Some(None)` removes it.
+    let mut new_description: Option<Option<String>> = match description_file {
+        Some(path) => read_description_file(path)?.map(Some),
+        None => None,
+    };
 
     let mut job_repo = JobRepository { connection };
     let matched_jobs = job_repo.list_jobs(query_args, current_sprint)?;
 
     if matched_jobs.is_empty() {
         return Err(FettersError::NoJobsAvailable(
-            query_args
-                .sprint
-                .clone()
-                .as_ref()
-                .unwrap_or(&current_sprint.name.clone())
-                .to_string(),
+            query_args.sprint_label(&current_sprint.name),
         ));
     }
 
-    display_jobs(&matched_jobs, &current_sprint.name);
+    display_jobs(
+        &matched_jobs,
+        &query_args.sprint_label(&current_sprint.name),
+        &[],
+    );
 
     if let Some(job) = Select::new("Select the job you want to modify:", matched_jobs)
         .with_render_config(get_inquire_config())
@@ -59,18 +75,45 @@ pub fn update_job(
         .with_render_config(get_inquire_config())
         .prompt_skippable()?
     {
+        let mut new_custom_field_values: Vec<(QueriedCustomField, Option<String>)> = Vec::new();
         let mut new_company_name: Option<String> = None;
         let mut new_link: Option<String> = None;
-        let mut new_notes: Option<String> = None;
+        let mut new_note: Option<String> = None;
+        let mut new_referrer: Option<String> = None;
+        let mut new_resume_id: Option<i32> = None;
+        let mut new_cover_letter_id: Option<i32> = None;
+        let mut new_source_id: Option<Option<i32>> = None;
         let mut new_sprint_id: Option<i32> = None;
         let mut new_status_id: Option<i32> = None;
         let mut new_title_id: Option<i32> = None;
 
         for selection in selections {
             match selection {
+                UpdatableField::CustomFields => {
+                    set_new_custom_field_values(
+                        connection,
+                        &job.custom_fields,
+                        &mut new_custom_field_values,
+                    )?;
+                }
                 UpdatableField::CompanyName => {
                     new_company_name = Some(input_prompt(&selection, &job.company_name)?);
                 }
+                UpdatableField::Description => {
+                    let previous_description = match new_description.take() {
+                        Some(description) => description,
+                        None => {
+                            let mut description_repo = DescriptionRepository { connection };
+                            description_repo
+                                .get_description(job.id)?
+                                .map(|description| description.content)
+                        }
+                    };
+
+                    new_description = Some(edit_description(
+                        &previous_description.unwrap_or("".to_string()),
+                    )?);
+                }
                 UpdatableField::Link => {
                     new_link = Some(input_prompt(
                         &selection,
@@ -78,11 +121,29 @@ pub fn update_job(
                     )?);
                 }
                 UpdatableField::Notes => {
-                    new_notes = Some(input_prompt(
+                    new_note =
+                        Some(input_prompt(&selection, "")?).filter(|note| !note.trim().is_empty());
+                }
+                UpdatableField::Referrer => {
+                    let mut job_repo = JobRepository { connection };
+                    let previous_referrer = job_repo.get_job(job.id)?.referrer;
+
+                    new_referrer = Some(input_prompt(
                         &selection,
-                        &job.notes.clone().unwrap_or("".to_string()),
+                        &previous_referrer.unwrap_or("".to_string()),
                     )?);
                 }
+                UpdatableField::Resume => {
+                    new_resume_id =
+                        select_document(connection, DocumentKind::Resume)?.map(|resume| resume.id);
+                }
+                UpdatableField::CoverLetter => {
+                    new_cover_letter_id = select_document(connection, DocumentKind::CoverLetter)?
+                        .map(|cover_letter| cover_letter.id);
+                }
+                UpdatableField::Source => {
+                    set_new_source(connection, &mut new_source_id)?;
+                }
                 UpdatableField::Sprint => {
                     set_new_sprint(connection, &mut new_sprint_id)?;
                 }
@@ -106,12 +167,60 @@ pub fn update_job(
                     title_id: new_title_id,
                     status_id: new_status_id,
                     link: new_link.as_deref(),
-                    notes: new_notes.as_deref(),
                     sprint_id: new_sprint_id,
+                    source_id: new_source_id.flatten(),
+                    referrer: new_referrer.as_deref(),
+                    resume_id: new_resume_id,
+                    cover_letter_id: new_cover_letter_id,
                 };
 
                 let mut job_repo = JobRepository { connection };
-                job_repo.update_job(job.id, job_update)?;
+                // Diesel refuses to run an update without any changes.
+                if job_update.company_name.is_some()
+                    || job_update.title_id.is_some()
+                    || job_update.status_id.is_some()
+                    || job_update.link.is_some()
+                    || job_update.sprint_id.is_some()
+                    || job_update.source_id.is_some()
+                    || job_update.referrer.is_some()
+                    || job_update.resume_id.is_some()
+                    || job_update.cover_letter_id.is_some()
+                {
+                    job_repo.update_job(job.id, job_update)?;
+                }
+                if new_source_id == Some(None) {
+                    job_repo.clear_source(job.id)?;
+                }
+
+                let mut field_repo = CustomFieldRepository { connection };
+                for (field, value) in &new_custom_field_values {
+                    match value {
+                        Some(value) => field_repo.set_value(job.id, field.id, value)?,
+                        None => field_repo.clear_value(job.id, field.id)?,
+                    }
+                }
+
+                if let Some(content) = &new_note {
+                    let mut note_repo = NoteRepository { connection };
+                    note_repo.add_note(NewJobNote {
+                        job_id: job.id,
+                        content: content.trim(),
+                        created: &Local::now().format("%Y-%m-%d %H:%M:%S").to_string(),
+                    })?;
+                }
+
+                let mut description_repo = DescriptionRepository { connection };
+                match &new_description {
+                    Some(Some(content)) => {
+                        description_repo.set_description(NewJobDescription {
+                            job_id: job.id,
+                            content,
+                            captured: &Local::now().format("%Y-%m-%d %H:%M:%S").to_string(),
+                        })?;
+                    }
+                    Some(None) => description_repo.delete_description(job.id)?,
+                    None => {}
+                }
 
                 println!(
                     "{}",
@@ -133,6 +242,256 @@ pub fn update_job(
     Ok(())
 }
 
+/// Apply the same status, sprint, and tag changes to multiple job applications in a single
+/// transaction. Jobs are looked up by ID if any IDs were provided. Otherwise, you will be prompted
+/// to select jobs matching the query.
+pub fn bulk_update_jobs(
+    connection: &mut SqliteConnection,
+    query_args: &mut QueryArgs,
+    job_ids: &[i32],
+    current_sprint: &QueriedSprint,
+) -> Result<(), FettersError> {
+    let jobs = if job_ids.is_empty() {
+        select_jobs(connection, query_args, current_sprint)?
+    } else {
+        let mut job_repo = JobRepository { connection };
+        let mut jobs = Vec::new();
+        for job_id in job_ids {
+            jobs.push(job_repo.get_tabled_job(*job_id)?);
+        }
+        jobs
+    };
+
+    if jobs.is_empty() {
+        println!("{}", "No job applications were selected.".yellow().bold());
+        return Ok(());
+    }
+
+    let selections = match MultiSelect::new(
+        &format!(
+            "Select the fields you want to update for {} jobs:",
+            jobs.len()
+        ),
+        BulkUpdatableField::iter().collect(),
+    )
+    .with_render_config(get_inquire_config())
+    .prompt_skippable()?
+    {
+        Some(selections) if !selections.is_empty() => selections,
+        _ => return Ok(()),
+    };
+
+    let mut new_sprint_id: Option<i32> = None;
+    let mut new_status_id: Option<i32> = None;
+    let mut tags_to_add: Vec<String> = Vec::new();
+    let mut tags_to_remove: Vec<QueriedTag> = Vec::new();
+
+    for selection in selections {
+        match selection {
+            BulkUpdatableField::Status => set_new_status(connection, &mut new_status_id)?,
+            BulkUpdatableField::Sprint => set_new_sprint(connection, &mut new_sprint_id)?,
+            BulkUpdatableField::AddTags => tags_to_add = select_tags_to_add(connection)?,
+            BulkUpdatableField::RemoveTags => {
+                tags_to_remove = select_tags_to_remove(connection, &jobs)?
+            }
+        }
+    }
+
+    if new_status_id.is_none()
+        && new_sprint_id.is_none()
+        && tags_to_add.is_empty()
+        && tags_to_remove.is_empty()
+    {
+        println!("{}", "No changes were selected.".yellow().bold());
+        return Ok(());
+    }
+
+    match Confirm::new(&format!("Apply these changes to {} jobs?", jobs.len()))
+        .with_default(true)
+        .with_render_config(get_inquire_config())
+        .prompt_skippable()?
+    {
+        Some(true) => {}
+        _ => {
+            println!("{}", "Cancelled.".red().bold());
+            return Ok(());
+        }
+    }
+
+    let mut previous_sprints = Vec::new();
+    for job in &jobs {
+        previous_sprints.push(get_sprint_name(connection, job.id)?);
+    }
+
+    connection.transaction::<_, FettersError, _>(|connection| {
+        for job in &jobs {
+            if new_status_id.is_some() || new_sprint_id.is_some() {
+                let mut job_repo = JobRepository { connection };
+                job_repo.update_job(
+                    job.id,
+                    JobUpdate {
+                        status_id: new_status_id,
+                        sprint_id: new_sprint_id,
+                        ..Default::default()
+                    },
+                )?;
+            }
+
+            let mut tag_repo = TagRepository { connection };
+            for tag_name in &tags_to_add {
+                let tag = tag_repo.add_tag(NewTag { name: tag_name })?;
+                tag_repo.tag_job(job.id, tag.id)?;
+            }
+            for tag in &tags_to_remove {
+                tag_repo.untag_job(job.id, tag.id)?;
+            }
+        }
+
+        Ok(())
+    })?;
+
+    let mut changes = Vec::new();
+    for (job, previous_sprint) in jobs.iter().zip(previous_sprints) {
+        let mut job_repo = JobRepository { connection };
+        let updated_job = job_repo.get_tabled_job(job.id)?;
+        let sprint = get_sprint_name(connection, job.id)?;
+
+        changes.push(TabledJobChange {
+            id: job.id,
+            company_name: job.company_name.clone(),
+            status: TabledJobChange::describe_change(
+                job.status.as_deref().unwrap_or_default(),
+                updated_job.status.as_deref().unwrap_or_default(),
+            ),
+            sprint: TabledJobChange::describe_change(&previous_sprint, &sprint),
+            tags: TabledJobChange::describe_change(
+                job.tags.as_deref().unwrap_or_default(),
+                updated_job.tags.as_deref().unwrap_or_default(),
+            ),
+        });
+    }
+
+    display_job_changes(&changes);
+
+    Ok(())
+}
+
+/// This enum contains all fields that can be updated for multiple jobs at once.
+#[derive(Debug, Display, EnumIter)]
+enum BulkUpdatableField {
+    /// Update the application status.
+    #[strum(to_string = "Status")]
+    Status,
+    /// Move the jobs to another sprint.
+    #[strum(to_string = "Sprint")]
+    Sprint,
+    /// Apply existing or new tags.
+    #[strum(to_string = "Add Tags")]
+    AddTags,
+    /// Remove tags applied to any of the jobs.
+    #[strum(to_string = "Remove Tags")]
+    RemoveTags,
+}
+
+/// Select multiple job applications from the query results.
+fn select_jobs(
+    connection: &mut SqliteConnection,
+    query_args: &mut QueryArgs,
+    current_sprint: &QueriedSprint,
+) -> Result<Vec<TabledJob>, FettersError> {
+    let mut job_repo = JobRepository { connection };
+    let matched_jobs = job_repo.list_jobs(query_args, current_sprint)?;
+
+    if matched_jobs.is_empty() {
+        return Err(FettersError::NoJobsAvailable(
+            query_args.sprint_label(&current_sprint.name),
+        ));
+    }
+
+    display_jobs(
+        &matched_jobs,
+        &query_args.sprint_label(&current_sprint.name),
+        &[],
+    );
+
+    Ok(
+        MultiSelect::new("Select the jobs you want to modify:", matched_jobs)
+            .with_render_config(get_inquire_config())
+            .prompt_skippable()?
+            .unwrap_or_default(),
+    )
+}
+
+/// Select existing tags or enter new tags to apply to the selected jobs.
+fn select_tags_to_add(connection: &mut SqliteConnection) -> Result<Vec<String>, FettersError> {
+    let mut tag_repo = TagRepository { connection };
+    let all_tags = tag_repo.get_all_tags()?;
+
+    let mut tag_names: Vec<String> = Vec::new();
+
+    if !all_tags.is_empty()
+        && let Some(selected_tags) = MultiSelect::new("Select existing tags to apply:", all_tags)
+            .with_render_config(get_inquire_config())
+            .prompt_skippable()?
+    {
+        tag_names.extend(selected_tags.into_iter().map(|tag| tag.name));
+    }
+
+    if let Some(new_tags) = Text::new("[OPTIONAL] Enter new tags separated by commas:")
+        .with_render_config(get_inquire_config())
+        .prompt_skippable()?
+    {
+        for new_tag in new_tags.split(',').map(normalize_tag_name) {
+            if !new_tag.is_empty() && !tag_names.contains(&new_tag) {
+                tag_names.push(new_tag);
+            }
+        }
+    }
+
+    Ok(tag_names)
+}
+
+/// Select tags to remove from every selected job. Only tags applied to at least one of the jobs
+/// are listed.
+fn select_tags_to_remove(
+    connection: &mut SqliteConnection,
+    jobs: &[TabledJob],
+) -> Result<Vec<QueriedTag>, FettersError> {
+    let mut tag_repo = TagRepository { connection };
+    let mut applied_tags: Vec<QueriedTag> = Vec::new();
+    for job in jobs {
+        for tag in tag_repo.get_tags_for_job(job.id)? {
+            if !applied_tags.iter().any(|applied| applied.id == tag.id) {
+                applied_tags.push(tag);
+            }
+        }
+    }
+
+    if applied_tags.is_empty() {
+        println!(
+            "{}",
+            "No tags are applied to the selected jobs.".yellow().bold()
+        );
+        return Ok(Vec::new());
+    }
+
+    applied_tags.sort_by(|a, b| a.name.cmp(&b.name));
+
+    Ok(MultiSelect::new("Select the tags to remove:", applied_tags)
+        .with_render_config(get_inquire_config())
+        .prompt_skippable()?
+        .unwrap_or_default())
+}
+
+/// Get the name of the sprint a job application belongs to.
+fn get_sprint_name(connection: &mut SqliteConnection, job_id: i32) -> Result<String, FettersError> {
+    let mut job_repo = JobRepository { connection };
+    let sprint_id = job_repo.get_job(job_id)?.sprint_id;
+
+    let mut sprint_repo = SprintRepository { connection };
+    Ok(sprint_repo.get_sprint(sprint_id)?.name)
+}
+
 /// This enum contains all updatable fields users can choose from.
 #[derive(Debug, Display, EnumIter)]
 enum UpdatableField {
@@ -148,12 +507,30 @@ enum UpdatableField {
     /// Update the link to the job listing/application.
     #[strum(to_string = "Link")]
     Link,
-    /// Update notes for this application.
-    #[strum(to_string = "Notes")]
+    /// Append a note to this application's notes log.
+    #[strum(to_string = "Add Note")]
     Notes,
+    /// Update the stored job description in `$EDITOR`.
+    #[strum(to_string = "Job Description")]
+    Description,
+    /// Update the channel through which this application was submitted.
+    #[strum(to_string = "Source")]
+    Source,
+    /// Update the contact who referred you for this application.
+    #[strum(to_string = "Referrer")]
+    Referrer,
+    /// Update the resume version submitted with this application.
+    #[strum(to_string = "Resume")]
+    Resume,
+    /// Update the cover letter version submitted with this application.
+    #[strum(to_string = "Cover Letter")]
+    CoverLetter,
     /// Update the sprint this job belongs to.
     #[strum(to_string = "Sprint")]
     Sprint,
+    /// Update the values of user-defined custom fields.
+    #[strum(to_string = "Custom Fields")]
+    CustomFields,
 }
 
 /// Show an input prompt for text-based fields.
@@ -165,7 +542,8 @@ fn input_prompt(
         UpdatableField::CompanyName => "Enter a new company name:",
         UpdatableField::Title => "Enter a new job title:",
         UpdatableField::Link => "Enter a new link to this job listing:",
-        UpdatableField::Notes => "Enter new notes for this application:",
+        UpdatableField::Notes => "Enter a note to add to this application:",
+        UpdatableField::Referrer => "Enter the name of the person who referred you:",
         _ => "Shiiii something went wrong here...",
     };
 
@@ -185,7 +563,10 @@ fn input_prompt(
             {
                 println!("{}", "A new value is required for this field!".red().bold())
             }
-            (Some(input), UpdatableField::Link | UpdatableField::Notes)
+            (
+                Some(input),
+                UpdatableField::Link | UpdatableField::Notes | UpdatableField::Referrer,
+            )
                 if input.trim().is_empty() =>
             {
                 return Ok("".to_string());
@@ -223,6 +604,62 @@ fn set_new_sprint(
     }
 }
 
+/// Set a new application source for this application. Skipping the prompt clears the source.
+fn set_new_source(
+    connection: &mut SqliteConnection,
+    new_source_id: &mut Option<Option<i32>>,
+) -> Result<(), FettersError> {
+    *new_source_id = Some(match create_or_use_source(connection)? {
+        Some(SourceType::NewSource(new_source)) => {
+            let mut source_repo = SourceRepository { connection };
+            Some(source_repo.add_source(NewSource { name: &new_source })?.id)
+        }
+        Some(SourceType::QueriedSource(queried_source)) => Some(queried_source.id),
+        None => None,
+    });
+
+    Ok(())
+}
+
+/// Select the custom fields to update and set new values for them. Skipping a prompt or entering
+/// an empty value clears the field.
+fn set_new_custom_field_values(
+    connection: &mut SqliteConnection,
+    previous_values: &[(String, String)],
+    new_values: &mut Vec<(QueriedCustomField, Option<String>)>,
+) -> Result<(), FettersError> {
+    let mut field_repo = CustomFieldRepository { connection };
+    let all_fields = field_repo.get_all_fields()?;
+
+    if all_fields.is_empty() {
+        println!(
+            "{}",
+            "There are currently no custom fields! Run `fetters field add` to create one."
+                .yellow()
+                .bold()
+        );
+        return Ok(());
+    }
+
+    if let Some(selected_fields) =
+        MultiSelect::new("Select the custom fields you want to update:", all_fields)
+            .with_render_config(get_inquire_config())
+            .prompt_skippable()?
+    {
+        for field in selected_fields {
+            let previous_value = previous_values
+                .iter()
+                .find(|(field_name, _)| *field_name == field.name)
+                .map(|(_, value)| value.as_str());
+            let new_value = input_custom_field_value(&field, previous_value)?;
+
+            new_values.push((field, new_value));
+        }
+    }
+
+    Ok(())
+}
+
 /// Set a new status for this application.
 fn set_new_status(
     connection: &mut SqliteConnection,
@@ -251,7 +688,7 @@ fn set_new_title(
     connection: &mut SqliteConnection,
     new_title_id: &mut Option<i32>,
 ) -> Result<(), FettersError> {
-    let title_type = create_or_use_title(connection)?;
+    let title_type = create_or_use_title(connection, None)?;
     let title_id = match title_type {
         TitleType::NewTitle(new_title) => {
             let mut title_repo = TitleRepository { connection };
@@ -0,0 +1,90 @@
+//! Contains functions called by the CLI when managing saved views.
+
+use diesel::SqliteConnection;
+use owo_colors::OwoColorize;
+
+use crate::{
+    cli::{JobColumn, OutputFormat, QueryArgs},
+    commands::list::list_jobs,
+    config::configuration::{Config, SavedView},
+    errors::FettersError,
+    models::sprint::QueriedSprint,
+    utils::display::display_views,
+};
+
+/// Save a query as a named view in the configuration file, replacing any view with the same name.
+pub fn save_view(config: Config, name: &str, view: SavedView) -> Result<(), FettersError> {
+    let mut new_config = config;
+    let replaced = new_config.views.insert(name.to_string(), view).is_some();
+    new_config.save_to_file()?;
+
+    let message = if replaced {
+        format!("\nReplaced saved view @{name}!\n")
+    } else {
+        format!("\nSaved view @{name}! Run it with `fetters list @{name}`.\n")
+    };
+    println!("{}", message.green().bold());
+
+    Ok(())
+}
+
+/// List the job applications matching a saved view. Query options in `overrides` refine the saved
+/// query, and `format` and `columns` replace the saved output format and columns if provided.
+pub fn run_view(
+    connection: &mut SqliteConnection,
+    config: &Config,
+    name: &str,
+    overrides: QueryArgs,
+    format: Option<OutputFormat>,
+    columns: &[JobColumn],
+    current_sprint: &QueriedSprint,
+) -> Result<(), FettersError> {
+    let view = config
+        .views
+        .get(name)
+        .ok_or(FettersError::UnknownView(name.to_string()))?;
+    let query_args = view.query_args.clone().with_overrides(overrides);
+    let columns = [columns, &view.columns, &config.columns]
+        .into_iter()
+        .find(|columns| !columns.is_empty())
+        .unwrap_or_default();
+
+    list_jobs(
+        connection,
+        &query_args,
+        format.unwrap_or(view.format),
+        columns,
+        current_sprint,
+    )
+}
+
+/// Display all saved views.
+pub fn list_views(config: &Config) {
+    if config.views.is_empty() {
+        println!(
+            "{}",
+            "There are currently no saved views! Save one with `fetters view save`."
+                .yellow()
+                .bold()
+        );
+        return;
+    }
+
+    display_views(&config.views);
+}
+
+/// Delete a saved view from the configuration file.
+pub fn delete_view(config: Config, name: &str) -> Result<(), FettersError> {
+    let mut new_config = config;
+    if new_config.views.remove(name).is_none() {
+        return Err(FettersError::UnknownView(name.to_string()));
+    }
+    new_config.save_to_file()?;
+
+    println!(
+        "{}",
+        format!("\nDeleted saved view @{name}!\n").green().bold()
+    );
+
+    Ok(())
+}
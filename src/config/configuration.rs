@@ -1,5 +1,6 @@
 //! Contains all functionality pertaining to modifying the configuration file for `fetters`.
 
+use std::collections::BTreeMap;
 use std::fs::{File, create_dir_all, read_to_string};
 use std::io::Write;
 use std::path::PathBuf;
@@ -10,10 +11,11 @@ use owo_colors::OwoColorize;
 use serde::{Deserialize, Serialize};
 use toml;
 
+use crate::cli::{JobColumn, OutputFormat, QueryArgs};
 use crate::errors::FettersError;
 
 /// Contains all configuration settings that will be stored in `fetters.toml`.
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct Config {
     /// The path to the configuration file.
     pub config_path: PathBuf,
@@ -21,6 +23,59 @@ pub struct Config {
     pub current_sprint: String,
     /// The path to the SQLite database.
     pub db_path: String,
+    /// The number of days after which deleted job applications are permanently removed from the
+    /// trash. Set this to 0 to keep them until the trash is emptied.
+    #[serde(default = "default_trash_purge_days")]
+    pub trash_purge_days: u32,
+    /// The columns displayed in job tables, ie. `["id", "company", "status", "age"]`. The
+    /// default columns are displayed if this is empty.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub columns: Vec<JobColumn>,
+    /// Named queries saved with `fetters view save`, keyed by name.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub views: BTreeMap<String, SavedView>,
+    /// The token clients must send to the local HTTP API started with `fetters serve`. A token is
+    /// generated the first time the API is started.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub api_token: Option<String>,
+}
+
+/// A named query saved in `fetters.toml`. The query options are stored under the same names as
+/// their command-line flags, ie. `status = "PENDING"` or `sort = "created:desc"`.
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct SavedView {
+    /// The output format used when running this view.
+    #[serde(default)]
+    pub format: OutputFormat,
+    /// The columns displayed when running this view. The configured columns are used if this is
+    /// empty.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub columns: Vec<JobColumn>,
+    /// The saved query options.
+    #[serde(flatten)]
+    pub query_args: QueryArgs,
+}
+
+/// Deleted job applications are kept in the trash for 30 days by default.
+fn default_trash_purge_days() -> u32 {
+    30
+}
+
+impl SavedView {
+    /// Describe the saved query options on a single line, ie. `status = "PENDING", sort = "id"`.
+    pub fn describe_query(&self) -> String {
+        let options = toml::to_string(&self.query_args)
+            .unwrap_or_default()
+            .lines()
+            .map(|line| line.to_string())
+            .collect::<Vec<String>>();
+
+        if options.is_empty() {
+            "All job applications in the current sprint".to_string()
+        } else {
+            options.join(", ")
+        }
+    }
 }
 
 impl Config {
@@ -29,7 +84,9 @@ impl Config {
         let config_path = Self::get_config_dir_path()?.join("fetters.toml");
 
         if !config_path.exists() {
-            println!(
+            // This is printed to stderr so it does not corrupt the messages of the native messaging
+            // host, which may be the first command that is run.
+            eprintln!(
                 "{}",
                 format!(
                     "ℹ️  Config file not found, creating default at {:?}",
@@ -47,6 +104,10 @@ impl Config {
                     .join("fetters.db")
                     .to_string_lossy()
                     .into_owned(),
+                trash_purge_days: default_trash_purge_days(),
+                columns: Vec::new(),
+                views: BTreeMap::new(),
+                api_token: None,
             };
             config.save_to_file()?;
 
@@ -69,7 +130,7 @@ impl Config {
     }
 
     /// Get the project data directory path.
-    fn get_data_dir_path() -> Result<PathBuf, FettersError> {
+    pub fn get_data_dir_path() -> Result<PathBuf, FettersError> {
         if let Some(ref project_directory) = ProjectDirs::from("", "", "fetters") {
             return Ok(project_directory.data_dir().to_owned());
         }
@@ -120,6 +181,10 @@ mod tests {
             config_path: config_path.clone(),
             current_sprint: "2025-01-15".to_string(),
             db_path: "/tmp/test.db".to_string(),
+            trash_purge_days: 30,
+            columns: Vec::new(),
+            views: BTreeMap::new(),
+            api_token: None,
         };
         config.save_to_file().unwrap();
 
@@ -147,6 +212,10 @@ mod tests {
             config_path: config_path.clone(),
             current_sprint: "sprint-1".to_string(),
             db_path: "/tmp/test.db".to_string(),
+            trash_purge_days: 30,
+            columns: Vec::new(),
+            views: BTreeMap::new(),
+            api_token: None,
         };
         config1.save_to_file().unwrap();
 
@@ -154,6 +223,10 @@ mod tests {
             config_path: config_path.clone(),
             current_sprint: "sprint-2".to_string(),
             db_path: "/tmp/test.db".to_string(),
+            trash_purge_days: 30,
+            columns: Vec::new(),
+            views: BTreeMap::new(),
+            api_token: None,
         };
         config2.save_to_file().unwrap();
 
@@ -161,4 +234,114 @@ mod tests {
         let loaded: Config = toml::from_str(&content).unwrap();
         assert_eq!(loaded.current_sprint, "sprint-2");
     }
+
+    #[test]
+    fn test_load_config_without_trash_purge_days_uses_default() {
+        let loaded: Config = toml::from_str(
+            r#"
+config_path = "/tmp/fetters.toml"
+current_sprint = "2025-01-15"
+db_path = "/tmp/test.db"
+"#,
+        )
+        .unwrap();
+        assert_eq!(loaded.trash_purge_days, 30);
+        assert!(loaded.columns.is_empty());
+        assert!(loaded.views.is_empty());
+        assert!(loaded.api_token.is_none());
+    }
+
+    #[test]
+    fn test_save_and_load_views_roundtrip() {
+        let dir = tempfile::tempdir().unwrap();
+        let config_path = dir.path().join("fetters.toml");
+
+        let query_args = QueryArgs {
+            status: Some("PENDING".to_string()),
+            tags: vec!["remote".to_string()],
+            fields: vec![("Salary".to_string(), "150000".to_string())],
+            all_sprints: true,
+            since: Some("2w".parse().unwrap()),
+            sort: Some("created:desc".parse().unwrap()),
+            limit: Some(10),
+            where_clause: Some("title:backend AND NOT company:acme".parse().unwrap()),
+            ..Default::default()
+        };
+        let mut views = BTreeMap::new();
+        views.insert(
+            "stale".to_string(),
+            SavedView {
+                format: OutputFormat::Json,
+                columns: vec![
+                    JobColumn::Id,
+                    JobColumn::Company,
+                    JobColumn::Age,
+                    JobColumn::Field("Salary".to_string()),
+                ],
+                query_args: query_args.clone(),
+            },
+        );
+
+        let config = Config {
+            config_path: config_path.clone(),
+            current_sprint: "2025-01-15".to_string(),
+            db_path: "/tmp/test.db".to_string(),
+            trash_purge_days: 30,
+            columns: Vec::new(),
+            views,
+            api_token: Some("5f0c2e9a".to_string()),
+        };
+        config.save_to_file().unwrap();
+
+        let content = fs::read_to_string(&config_path).unwrap();
+        assert!(content.contains("[views.stale]"));
+        assert!(content.contains(r#"sort = "created:desc""#));
+        assert!(content.contains(r#""Salary""#));
+        assert!(content.contains(r#"where = "title:backend AND NOT company:acme""#));
+        assert!(!content.contains("company ="));
+
+        let loaded: Config = toml::from_str(&content).unwrap();
+        let view = &loaded.views["stale"];
+        assert_eq!(view.format, OutputFormat::Json);
+        assert_eq!(view.columns[3], JobColumn::Field("Salary".to_string()));
+        assert_eq!(view.query_args, query_args);
+        assert_eq!(loaded.api_token.as_deref(), Some("5f0c2e9a"));
+    }
+
+    #[test]
+    fn test_describe_query() {
+        let view = SavedView {
+            format: OutputFormat::Text,
+            columns: Vec::new(),
+            query_args: QueryArgs {
+                status: Some("PENDING".to_string()),
+                sort: Some("created:desc".parse().unwrap()),
+                ..Default::default()
+            },
+        };
+        assert_eq!(
+            view.describe_query(),
+            r#"status = "PENDING", sort = "created:desc""#
+        );
+
+        assert_eq!(
+            SavedView::default().describe_query(),
+            "All job applications in the current sprint"
+        );
+    }
+
+    #[test]
+    fn test_load_view_with_invalid_expression() {
+        let result = toml::from_str::<Config>(
+            r#"
+config_path = "/tmp/fetters.toml"
+current_sprint = "2025-01-15"
+db_path = "/tmp/test.db"
+
+[views.broken]
+where = "status:"
+"#,
+        );
+        assert!(result.is_err());
+    }
 }
@@ -14,6 +14,32 @@ pub enum FettersError {
     #[error("Diesel query result error: {0}")]
     DieselResultError(#[from] diesel::result::Error),
 
+    /// This error may be raised if the file of a resume or cover letter version was moved or
+    /// deleted after it was added.
+    #[error("The file {0} no longer exists. Add the moved file as a new version.")]
+    DocumentNotFound(String),
+
+    /// This error may be raised if a custom field is created with a blank name.
+    #[error("Custom field names cannot be empty.")]
+    EmptyCustomFieldName,
+
+    /// This error may be raised if a job application edited in `$EDITOR` contains invalid values.
+    #[error("Invalid edit: {0}")]
+    InvalidEdit(String),
+
+    /// This error may be raised if a full-text search query is malformed or filters by a column
+    /// that does not exist.
+    #[error("Invalid search query: {0}")]
+    InvalidSearchQuery(String),
+
+    /// This error may be raised if a custom field value does not match the field's type.
+    #[error("Invalid custom field value: {0}")]
+    InvalidCustomFieldValue(String),
+
+    /// Something went wrong when serializing JSON.
+    #[error("JSON serialization error: {0}")]
+    JSONSerializationError(#[from] serde_json::Error),
+
     /// An IO error occurred.
     #[error("IO Error: {0}")]
     IOError(#[from] std::io::Error),
@@ -22,20 +48,50 @@ pub enum FettersError {
     #[error("Inquire error: {0}")]
     InquireError(#[from] inquire::error::InquireError),
 
+    /// This error may be raised if the user looks up a job application by an ID that does not
+    /// exist.
+    #[error("There is no job application with ID {0}.")]
+    JobNotFound(i32),
+
+    /// This error may be raised if the user tries to restore a job application that is not in the
+    /// trash.
+    #[error("There is no job application with ID {0} in the trash.")]
+    JobNotInTrash(i32),
+
     /// Something fucked up when running the SQLite migrations with `diesel_migrations`.
     #[error("Failed to run migrations!")]
     MigrationFailure,
 
+    /// This error may be raised if the browser extension sends a message larger than the native
+    /// messaging host accepts.
+    #[error("The message of {0} bytes is larger than the 1 MiB limit.")]
+    NativeMessageTooLarge(usize),
+
     /// This error may be raised if the user tries to update or delete a job, but no job
     /// applications have been tracked for the current sprint.
     #[error("No job applications tracked for the current sprint [{0}]")]
     NoJobsAvailable(String),
 
+    /// Something went wrong when starting the local HTTP API.
+    #[error("Server error: {0}")]
+    ServerError(String),
+
     /// This error is used when a result returns an error message. This is currently used to
     /// propagate the error returned when attempting to call `book.set_sheet_name()`.
     #[error("Set sheet name error: {0}")]
     SheetNameError(String),
 
+    /// Something went wrong when reading a line in the interactive shell.
+    #[error("Shell error: {0}")]
+    ShellError(#[from] rustyline::error::ReadlineError),
+
+    /// Undoing the creation of a sprint would leave the job applications added to it since then
+    /// without a sprint.
+    #[error(
+        "The sprint {0} still has job applications. Undo adding them or move them to another sprint first."
+    )]
+    SprintInUse(String),
+
     /// This error may be raised if the user attempts to create two new sprints in the same day,
     /// causing a sprint naming conflict (all sprint names should be unique).
     #[error("There is already a sprint with name {0}. Try renaming the sprint.")]
@@ -53,6 +109,25 @@ pub enum FettersError {
     #[error("TOML serialization error: {0}")]
     TOMLSerializationError(#[from] toml::ser::Error),
 
+    /// This error may be raised if the user displays a column that is neither built in nor a
+    /// custom field.
+    #[error(
+        "There is no column named {0}. Use id, created, company, title, status, source, stages, tags, link, notes, age, activity, or a custom field name."
+    )]
+    UnknownColumn(String),
+
+    /// This error may be raised if the user filters by a custom field that has not been defined.
+    #[error("There is no custom field named {0}. Run `fetters field list` to see all fields.")]
+    UnknownCustomField(String),
+
+    /// This error may be raised if the user generates a report for a sprint that does not exist.
+    #[error("There is no sprint named {0}. Run `fetters sprint show-all` to see all sprints.")]
+    UnknownSprint(String),
+
+    /// This error may be raised if the user runs or deletes a saved view that does not exist.
+    #[error("There is no saved view named {0}. Run `fetters view list` to see all views.")]
+    UnknownView(String),
+
     /// An unknown error occurred.
     #[error("{0}")]
     UnknownError(String),
@@ -90,6 +165,21 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_error_display_document_not_found() {
+        let error = FettersError::DocumentNotFound("/tmp/resume.pdf".to_string());
+        assert_eq!(
+            format!("{}", error),
+            "The file /tmp/resume.pdf no longer exists. Add the moved file as a new version."
+        );
+    }
+
+    #[test]
+    fn test_error_display_empty_custom_field_name() {
+        let error = FettersError::EmptyCustomFieldName;
+        assert_eq!(format!("{}", error), "Custom field names cannot be empty.");
+    }
+
     #[test]
     fn test_error_display_migration_failure() {
         let error = FettersError::MigrationFailure;
@@ -120,6 +210,39 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_error_display_sprint_in_use() {
+        let error = FettersError::SprintInUse("2025-01-15".to_string());
+        assert_eq!(
+            format!("{}", error),
+            "The sprint 2025-01-15 still has job applications. Undo adding them or move them to another sprint first."
+        );
+    }
+
+    #[test]
+    fn test_error_display_job_not_found() {
+        let error = FettersError::JobNotFound(42);
+        assert_eq!(format!("{}", error), "There is no job application with ID 42.");
+    }
+
+    #[test]
+    fn test_error_display_unknown_custom_field() {
+        let error = FettersError::UnknownCustomField("Team".to_string());
+        assert_eq!(
+            format!("{}", error),
+            "There is no custom field named Team. Run `fetters field list` to see all fields."
+        );
+    }
+
+    #[test]
+    fn test_error_display_unknown_view() {
+        let error = FettersError::UnknownView("stale".to_string());
+        assert_eq!(
+            format!("{}", error),
+            "There is no saved view named stale. Run `fetters view list` to see all views."
+        );
+    }
+
     #[test]
     fn test_error_display_unknown_error() {
         let error = FettersError::UnknownError("something broke".to_string());
@@ -1,5 +1,6 @@
 //! `fetters` - a CLI tool for tracking your job applications.
 
+mod api;
 mod cli;
 mod commands;
 mod config;
@@ -8,28 +9,54 @@ mod models;
 mod repositories;
 mod schema;
 mod sqlite;
+mod tui;
 mod utils;
 
 use clap::Parser;
+use diesel::SqliteConnection;
 use lazy_static::lazy_static;
 use owo_colors::OwoColorize;
 
-use crate::cli::{Cli, Command, ConfigOption, SprintOption, StageOption};
+use crate::cli::{
+    Cli, Command, ConfigOption, DocOption, FieldOption, NoteOption, QueryArgs, ReportOption,
+    SprintOption, StageOption, TagOption, TrashOption, ViewOption,
+};
 use crate::commands::add::add_job;
+use crate::commands::attach::attach_file;
+use crate::commands::board::show_board;
 use crate::commands::config::edit_config;
 use crate::commands::delete::delete_job;
+use crate::commands::doc::{add_document, list_documents, open_document};
+use crate::commands::edit::edit_job;
 use crate::commands::export::export_jobs;
+use crate::commands::field::{add_field, delete_field, list_fields};
 use crate::commands::insights::show_insights;
 use crate::commands::list::list_jobs;
+use crate::commands::native_host::run_native_host;
+use crate::commands::note::{add_note, list_notes};
 use crate::commands::open::open_application;
+use crate::commands::report::write_html_report;
+use crate::commands::shell::start_shell;
+use crate::commands::show::show_job;
+use crate::commands::search::search_jobs;
+use crate::commands::serve::start_server;
 use crate::commands::sprint::{
     create_new_sprint, set_sprint, show_all_sprints, show_current_sprint,
 };
 use crate::commands::stage::{add_stage, delete_stage, show_stage_tree, update_stage};
-use crate::commands::update::update_job;
-use crate::config::configuration::Config;
+use crate::commands::tag::{add_tags, list_tags, remove_tags};
+use crate::commands::trash::{empty_trash, list_trash, purge_expired_trash, restore_from_trash};
+use crate::commands::tui::open_dashboard;
+use crate::commands::undo::{show_log, undo};
+use crate::commands::update::{bulk_update_jobs, update_job};
+use crate::commands::view::{delete_view, list_views, run_view, save_view};
+use crate::config::configuration::{Config, SavedView};
 use crate::errors::FettersError;
-use crate::repositories::{sprint::SprintRepository, statuses::StatusRepository};
+use crate::models::operation::NewOperation;
+use crate::models::sprint::QueriedSprint;
+use crate::repositories::{
+    operation::OperationRepository, sprint::SprintRepository, statuses::StatusRepository,
+};
 use crate::sqlite::Database;
 use crate::utils::migrations::run_migrations;
 
@@ -51,20 +78,70 @@ fn main() -> Result<(), FettersError> {
     };
     status_repo.seed_statuses()?;
 
+    // Permanently delete job applications that have been in the trash for too long.
+    // This is printed to stderr since the native messaging host may not print anything else to
+    // stdout.
+    if let Err(error) = purge_expired_trash(&mut database.connection, config.trash_purge_days) {
+        eprintln!("{}", error.red().bold());
+    }
+
+    let cli = Cli::parse();
+
     let mut sprint_repo = SprintRepository {
         connection: &mut database.connection,
     };
     let current_sprint = sprint_repo.get_current_sprint(&config.current_sprint)?;
 
-    let cli = Cli::parse();
+    // Every change made by this command is recorded in a new operation so it can be undone.
+    let mut operation_repo = OperationRepository {
+        connection: &mut database.connection,
+    };
+    let command = std::iter::once("fetters".to_string())
+        .chain(std::env::args().skip(1))
+        .collect::<Vec<String>>()
+        .join(" ");
+    operation_repo.begin_operation(NewOperation {
+        performed: &chrono::Local::now()
+            .format("%Y-%m-%d %H:%M:%S")
+            .to_string(),
+        command: &command,
+    })?;
+
+    run_command(cli.command, &mut database.connection, config, &current_sprint);
+
+    let mut operation_repo = OperationRepository {
+        connection: &mut database.connection,
+    };
+    operation_repo.finish_operation()?;
+
+    Ok(())
+}
 
-    match cli.command {
-        Command::Add { company } => {
-            if let Err(error) = add_job(&mut database.connection, &company, &current_sprint) {
+/// Run a single command. Errors are displayed rather than returned so the shell keeps running
+/// after a command fails.
+fn run_command(
+    command: Command,
+    connection: &mut SqliteConnection,
+    config: Config,
+    current_sprint: &QueriedSprint,
+) {
+    match command {
+        Command::Add(add_args) => {
+            if let Err(error) = add_job(connection, &add_args, current_sprint) {
+                println!("{}", error.red().bold());
+            }
+        }
+        Command::Attach { job_id, file } => {
+            if let Err(error) = attach_file(connection, job_id, &file) {
                 println!("{}", error.red().bold());
             }
         }
         Command::Banner => println!("{}", String::from_utf8_lossy(&ASCII_ART[..]).red().bold()),
+        Command::Board { interactive } => {
+            if let Err(error) = show_board(connection, interactive, current_sprint) {
+                println!("{}", error.red().bold());
+            }
+        }
         Command::Config(config_option) => match config_option {
             ConfigOption::Edit => {
                 if let Err(error) = edit_config() {
@@ -77,95 +154,307 @@ fn main() -> Result<(), FettersError> {
         },
         Command::Delete(mut query_args) => {
             if let Err(error) =
-                delete_job(&mut database.connection, &mut query_args, &current_sprint)
+                delete_job(connection, &mut query_args, current_sprint)
             {
                 println!("{}", error.red().bold());
             }
         }
+        Command::Doc(doc_option) => match doc_option {
+            DocOption::Add { file } => {
+                if let Err(error) = add_document(connection, &file) {
+                    println!("{}", error.red().bold());
+                }
+            }
+            DocOption::List => {
+                if let Err(error) = list_documents(connection) {
+                    println!("{}", error.red().bold());
+                }
+            }
+            DocOption::Open => {
+                if let Err(error) = open_document(connection) {
+                    println!("{}", error.red().bold());
+                }
+            }
+        },
+        Command::Edit { job_id } => {
+            if let Err(error) = edit_job(connection, job_id) {
+                println!("{}", error.red().bold());
+            }
+        }
         Command::Export(mut export_args) => {
             if let Err(error) =
-                export_jobs(&mut database.connection, &mut export_args, &current_sprint)
+                export_jobs(connection, &mut export_args, current_sprint)
             {
                 println!("{}", error.red().bold());
             }
         }
+        Command::Field(field_option) => match field_option {
+            FieldOption::Add { name } => {
+                if let Err(error) = add_field(connection, &name) {
+                    println!("{}", error.red().bold());
+                }
+            }
+            FieldOption::Delete => {
+                if let Err(error) = delete_field(connection) {
+                    println!("{}", error.red().bold());
+                }
+            }
+            FieldOption::List => {
+                if let Err(error) = list_fields(connection) {
+                    println!("{}", error.red().bold());
+                }
+            }
+        },
         Command::Insights => {
-            if let Err(error) = show_insights(&mut database.connection, &current_sprint) {
+            if let Err(error) = show_insights(connection, current_sprint) {
+                println!("{}", error.red().bold());
+            }
+        }
+        Command::List(list_args) => {
+            let result = match list_args.view {
+                Some(name) => run_view(
+                    connection,
+                    &config,
+                    &name,
+                    list_args.query_args,
+                    list_args.format,
+                    &list_args.columns,
+                    current_sprint,
+                ),
+                None => list_jobs(
+                    connection,
+                    &list_args.query_args,
+                    list_args.format.unwrap_or_default(),
+                    if list_args.columns.is_empty() {
+                        &config.columns
+                    } else {
+                        &list_args.columns
+                    },
+                    current_sprint,
+                ),
+            };
+            if let Err(error) = result {
                 println!("{}", error.red().bold());
             }
         }
-        Command::List(query_args) => {
-            if let Err(error) = list_jobs(&mut database.connection, &query_args, &current_sprint) {
+        Command::Log { limit } => {
+            if let Err(error) = show_log(connection, limit) {
                 println!("{}", error.red().bold());
             }
         }
+        Command::NativeHost(native_host_args) => {
+            if let Err(error) = run_native_host(connection, &native_host_args, current_sprint) {
+                eprintln!("{}", error.red().bold());
+            }
+        }
+        Command::Note(note_option) => match note_option {
+            NoteOption::Add { job_id } => {
+                if let Err(error) = add_note(connection, job_id) {
+                    println!("{}", error.red().bold());
+                }
+            }
+            NoteOption::List { job_id } => {
+                if let Err(error) = list_notes(connection, job_id) {
+                    println!("{}", error.red().bold());
+                }
+            }
+        },
         Command::Open(mut query_args) => {
             if let Err(error) =
-                open_application(&mut database.connection, &mut query_args, &current_sprint)
+                open_application(connection, &mut query_args, current_sprint)
             {
                 println!("{}", error.red().bold());
             }
         }
+        Command::Report(report_option) => match report_option {
+            ReportOption::Html { out, sprint } => {
+                if let Err(error) =
+                    write_html_report(connection, &out, sprint.as_deref(), current_sprint)
+                {
+                    println!("{}", error.red().bold());
+                }
+            }
+        },
+        Command::Search(search_args) => {
+            if let Err(error) = search_jobs(connection, &search_args) {
+                println!("{}", error.red().bold());
+            }
+        }
         Command::Sprint(sprint_option) => match sprint_option {
             SprintOption::Current => {
-                show_current_sprint(current_sprint);
+                show_current_sprint(current_sprint.clone());
             }
             SprintOption::New { name } => {
                 if let Err(error) =
-                    create_new_sprint(&mut database.connection, &name, config, &current_sprint)
+                    create_new_sprint(connection, &name, config, current_sprint)
                 {
                     println!("{}", error.red().bold());
                 }
             }
             SprintOption::ShowAll => {
-                if let Err(error) = show_all_sprints(&mut database.connection) {
+                if let Err(error) = show_all_sprints(connection) {
                     println!("{}", error.red().bold());
                 }
             }
             SprintOption::Set => {
-                if let Err(error) = set_sprint(&mut database.connection, config, &current_sprint) {
+                if let Err(error) = set_sprint(connection, config, current_sprint) {
                     println!("{}", error.red().bold());
                 }
             }
         },
+        Command::Serve { port } => {
+            if let Err(error) = start_server(connection, config, current_sprint, port) {
+                println!("{}", error.red().bold());
+            }
+        }
+        Command::Shell => {
+            if let Err(error) = start_shell(connection, config, current_sprint) {
+                println!("{}", error.red().bold());
+            }
+        }
+        Command::Show(mut show_args) => {
+            if let Err(error) = show_job(connection, &mut show_args, current_sprint)
+            {
+                println!("{}", error.red().bold());
+            }
+        }
         Command::Stage(stage_option) => match stage_option {
             StageOption::Add(mut query_args) => {
                 if let Err(error) =
-                    add_stage(&mut database.connection, &mut query_args, &current_sprint)
+                    add_stage(connection, &mut query_args, current_sprint)
                 {
                     println!("{}", error.red().bold());
                 }
             }
             StageOption::Delete(mut query_args) => {
                 if let Err(error) =
-                    delete_stage(&mut database.connection, &mut query_args, &current_sprint)
+                    delete_stage(connection, &mut query_args, current_sprint)
                 {
                     println!("{}", error.red().bold());
                 }
             }
             StageOption::Tree(mut query_args) => {
                 if let Err(error) =
-                    show_stage_tree(&mut database.connection, &mut query_args, &current_sprint)
+                    show_stage_tree(connection, &mut query_args, current_sprint)
                 {
                     println!("{}", error.red().bold());
                 }
             }
             StageOption::Update(mut query_args) => {
                 if let Err(error) =
-                    update_stage(&mut database.connection, &mut query_args, &current_sprint)
+                    update_stage(connection, &mut query_args, current_sprint)
                 {
                     println!("{}", error.red().bold());
                 }
             }
         },
-        Command::Update(mut query_args) => {
-            if let Err(error) =
-                update_job(&mut database.connection, &mut query_args, &current_sprint)
-            {
+        Command::Tag(tag_option) => match tag_option {
+            TagOption::Add(mut query_args) => {
+                if let Err(error) =
+                    add_tags(connection, &mut query_args, current_sprint)
+                {
+                    println!("{}", error.red().bold());
+                }
+            }
+            TagOption::List => {
+                if let Err(error) = list_tags(connection) {
+                    println!("{}", error.red().bold());
+                }
+            }
+            TagOption::Remove(mut query_args) => {
+                if let Err(error) =
+                    remove_tags(connection, &mut query_args, current_sprint)
+                {
+                    println!("{}", error.red().bold());
+                }
+            }
+        },
+        Command::Trash(trash_option) => match trash_option {
+            TrashOption::Empty => {
+                if let Err(error) = empty_trash(connection) {
+                    println!("{}", error.red().bold());
+                }
+            }
+            TrashOption::List => {
+                if let Err(error) = list_trash(connection) {
+                    println!("{}", error.red().bold());
+                }
+            }
+            TrashOption::Restore { job_id } => {
+                if let Err(error) = restore_from_trash(connection, job_id) {
+                    println!("{}", error.red().bold());
+                }
+            }
+        },
+        Command::Tui => {
+            if let Err(error) = open_dashboard(connection, current_sprint) {
                 println!("{}", error.red().bold());
             }
         }
-    }
+        Command::Undo { steps } => {
+            if let Err(error) = undo(connection, steps) {
+                println!("{}", error.red().bold());
+            }
+        }
+        Command::Update(mut update_args) => {
+            let result = if update_args.bulk || !update_args.ids.is_empty() {
+                bulk_update_jobs(
+                    connection,
+                    &mut update_args.query_args,
+                    &update_args.ids,
+                    current_sprint,
+                )
+            } else {
+                update_job(
+                    connection,
+                    &mut update_args.query_args,
+                    update_args.description_file.as_deref(),
+                    current_sprint,
+                )
+            };
 
-    Ok(())
+            if let Err(error) = result {
+                println!("{}", error.red().bold());
+            }
+        }
+        Command::View(view_option) => match view_option {
+            ViewOption::Delete { name } => {
+                if let Err(error) = delete_view(config, &name) {
+                    println!("{}", error.red().bold());
+                }
+            }
+            ViewOption::List => list_views(&config),
+            ViewOption::Run { name } => {
+                if let Err(error) = run_view(
+                    connection,
+                    &config,
+                    &name,
+                    QueryArgs::default(),
+                    None,
+                    &[],
+                    current_sprint,
+                ) {
+                    println!("{}", error.red().bold());
+                }
+            }
+            ViewOption::Save {
+                name,
+                format,
+                columns,
+                query_args,
+            } => {
+                if let Err(error) = save_view(
+                    config,
+                    &name,
+                    SavedView {
+                        format,
+                        columns,
+                        query_args: *query_args,
+                    },
+                ) {
+                    println!("{}", error.red().bold());
+                }
+            }
+        },
+    }
 }
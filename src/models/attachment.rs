@@ -0,0 +1,71 @@
+//! Contains all models for files attached to job applications.
+
+use std::fmt::{self, Display, Formatter};
+
+use diesel::sqlite::Sqlite;
+use diesel::{Insertable, Queryable, Selectable};
+use serde::Serialize;
+use tabled::Tabled;
+
+use crate::schema::attachments;
+
+/// This struct defines a new attachment that will be written to the `attachments` table in
+/// SQLite.
+#[derive(Debug, Insertable)]
+#[diesel(table_name = attachments)]
+#[diesel(check_for_backend(Sqlite))]
+pub struct NewAttachment<'a> {
+    /// The ID of the job this file is attached to.
+    pub job_id: i32,
+    /// The name of the attached file.
+    pub file_name: &'a str,
+    /// The path to the copy of the file in the fetters data directory.
+    pub file_path: &'a str,
+    /// The timestamp at which this file was attached.
+    pub added: &'a str,
+}
+
+/// This struct defines the attachment object returned from querying SQLite.
+#[derive(Clone, Debug, Queryable, Selectable, Serialize, Tabled)]
+#[diesel(table_name = attachments)]
+#[diesel(check_for_backend(Sqlite))]
+pub struct QueriedAttachment {
+    /// The SQLite ID.
+    #[tabled(rename = "ID")]
+    pub id: i32,
+    /// The name of the attached file.
+    #[tabled(rename = "File")]
+    pub file_name: String,
+    /// The path to the copy of the file in the fetters data directory.
+    #[tabled(rename = "Path")]
+    pub file_path: String,
+    /// The timestamp at which this file was attached.
+    #[tabled(rename = "Added")]
+    pub added: String,
+}
+
+/// Implementing `Display` allows this struct to be displayed in `Select` Inquire menus.
+impl Display for QueriedAttachment {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{} (attached {})", self.file_name, self.added)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_queried_attachment_display() {
+        let attachment = QueriedAttachment {
+            id: 1,
+            file_name: "offer-letter.pdf".to_string(),
+            file_path: "/data/fetters/attachments/3/offer-letter.pdf".to_string(),
+            added: "2026-04-19 16:42:08".to_string(),
+        };
+        assert_eq!(
+            format!("{}", attachment),
+            "offer-letter.pdf (attached 2026-04-19 16:42:08)"
+        );
+    }
+}
@@ -0,0 +1,264 @@
+//! Contains all models for user-defined custom fields.
+
+use std::fmt::{self, Display, Formatter};
+
+use chrono::NaiveDate;
+use diesel::sqlite::Sqlite;
+use diesel::{Insertable, Queryable, Selectable};
+use tabled::Tabled;
+use tabled::derive::display;
+
+use crate::schema::{custom_field_values, custom_fields};
+
+/// The type of value a custom field holds.
+#[derive(Clone, Debug, PartialEq)]
+pub enum CustomFieldType {
+    /// Free-form text.
+    Text,
+    /// A number (integer or decimal).
+    Number,
+    /// A date (formatted as YYYY-MM-DD).
+    Date,
+    /// A yes/no value.
+    Bool,
+    /// One value out of a fixed list of options.
+    Enum,
+}
+
+impl CustomFieldType {
+    /// Returns all variants for use in `inquire::Select` prompts.
+    pub fn variants() -> Vec<CustomFieldType> {
+        vec![
+            CustomFieldType::Text,
+            CustomFieldType::Number,
+            CustomFieldType::Date,
+            CustomFieldType::Bool,
+            CustomFieldType::Enum,
+        ]
+    }
+
+    /// Returns the string representation stored in SQLite.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            CustomFieldType::Text => "TEXT",
+            CustomFieldType::Number => "NUMBER",
+            CustomFieldType::Date => "DATE",
+            CustomFieldType::Bool => "BOOL",
+            CustomFieldType::Enum => "ENUM",
+        }
+    }
+
+    /// Validate a raw value for this field type and return the normalized value that will be
+    /// stored in SQLite. Booleans are stored as "true" or "false" and dates as YYYY-MM-DD.
+    pub fn validate(&self, value: &str, options: &[String]) -> Result<String, String> {
+        let value = value.trim();
+
+        match self {
+            CustomFieldType::Text => Ok(value.to_string()),
+            CustomFieldType::Number => value
+                .parse::<f64>()
+                .ok()
+                .filter(|number| number.is_finite())
+                .map(|_| value.to_string())
+                .ok_or(format!("\"{value}\" is not a finite number")),
+            CustomFieldType::Date => NaiveDate::parse_from_str(value, "%Y-%m-%d")
+                .map(|date| date.format("%Y-%m-%d").to_string())
+                .map_err(|_| format!("\"{value}\" is not a date formatted as YYYY-MM-DD")),
+            CustomFieldType::Bool => match value.to_lowercase().as_str() {
+                "true" | "yes" | "y" => Ok("true".to_string()),
+                "false" | "no" | "n" => Ok("false".to_string()),
+                _ => Err(format!("\"{value}\" is not a yes/no value")),
+            },
+            CustomFieldType::Enum => options
+                .iter()
+                .find(|option| option.eq_ignore_ascii_case(value))
+                .cloned()
+                .ok_or(format!(
+                    "\"{value}\" is not one of the options [{}]",
+                    options.join(", ")
+                )),
+        }
+    }
+}
+
+impl Display for CustomFieldType {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl std::str::FromStr for CustomFieldType {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_uppercase().as_str() {
+            "TEXT" => Ok(CustomFieldType::Text),
+            "NUMBER" => Ok(CustomFieldType::Number),
+            "DATE" => Ok(CustomFieldType::Date),
+            "BOOL" => Ok(CustomFieldType::Bool),
+            "ENUM" => Ok(CustomFieldType::Enum),
+            _ => Err(format!("Unknown custom field type: {}", s)),
+        }
+    }
+}
+
+/// This struct defines a new custom field that will be written to the `custom_fields` table in
+/// SQLite.
+#[derive(Debug, Insertable)]
+#[diesel(table_name = custom_fields)]
+#[diesel(check_for_backend(Sqlite))]
+pub struct NewCustomField<'a> {
+    /// The field name.
+    pub name: &'a str,
+    /// The field type (e.g. "TEXT", "NUMBER", "DATE", "BOOL", "ENUM").
+    pub field_type: &'a str,
+    /// The comma-separated options for "ENUM" fields.
+    pub options: Option<&'a str>,
+}
+
+/// This struct defines the custom field object returned from querying SQLite.
+#[derive(Clone, Debug, Queryable, Selectable, Tabled)]
+#[diesel(table_name = custom_fields)]
+#[diesel(check_for_backend(Sqlite))]
+pub struct QueriedCustomField {
+    /// The SQLite ID.
+    #[tabled(skip)]
+    pub id: i32,
+    /// The field name.
+    #[tabled(rename = "Field Name")]
+    pub name: String,
+    /// The field type (e.g. "TEXT", "NUMBER", "DATE", "BOOL", "ENUM").
+    #[tabled(rename = "Type")]
+    pub field_type: String,
+    /// The comma-separated options for "ENUM" fields.
+    #[tabled(rename = "Options")]
+    #[tabled(display("display::option", ""))]
+    pub options: Option<String>,
+}
+
+impl QueriedCustomField {
+    /// Returns the parsed field type. Unknown types are treated as free-form text.
+    pub fn parsed_type(&self) -> CustomFieldType {
+        self.field_type
+            .parse::<CustomFieldType>()
+            .unwrap_or(CustomFieldType::Text)
+    }
+
+    /// Returns the list of options for "ENUM" fields.
+    pub fn option_list(&self) -> Vec<String> {
+        self.options
+            .as_deref()
+            .unwrap_or("")
+            .split(',')
+            .map(|option| option.trim().to_string())
+            .filter(|option| !option.is_empty())
+            .collect()
+    }
+}
+
+/// Implementing `Display` allows this struct to be displayed in `Select` Inquire menus.
+impl Display for QueriedCustomField {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{} ({})", self.name, self.field_type)
+    }
+}
+
+/// This struct defines a custom field value for a job application that will be written to the
+/// `custom_field_values` table in SQLite.
+#[derive(Debug, Insertable)]
+#[diesel(table_name = custom_field_values)]
+#[diesel(check_for_backend(Sqlite))]
+pub struct NewCustomFieldValue<'a> {
+    /// The job application ID. References the record ID in SQLite.
+    pub job_id: i32,
+    /// The custom field ID. References the record ID in SQLite.
+    pub field_id: i32,
+    /// The value for this field.
+    pub value: &'a str,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn make_field(field_type: &str, options: Option<&str>) -> QueriedCustomField {
+        QueriedCustomField {
+            id: 1,
+            name: "Visa Sponsorship".to_string(),
+            field_type: field_type.to_string(),
+            options: options.map(|o| o.to_string()),
+        }
+    }
+
+    #[test]
+    fn test_custom_field_type_roundtrip() {
+        for variant in CustomFieldType::variants() {
+            let parsed: CustomFieldType = variant.as_str().parse().unwrap();
+            assert_eq!(parsed, variant);
+        }
+        assert!("UNKNOWN".parse::<CustomFieldType>().is_err());
+    }
+
+    #[test]
+    fn test_validate_number() {
+        assert_eq!(
+            CustomFieldType::Number.validate("42.5", &[]),
+            Ok("42.5".to_string())
+        );
+        assert!(CustomFieldType::Number.validate("lots", &[]).is_err());
+        assert!(CustomFieldType::Number.validate("NaN", &[]).is_err());
+        assert!(CustomFieldType::Number.validate("-inf", &[]).is_err());
+        assert!(CustomFieldType::Number.validate("1e400", &[]).is_err());
+    }
+
+    #[test]
+    fn test_validate_date() {
+        assert_eq!(
+            CustomFieldType::Date.validate(" 2026-03-08 ", &[]),
+            Ok("2026-03-08".to_string())
+        );
+        assert!(CustomFieldType::Date.validate("03/08/2026", &[]).is_err());
+    }
+
+    #[test]
+    fn test_validate_bool() {
+        assert_eq!(
+            CustomFieldType::Bool.validate("Yes", &[]),
+            Ok("true".to_string())
+        );
+        assert_eq!(
+            CustomFieldType::Bool.validate("false", &[]),
+            Ok("false".to_string())
+        );
+        assert!(CustomFieldType::Bool.validate("maybe", &[]).is_err());
+    }
+
+    #[test]
+    fn test_validate_enum_is_case_insensitive() {
+        let options = vec!["Secret".to_string(), "Top Secret".to_string()];
+        assert_eq!(
+            CustomFieldType::Enum.validate("top secret", &options),
+            Ok("Top Secret".to_string())
+        );
+        assert!(CustomFieldType::Enum.validate("Public", &options).is_err());
+    }
+
+    #[test]
+    fn test_option_list_trims_and_skips_empty() {
+        let field = make_field("ENUM", Some("Remote, Hybrid,,On-site "));
+        assert_eq!(field.option_list(), vec!["Remote", "Hybrid", "On-site"]);
+        assert!(make_field("TEXT", None).option_list().is_empty());
+    }
+
+    #[test]
+    fn test_parsed_type_defaults_to_text() {
+        assert_eq!(make_field("BOOL", None).parsed_type(), CustomFieldType::Bool);
+        assert_eq!(make_field("???", None).parsed_type(), CustomFieldType::Text);
+    }
+
+    #[test]
+    fn test_queried_custom_field_display() {
+        let field = make_field("BOOL", None);
+        assert_eq!(format!("{}", field), "Visa Sponsorship (BOOL)");
+    }
+}
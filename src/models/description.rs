@@ -0,0 +1,32 @@
+//! Contains all models for job description snapshots.
+
+use diesel::sqlite::Sqlite;
+use diesel::{Insertable, Queryable, Selectable};
+use serde::Serialize;
+
+use crate::schema::job_descriptions;
+
+/// This struct defines a new job description snapshot that will be written to the
+/// `job_descriptions` table in SQLite.
+#[derive(Debug, Insertable)]
+#[diesel(table_name = job_descriptions)]
+#[diesel(check_for_backend(Sqlite))]
+pub struct NewJobDescription<'a> {
+    /// The job application ID. References the record ID in SQLite.
+    pub job_id: i32,
+    /// The full text of the job posting.
+    pub content: &'a str,
+    /// The timestamp when this snapshot was captured (`YYYY-MM-DD HH:MM:SS`).
+    pub captured: &'a str,
+}
+
+/// This struct defines the job description snapshot returned from querying SQLite.
+#[derive(Debug, Queryable, Selectable, Serialize)]
+#[diesel(table_name = job_descriptions)]
+#[diesel(check_for_backend(Sqlite))]
+pub struct QueriedJobDescription {
+    /// The full text of the job posting.
+    pub content: String,
+    /// The timestamp when this snapshot was captured (`YYYY-MM-DD HH:MM:SS`).
+    pub captured: String,
+}
@@ -0,0 +1,111 @@
+//! Contains all models for resume and cover letter versions.
+
+use std::fmt::{self, Display, Formatter};
+
+use diesel::sqlite::Sqlite;
+use diesel::{Insertable, Queryable, Selectable};
+use strum::{Display as StrumDisplay, EnumIter};
+use tabled::Tabled;
+
+use crate::schema::documents;
+
+/// The kinds of documents that may be submitted with a job application.
+#[derive(Clone, Copy, Debug, EnumIter, PartialEq, StrumDisplay)]
+pub enum DocumentKind {
+    /// A resume version.
+    #[strum(to_string = "RESUME")]
+    Resume,
+    /// A cover letter version.
+    #[strum(to_string = "COVER LETTER")]
+    CoverLetter,
+}
+
+/// This struct defines a new document version that will be written to the `documents` table in
+/// SQLite.
+#[derive(Debug, Insertable)]
+#[diesel(table_name = documents)]
+#[diesel(check_for_backend(Sqlite))]
+pub struct NewDocument<'a> {
+    /// The document kind (ie. "RESUME" or "COVER LETTER").
+    pub kind: &'a str,
+    /// The label for this version (ie. "backend-v3").
+    pub label: &'a str,
+    /// The absolute path to the document.
+    pub file_path: &'a str,
+    /// The SHA-256 checksum of the document when it was added.
+    pub checksum: &'a str,
+    /// The timestamp at which this document was added.
+    pub created: &'a str,
+}
+
+/// This struct defines the document object returned from querying SQLite.
+#[derive(Clone, Debug, Queryable, Selectable, Tabled)]
+#[diesel(table_name = documents)]
+#[diesel(check_for_backend(Sqlite))]
+pub struct QueriedDocument {
+    /// The SQLite ID.
+    #[tabled(rename = "ID")]
+    pub id: i32,
+    /// The document kind (ie. "RESUME" or "COVER LETTER").
+    #[tabled(rename = "Kind")]
+    pub kind: String,
+    /// The label for this version (ie. "backend-v3").
+    #[tabled(rename = "Label")]
+    pub label: String,
+    /// The absolute path to the document.
+    #[tabled(rename = "File")]
+    pub file_path: String,
+    /// The SHA-256 checksum of the document when it was added.
+    #[tabled(rename = "Checksum")]
+    #[tabled(display("short_checksum"))]
+    pub checksum: String,
+    /// The timestamp at which this document was added.
+    #[tabled(rename = "Created")]
+    pub created: String,
+}
+
+/// Implementing `Display` allows this struct to be displayed in `Select` Inquire menus.
+impl Display for QueriedDocument {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{} ({})", self.label, self.created)
+    }
+}
+
+/// Only the first 12 characters of the checksum are displayed in tables.
+fn short_checksum(checksum: &str) -> String {
+    checksum.chars().take(12).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use strum::IntoEnumIterator;
+
+    #[test]
+    fn test_document_kind_display() {
+        let kinds: Vec<String> = DocumentKind::iter().map(|kind| kind.to_string()).collect();
+        assert_eq!(kinds, vec!["RESUME", "COVER LETTER"]);
+    }
+
+    #[test]
+    fn test_short_checksum() {
+        assert_eq!(
+            short_checksum("9f86d081884c7d659a2feaa0c55ad015a3bf4f1b2b0b822cd15d6c15b0f00a08"),
+            "9f86d081884c"
+        );
+        assert_eq!(short_checksum("abc"), "abc");
+    }
+
+    #[test]
+    fn test_queried_document_display() {
+        let document = QueriedDocument {
+            id: 1,
+            kind: "RESUME".to_string(),
+            label: "backend-v3".to_string(),
+            file_path: "/home/user/resumes/backend-v3.pdf".to_string(),
+            checksum: "9f86d081884c7d659a2feaa0c55ad015".to_string(),
+            created: "2026-04-05 10:15:22".to_string(),
+        };
+        assert_eq!(format!("{}", document), "backend-v3 (2026-04-05 10:15:22)");
+    }
+}
@@ -1,5 +1,7 @@
 //! Contains all models for job inslghts.
 
+use std::collections::BTreeMap;
+
 use tabled::Tabled;
 
 /// Contains the name of the field, the total count for the field, as well as the percentage over
@@ -19,3 +21,137 @@ pub struct CountAndPercentage {
     #[tabled(rename = "% Overall")]
     pub overall_percentage: String,
 }
+
+/// Contains the response, interview, and offer rates for job applications sharing the same
+/// label (ie. the same application source).
+#[derive(Debug, Tabled)]
+pub struct ConversionRates {
+    /// The number of job applications with the label and their share of all job applications.
+    #[tabled(inline)]
+    pub jobs: CountAndPercentage,
+    /// The percentage of job applications that received any response.
+    #[tabled(rename = "Response Rate")]
+    pub response_rate: String,
+    /// The percentage of job applications that led to at least one interview.
+    #[tabled(rename = "Interview Rate")]
+    pub interview_rate: String,
+    /// The percentage of job applications that led to an offer.
+    #[tabled(rename = "Offer Rate")]
+    pub offer_rate: String,
+}
+
+impl ConversionRates {
+    /// Aggregate `(label, status, number of interview stages, in current sprint)` tuples into
+    /// conversion rates per label. Applications without a label are grouped under "N/A".
+    ///
+    /// An application counts as a response if it has left the "PENDING" or "GHOSTED" statuses or
+    /// has any interview stages, as an interview if it has any interview stages or is in a status
+    /// that implies one, and as an offer if it is in the "OFFER RECEIVED" or "HIRED" statuses.
+    pub fn from_outcomes(outcomes: Vec<(Option<String>, Option<String>, i64, bool)>) -> Vec<Self> {
+        let total_jobs = outcomes.len() as i64;
+        let total_jobs_in_sprint = outcomes
+            .iter()
+            .filter(|(_, _, _, in_current_sprint)| *in_current_sprint)
+            .count() as i64;
+        let mut tallies: BTreeMap<String, (i64, i64, i64, i64, i64)> = BTreeMap::new();
+
+        for (label, status, num_stages, in_current_sprint) in outcomes {
+            let status = status.unwrap_or_default();
+            let tally = tallies
+                .entry(label.unwrap_or("N/A".to_string()))
+                .or_default();
+
+            tally.0 += 1;
+            if in_current_sprint {
+                tally.1 += 1;
+            }
+            if num_stages > 0 || !matches!(status.as_str(), "PENDING" | "GHOSTED") {
+                tally.2 += 1;
+            }
+            if num_stages > 0 || matches!(status.as_str(), "IN PROGRESS" | "OFFER RECEIVED" | "HIRED")
+            {
+                tally.3 += 1;
+            }
+            if matches!(status.as_str(), "OFFER RECEIVED" | "HIRED") {
+                tally.4 += 1;
+            }
+        }
+
+        tallies
+            .into_iter()
+            .map(
+                |(label, (count, count_in_sprint, responses, interviews, offers))| ConversionRates {
+                    jobs: CountAndPercentage {
+                        label,
+                        count,
+                        sprint_percentage: percentage(count_in_sprint, total_jobs_in_sprint),
+                        overall_percentage: percentage(count, total_jobs),
+                    },
+                    response_rate: percentage(responses, count),
+                    interview_rate: percentage(interviews, count),
+                    offer_rate: percentage(offers, count),
+                },
+            )
+            .collect()
+    }
+}
+
+/// Format `count` as a percentage of `total`. An empty total is shown as 0%.
+fn percentage(count: i64, total: i64) -> String {
+    if total == 0 {
+        return "0.00%".to_string();
+    }
+
+    format!("{:.2}%", (count as f64 / total as f64) * 100.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_outcomes_groups_by_label() {
+        let rates = ConversionRates::from_outcomes(vec![
+            (Some("REFERRAL".to_string()), Some("PENDING".to_string()), 0, true),
+            (Some("REFERRAL".to_string()), Some("OFFER RECEIVED".to_string()), 3, false),
+            (Some("JOB BOARD".to_string()), Some("GHOSTED".to_string()), 0, true),
+            (None, Some("REJECTED".to_string()), 0, false),
+        ]);
+
+        assert_eq!(rates.len(), 3);
+
+        let referral = rates.iter().find(|r| r.jobs.label == "REFERRAL").unwrap();
+        assert_eq!(referral.jobs.count, 2);
+        assert_eq!(referral.jobs.sprint_percentage, "50.00%");
+        assert_eq!(referral.jobs.overall_percentage, "50.00%");
+        assert_eq!(referral.response_rate, "50.00%");
+        assert_eq!(referral.interview_rate, "50.00%");
+        assert_eq!(referral.offer_rate, "50.00%");
+
+        let job_board = rates.iter().find(|r| r.jobs.label == "JOB BOARD").unwrap();
+        assert_eq!(job_board.response_rate, "0.00%");
+
+        let unlabeled = rates.iter().find(|r| r.jobs.label == "N/A").unwrap();
+        assert_eq!(unlabeled.response_rate, "100.00%");
+        assert_eq!(unlabeled.interview_rate, "0.00%");
+    }
+
+    #[test]
+    fn test_from_outcomes_stages_count_as_interviews() {
+        let rates = ConversionRates::from_outcomes(vec![(
+            Some("RECRUITER INBOUND".to_string()),
+            Some("PENDING".to_string()),
+            1,
+            true,
+        )]);
+
+        assert_eq!(rates[0].response_rate, "100.00%");
+        assert_eq!(rates[0].interview_rate, "100.00%");
+        assert_eq!(rates[0].offer_rate, "0.00%");
+    }
+
+    #[test]
+    fn test_from_outcomes_empty() {
+        assert!(ConversionRates::from_outcomes(Vec::new()).is_empty());
+    }
+}
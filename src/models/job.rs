@@ -2,17 +2,28 @@
 
 use std::fmt::{self, Display, Formatter};
 
+use chrono::NaiveDate;
+use diesel::deserialize;
+use diesel::sql_types::{Integer, Nullable, Text};
 use diesel::sqlite::Sqlite;
 use diesel::{AsChangeset, Insertable, Queryable, Selectable};
 use owo_colors::OwoColorize;
+use serde::ser::SerializeMap;
+use serde::{Deserialize, Serialize, Serializer};
 use tabled::Tabled;
 use tabled::derive::display;
 
+use crate::cli::JobColumn;
+use crate::models::{
+    attachment::QueriedAttachment, description::QueriedJobDescription, note::QueriedJobNote,
+    stage::QueriedInterviewStage,
+};
 use crate::schema::jobs;
 
-/// This struct defines the job object returned from querying SQLite.
+/// This struct defines the job object returned from querying SQLite. It is also stored as a JSON
+/// snapshot in the operation log and inserted again when a change is undone.
 #[allow(dead_code)]
-#[derive(Debug, Queryable, Selectable)]
+#[derive(Clone, Debug, Deserialize, Insertable, Queryable, Selectable, Serialize)]
 #[diesel(table_name = jobs)]
 #[diesel(check_for_backend(Sqlite))]
 pub struct QueriedJob {
@@ -28,10 +39,19 @@ pub struct QueriedJob {
     pub status_id: i32,
     /// The link to the job application.
     pub link: Option<String>,
-    /// Any notes about this job application.
-    pub notes: Option<String>,
     /// The sprint ID. References the record ID in SQLite.
     pub sprint_id: i32,
+    /// The application source ID. References the record ID in SQLite.
+    pub source_id: Option<i32>,
+    /// The contact who referred you for this job application.
+    pub referrer: Option<String>,
+    /// The resume version submitted with this application. References the record ID in SQLite.
+    pub resume_id: Option<i32>,
+    /// The cover letter version submitted with this application. References the record ID in
+    /// SQLite.
+    pub cover_letter_id: Option<i32>,
+    /// The timestamp at which this job application was moved to the trash, if it was deleted.
+    pub deleted_at: Option<String>,
 }
 
 /// This struct defines a new job application that will be inserted into SQLite.
@@ -49,10 +69,17 @@ pub struct NewJob<'a> {
     pub status_id: i32,
     /// The link to the job application.
     pub link: Option<&'a str>,
-    /// Any notes about this job application.
-    pub notes: Option<&'a str>,
     /// The sprint ID. References the record ID in SQLite.
     pub sprint_id: i32,
+    /// The application source ID. References the record ID in SQLite.
+    pub source_id: Option<i32>,
+    /// The contact who referred you for this job application.
+    pub referrer: Option<&'a str>,
+    /// The resume version submitted with this application. References the record ID in SQLite.
+    pub resume_id: Option<i32>,
+    /// The cover letter version submitted with this application. References the record ID in
+    /// SQLite.
+    pub cover_letter_id: Option<i32>,
 }
 
 /// This struct defines an updated job application that will overwrite an existing one in SQLite.
@@ -68,16 +95,23 @@ pub struct JobUpdate<'a> {
     pub status_id: Option<i32>,
     /// The link to the job application.
     pub link: Option<&'a str>,
-    /// Any notes about this job application.
-    pub notes: Option<&'a str>,
     /// The sprint ID. References the record ID in SQLite.
     pub sprint_id: Option<i32>,
+    /// The application source ID. References the record ID in SQLite.
+    pub source_id: Option<i32>,
+    /// The contact who referred you for this job application.
+    pub referrer: Option<&'a str>,
+    /// The resume version submitted with this application. References the record ID in SQLite.
+    pub resume_id: Option<i32>,
+    /// The cover letter version submitted with this application. References the record ID in
+    /// SQLite.
+    pub cover_letter_id: Option<i32>,
 }
 
 /// This struct defines a job application with the title, status, and sprint name after querying
 /// SQLite for those fields based on their record IDs and is used when displaying job applications
-/// in tables.
-#[derive(Clone, Debug, Queryable, Tabled)]
+/// in tables. It is also serialized when job applications are listed as JSON.
+#[derive(Clone, Debug, Serialize, Tabled)]
 pub struct TabledJob {
     /// The SQLite ID.
     #[tabled(rename = "ID")]
@@ -96,41 +130,120 @@ pub struct TabledJob {
     #[tabled(rename = "Status")]
     #[tabled(display("display::option", "N/A"))]
     pub status: Option<String>,
+    /// The channel through which this job application was submitted.
+    #[tabled(rename = "Source")]
+    #[tabled(display("display::option", "N/A"))]
+    pub source: Option<String>,
     /// The number of interview stages tracked for this job application.
     #[tabled(rename = "Num Stages")]
     #[tabled(display("display::option", ""))]
     pub stages: Option<i32>,
+    /// A comma-separated list of tags applied to this job application.
+    #[tabled(rename = "Tags")]
+    #[tabled(display("display::option", ""))]
+    pub tags: Option<String>,
     /// The link to the job application.
     #[tabled(rename = "Link")]
     #[tabled(display("display::option", "N/A"))]
     pub link: Option<String>,
-    /// Any notes about this job application.
+    /// The most recent note about this job application.
     #[tabled(rename = "Notes")]
     #[tabled(display("display::option", "N/A"))]
     pub notes: Option<String>,
+    /// The timestamp of the most recent note, interview stage, or change to this job application.
+    #[tabled(skip)]
+    pub last_activity: String,
+    /// Values for user-defined custom fields as `(field name, value)` pairs in field definition
+    /// order. These are not selected by the `TabledJob` query and are filled in afterwards.
+    #[serde(serialize_with = "serialize_pairs")]
+    #[tabled(skip)]
+    pub custom_fields: Vec<(String, String)>,
+}
+
+/// The SQL types of the columns selected when querying a `TabledJob`.
+type TabledJobSqlType = (
+    Integer,
+    Text,
+    Text,
+    Nullable<Text>,
+    Nullable<Text>,
+    Nullable<Text>,
+    Nullable<Integer>,
+    Nullable<Text>,
+    Nullable<Text>,
+    Nullable<Text>,
+    Text,
+);
+
+/// `Queryable` is implemented by hand so that `custom_fields` can be left out of the query.
+impl Queryable<TabledJobSqlType, Sqlite> for TabledJob {
+    type Row = (
+        i32,
+        String,
+        String,
+        Option<String>,
+        Option<String>,
+        Option<String>,
+        Option<i32>,
+        Option<String>,
+        Option<String>,
+        Option<String>,
+        String,
+    );
+
+    fn build(row: Self::Row) -> deserialize::Result<Self> {
+        let (
+            id,
+            created,
+            company_name,
+            title,
+            status,
+            source,
+            stages,
+            tags,
+            link,
+            notes,
+            last_activity,
+        ) = row;
+
+        Ok(TabledJob {
+            id,
+            created,
+            company_name,
+            title,
+            status,
+            source,
+            stages,
+            tags,
+            link,
+            notes,
+            last_activity,
+            custom_fields: Vec::new(),
+        })
+    }
+}
+
+/// Colorize a string with the color of an application status. Unknown statuses are not colored.
+pub fn colorize_by_status(status: &str, text: &str) -> String {
+    match status {
+        "GHOSTED" => text.white().bold().to_string(),
+        "HIRED" => text.green().bold().to_string(),
+        "IN PROGRESS" => text.yellow().bold().to_string(),
+        "NOT HIRING ANYMORE" => text.fg_rgb::<201, 201, 201>().to_string(),
+        "OFFER RECEIVED" => text.magenta().bold().to_string(),
+        "PENDING" => text.blue().bold().to_string(),
+        "REJECTED" => text.red().bold().to_string(),
+        _ => text.to_string(),
+    }
 }
 
 impl TabledJob {
     /// Colorize a string based on the `status` field of the job application.
-    fn colorize_field(&self, field_name: &str) -> String {
-        if let Some(ref status) = self.status {
-            match status {
-                val if val == "GHOSTED" => {
-                    return field_name.white().bold().to_string();
-                }
-                val if val == "HIRED" => return field_name.green().bold().to_string(),
-                val if val == "IN PROGRESS" => return field_name.yellow().bold().to_string(),
-                val if val == "NOT HIRING ANYMORE" => {
-                    return field_name.fg_rgb::<201, 201, 201>().to_string();
-                }
-                val if val == "OFFER RECEIVED" => return field_name.magenta().bold().to_string(),
-                val if val == "PENDING" => return field_name.blue().bold().to_string(),
-                val if val == "REJECTED" => return field_name.red().bold().to_string(),
-                _ => return field_name.to_string(),
-            }
+    pub fn colorize_field(&self, field_name: &str) -> String {
+        match self.status {
+            Some(ref status) => colorize_by_status(status, field_name),
+            None => field_name.to_string(),
         }
-
-        field_name.to_string()
     }
 
     /// Convert the struct to a row of strings to write to a spreadsheet when exporting job
@@ -143,8 +256,52 @@ impl TabledJob {
             self.status.clone().unwrap_or("N/A".to_string()),
             self.link.clone().unwrap_or("".to_string()),
             self.notes.clone().unwrap_or("".to_string()),
+            self.tags.clone().unwrap_or("".to_string()),
         ]
+        .into_iter()
+        .chain(self.custom_fields.iter().map(|(_, value)| value.clone()))
+        .collect()
     }
+
+    /// The value displayed in a job table column. Values are displayed the same way as the derived
+    /// `Tabled` implementation.
+    pub fn column_value(&self, column: &JobColumn, today: NaiveDate) -> String {
+        let or =
+            |value: &Option<String>, default: &str| value.clone().unwrap_or(default.to_string());
+
+        match column {
+            JobColumn::Id => self.id.to_string(),
+            JobColumn::Created => self.created.clone(),
+            JobColumn::Company => self.company_name.clone(),
+            JobColumn::Title => or(&self.title, "N/A"),
+            JobColumn::Status => or(&self.status, "N/A"),
+            JobColumn::Source => or(&self.source, "N/A"),
+            JobColumn::Stages => self
+                .stages
+                .map(|stages| stages.to_string())
+                .unwrap_or_default(),
+            JobColumn::Tags => or(&self.tags, ""),
+            JobColumn::Link => or(&self.link, "N/A"),
+            JobColumn::Notes => or(&self.notes, "N/A"),
+            JobColumn::Age => parse_date(&self.created)
+                .map(|created| (today - created).num_days().to_string())
+                .unwrap_or_default(),
+            JobColumn::Activity => self.last_activity.chars().take(10).collect(),
+            JobColumn::Field(name) => self
+                .custom_fields
+                .iter()
+                .find(|(field_name, _)| field_name.eq_ignore_ascii_case(name))
+                .map(|(_, value)| value.clone())
+                .unwrap_or_default(),
+        }
+    }
+}
+
+/// Parse the date at the start of a `YYYY-MM-DD HH:MM:SS` or `YYYY-MM-DD` timestamp.
+fn parse_date(timestamp: &str) -> Option<NaiveDate> {
+    timestamp
+        .get(..10)
+        .and_then(|date| NaiveDate::parse_from_str(date, "%Y-%m-%d").ok())
 }
 
 impl Display for TabledJob {
@@ -160,6 +317,141 @@ impl Display for TabledJob {
     }
 }
 
+/// Contains everything stored about a single job application. This is displayed by the `show`
+/// subcommand and serialized when a job is shown as JSON.
+#[derive(Debug, Serialize)]
+pub struct JobDetails {
+    /// The SQLite ID.
+    pub id: i32,
+    /// The timestamp at which this job application was created.
+    pub created: String,
+    /// The name of the company.
+    pub company_name: String,
+    /// The job title.
+    pub title: Option<String>,
+    /// The application status.
+    pub status: Option<String>,
+    /// The name of the sprint this job application belongs to.
+    pub sprint: String,
+    /// The channel through which this job application was submitted.
+    pub source: Option<String>,
+    /// The contact who referred you to this job, if any.
+    pub referrer: Option<String>,
+    /// The link to the job application.
+    pub link: Option<String>,
+    /// The notes about this job application in chronological order.
+    pub notes: Vec<QueriedJobNote>,
+    /// The tags applied to this job application.
+    pub tags: Vec<String>,
+    /// Values for user-defined custom fields as `(field name, value)` pairs. Fields without a value
+    /// are left out.
+    #[serde(serialize_with = "serialize_pairs")]
+    pub custom_fields: Vec<(String, String)>,
+    /// The label of the resume version submitted with this application.
+    pub resume: Option<String>,
+    /// The label of the cover letter version submitted with this application.
+    pub cover_letter: Option<String>,
+    /// The interview stages tracked for this job application.
+    pub stages: Vec<QueriedInterviewStage>,
+    /// The files attached to this job application.
+    pub attachments: Vec<QueriedAttachment>,
+    /// The stored job description snapshot.
+    pub description: Option<QueriedJobDescription>,
+}
+
+/// This struct describes how a job application changed after a bulk update and is used when
+/// displaying the summary table.
+#[derive(Debug, Tabled)]
+pub struct TabledJobChange {
+    /// The SQLite ID.
+    #[tabled(rename = "ID")]
+    pub id: i32,
+    /// The name of the company.
+    #[tabled(rename = "Company")]
+    pub company_name: String,
+    /// The application status before and after the update.
+    #[tabled(rename = "Status")]
+    pub status: String,
+    /// The sprint name before and after the update.
+    #[tabled(rename = "Sprint")]
+    pub sprint: String,
+    /// The applied tags before and after the update.
+    #[tabled(rename = "Tags")]
+    pub tags: String,
+}
+
+impl TabledJobChange {
+    /// Describe a changed value as `BEFORE -> AFTER`. Unchanged values are displayed as is.
+    pub fn describe_change(before: &str, after: &str) -> String {
+        let or_na = |value: &str| {
+            if value.is_empty() {
+                "N/A".to_string()
+            } else {
+                value.to_string()
+            }
+        };
+
+        if before == after {
+            or_na(after)
+        } else {
+            format!("{} -> {}", or_na(before), or_na(after))
+        }
+    }
+}
+
+/// This struct defines a job application in the trash and is used when displaying the trash in
+/// tables.
+#[derive(Clone, Debug, Queryable, Tabled)]
+pub struct TabledTrashedJob {
+    /// The SQLite ID.
+    #[tabled(rename = "ID")]
+    pub id: i32,
+    /// The timestamp at which this job application was moved to the trash.
+    #[tabled(rename = "Deleted")]
+    pub deleted_at: String,
+    /// The name of the company.
+    #[tabled(rename = "Company Name")]
+    pub company_name: String,
+    /// The job title.
+    #[tabled(rename = "Title")]
+    #[tabled(display("display::option", "N/A"))]
+    pub title: Option<String>,
+    /// The application status.
+    #[tabled(rename = "Status")]
+    #[tabled(display("display::option", "N/A"))]
+    pub status: Option<String>,
+    /// The name of the sprint this job application belongs to.
+    #[tabled(rename = "Sprint")]
+    #[tabled(display("display::option", "N/A"))]
+    pub sprint: Option<String>,
+}
+
+/// Implementing `Display` allows this struct to be displayed in the `Select` Inquire menu.
+impl Display for TabledTrashedJob {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "ID: {} | Company: {} | Title: {} | Deleted: {}",
+            self.id.white().bold(),
+            self.company_name,
+            self.title.as_deref().unwrap_or("N/A"),
+            self.deleted_at
+        )
+    }
+}
+
+/// Serialize `(name, value)` pairs as a map while keeping their order.
+fn serialize_pairs<S: Serializer>(
+    pairs: &[(String, String)],
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    let mut map = serializer.serialize_map(Some(pairs.len()))?;
+    for (name, value) in pairs {
+        map.serialize_entry(name, value)?;
+    }
+    map.end()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -171,9 +463,13 @@ mod tests {
             company_name: "Acme Corp".to_string(),
             title: Some("Software Engineer".to_string()),
             status: status.map(|s| s.to_string()),
+            source: Some("REFERRAL".to_string()),
             stages: Some(2),
+            tags: Some("fintech, remote".to_string()),
             link: Some("https://example.com/apply".to_string()),
             notes: Some("Great opportunity".to_string()),
+            last_activity: "2025-01-15".to_string(),
+            custom_fields: Vec::new(),
         }
     }
 
@@ -181,13 +477,14 @@ mod tests {
     fn test_convert_to_row_with_all_fields() {
         let job = make_tabled_job(Some("PENDING"));
         let row = job.convert_to_row();
-        assert_eq!(row.len(), 6);
+        assert_eq!(row.len(), 7);
         assert_eq!(row[0], "2025-01-15");
         assert_eq!(row[1], "Acme Corp");
         assert_eq!(row[2], "Software Engineer");
         assert_eq!(row[3], "PENDING");
         assert_eq!(row[4], "https://example.com/apply");
         assert_eq!(row[5], "Great opportunity");
+        assert_eq!(row[6], "fintech, remote");
     }
 
     #[test]
@@ -198,15 +495,73 @@ mod tests {
             company_name: "Test Co".to_string(),
             title: None,
             status: None,
+            source: None,
             stages: None,
+            tags: None,
             link: None,
             notes: None,
+            last_activity: "2025-01-15".to_string(),
+            custom_fields: Vec::new(),
         };
         let row = job.convert_to_row();
         assert_eq!(row[2], "N/A");
         assert_eq!(row[3], "N/A");
         assert_eq!(row[4], "");
         assert_eq!(row[5], "");
+        assert_eq!(row[6], "");
+    }
+
+    #[test]
+    fn test_convert_to_row_appends_custom_fields() {
+        let mut job = make_tabled_job(Some("PENDING"));
+        job.custom_fields = vec![
+            ("Team".to_string(), "Payments".to_string()),
+            ("Visa Sponsorship".to_string(), "".to_string()),
+        ];
+        let row = job.convert_to_row();
+        assert_eq!(row.len(), 9);
+        assert_eq!(row[7], "Payments");
+        assert_eq!(row[8], "");
+    }
+
+    #[test]
+    fn test_column_value() {
+        let mut job = make_tabled_job(None);
+        job.created = "2025-01-15 10:00:00".to_string();
+        job.last_activity = "2025-02-01 09:30:00".to_string();
+        job.custom_fields = vec![("Team".to_string(), "Payments".to_string())];
+        let today = NaiveDate::from_ymd_opt(2025, 2, 4).unwrap();
+
+        assert_eq!(job.column_value(&JobColumn::Id, today), "1");
+        assert_eq!(job.column_value(&JobColumn::Status, today), "N/A");
+        assert_eq!(job.column_value(&JobColumn::Stages, today), "2");
+        assert_eq!(job.column_value(&JobColumn::Age, today), "20");
+        assert_eq!(job.column_value(&JobColumn::Activity, today), "2025-02-01");
+        assert_eq!(
+            job.column_value(&JobColumn::Field("team".to_string()), today),
+            "Payments"
+        );
+        assert_eq!(
+            job.column_value(&JobColumn::Field("Salary".to_string()), today),
+            ""
+        );
+    }
+
+    #[test]
+    fn test_describe_change() {
+        assert_eq!(
+            TabledJobChange::describe_change("PENDING", "REJECTED"),
+            "PENDING -> REJECTED"
+        );
+        assert_eq!(
+            TabledJobChange::describe_change("PENDING", "PENDING"),
+            "PENDING"
+        );
+        assert_eq!(
+            TabledJobChange::describe_change("", "remote"),
+            "N/A -> remote"
+        );
+        assert_eq!(TabledJobChange::describe_change("", ""), "N/A");
     }
 
     #[test]
@@ -217,9 +572,13 @@ mod tests {
             company_name: "Test".to_string(),
             title: None,
             status: None,
+            source: None,
             stages: None,
+            tags: None,
             link: None,
             notes: None,
+            last_activity: "2025-01-15".to_string(),
+            custom_fields: Vec::new(),
         };
         assert_eq!(job.colorize_field("test"), "test");
     }
@@ -267,7 +626,8 @@ mod tests {
         assert!(update.title_id.is_none());
         assert!(update.status_id.is_none());
         assert!(update.link.is_none());
-        assert!(update.notes.is_none());
         assert!(update.sprint_id.is_none());
+        assert!(update.source_id.is_none());
+        assert!(update.referrer.is_none());
     }
 }
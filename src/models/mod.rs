@@ -1,8 +1,17 @@
 //! Contains all models used in `fetters`.
 
+pub mod attachment;
+pub mod custom_field;
+pub mod description;
+pub mod document;
 pub mod insight;
 pub mod job;
+pub mod note;
+pub mod operation;
+pub mod search;
+pub mod source;
 pub mod sprint;
 pub mod stage;
 pub mod status;
+pub mod tag;
 pub mod title;
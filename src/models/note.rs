@@ -0,0 +1,34 @@
+//! Contains all models for the notes logged for job applications.
+
+use diesel::sqlite::Sqlite;
+use diesel::{Insertable, Queryable, Selectable};
+use serde::Serialize;
+use tabled::Tabled;
+
+use crate::schema::job_notes;
+
+/// This struct defines a new note that will be written to the `job_notes` table in SQLite.
+#[derive(Debug, Insertable)]
+#[diesel(table_name = job_notes)]
+#[diesel(check_for_backend(Sqlite))]
+pub struct NewJobNote<'a> {
+    /// The job application ID. References the record ID in SQLite.
+    pub job_id: i32,
+    /// The content of the note.
+    pub content: &'a str,
+    /// The timestamp at which this note was written (`YYYY-MM-DD HH:MM:SS`).
+    pub created: &'a str,
+}
+
+/// This struct defines the note object returned from querying SQLite.
+#[derive(Clone, Debug, Queryable, Selectable, Serialize, Tabled)]
+#[diesel(table_name = job_notes)]
+#[diesel(check_for_backend(Sqlite))]
+pub struct QueriedJobNote {
+    /// The timestamp at which this note was written (`YYYY-MM-DD HH:MM:SS`).
+    #[tabled(rename = "Created")]
+    pub created: String,
+    /// The content of the note.
+    #[tabled(rename = "Note")]
+    pub content: String,
+}
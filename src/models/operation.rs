@@ -0,0 +1,186 @@
+//! Contains all models for the operation log used to undo changes.
+
+use diesel::sqlite::Sqlite;
+use diesel::{Insertable, Queryable, Selectable};
+use tabled::Tabled;
+use tabled::derive::display;
+
+use crate::schema::{operation_changes, operations};
+
+/// This struct defines a new operation that will be written to the `operations` table in SQLite.
+#[derive(Debug, Insertable)]
+#[diesel(table_name = operations)]
+#[diesel(check_for_backend(Sqlite))]
+pub struct NewOperation<'a> {
+    /// The timestamp at which this operation was performed (`YYYY-MM-DD HH:MM:SS`).
+    pub performed: &'a str,
+    /// The `fetters` command that performed this operation.
+    pub command: &'a str,
+}
+
+/// This struct defines the operation object returned from querying SQLite.
+#[derive(Clone, Debug, Queryable, Selectable)]
+#[diesel(table_name = operations)]
+#[diesel(check_for_backend(Sqlite))]
+pub struct QueriedOperation {
+    /// The SQLite ID.
+    pub id: i32,
+    /// The timestamp at which this operation was performed (`YYYY-MM-DD HH:MM:SS`).
+    pub performed: String,
+    /// The `fetters` command that performed this operation.
+    pub command: String,
+    /// The timestamp at which this operation was undone, if it was undone.
+    pub undone_at: Option<String>,
+}
+
+/// This struct defines a new row change that will be written to the `operation_changes` table in
+/// SQLite.
+#[derive(Debug, Insertable)]
+#[diesel(table_name = operation_changes)]
+#[diesel(check_for_backend(Sqlite))]
+pub struct NewOperationChange<'a> {
+    /// The operation ID. References the record ID in SQLite.
+    pub operation_id: i32,
+    /// The name of the table containing the changed row.
+    pub table_name: &'a str,
+    /// The SQLite ID of the changed row.
+    pub row_id: i32,
+    /// A JSON snapshot of the row before it was changed. This is `None` for inserted rows.
+    pub before_snapshot: Option<&'a str>,
+    /// A JSON snapshot of the row after it was changed. This is `None` for deleted rows.
+    pub after_snapshot: Option<&'a str>,
+}
+
+/// This struct defines the row change object returned from querying SQLite.
+#[allow(dead_code)]
+#[derive(Clone, Debug, Queryable, Selectable)]
+#[diesel(table_name = operation_changes)]
+#[diesel(check_for_backend(Sqlite))]
+pub struct QueriedOperationChange {
+    /// The SQLite ID.
+    pub id: i32,
+    /// The operation ID. References the record ID in SQLite.
+    pub operation_id: i32,
+    /// The name of the table containing the changed row.
+    pub table_name: String,
+    /// The SQLite ID of the changed row.
+    pub row_id: i32,
+    /// A JSON snapshot of the row before it was changed. This is `None` for inserted rows.
+    pub before_snapshot: Option<String>,
+    /// A JSON snapshot of the row after it was changed. This is `None` for deleted rows.
+    pub after_snapshot: Option<String>,
+}
+
+impl QueriedOperationChange {
+    /// Describe this change in plain words (ie. "Updated job 5").
+    pub fn describe(&self) -> String {
+        let action = match (&self.before_snapshot, &self.after_snapshot) {
+            (None, _) => "Added",
+            (_, None) => "Deleted",
+            _ => "Updated",
+        };
+        let row_name = match self.table_name.as_str() {
+            "jobs" => "job",
+            "interview_stages" => "stage",
+            "sprints" => "sprint",
+            table_name => table_name,
+        };
+
+        format!("{action} {row_name} {}", self.row_id)
+    }
+}
+
+/// This struct defines an operation with a summary of its changes and is used when displaying the
+/// operation log in tables.
+#[derive(Debug, Tabled)]
+pub struct TabledOperation {
+    /// The SQLite ID.
+    #[tabled(rename = "ID")]
+    pub id: i32,
+    /// The timestamp at which this operation was performed.
+    #[tabled(rename = "Performed")]
+    pub performed: String,
+    /// The `fetters` command that performed this operation.
+    #[tabled(rename = "Command")]
+    pub command: String,
+    /// A comma-separated summary of the changed rows.
+    #[tabled(rename = "Changes")]
+    pub changes: String,
+    /// The timestamp at which this operation was undone.
+    #[tabled(rename = "Undone")]
+    #[tabled(display("display::option", ""))]
+    pub undone_at: Option<String>,
+}
+
+impl TabledOperation {
+    /// Summarize an operation and its changes. Repeated changes to the same row (ie. a sprint's
+    /// job count being updated twice) are only listed once.
+    pub fn new(operation: QueriedOperation, changes: &[QueriedOperationChange]) -> Self {
+        let mut descriptions: Vec<String> = Vec::new();
+        for change in changes {
+            let description = change.describe();
+            if !descriptions.contains(&description) {
+                descriptions.push(description);
+            }
+        }
+
+        TabledOperation {
+            id: operation.id,
+            performed: operation.performed,
+            command: operation.command,
+            changes: descriptions.join(", "),
+            undone_at: operation.undone_at,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn change(
+        table_name: &str,
+        before: Option<&str>,
+        after: Option<&str>,
+    ) -> QueriedOperationChange {
+        QueriedOperationChange {
+            id: 1,
+            operation_id: 1,
+            table_name: table_name.to_string(),
+            row_id: 5,
+            before_snapshot: before.map(|snapshot| snapshot.to_string()),
+            after_snapshot: after.map(|snapshot| snapshot.to_string()),
+        }
+    }
+
+    #[test]
+    fn test_describe_change() {
+        assert_eq!(change("jobs", None, Some("{}")).describe(), "Added job 5");
+        assert_eq!(
+            change("interview_stages", Some("{}"), Some("{}")).describe(),
+            "Updated stage 5"
+        );
+        assert_eq!(
+            change("sprints", Some("{}"), None).describe(),
+            "Deleted sprint 5"
+        );
+    }
+
+    #[test]
+    fn test_tabled_operation_deduplicates_changes() {
+        let operation = QueriedOperation {
+            id: 3,
+            performed: "2026-05-31 08:15:44".to_string(),
+            command: "fetters delete".to_string(),
+            undone_at: None,
+        };
+        let changes = vec![
+            change("jobs", Some("{}"), Some("{}")),
+            change("sprints", Some("{}"), Some("{}")),
+            change("sprints", Some("{}"), Some("{}")),
+        ];
+
+        let tabled = TabledOperation::new(operation, &changes);
+        assert_eq!(tabled.changes, "Updated job 5, Updated sprint 5");
+    }
+}
@@ -0,0 +1,104 @@
+//! Contains all models for full-text search results.
+
+use diesel::QueryableByName;
+use diesel::sql_types::{Integer, Nullable, Text};
+use owo_colors::OwoColorize;
+use tabled::Tabled;
+use tabled::derive::display;
+
+/// Marks the start of a matched term in a search snippet.
+pub const MATCH_START: &str = "\u{2}";
+/// Marks the end of a matched term in a search snippet.
+pub const MATCH_END: &str = "\u{3}";
+
+/// This struct defines a job application matching a full-text search query and is used when
+/// displaying search results in tables. Results are ordered by relevance.
+#[derive(Debug, QueryableByName, Tabled)]
+pub struct SearchResult {
+    /// The SQLite ID.
+    #[diesel(sql_type = Integer)]
+    #[tabled(rename = "ID")]
+    pub id: i32,
+    /// The name of the sprint this job application belongs to.
+    #[diesel(sql_type = Nullable<Text>)]
+    #[tabled(rename = "Sprint")]
+    #[tabled(display("display::option", "N/A"))]
+    pub sprint: Option<String>,
+    /// The name of the company.
+    #[diesel(sql_type = Text)]
+    #[tabled(rename = "Company Name")]
+    pub company_name: String,
+    /// The job title.
+    #[diesel(sql_type = Nullable<Text>)]
+    #[tabled(rename = "Title")]
+    #[tabled(display("display::option", "N/A"))]
+    pub title: Option<String>,
+    /// The application status.
+    #[diesel(sql_type = Nullable<Text>)]
+    #[tabled(rename = "Status")]
+    #[tabled(display("display::option", "N/A"))]
+    pub status: Option<String>,
+    /// An excerpt of the best matching field. Matched terms are wrapped in `MATCH_START` and
+    /// `MATCH_END`.
+    #[diesel(sql_type = Text)]
+    #[tabled(rename = "Match")]
+    #[tabled(display("highlight_matches"))]
+    pub snippet: String,
+}
+
+/// Highlight the matched terms in a search snippet and put the snippet on a single line.
+pub fn highlight_matches(snippet: &str) -> String {
+    let mut highlighted = String::new();
+
+    for (index, part) in snippet.split(MATCH_START).enumerate() {
+        // Everything before the first marker is unmatched text.
+        if index == 0 {
+            highlighted.push_str(part);
+            continue;
+        }
+
+        match part.split_once(MATCH_END) {
+            Some((matched, rest)) => {
+                highlighted.push_str(&matched.yellow().bold().to_string());
+                highlighted.push_str(rest);
+            }
+            None => highlighted.push_str(&part.yellow().bold().to_string()),
+        }
+    }
+
+    highlighted
+        .split_whitespace()
+        .collect::<Vec<&str>>()
+        .join(" ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_highlight_matches() {
+        let snippet = format!("...prep for the {MATCH_START}onsite{MATCH_END} next week");
+        assert_eq!(
+            highlight_matches(&snippet),
+            format!("...prep for the {} next week", "onsite".yellow().bold())
+        );
+    }
+
+    #[test]
+    fn test_highlight_matches_joins_lines() {
+        assert_eq!(
+            highlight_matches("first note\nsecond   note"),
+            "first note second note"
+        );
+    }
+
+    #[test]
+    fn test_highlight_matches_unterminated_match() {
+        let snippet = format!("the {MATCH_START}onsite");
+        assert_eq!(
+            highlight_matches(&snippet),
+            format!("the {}", "onsite".yellow().bold())
+        );
+    }
+}
@@ -4,6 +4,7 @@ use std::fmt::{self, Display, Formatter};
 
 use diesel::sqlite::Sqlite;
 use diesel::{AsChangeset, Insertable, Queryable, Selectable};
+use serde::{Deserialize, Serialize};
 use tabled::Tabled;
 use tabled::derive::display;
 
@@ -24,8 +25,9 @@ pub struct NewSprint<'a> {
     pub num_jobs: &'a i32,
 }
 
-/// This struct defines the sprint object returned from querying SQLite.
-#[derive(Debug, Queryable, Selectable, Tabled)]
+/// This struct defines the sprint object returned from querying SQLite. It is also stored as a JSON
+/// snapshot in the operation log and inserted again when a change is undone.
+#[derive(Clone, Debug, Deserialize, Insertable, Queryable, Selectable, Serialize, Tabled)]
 #[diesel(table_name = sprints)]
 #[diesel(check_for_backend(Sqlite))]
 pub struct QueriedSprint {
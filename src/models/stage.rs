@@ -5,6 +5,7 @@ use std::fmt::{self, Display, Formatter};
 use diesel::sqlite::Sqlite;
 use diesel::{AsChangeset, Insertable, Queryable, Selectable};
 use owo_colors::OwoColorize;
+use serde::{Deserialize, Serialize};
 
 use crate::schema::interview_stages;
 
@@ -98,9 +99,10 @@ pub struct NewInterviewStage {
     pub created: String,
 }
 
-/// This struct defines the interview stage object returned from querying SQLite.
+/// This struct defines the interview stage object returned from querying SQLite. It is also stored
+/// as a JSON snapshot in the operation log and inserted again when a change is undone.
 #[allow(dead_code)]
-#[derive(Clone, Debug, Queryable, Selectable)]
+#[derive(Clone, Debug, Deserialize, Insertable, Queryable, Selectable, Serialize)]
 #[diesel(table_name = interview_stages)]
 #[diesel(check_for_backend(Sqlite))]
 pub struct QueriedInterviewStage {
@@ -4,6 +4,7 @@ use std::fmt::{self, Display, Formatter};
 
 use diesel::sqlite::Sqlite;
 use diesel::{Insertable, Queryable, Selectable};
+use serde::Serialize;
 
 use crate::schema::statuses;
 
@@ -17,7 +18,7 @@ pub struct NewStatus<'a> {
 }
 
 /// This struct defines the status object returned from querying SQLite.
-#[derive(Debug, Queryable, Selectable)]
+#[derive(Debug, Queryable, Selectable, Serialize)]
 #[diesel(table_name = statuses)]
 #[diesel(check_for_backend(Sqlite))]
 pub struct QueriedStatus {
@@ -0,0 +1,85 @@
+//! Contains all models for job application tags.
+
+use std::fmt::{self, Display, Formatter};
+
+use diesel::sqlite::Sqlite;
+use diesel::{Insertable, Queryable, Selectable};
+use tabled::Tabled;
+
+use crate::schema::{job_tags, tags};
+
+/// This struct defines a new tag that will be written to the `tags` table in SQLite.
+#[derive(Debug, Insertable)]
+#[diesel(table_name = tags)]
+#[diesel(check_for_backend(Sqlite))]
+pub struct NewTag<'a> {
+    /// The tag name.
+    pub name: &'a str,
+}
+
+/// This struct defines the tag object returned from querying SQLite.
+#[derive(Clone, Debug, Queryable, Selectable)]
+#[diesel(table_name = tags)]
+#[diesel(check_for_backend(Sqlite))]
+pub struct QueriedTag {
+    /// The SQLite ID.
+    pub id: i32,
+    /// The tag name.
+    pub name: String,
+}
+
+/// Implementing `Display` allows this struct to be displayed in the `MultiSelect` Inquire menu.
+impl Display for QueriedTag {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name)
+    }
+}
+
+/// This struct defines a new link between a job application and a tag that will be written to the
+/// `job_tags` table in SQLite.
+#[derive(Debug, Insertable)]
+#[diesel(table_name = job_tags)]
+#[diesel(check_for_backend(Sqlite))]
+pub struct NewJobTag {
+    /// The job application ID. References the record ID in SQLite.
+    pub job_id: i32,
+    /// The tag ID. References the record ID in SQLite.
+    pub tag_id: i32,
+}
+
+/// This struct defines a tag and the number of job applications it is applied to and is used when
+/// displaying tags in tables.
+#[derive(Debug, Queryable, Tabled)]
+pub struct TabledTag {
+    /// The tag name.
+    #[tabled(rename = "Tag")]
+    pub name: String,
+    /// The number of job applications with this tag.
+    #[tabled(rename = "# of Jobs")]
+    pub num_jobs: i64,
+}
+
+/// Normalize a tag name so that "FinTech " and "fintech" refer to the same tag.
+pub fn normalize_tag_name(name: &str) -> String {
+    name.trim().to_lowercase()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_queried_tag_display() {
+        let tag = QueriedTag {
+            id: 1,
+            name: "fintech".to_string(),
+        };
+        assert_eq!(format!("{}", tag), "fintech");
+    }
+
+    #[test]
+    fn test_normalize_tag_name() {
+        assert_eq!(normalize_tag_name("  Dream-Company "), "dream-company");
+        assert_eq!(normalize_tag_name("needs-visa"), "needs-visa");
+    }
+}
@@ -0,0 +1,84 @@
+//! Contains the attachment repository abstraction class.
+
+use diesel::insert_into;
+use diesel::prelude::*;
+
+use crate::errors::FettersError;
+use crate::models::attachment::{NewAttachment, QueriedAttachment};
+use crate::schema::attachments;
+
+/// Contains all methods pertaining to CRUD operations for the `attachments` table.
+pub struct AttachmentRepository<'a> {
+    /// A mutable reference to the SQLite database connection.
+    pub connection: &'a mut SqliteConnection,
+}
+
+impl<'a> AttachmentRepository<'a> {
+    /// Adds a new attachment to a job.
+    pub fn add_attachment(
+        &mut self,
+        new_attachment: NewAttachment,
+    ) -> Result<QueriedAttachment, FettersError> {
+        Ok(insert_into(attachments::table)
+            .values(&new_attachment)
+            .returning(QueriedAttachment::as_returning())
+            .get_result(self.connection)?)
+    }
+
+    /// Retrieves all attachments for a job in the order they were added.
+    pub fn get_attachments(
+        &mut self,
+        target_job_id: i32,
+    ) -> Result<Vec<QueriedAttachment>, FettersError> {
+        Ok(attachments::table
+            .filter(attachments::job_id.eq(target_job_id))
+            .select(QueriedAttachment::as_select())
+            .order(attachments::id.asc())
+            .load(self.connection)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use diesel::Connection;
+
+    fn setup_test_db() -> SqliteConnection {
+        let mut connection = SqliteConnection::establish(":memory:")
+            .expect("Failed to create in-memory database");
+        crate::utils::migrations::run_migrations(&mut connection)
+            .expect("Failed to run migrations");
+        connection
+    }
+
+    fn attach(conn: &mut SqliteConnection, job_id: i32, file_name: &str) -> QueriedAttachment {
+        let mut repo = AttachmentRepository { connection: conn };
+        repo.add_attachment(NewAttachment {
+            job_id,
+            file_name,
+            file_path: &format!("/tmp/attachments/{job_id}/{file_name}"),
+            added: "2026-04-19 16:42:08",
+        })
+        .unwrap()
+    }
+
+    #[test]
+    fn test_add_and_get_attachments() {
+        let mut conn = setup_test_db();
+        attach(&mut conn, 1, "offer-letter.pdf");
+        attach(&mut conn, 1, "take-home.zip");
+        attach(&mut conn, 2, "screenshot.png");
+
+        let mut repo = AttachmentRepository {
+            connection: &mut conn,
+        };
+        let file_names: Vec<String> = repo
+            .get_attachments(1)
+            .unwrap()
+            .into_iter()
+            .map(|attachment| attachment.file_name)
+            .collect();
+        assert_eq!(file_names, vec!["offer-letter.pdf", "take-home.zip"]);
+        assert!(repo.get_attachments(3).unwrap().is_empty());
+    }
+}
@@ -0,0 +1,216 @@
+//! Contains the custom field repository abstraction class.
+
+use diesel::prelude::*;
+use diesel::{delete, insert_into};
+
+use crate::errors::FettersError;
+use crate::models::custom_field::{NewCustomField, NewCustomFieldValue, QueriedCustomField};
+use crate::schema::{custom_field_values, custom_fields};
+
+/// Contains all methods pertaining to CRUD operations for the `custom_fields` and
+/// `custom_field_values` tables.
+pub struct CustomFieldRepository<'a> {
+    /// A mutable reference to the SQLite database connection.
+    pub connection: &'a mut SqliteConnection,
+}
+
+impl<'a> CustomFieldRepository<'a> {
+    /// Adds a new custom field definition.
+    pub fn add_field(
+        &mut self,
+        new_field: NewCustomField,
+    ) -> Result<QueriedCustomField, FettersError> {
+        Ok(insert_into(custom_fields::table)
+            .values(&new_field)
+            .returning(QueriedCustomField::as_returning())
+            .get_result(self.connection)?)
+    }
+
+    /// Retrieves a custom field definition by name.
+    pub fn get_field_by_name(
+        &mut self,
+        field_name: &str,
+    ) -> Result<Option<QueriedCustomField>, FettersError> {
+        Ok(custom_fields::table
+            .filter(custom_fields::name.eq(field_name))
+            .select(QueriedCustomField::as_select())
+            .first(self.connection)
+            .optional()?)
+    }
+
+    /// Retrieves all custom field definitions in the order they were created.
+    pub fn get_all_fields(&mut self) -> Result<Vec<QueriedCustomField>, FettersError> {
+        Ok(custom_fields::table
+            .order(custom_fields::id.asc())
+            .select(QueriedCustomField::as_select())
+            .load(self.connection)?)
+    }
+
+    /// Deletes a custom field definition along with every value stored for it.
+    pub fn delete_field(&mut self, field_id: i32) -> Result<QueriedCustomField, FettersError> {
+        delete(custom_field_values::table.filter(custom_field_values::field_id.eq(field_id)))
+            .execute(self.connection)?;
+
+        Ok(delete(custom_fields::table.find(field_id))
+            .returning(QueriedCustomField::as_returning())
+            .get_result(self.connection)?)
+    }
+
+    /// Sets the value of a custom field for a job, overwriting any existing value.
+    pub fn set_value(
+        &mut self,
+        target_job_id: i32,
+        target_field_id: i32,
+        new_value: &str,
+    ) -> Result<(), FettersError> {
+        insert_into(custom_field_values::table)
+            .values(&NewCustomFieldValue {
+                job_id: target_job_id,
+                field_id: target_field_id,
+                value: new_value,
+            })
+            .on_conflict((custom_field_values::job_id, custom_field_values::field_id))
+            .do_update()
+            .set(custom_field_values::value.eq(new_value))
+            .execute(self.connection)?;
+
+        Ok(())
+    }
+
+    /// Removes the value of a custom field for a job.
+    pub fn clear_value(
+        &mut self,
+        target_job_id: i32,
+        target_field_id: i32,
+    ) -> Result<(), FettersError> {
+        delete(custom_field_values::table.find((target_job_id, target_field_id)))
+            .execute(self.connection)?;
+
+        Ok(())
+    }
+
+    /// Retrieves all `(job ID, field ID, value)` tuples stored for the given jobs.
+    pub fn get_values_for_jobs(
+        &mut self,
+        job_ids: &[i32],
+    ) -> Result<Vec<(i32, i32, String)>, FettersError> {
+        Ok(custom_field_values::table
+            .filter(custom_field_values::job_id.eq_any(job_ids))
+            .select((
+                custom_field_values::job_id,
+                custom_field_values::field_id,
+                custom_field_values::value,
+            ))
+            .load(self.connection)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use diesel::Connection;
+
+    fn setup_test_db() -> SqliteConnection {
+        let mut connection = SqliteConnection::establish(":memory:")
+            .expect("Failed to create in-memory database");
+        crate::utils::migrations::run_migrations(&mut connection)
+            .expect("Failed to run migrations");
+        connection
+    }
+
+    #[test]
+    fn test_add_and_get_fields() {
+        let mut conn = setup_test_db();
+        let mut repo = CustomFieldRepository {
+            connection: &mut conn,
+        };
+
+        repo.add_field(NewCustomField {
+            name: "Team",
+            field_type: "TEXT",
+            options: None,
+        })
+        .unwrap();
+        let clearance = repo
+            .add_field(NewCustomField {
+                name: "Clearance",
+                field_type: "ENUM",
+                options: Some("Secret,Top Secret"),
+            })
+            .unwrap();
+
+        let fields = repo.get_all_fields().unwrap();
+        assert_eq!(fields.len(), 2);
+        assert_eq!(fields[0].name, "Team");
+        assert_eq!(fields[1].id, clearance.id);
+
+        let fetched = repo.get_field_by_name("Clearance").unwrap().unwrap();
+        assert_eq!(fetched.options.as_deref(), Some("Secret,Top Secret"));
+        assert!(repo.get_field_by_name("Missing").unwrap().is_none());
+    }
+
+    #[test]
+    fn test_add_duplicate_field_fails() {
+        let mut conn = setup_test_db();
+        let mut repo = CustomFieldRepository {
+            connection: &mut conn,
+        };
+
+        let new_field = || NewCustomField {
+            name: "Team",
+            field_type: "TEXT",
+            options: None,
+        };
+        repo.add_field(new_field()).unwrap();
+        assert!(repo.add_field(new_field()).is_err());
+    }
+
+    #[test]
+    fn test_set_value_overwrites_and_clear_value_removes() {
+        let mut conn = setup_test_db();
+        let mut repo = CustomFieldRepository {
+            connection: &mut conn,
+        };
+
+        let field = repo
+            .add_field(NewCustomField {
+                name: "Team",
+                field_type: "TEXT",
+                options: None,
+            })
+            .unwrap();
+
+        repo.set_value(1, field.id, "Payments").unwrap();
+        repo.set_value(1, field.id, "Billing").unwrap();
+        repo.set_value(2, field.id, "Search").unwrap();
+
+        let values = repo.get_values_for_jobs(&[1]).unwrap();
+        assert_eq!(values, vec![(1, field.id, "Billing".to_string())]);
+
+        repo.clear_value(1, field.id).unwrap();
+        assert!(repo.get_values_for_jobs(&[1]).unwrap().is_empty());
+        assert_eq!(repo.get_values_for_jobs(&[1, 2]).unwrap().len(), 1);
+    }
+
+    #[test]
+    fn test_delete_field_removes_values() {
+        let mut conn = setup_test_db();
+        let mut repo = CustomFieldRepository {
+            connection: &mut conn,
+        };
+
+        let field = repo
+            .add_field(NewCustomField {
+                name: "Visa Sponsorship",
+                field_type: "BOOL",
+                options: None,
+            })
+            .unwrap();
+        repo.set_value(1, field.id, "true").unwrap();
+
+        let deleted = repo.delete_field(field.id).unwrap();
+        assert_eq!(deleted.name, "Visa Sponsorship");
+        assert!(repo.get_all_fields().unwrap().is_empty());
+        assert!(repo.get_values_for_jobs(&[1]).unwrap().is_empty());
+    }
+}
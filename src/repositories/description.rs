@@ -0,0 +1,133 @@
+//! Contains the job description repository abstraction class.
+
+use diesel::prelude::*;
+use diesel::{delete, insert_into};
+
+use crate::errors::FettersError;
+use crate::models::description::{NewJobDescription, QueriedJobDescription};
+use crate::schema::job_descriptions;
+
+/// Contains all methods pertaining to CRUD operations for the `job_descriptions` table.
+pub struct DescriptionRepository<'a> {
+    /// A mutable reference to the SQLite database connection.
+    pub connection: &'a mut SqliteConnection,
+}
+
+impl<'a> DescriptionRepository<'a> {
+    /// Stores the job description snapshot for a job, replacing any existing snapshot.
+    pub fn set_description(
+        &mut self,
+        new_description: NewJobDescription,
+    ) -> Result<QueriedJobDescription, FettersError> {
+        Ok(insert_into(job_descriptions::table)
+            .values(&new_description)
+            .on_conflict(job_descriptions::job_id)
+            .do_update()
+            .set((
+                job_descriptions::content.eq(new_description.content),
+                job_descriptions::captured.eq(new_description.captured),
+            ))
+            .returning(QueriedJobDescription::as_returning())
+            .get_result(self.connection)?)
+    }
+
+    /// Retrieves the job description snapshot for a job, if one was stored.
+    pub fn get_description(
+        &mut self,
+        target_job_id: i32,
+    ) -> Result<Option<QueriedJobDescription>, FettersError> {
+        Ok(job_descriptions::table
+            .find(target_job_id)
+            .select(QueriedJobDescription::as_select())
+            .first(self.connection)
+            .optional()?)
+    }
+
+    /// Deletes the job description snapshot for a job.
+    pub fn delete_description(&mut self, target_job_id: i32) -> Result<(), FettersError> {
+        delete(job_descriptions::table.find(target_job_id)).execute(self.connection)?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use diesel::Connection;
+
+    fn setup_test_db() -> SqliteConnection {
+        let mut connection = SqliteConnection::establish(":memory:")
+            .expect("Failed to create in-memory database");
+        crate::utils::migrations::run_migrations(&mut connection)
+            .expect("Failed to run migrations");
+        connection
+    }
+
+    #[test]
+    fn test_set_and_get_description() {
+        let mut conn = setup_test_db();
+        let mut repo = DescriptionRepository {
+            connection: &mut conn,
+        };
+
+        assert!(repo.get_description(1).unwrap().is_none());
+
+        repo.set_description(NewJobDescription {
+            job_id: 1,
+            content: "Build payment rails.",
+            captured: "2026-03-22 10:00:00",
+        })
+        .unwrap();
+
+        let description = repo.get_description(1).unwrap().unwrap();
+        assert_eq!(description.content, "Build payment rails.");
+        assert_eq!(description.captured, "2026-03-22 10:00:00");
+    }
+
+    #[test]
+    fn test_set_description_replaces_existing_snapshot() {
+        let mut conn = setup_test_db();
+        let mut repo = DescriptionRepository {
+            connection: &mut conn,
+        };
+
+        repo.set_description(NewJobDescription {
+            job_id: 1,
+            content: "Old posting",
+            captured: "2026-03-22 10:00:00",
+        })
+        .unwrap();
+        let replaced = repo
+            .set_description(NewJobDescription {
+                job_id: 1,
+                content: "New posting",
+                captured: "2026-03-23 09:30:00",
+            })
+            .unwrap();
+
+        assert_eq!(replaced.content, "New posting");
+        assert_eq!(
+            repo.get_description(1).unwrap().unwrap().captured,
+            "2026-03-23 09:30:00"
+        );
+    }
+
+    #[test]
+    fn test_delete_description() {
+        let mut conn = setup_test_db();
+        let mut repo = DescriptionRepository {
+            connection: &mut conn,
+        };
+
+        repo.set_description(NewJobDescription {
+            job_id: 1,
+            content: "Build payment rails.",
+            captured: "2026-03-22 10:00:00",
+        })
+        .unwrap();
+        repo.delete_description(1).unwrap();
+
+        assert!(repo.get_description(1).unwrap().is_none());
+    }
+}
@@ -0,0 +1,279 @@
+//! Contains the document repository abstraction class.
+
+use diesel::dsl::sql;
+use diesel::insert_into;
+use diesel::prelude::*;
+
+use crate::errors::FettersError;
+use crate::models::document::{NewDocument, QueriedDocument};
+use crate::models::insight::ConversionRates;
+use crate::models::sprint::QueriedSprint;
+use crate::schema::{documents, jobs, statuses};
+
+/// Contains all methods pertaining to CRUD operations for the `documents` table.
+pub struct DocumentRepository<'a> {
+    /// A mutable reference to the SQLite database connection.
+    pub connection: &'a mut SqliteConnection,
+}
+
+impl<'a> DocumentRepository<'a> {
+    /// Adds a new document version.
+    pub fn add_document(
+        &mut self,
+        new_document: NewDocument,
+    ) -> Result<QueriedDocument, FettersError> {
+        Ok(insert_into(documents::table)
+            .values(&new_document)
+            .returning(QueriedDocument::as_returning())
+            .get_result(self.connection)?)
+    }
+
+    /// Retrieves all document versions, optionally limited to one kind, sorted by kind and the
+    /// order in which they were added.
+    pub fn get_all_documents(
+        &mut self,
+        kind: Option<&str>,
+    ) -> Result<Vec<QueriedDocument>, FettersError> {
+        let mut query = documents::table
+            .select(QueriedDocument::as_select())
+            .order((documents::kind.desc(), documents::id.asc()))
+            .into_boxed();
+
+        if let Some(kind) = kind {
+            query = query.filter(documents::kind.eq(kind.to_string()));
+        }
+
+        Ok(query.load(self.connection)?)
+    }
+
+    /// Retrieves a document version by ID.
+    pub fn get_document(&mut self, document_id: i32) -> Result<QueriedDocument, FettersError> {
+        Ok(documents::table
+            .find(document_id)
+            .select(QueriedDocument::as_select())
+            .first(self.connection)?)
+    }
+
+    /// Retrieves a document version that has the given checksum, if one was already added.
+    pub fn get_document_by_checksum(
+        &mut self,
+        checksum: &str,
+    ) -> Result<Option<QueriedDocument>, FettersError> {
+        Ok(documents::table
+            .filter(documents::checksum.eq(checksum))
+            .select(QueriedDocument::as_select())
+            .first(self.connection)
+            .optional()?)
+    }
+
+    /// Get the response, interview, and offer rates per resume version across all sprints.
+    pub fn count_rates_per_resume(
+        &mut self,
+        current_sprint: &QueriedSprint,
+    ) -> Result<Vec<ConversionRates>, FettersError> {
+        let outcomes = jobs::table
+            .left_join(documents::table.on(jobs::resume_id.eq(documents::id.nullable())))
+            .left_join(statuses::table.on(jobs::status_id.eq(statuses::id)))
+            .filter(jobs::deleted_at.is_null())
+            .select((
+                documents::label.nullable(),
+                statuses::name.nullable(),
+                sql::<diesel::sql_types::BigInt>(
+                    "(SELECT COUNT(*) FROM interview_stages WHERE interview_stages.job_id = jobs.id)",
+                ),
+                jobs::sprint_id.eq(current_sprint.id),
+            ))
+            .load::<(Option<String>, Option<String>, i64, bool)>(self.connection)?;
+
+        Ok(ConversionRates::from_outcomes(outcomes))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use diesel::Connection;
+
+    use crate::models::job::NewJob;
+    use crate::models::sprint::NewSprint;
+    use crate::models::stage::NewInterviewStage;
+    use crate::models::title::NewTitle;
+    use crate::repositories::job::JobRepository;
+    use crate::repositories::sprint::SprintRepository;
+    use crate::repositories::stage::StageRepository;
+    use crate::repositories::statuses::StatusRepository;
+    use crate::repositories::title::TitleRepository;
+
+    fn setup_test_db() -> SqliteConnection {
+        let mut connection = SqliteConnection::establish(":memory:")
+            .expect("Failed to create in-memory database");
+        crate::utils::migrations::run_migrations(&mut connection)
+            .expect("Failed to run migrations");
+
+        let mut status_repo = StatusRepository {
+            connection: &mut connection,
+        };
+        status_repo.seed_statuses().expect("Failed to seed statuses");
+
+        connection
+    }
+
+    fn add_document(conn: &mut SqliteConnection, kind: &str, label: &str) -> QueriedDocument {
+        let mut repo = DocumentRepository { connection: conn };
+        repo.add_document(NewDocument {
+            kind,
+            label,
+            file_path: &format!("/tmp/{label}.pdf"),
+            checksum: &format!("checksum-{label}"),
+            created: "2026-04-05 10:15:22",
+        })
+        .unwrap()
+    }
+
+    #[test]
+    fn test_add_and_get_documents() {
+        let mut conn = setup_test_db();
+        add_document(&mut conn, "COVER LETTER", "generic");
+        add_document(&mut conn, "RESUME", "backend-v1");
+        add_document(&mut conn, "RESUME", "backend-v2");
+
+        let mut repo = DocumentRepository {
+            connection: &mut conn,
+        };
+        let all_documents = repo.get_all_documents(None).unwrap();
+        let labels: Vec<&str> = all_documents.iter().map(|d| d.label.as_str()).collect();
+        assert_eq!(labels, vec!["backend-v1", "backend-v2", "generic"]);
+
+        let cover_letters = repo.get_all_documents(Some("COVER LETTER")).unwrap();
+        assert_eq!(cover_letters.len(), 1);
+        assert_eq!(cover_letters[0].label, "generic");
+    }
+
+    #[test]
+    fn test_add_duplicate_label_fails() {
+        let mut conn = setup_test_db();
+        add_document(&mut conn, "RESUME", "backend-v1");
+
+        let mut repo = DocumentRepository {
+            connection: &mut conn,
+        };
+        assert!(
+            repo.add_document(NewDocument {
+                kind: "RESUME",
+                label: "backend-v1",
+                file_path: "/tmp/other.pdf",
+                checksum: "other",
+                created: "2026-04-05 10:15:22",
+            })
+            .is_err()
+        );
+    }
+
+    #[test]
+    fn test_get_document_by_checksum() {
+        let mut conn = setup_test_db();
+        let document = add_document(&mut conn, "RESUME", "backend-v1");
+
+        let mut repo = DocumentRepository {
+            connection: &mut conn,
+        };
+        assert_eq!(
+            repo.get_document_by_checksum("checksum-backend-v1")
+                .unwrap()
+                .unwrap()
+                .id,
+            document.id
+        );
+        assert!(repo.get_document_by_checksum("missing").unwrap().is_none());
+    }
+
+    #[test]
+    fn test_count_rates_per_resume() {
+        let mut conn = setup_test_db();
+        let v1 = add_document(&mut conn, "RESUME", "backend-v1");
+        let v2 = add_document(&mut conn, "RESUME", "backend-v2");
+
+        let mut sprint_repo = SprintRepository {
+            connection: &mut conn,
+        };
+        let sprint = sprint_repo
+            .add_job_sprint(NewSprint {
+                name: "test-sprint",
+                start_date: "2026-04-01",
+                end_date: None,
+                num_jobs: &0,
+            })
+            .unwrap();
+        let mut title_repo = TitleRepository {
+            connection: &mut conn,
+        };
+        let title = title_repo.add_title(NewTitle { name: "SWE" }).unwrap();
+        let mut status_repo = StatusRepository {
+            connection: &mut conn,
+        };
+        let pending_id = status_repo
+            .get_all_statuses()
+            .unwrap()
+            .into_iter()
+            .find(|status| status.name == "PENDING")
+            .unwrap()
+            .id;
+
+        let mut job_ids = Vec::new();
+        let mut job_repo = JobRepository {
+            connection: &mut conn,
+        };
+        for (company, resume_id) in [
+            ("Stripe", Some(v1.id)),
+            ("Plaid", Some(v2.id)),
+            ("Ramp", Some(v2.id)),
+            ("Brex", None),
+        ] {
+            let job = job_repo
+                .add_job(NewJob {
+                    company_name: company,
+                    created: "2026-04-05 10:15:22".to_string(),
+                    title_id: title.id,
+                    status_id: pending_id,
+                    link: None,
+                    sprint_id: sprint.id,
+                    source_id: None,
+                    referrer: None,
+                    resume_id,
+                    cover_letter_id: None,
+                })
+                .unwrap();
+            job_ids.push(job.id);
+        }
+
+        let mut stage_repo = StageRepository {
+            connection: &mut conn,
+        };
+        stage_repo
+            .add_stage(NewInterviewStage {
+                job_id: job_ids[1],
+                stage_number: 1,
+                name: Some("Phone Screen".to_string()),
+                status: "SCHEDULED".to_string(),
+                scheduled_date: "2026/04/10".to_string(),
+                notes: None,
+                created: "2026-04-05 10:15:22".to_string(),
+            })
+            .unwrap();
+
+        let mut repo = DocumentRepository {
+            connection: &mut conn,
+        };
+        let rates = repo.count_rates_per_resume(&sprint).unwrap();
+        assert_eq!(rates.len(), 3);
+
+        let v2_rates = rates.iter().find(|r| r.jobs.label == "backend-v2").unwrap();
+        assert_eq!(v2_rates.jobs.count, 2);
+        assert_eq!(v2_rates.interview_rate, "50.00%");
+
+        let v1_rates = rates.iter().find(|r| r.jobs.label == "backend-v1").unwrap();
+        assert_eq!(v1_rates.interview_rate, "0.00%");
+
+        assert_eq!(rates.iter().find(|r| r.jobs.label == "N/A").unwrap().jobs.count, 1);
+    }
+}
@@ -1,20 +1,50 @@
 //! Contains the job repository abstraction class.
 
+use std::collections::HashMap;
+
+use chrono::{Duration, Local};
 use diesel::dsl::{count, sql};
 use diesel::prelude::*;
-use diesel::sql_types::Nullable;
+use diesel::sql_types::{Bool, Integer, Nullable};
 use diesel::sqlite::Sqlite;
 use diesel::{delete, insert_into, update};
 
-use crate::cli::QueryArgs;
+use crate::cli::{QueryArgs, SortField};
 use crate::errors::FettersError;
-use crate::models::insight::CountAndPercentage;
+use crate::models::custom_field::CustomFieldType;
+use crate::models::insight::{ConversionRates, CountAndPercentage};
+use crate::models::tag::normalize_tag_name;
 use crate::models::{
-    job::{JobUpdate, NewJob, QueriedJob, TabledJob},
+    job::{JobDetails, JobUpdate, NewJob, QueriedJob, TabledJob, TabledTrashedJob},
     sprint::QueriedSprint,
 };
+use crate::repositories::attachment::AttachmentRepository;
+use crate::repositories::custom_field::CustomFieldRepository;
+use crate::repositories::description::DescriptionRepository;
+use crate::repositories::document::DocumentRepository;
+use crate::repositories::note::NoteRepository;
+use crate::repositories::operation::OperationRepository;
 use crate::repositories::sprint::SprintRepository;
-use crate::schema::{jobs, sprints, statuses, titles};
+use crate::repositories::stage::StageRepository;
+use crate::repositories::tag::TagRepository;
+use crate::schema::{
+    attachments, custom_field_values, interview_stages, job_descriptions, job_notes, job_tags,
+    jobs, sources, sprints, statuses, tags, titles,
+};
+
+/// Selects the timestamp of the most recent activity for a job application: its creation, its
+/// latest note or interview stage, or the latest change recorded in the operation log that has not
+/// been undone. Timestamps all start with `YYYY-MM-DD`, so the latest one sorts last.
+const LAST_ACTIVITY_SQL: &str = "MAX(\
+    jobs.created, \
+    COALESCE((SELECT MAX(created) FROM job_notes WHERE job_notes.job_id = jobs.id), ''), \
+    COALESCE((SELECT MAX(created) FROM interview_stages WHERE interview_stages.job_id = jobs.id), ''), \
+    COALESCE((SELECT MAX(operations.performed) FROM operation_changes \
+        INNER JOIN operations ON operations.id = operation_changes.operation_id \
+        WHERE operations.undone_at IS NULL AND (\
+            (operation_changes.table_name = 'jobs' AND operation_changes.row_id = jobs.id) \
+            OR (operation_changes.table_name = 'interview_stages' AND operation_changes.row_id IN \
+                (SELECT id FROM interview_stages WHERE interview_stages.job_id = jobs.id)))), ''))";
 
 /// Contains all methods pertaining to CRUD operations for the `jobs` table.
 pub struct JobRepository<'a> {
@@ -31,6 +61,11 @@ impl<'a> JobRepository<'a> {
             .returning(QueriedJob::as_returning())
             .get_result(self.connection)?;
 
+        let mut operation_repo = OperationRepository {
+            connection: self.connection,
+        };
+        operation_repo.record_change("jobs", queried_job.id, None, Some(&queried_job))?;
+
         let mut sprint_repo = SprintRepository {
             connection: self.connection,
         };
@@ -39,6 +74,120 @@ impl<'a> JobRepository<'a> {
         Ok(queried_job)
     }
 
+    /// Retrieves an existing job by ID.
+    pub fn get_job(&mut self, job_id: i32) -> Result<QueriedJob, FettersError> {
+        use crate::schema::jobs::dsl::*;
+
+        Ok(jobs
+            .find(job_id)
+            .select(QueriedJob::as_select())
+            .first(self.connection)?)
+    }
+
+    /// Retrieves an existing job by ID in the same format as `list_jobs`.
+    pub fn get_tabled_job(&mut self, job_id: i32) -> Result<TabledJob, FettersError> {
+        let queried_job = self.get_job(job_id).map_err(|error| match error {
+            FettersError::DieselResultError(diesel::result::Error::NotFound) => {
+                FettersError::JobNotFound(job_id)
+            }
+            error => error,
+        })?;
+
+        let mut sprint_repo = SprintRepository {
+            connection: self.connection,
+        };
+        let sprint = sprint_repo.get_sprint(queried_job.sprint_id)?;
+
+        let query_args = QueryArgs {
+            id: Some(job_id),
+            all_sprints: true,
+            ..Default::default()
+        };
+
+        self.list_jobs(&query_args, &sprint)?
+            .into_iter()
+            .next()
+            .ok_or(FettersError::JobNotFound(job_id))
+    }
+
+    /// Collects everything stored about a job returned from `list_jobs` or `get_tabled_job`.
+    pub fn get_job_details(&mut self, job: &TabledJob) -> Result<JobDetails, FettersError> {
+        let queried_job = self.get_job(job.id)?;
+
+        let mut sprint_repo = SprintRepository {
+            connection: self.connection,
+        };
+        let sprint = sprint_repo.get_sprint(queried_job.sprint_id)?;
+
+        let mut tag_repo = TagRepository {
+            connection: self.connection,
+        };
+        let tags = tag_repo
+            .get_tags_for_job(job.id)?
+            .into_iter()
+            .map(|tag| tag.name)
+            .collect();
+
+        let mut document_repo = DocumentRepository {
+            connection: self.connection,
+        };
+        let resume = queried_job
+            .resume_id
+            .map(|resume_id| document_repo.get_document(resume_id))
+            .transpose()?
+            .map(|document| document.label);
+        let cover_letter = queried_job
+            .cover_letter_id
+            .map(|cover_letter_id| document_repo.get_document(cover_letter_id))
+            .transpose()?
+            .map(|document| document.label);
+
+        let mut note_repo = NoteRepository {
+            connection: self.connection,
+        };
+        let notes = note_repo.get_notes_for_job(job.id)?;
+
+        let mut stage_repo = StageRepository {
+            connection: self.connection,
+        };
+        let stages = stage_repo.get_stages_for_job(job.id)?;
+
+        let mut attachment_repo = AttachmentRepository {
+            connection: self.connection,
+        };
+        let attachments = attachment_repo.get_attachments(job.id)?;
+
+        let mut description_repo = DescriptionRepository {
+            connection: self.connection,
+        };
+        let description = description_repo.get_description(job.id)?;
+
+        Ok(JobDetails {
+            id: job.id,
+            created: job.created.clone(),
+            company_name: job.company_name.clone(),
+            title: job.title.clone(),
+            status: job.status.clone(),
+            sprint: sprint.name,
+            source: job.source.clone(),
+            referrer: queried_job.referrer,
+            link: job.link.clone(),
+            notes,
+            tags,
+            custom_fields: job
+                .custom_fields
+                .iter()
+                .filter(|(_, value)| !value.is_empty())
+                .cloned()
+                .collect(),
+            resume,
+            cover_letter,
+            stages,
+            attachments,
+            description,
+        })
+    }
+
     /// Updates an existing job with new changes.
     pub fn update_job(
         &mut self,
@@ -47,20 +196,76 @@ impl<'a> JobRepository<'a> {
     ) -> Result<QueriedJob, FettersError> {
         use crate::schema::jobs::dsl::*;
 
-        Ok(update(jobs.find(job_id))
+        let previous_job = self.get_job(job_id)?;
+
+        let queried_job = update(jobs.find(job_id))
             .set(&changes)
             .returning(QueriedJob::as_returning())
-            .get_result(self.connection)?)
+            .get_result(self.connection)?;
+
+        let mut operation_repo = OperationRepository {
+            connection: self.connection,
+        };
+        operation_repo.record_change("jobs", job_id, Some(&previous_job), Some(&queried_job))?;
+
+        // Keep the job counts of both sprints in sync when a job is moved to another sprint.
+        if queried_job.sprint_id != previous_job.sprint_id {
+            let mut sprint_repo = SprintRepository {
+                connection: self.connection,
+            };
+            sprint_repo.decrement_num_jobs(previous_job.sprint_id)?;
+            sprint_repo.increment_num_jobs(queried_job.sprint_id)?;
+        }
+
+        Ok(queried_job)
     }
 
-    /// Deletes an existing job.
-    pub fn delete_job(&mut self, job_id: i32) -> Result<QueriedJob, FettersError> {
+    /// Removes the application source of an existing job.
+    pub fn clear_source(&mut self, job_id: i32) -> Result<QueriedJob, FettersError> {
         use crate::schema::jobs::dsl::*;
 
-        let queried_job = delete(jobs.find(job_id))
+        let previous_job = self.get_job(job_id)?;
+
+        let queried_job = update(jobs.find(job_id))
+            .set(source_id.eq(None::<i32>))
             .returning(QueriedJob::as_returning())
             .get_result(self.connection)?;
 
+        let mut operation_repo = OperationRepository {
+            connection: self.connection,
+        };
+        operation_repo.record_change("jobs", job_id, Some(&previous_job), Some(&queried_job))?;
+
+        Ok(queried_job)
+    }
+
+    /// Moves an existing job to the trash. Trashed jobs are excluded from all queries until they
+    /// are restored.
+    pub fn trash_job(&mut self, job_id: i32, timestamp: &str) -> Result<QueriedJob, FettersError> {
+        use crate::schema::jobs::dsl::*;
+
+        let queried_job = update(jobs.find(job_id).filter(deleted_at.is_null()))
+            .set(deleted_at.eq(timestamp))
+            .returning(QueriedJob::as_returning())
+            .get_result(self.connection)
+            .map_err(|error| match error {
+                diesel::result::Error::NotFound => FettersError::JobNotFound(job_id),
+                error => FettersError::DieselResultError(error),
+            })?;
+
+        let mut operation_repo = OperationRepository {
+            connection: self.connection,
+        };
+        operation_repo.record_change(
+            "jobs",
+            job_id,
+            Some(&QueriedJob {
+                deleted_at: None,
+                ..queried_job.clone()
+            }),
+            Some(&queried_job),
+        )?;
+
         let mut sprint_repo = SprintRepository {
             connection: self.connection,
         };
@@ -69,6 +274,147 @@ impl<'a> JobRepository<'a> {
         Ok(queried_job)
     }
 
+    /// Restores a job from the trash.
+    pub fn restore_job(&mut self, job_id: i32) -> Result<QueriedJob, FettersError> {
+        use crate::schema::jobs::dsl::*;
+
+        let previous_deleted_at = jobs
+            .find(job_id)
+            .select(deleted_at)
+            .first::<Option<String>>(self.connection)
+            .optional()?
+            .flatten();
+
+        let queried_job = update(jobs.find(job_id).filter(deleted_at.is_not_null()))
+            .set(deleted_at.eq(None::<String>))
+            .returning(QueriedJob::as_returning())
+            .get_result(self.connection)
+            .map_err(|error| match error {
+                diesel::result::Error::NotFound => FettersError::JobNotInTrash(job_id),
+                error => FettersError::DieselResultError(error),
+            })?;
+
+        let mut operation_repo = OperationRepository {
+            connection: self.connection,
+        };
+        operation_repo.record_change(
+            "jobs",
+            job_id,
+            Some(&QueriedJob {
+                deleted_at: previous_deleted_at,
+                ..queried_job.clone()
+            }),
+            Some(&queried_job),
+        )?;
+
+        let mut sprint_repo = SprintRepository {
+            connection: self.connection,
+        };
+        sprint_repo.increment_num_jobs(queried_job.sprint_id)?;
+
+        Ok(queried_job)
+    }
+
+    /// Retrieves the distinct company names of all jobs that are not in the trash, in alphabetical
+    /// order.
+    pub fn get_company_names(&mut self) -> Result<Vec<String>, FettersError> {
+        Ok(jobs::table
+            .filter(jobs::deleted_at.is_null())
+            .select(jobs::company_name)
+            .distinct()
+            .order(jobs::company_name.asc())
+            .load::<String>(self.connection)?)
+    }
+
+    /// Retrieves all jobs in the trash, most recently deleted first.
+    pub fn get_trashed_jobs(&mut self) -> Result<Vec<TabledTrashedJob>, FettersError> {
+        Ok(jobs::table
+            .left_join(titles::table.on(jobs::title_id.eq(titles::id)))
+            .left_join(statuses::table.on(jobs::status_id.eq(statuses::id)))
+            .left_join(sprints::table.on(jobs::sprint_id.eq(sprints::id)))
+            .filter(jobs::deleted_at.is_not_null())
+            .order((jobs::deleted_at.desc(), jobs::id.desc()))
+            .select((
+                jobs::id,
+                jobs::deleted_at.assume_not_null(),
+                jobs::company_name,
+                titles::name.nullable(),
+                statuses::name.nullable(),
+                sprints::name.nullable(),
+            ))
+            .load::<TabledTrashedJob>(self.connection)?)
+    }
+
+    /// Permanently deletes all jobs in the trash that were deleted at or before the cutoff
+    /// timestamp, or every job in the trash if no cutoff is provided. Returns the IDs of the
+    /// deleted jobs.
+    pub fn purge_trashed_jobs(&mut self, cutoff: Option<&str>) -> Result<Vec<i32>, FettersError> {
+        let mut query = jobs::table
+            .filter(jobs::deleted_at.is_not_null())
+            .select(jobs::id)
+            .into_boxed::<Sqlite>();
+
+        if let Some(cutoff) = cutoff {
+            query = query.filter(jobs::deleted_at.le(cutoff.to_string()));
+        }
+
+        let job_ids = query.load::<i32>(self.connection)?;
+        for job_id in &job_ids {
+            self.delete_job(*job_id)?;
+        }
+
+        Ok(job_ids)
+    }
+
+    /// Permanently deletes an existing job. The job can no longer be restored by undoing earlier
+    /// operations.
+    pub fn delete_job(&mut self, job_id: i32) -> Result<QueriedJob, FettersError> {
+        use crate::schema::jobs::dsl::*;
+
+        let stage_ids = interview_stages::table
+            .filter(interview_stages::job_id.eq(job_id))
+            .select(interview_stages::id)
+            .load::<i32>(self.connection)?;
+        let mut operation_repo = OperationRepository {
+            connection: self.connection,
+        };
+        operation_repo.forget_job(job_id, &stage_ids)?;
+
+        self.delete_related_rows(job_id)?;
+
+        let queried_job = delete(jobs.find(job_id))
+            .returning(QueriedJob::as_returning())
+            .get_result(self.connection)?;
+
+        // Trashed jobs were already removed from their sprint's job count.
+        if queried_job.deleted_at.is_none() {
+            let mut sprint_repo = SprintRepository {
+                connection: self.connection,
+            };
+            sprint_repo.decrement_num_jobs(queried_job.sprint_id)?;
+        }
+
+        Ok(queried_job)
+    }
+
+    /// Deletes all rows referencing a job, including its interview stages, tags, custom field
+    /// values, description, notes, and attachment records.
+    pub fn delete_related_rows(&mut self, job_id: i32) -> Result<(), FettersError> {
+        // Foreign key constraints are not enforced, so rows referencing this job have to be
+        // removed explicitly. Otherwise they would be left orphaned.
+        delete(interview_stages::table.filter(interview_stages::job_id.eq(job_id)))
+            .execute(self.connection)?;
+        delete(job_tags::table.filter(job_tags::job_id.eq(job_id))).execute(self.connection)?;
+        delete(custom_field_values::table.filter(custom_field_values::job_id.eq(job_id)))
+            .execute(self.connection)?;
+        delete(job_descriptions::table.find(job_id)).execute(self.connection)?;
+        delete(job_notes::table.filter(job_notes::job_id.eq(job_id))).execute(self.connection)?;
+        delete(attachments::table.filter(attachments::job_id.eq(job_id)))
+            .execute(self.connection)?;
+
+        Ok(())
+    }
+
     /// List all jobs matching the query.
     pub fn list_jobs(
         &mut self,
@@ -79,36 +425,80 @@ impl<'a> JobRepository<'a> {
             .left_join(titles::table.on(jobs::title_id.eq(titles::id)))
             .left_join(statuses::table.on(jobs::status_id.eq(statuses::id)))
             .left_join(sprints::table.on(jobs::sprint_id.eq(sprints::id)))
+            .left_join(sources::table.on(jobs::source_id.eq(sources::id.nullable())))
             .select((
                 jobs::id,
                 jobs::created,
                 jobs::company_name,
                 titles::name.nullable(),
                 statuses::name.nullable(),
+                sources::name.nullable(),
                 sql::<Nullable<diesel::sql_types::Integer>>(
                     "NULLIF((SELECT COUNT(*) FROM interview_stages WHERE interview_stages.job_id = jobs.id), 0)",
                 ),
+                sql::<Nullable<diesel::sql_types::Text>>(
+                    "(SELECT GROUP_CONCAT(name, ', ') FROM (SELECT tags.name AS name FROM job_tags INNER JOIN tags ON tags.id = job_tags.tag_id WHERE job_tags.job_id = jobs.id ORDER BY tags.name))",
+                ),
                 jobs::link,
-                jobs::notes,
+                sql::<Nullable<diesel::sql_types::Text>>(
+                    "(SELECT content FROM job_notes WHERE job_notes.job_id = jobs.id ORDER BY created DESC, id DESC LIMIT 1)",
+                ),
+                sql::<diesel::sql_types::Text>(LAST_ACTIVITY_SQL),
             ))
+            .filter(jobs::deleted_at.is_null())
             .into_boxed::<Sqlite>();
 
         if let Some(sprint) = &query_args.sprint {
             query = query.filter(sprints::name.like(format!("%{}%", sprint)));
-        } else {
+        } else if !query_args.searches_all_sprints() {
             query = query.filter(sprints::id.eq(current_sprint.id));
         }
 
+        if let Some(id) = query_args.id {
+            query = query.filter(jobs::id.eq(id));
+        }
+
+        let today = Local::now().date_naive();
+        if let Some(since) = &query_args.since {
+            let since = since.resolve(today);
+            query = query.filter(jobs::created.ge(since.format("%Y-%m-%d").to_string()));
+        }
+        if let Some(until) = &query_args.until {
+            // Timestamps on the `until` date are included by comparing against the next day.
+            let next_day = until.resolve(today) + Duration::days(1);
+            query = query.filter(jobs::created.lt(next_day.format("%Y-%m-%d").to_string()));
+        }
+
         if let Some(company) = &query_args.company {
             query = query.filter(jobs::company_name.like(format!("%{}%", company)));
         }
 
+        if let Some(description) = &query_args.description {
+            query = query.filter(
+                jobs::id.eq_any(
+                    job_descriptions::table
+                        .filter(job_descriptions::content.like(format!("%{}%", description)))
+                        .select(job_descriptions::job_id),
+                ),
+            );
+        }
+
         if let Some(link) = &query_args.link {
             query = query.filter(jobs::link.like(format!("%{}%", link)));
         }
 
         if let Some(notes) = &query_args.notes {
-            query = query.filter(jobs::notes.like(format!("%{}%", notes)));
+            query = query.filter(
+                jobs::id.eq_any(
+                    job_notes::table
+                        .filter(job_notes::content.like(format!("%{}%", notes)))
+                        .select(job_notes::job_id),
+                ),
+            );
+        }
+
+        if let Some(source) = &query_args.source {
+            query = query.filter(sources::name.like(format!("%{}%", source)));
         }
 
         if let Some(status) = &query_args.status {
@@ -119,24 +509,159 @@ impl<'a> JobRepository<'a> {
             query = query.filter(titles::name.like(format!("%{}%", title)));
         }
 
-        let mut jobs = query.load::<TabledJob>(self.connection)?;
+        if !query_args.tags.is_empty() {
+            let tag_names: Vec<String> = query_args
+                .tags
+                .iter()
+                .map(|tag| normalize_tag_name(tag))
+                .collect();
+
+            if query_args.all_tags {
+                for tag_name in tag_names {
+                    query = query.filter(
+                        jobs::id.eq_any(
+                            job_tags::table
+                                .inner_join(tags::table)
+                                .filter(tags::name.eq(tag_name))
+                                .select(job_tags::job_id),
+                        ),
+                    );
+                }
+            } else {
+                query = query.filter(
+                    jobs::id.eq_any(
+                        job_tags::table
+                            .inner_join(tags::table)
+                            .filter(tags::name.eq_any(tag_names))
+                            .select(job_tags::job_id),
+                    ),
+                );
+            }
+        }
+
+        for (field_name, field_value) in &query_args.fields {
+            let mut field_repo = CustomFieldRepository {
+                connection: self.connection,
+            };
+            let field = field_repo
+                .get_field_by_name(field_name)?
+                .ok_or(FettersError::UnknownCustomField(field_name.to_string()))?;
+
+            let matching_job_ids = custom_field_values::table
+                .filter(custom_field_values::field_id.eq(field.id))
+                .select(custom_field_values::job_id);
+
+            query = match field.parsed_type() {
+                CustomFieldType::Text => query.filter(
+                    jobs::id.eq_any(
+                        matching_job_ids
+                            .filter(custom_field_values::value.like(format!("%{}%", field_value))),
+                    ),
+                ),
+                field_type => {
+                    let normalized_value = field_type
+                        .validate(field_value, &field.option_list())
+                        .map_err(FettersError::InvalidCustomFieldValue)?;
+
+                    query.filter(jobs::id.eq_any(
+                        matching_job_ids.filter(custom_field_values::value.eq(normalized_value)),
+                    ))
+                }
+            };
+        }
+
+        if let Some(where_clause) = &query_args.where_clause {
+            query = query.filter(where_clause.expr.compile(today));
+        }
 
         if let Some(stages_filter) = query_args.stages {
-            if stages_filter == 0 {
-                jobs.retain(|j| j.stages.is_some());
+            let stage_count =
+                "(SELECT COUNT(*) FROM interview_stages WHERE interview_stages.job_id = jobs.id)";
+
+            query = if stages_filter == 0 {
+                query.filter(sql::<Bool>(&format!("{stage_count} > 0")))
             } else {
-                jobs.retain(|j| j.stages == Some(stages_filter));
-            }
+                query.filter(
+                    sql::<Bool>(&format!("{stage_count} = "))
+                        .bind::<Integer, _>(stages_filter),
+                )
+            };
+        }
+
+        if let Some(sort) = &query_args.sort {
+            query = match (sort.field, sort.descending) {
+                (SortField::Id, false) => query.order(jobs::id.asc()),
+                (SortField::Id, true) => query.order(jobs::id.desc()),
+                (SortField::Created, false) => query.order(jobs::created.asc()),
+                (SortField::Created, true) => query.order(jobs::created.desc()),
+                (SortField::Company, false) => query.order(jobs::company_name.asc()),
+                (SortField::Company, true) => query.order(jobs::company_name.desc()),
+                (SortField::Title, false) => query.order(titles::name.asc()),
+                (SortField::Title, true) => query.order(titles::name.desc()),
+                (SortField::Status, false) => query.order(statuses::name.asc()),
+                (SortField::Status, true) => query.order(statuses::name.desc()),
+                (SortField::Source, false) => query.order(sources::name.asc()),
+                (SortField::Source, true) => query.order(sources::name.desc()),
+                (SortField::Sprint, false) => query.order(sprints::name.asc()),
+                (SortField::Sprint, true) => query.order(sprints::name.desc()),
+            };
         }
+        // Jobs with equal sort values keep the order in which they were added.
+        query = query.then_order_by(jobs::id.asc());
+
+        if let Some(limit) = query_args.limit {
+            query = query.limit(limit);
+        }
+
+        let mut jobs = query.load::<TabledJob>(self.connection)?;
+
+        self.fill_custom_fields(&mut jobs)?;
 
         Ok(jobs)
     }
 
+    /// Fill in the values of every defined custom field for the given jobs. Fields without a value
+    /// for a job are set to an empty string so that all jobs have the same columns.
+    fn fill_custom_fields(&mut self, jobs: &mut [TabledJob]) -> Result<(), FettersError> {
+        let mut field_repo = CustomFieldRepository {
+            connection: self.connection,
+        };
+        let all_fields = field_repo.get_all_fields()?;
+
+        if all_fields.is_empty() {
+            return Ok(());
+        }
+
+        let job_ids: Vec<i32> = jobs.iter().map(|job| job.id).collect();
+        let values: HashMap<(i32, i32), String> = field_repo
+            .get_values_for_jobs(&job_ids)?
+            .into_iter()
+            .map(|(job_id, field_id, value)| ((job_id, field_id), value))
+            .collect();
+
+        for job in jobs.iter_mut() {
+            job.custom_fields = all_fields
+                .iter()
+                .map(|field| {
+                    (
+                        field.name.clone(),
+                        values.get(&(job.id, field.id)).cloned().unwrap_or_default(),
+                    )
+                })
+                .collect();
+        }
+
+        Ok(())
+    }
+
     /// Get the total number of jobs in the database.
     fn count_total_jobs(&mut self) -> Result<i64, FettersError> {
         use crate::schema::jobs::dsl::*;
 
-        Ok(jobs.select(count(id)).first(self.connection)?)
+        Ok(jobs
+            .filter(deleted_at.is_null())
+            .select(count(id))
+            .first(self.connection)?)
     }
 
     /// Get the total number of jobs in the database by sprint.
@@ -148,6 +673,7 @@ impl<'a> JobRepository<'a> {
 
         Ok(jobs::table
             .left_join(sprints::table.on(jobs::sprint_id.eq(current_sprint.id)))
+            .filter(jobs::deleted_at.is_null())
             .select(count(jobs::id))
             .first(self.connection)?)
     }
@@ -168,6 +694,7 @@ impl<'a> JobRepository<'a> {
             .group_by(statuses::name)
             .select((statuses::name.nullable(), count(jobs::id)))
             .filter(sprints::id.eq(current_sprint.id))
+            .filter(jobs::deleted_at.is_null())
             .load::<(Option<String>, i64)>(self.connection)?;
 
         let mut jobs_per_status: Vec<CountAndPercentage> = Vec::new();
@@ -203,6 +730,7 @@ impl<'a> JobRepository<'a> {
 
         let counts = jobs::table
             .left_join(sprints::table.on(jobs::sprint_id.eq(sprints::id)))
+            .filter(jobs::deleted_at.is_null())
             .group_by(sprints::name)
             .select((sprints::name.nullable(), count(jobs::id)))
             .load::<(Option<String>, i64)>(self.connection)?;
@@ -227,6 +755,28 @@ impl<'a> JobRepository<'a> {
 
         Ok(jobs_per_sprint)
     }
+
+    /// Get the response, interview, and offer rates per application source across all sprints.
+    pub fn count_rates_per_source(
+        &mut self,
+        current_sprint: &QueriedSprint,
+    ) -> Result<Vec<ConversionRates>, FettersError> {
+        let outcomes = jobs::table
+            .left_join(sources::table.on(jobs::source_id.eq(sources::id.nullable())))
+            .left_join(statuses::table.on(jobs::status_id.eq(statuses::id)))
+            .filter(jobs::deleted_at.is_null())
+            .select((
+                sources::name.nullable(),
+                statuses::name.nullable(),
+                sql::<diesel::sql_types::BigInt>(
+                    "(SELECT COUNT(*) FROM interview_stages WHERE interview_stages.job_id = jobs.id)",
+                ),
+                jobs::sprint_id.eq(current_sprint.id),
+            ))
+            .load::<(Option<String>, Option<String>, i64, bool)>(self.connection)?;
+
+        Ok(ConversionRates::from_outcomes(outcomes))
+    }
 }
 
 #[cfg(test)]
@@ -234,9 +784,16 @@ mod tests {
     use super::*;
     use diesel::Connection;
 
+    use crate::models::attachment::NewAttachment;
+    use crate::models::custom_field::NewCustomField;
+    use crate::models::description::NewJobDescription;
+    use crate::models::note::NewJobNote;
+    use crate::models::source::NewSource;
     use crate::models::sprint::NewSprint;
+    use crate::models::stage::NewInterviewStage;
+    use crate::models::tag::NewTag;
     use crate::models::title::NewTitle;
-    use crate::repositories::sprint::SprintRepository;
+    use crate::repositories::source::SourceRepository;
     use crate::repositories::statuses::StatusRepository;
     use crate::repositories::title::TitleRepository;
 
@@ -270,6 +827,14 @@ mod tests {
         repo.add_title(NewTitle { name }).unwrap()
     }
 
+    fn create_source(
+        conn: &mut SqliteConnection,
+        name: &str,
+    ) -> crate::models::source::QueriedSource {
+        let mut repo = SourceRepository { connection: conn };
+        repo.add_source(NewSource { name }).unwrap()
+    }
+
     fn get_status_id(conn: &mut SqliteConnection, target: &str) -> i32 {
         let mut repo = StatusRepository { connection: conn };
         let statuses = repo.get_all_statuses().unwrap();
@@ -293,8 +858,11 @@ mod tests {
                 title_id: title.id,
                 status_id,
                 link: Some("https://google.com/careers"),
-                notes: Some("Dream job"),
                 sprint_id: sprint.id,
+                source_id: None,
+                referrer: None,
+                resume_id: None,
+                cover_letter_id: None,
             })
             .unwrap();
 
@@ -302,7 +870,6 @@ mod tests {
         assert_eq!(job.title_id, title.id);
         assert_eq!(job.status_id, status_id);
         assert_eq!(job.link.as_deref(), Some("https://google.com/careers"));
-        assert_eq!(job.notes.as_deref(), Some("Dream job"));
         assert_eq!(job.sprint_id, sprint.id);
     }
 
@@ -322,8 +889,11 @@ mod tests {
             title_id: title.id,
             status_id,
             link: None,
-            notes: None,
             sprint_id: sprint.id,
+            source_id: None,
+            referrer: None,
+            resume_id: None,
+            cover_letter_id: None,
         })
         .unwrap();
 
@@ -334,6 +904,37 @@ mod tests {
         assert_eq!(updated_sprint.num_jobs, 1);
     }
 
+    #[test]
+    fn test_get_job() {
+        let mut conn = setup_test_db();
+        let sprint = create_sprint(&mut conn, "test-sprint");
+        let title = create_title(&mut conn, "SWE");
+        let status_id = get_status_id(&mut conn, "PENDING");
+
+        let mut repo = JobRepository {
+            connection: &mut conn,
+        };
+        let job = repo
+            .add_job(NewJob {
+                company_name: "Google",
+                created: "2025-01-15 10:00:00".to_string(),
+                title_id: title.id,
+                status_id,
+                link: None,
+                sprint_id: sprint.id,
+                source_id: None,
+                referrer: Some("Jane Doe"),
+                resume_id: None,
+                cover_letter_id: None,
+            })
+            .unwrap();
+
+        let fetched = repo.get_job(job.id).unwrap();
+        assert_eq!(fetched.company_name, "Google");
+        assert_eq!(fetched.referrer.as_deref(), Some("Jane Doe"));
+        assert!(repo.get_job(999).is_err());
+    }
+
     #[test]
     fn test_update_job() {
         let mut conn = setup_test_db();
@@ -351,8 +952,11 @@ mod tests {
                 title_id: title.id,
                 status_id,
                 link: None,
-                notes: None,
                 sprint_id: sprint.id,
+                source_id: None,
+                referrer: None,
+                resume_id: None,
+                cover_letter_id: None,
             })
             .unwrap();
 
@@ -361,21 +965,22 @@ mod tests {
                 job.id,
                 JobUpdate {
                     company_name: Some("Alphabet"),
-                    notes: Some("Updated notes"),
+                    link: Some("https://abc.xyz"),
                     ..Default::default()
                 },
             )
             .unwrap();
         assert_eq!(updated.company_name, "Alphabet");
-        assert_eq!(updated.notes.as_deref(), Some("Updated notes"));
+        assert_eq!(updated.link.as_deref(), Some("https://abc.xyz"));
     }
 
     #[test]
-    fn test_delete_job() {
+    fn test_clear_source() {
         let mut conn = setup_test_db();
         let sprint = create_sprint(&mut conn, "test-sprint");
         let title = create_title(&mut conn, "SWE");
         let status_id = get_status_id(&mut conn, "PENDING");
+        let referral = create_source(&mut conn, "REFERRAL");
 
         let mut repo = JobRepository {
             connection: &mut conn,
@@ -387,20 +992,24 @@ mod tests {
                 title_id: title.id,
                 status_id,
                 link: None,
-                notes: None,
                 sprint_id: sprint.id,
+                source_id: Some(referral.id),
+                referrer: None,
+                resume_id: None,
+                cover_letter_id: None,
             })
             .unwrap();
 
-        let deleted = repo.delete_job(job.id).unwrap();
-        assert_eq!(deleted.id, job.id);
-        assert_eq!(deleted.company_name, "Google");
+        let updated = repo.clear_source(job.id).unwrap();
+        assert!(updated.source_id.is_none());
+        assert!(repo.get_job(job.id).unwrap().source_id.is_none());
     }
 
     #[test]
-    fn test_delete_job_decrements_sprint_count() {
+    fn test_update_job_moves_sprint_job_counts() {
         let mut conn = setup_test_db();
-        let sprint = create_sprint(&mut conn, "test-sprint");
+        let old_sprint = create_sprint(&mut conn, "old-sprint");
+        let new_sprint = create_sprint(&mut conn, "new-sprint");
         let title = create_title(&mut conn, "SWE");
         let status_id = get_status_id(&mut conn, "PENDING");
 
@@ -414,22 +1023,32 @@ mod tests {
                 title_id: title.id,
                 status_id,
                 link: None,
-                notes: None,
-                sprint_id: sprint.id,
+                sprint_id: old_sprint.id,
+                source_id: None,
+                referrer: None,
+                resume_id: None,
+                cover_letter_id: None,
             })
             .unwrap();
 
-        repo.delete_job(job.id).unwrap();
+        repo.update_job(
+            job.id,
+            JobUpdate {
+                sprint_id: Some(new_sprint.id),
+                ..Default::default()
+            },
+        )
+        .unwrap();
 
         let mut sprint_repo = SprintRepository {
             connection: &mut conn,
         };
-        let updated_sprint = sprint_repo.get_current_sprint("test-sprint").unwrap();
-        assert_eq!(updated_sprint.num_jobs, 0);
+        assert_eq!(sprint_repo.get_sprint(old_sprint.id).unwrap().num_jobs, 0);
+        assert_eq!(sprint_repo.get_sprint(new_sprint.id).unwrap().num_jobs, 1);
     }
 
     #[test]
-    fn test_list_jobs_returns_jobs_in_sprint() {
+    fn test_delete_job() {
         let mut conn = setup_test_db();
         let sprint = create_sprint(&mut conn, "test-sprint");
         let title = create_title(&mut conn, "SWE");
@@ -438,39 +1057,470 @@ mod tests {
         let mut repo = JobRepository {
             connection: &mut conn,
         };
-        repo.add_job(NewJob {
-            company_name: "Google",
-            created: "2025-01-15 10:00:00".to_string(),
-            title_id: title.id,
-            status_id,
-            link: None,
-            notes: None,
-            sprint_id: sprint.id,
-        })
-        .unwrap();
-        repo.add_job(NewJob {
-            company_name: "Meta",
-            created: "2025-01-16 10:00:00".to_string(),
-            title_id: title.id,
-            status_id,
-            link: None,
-            notes: None,
-            sprint_id: sprint.id,
-        })
-        .unwrap();
+        let job = repo
+            .add_job(NewJob {
+                company_name: "Google",
+                created: "2025-01-15 10:00:00".to_string(),
+                title_id: title.id,
+                status_id,
+                link: None,
+                sprint_id: sprint.id,
+                source_id: None,
+                referrer: None,
+                resume_id: None,
+                cover_letter_id: None,
+            })
+            .unwrap();
 
-        let query_args = QueryArgs::default();
-        let jobs = repo.list_jobs(&query_args, &sprint).unwrap();
-        assert_eq!(jobs.len(), 2);
+        let deleted = repo.delete_job(job.id).unwrap();
+        assert_eq!(deleted.id, job.id);
+        assert_eq!(deleted.company_name, "Google");
     }
 
     #[test]
-    fn test_list_jobs_filters_by_company() {
+    fn test_trash_and_restore_job() {
         let mut conn = setup_test_db();
         let sprint = create_sprint(&mut conn, "test-sprint");
         let title = create_title(&mut conn, "SWE");
         let status_id = get_status_id(&mut conn, "PENDING");
 
+        let mut repo = JobRepository {
+            connection: &mut conn,
+        };
+        let job = repo
+            .add_job(NewJob {
+                company_name: "Google",
+                created: "2025-01-15 10:00:00".to_string(),
+                title_id: title.id,
+                status_id,
+                link: None,
+                sprint_id: sprint.id,
+                source_id: None,
+                referrer: None,
+                resume_id: None,
+                cover_letter_id: None,
+            })
+            .unwrap();
+
+        let trashed = repo.trash_job(job.id, "2025-01-20 09:00:00").unwrap();
+        assert_eq!(trashed.deleted_at.as_deref(), Some("2025-01-20 09:00:00"));
+        assert!(matches!(
+            repo.trash_job(job.id, "2025-01-21 09:00:00"),
+            Err(FettersError::JobNotFound(_))
+        ));
+
+        // Trashed jobs are hidden from queries and insights.
+        assert!(
+            repo.list_jobs(&QueryArgs::default(), &sprint)
+                .unwrap()
+                .is_empty()
+        );
+        assert!(matches!(
+            repo.get_tabled_job(job.id),
+            Err(FettersError::JobNotFound(_))
+        ));
+        assert!(repo.count_jobs_per_status(&sprint).unwrap().is_empty());
+        assert!(repo.count_rates_per_source(&sprint).unwrap().is_empty());
+
+        let trashed_jobs = repo.get_trashed_jobs().unwrap();
+        assert_eq!(trashed_jobs.len(), 1);
+        assert_eq!(trashed_jobs[0].company_name, "Google");
+        assert_eq!(trashed_jobs[0].deleted_at, "2025-01-20 09:00:00");
+        assert_eq!(trashed_jobs[0].sprint.as_deref(), Some("test-sprint"));
+
+        let mut sprint_repo = SprintRepository {
+            connection: &mut conn,
+        };
+        assert_eq!(sprint_repo.get_sprint(sprint.id).unwrap().num_jobs, 0);
+
+        let mut repo = JobRepository {
+            connection: &mut conn,
+        };
+        let restored = repo.restore_job(job.id).unwrap();
+        assert!(restored.deleted_at.is_none());
+        assert!(matches!(
+            repo.restore_job(job.id),
+            Err(FettersError::JobNotInTrash(_))
+        ));
+        assert_eq!(
+            repo.list_jobs(&QueryArgs::default(), &sprint)
+                .unwrap()
+                .len(),
+            1
+        );
+        assert!(repo.get_trashed_jobs().unwrap().is_empty());
+
+        let mut sprint_repo = SprintRepository {
+            connection: &mut conn,
+        };
+        assert_eq!(sprint_repo.get_sprint(sprint.id).unwrap().num_jobs, 1);
+    }
+
+    #[test]
+    fn test_get_company_names() {
+        let mut conn = setup_test_db();
+        let sprint = create_sprint(&mut conn, "test-sprint");
+        let title = create_title(&mut conn, "SWE");
+        let status_id = get_status_id(&mut conn, "PENDING");
+
+        let mut repo = JobRepository {
+            connection: &mut conn,
+        };
+        let mut job_ids = Vec::new();
+        for company_name in ["Stripe", "Google", "Stripe", "Netflix"] {
+            let job = repo
+                .add_job(NewJob {
+                    company_name,
+                    created: "2025-01-15 10:00:00".to_string(),
+                    title_id: title.id,
+                    status_id,
+                    link: None,
+                    sprint_id: sprint.id,
+                    source_id: None,
+                    referrer: None,
+                    resume_id: None,
+                    cover_letter_id: None,
+                })
+                .unwrap();
+            job_ids.push(job.id);
+        }
+        repo.trash_job(job_ids[3], "2025-01-20 09:00:00").unwrap();
+
+        assert_eq!(repo.get_company_names().unwrap(), vec!["Google", "Stripe"]);
+    }
+
+    #[test]
+    fn test_purge_trashed_jobs() {
+        let mut conn = setup_test_db();
+        let sprint = create_sprint(&mut conn, "test-sprint");
+        let title = create_title(&mut conn, "SWE");
+        let status_id = get_status_id(&mut conn, "PENDING");
+
+        let mut repo = JobRepository {
+            connection: &mut conn,
+        };
+        let mut job_ids = Vec::new();
+        for company_name in ["Google", "Netflix", "Stripe"] {
+            let job = repo
+                .add_job(NewJob {
+                    company_name,
+                    created: "2025-01-15 10:00:00".to_string(),
+                    title_id: title.id,
+                    status_id,
+                    link: None,
+                    sprint_id: sprint.id,
+                    source_id: None,
+                    referrer: None,
+                    resume_id: None,
+                    cover_letter_id: None,
+                })
+                .unwrap();
+            job_ids.push(job.id);
+        }
+
+        repo.trash_job(job_ids[0], "2025-01-10 09:00:00").unwrap();
+        repo.trash_job(job_ids[1], "2025-02-10 09:00:00").unwrap();
+
+        let mut stage_repo = StageRepository {
+            connection: &mut conn,
+        };
+        stage_repo
+            .add_stage(NewInterviewStage {
+                job_id: job_ids[0],
+                stage_number: 1,
+                name: None,
+                status: "PASSED".to_string(),
+                scheduled_date: "2025/01/12".to_string(),
+                notes: None,
+                created: "2025-01-12 09:00:00".to_string(),
+            })
+            .unwrap();
+
+        let mut repo = JobRepository {
+            connection: &mut conn,
+        };
+        assert_eq!(
+            repo.purge_trashed_jobs(Some("2025-02-01 00:00:00"))
+                .unwrap(),
+            vec![job_ids[0]]
+        );
+        assert_eq!(repo.get_trashed_jobs().unwrap().len(), 1);
+        assert!(repo.get_job(job_ids[0]).is_err());
+
+        assert_eq!(repo.purge_trashed_jobs(None).unwrap(), vec![job_ids[1]]);
+        assert!(repo.get_trashed_jobs().unwrap().is_empty());
+        assert!(repo.get_job(job_ids[2]).is_ok());
+
+        let mut stage_repo = StageRepository {
+            connection: &mut conn,
+        };
+        assert!(
+            stage_repo
+                .get_stages_for_job(job_ids[0])
+                .unwrap()
+                .is_empty()
+        );
+
+        // Trashed jobs were already removed from the sprint's job count.
+        let mut sprint_repo = SprintRepository {
+            connection: &mut conn,
+        };
+        assert_eq!(sprint_repo.get_sprint(sprint.id).unwrap().num_jobs, 1);
+    }
+
+    #[test]
+    fn test_delete_job_removes_tags_and_custom_field_values() {
+        let mut conn = setup_test_db();
+        let sprint = create_sprint(&mut conn, "test-sprint");
+        let title = create_title(&mut conn, "SWE");
+        let status_id = get_status_id(&mut conn, "PENDING");
+
+        let mut repo = JobRepository {
+            connection: &mut conn,
+        };
+        let job = repo
+            .add_job(NewJob {
+                company_name: "Google",
+                created: "2025-01-15 10:00:00".to_string(),
+                title_id: title.id,
+                status_id,
+                link: None,
+                sprint_id: sprint.id,
+                source_id: None,
+                referrer: None,
+                resume_id: None,
+                cover_letter_id: None,
+            })
+            .unwrap();
+
+        let mut tag_repo = TagRepository {
+            connection: &mut conn,
+        };
+        let tag = tag_repo.add_tag(NewTag { name: "remote" }).unwrap();
+        tag_repo.tag_job(job.id, tag.id).unwrap();
+
+        let mut field_repo = CustomFieldRepository {
+            connection: &mut conn,
+        };
+        let field = field_repo
+            .add_field(NewCustomField {
+                name: "Team",
+                field_type: "TEXT",
+                options: None,
+            })
+            .unwrap();
+        field_repo.set_value(job.id, field.id, "Search").unwrap();
+
+        let mut attachment_repo = AttachmentRepository {
+            connection: &mut conn,
+        };
+        attachment_repo
+            .add_attachment(NewAttachment {
+                job_id: job.id,
+                file_name: "offer.pdf",
+                file_path: "/tmp/attachments/1/offer.pdf",
+                added: "2025-01-15 10:00:00",
+            })
+            .unwrap();
+
+        let mut repo = JobRepository {
+            connection: &mut conn,
+        };
+        repo.delete_job(job.id).unwrap();
+
+        let mut tag_repo = TagRepository {
+            connection: &mut conn,
+        };
+        assert!(tag_repo.get_tags_for_job(job.id).unwrap().is_empty());
+
+        let mut field_repo = CustomFieldRepository {
+            connection: &mut conn,
+        };
+        assert!(field_repo.get_values_for_jobs(&[job.id]).unwrap().is_empty());
+
+        let mut attachment_repo = AttachmentRepository {
+            connection: &mut conn,
+        };
+        assert!(attachment_repo.get_attachments(job.id).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_delete_job_decrements_sprint_count() {
+        let mut conn = setup_test_db();
+        let sprint = create_sprint(&mut conn, "test-sprint");
+        let title = create_title(&mut conn, "SWE");
+        let status_id = get_status_id(&mut conn, "PENDING");
+
+        let mut repo = JobRepository {
+            connection: &mut conn,
+        };
+        let job = repo
+            .add_job(NewJob {
+                company_name: "Google",
+                created: "2025-01-15 10:00:00".to_string(),
+                title_id: title.id,
+                status_id,
+                link: None,
+                sprint_id: sprint.id,
+                source_id: None,
+                referrer: None,
+                resume_id: None,
+                cover_letter_id: None,
+            })
+            .unwrap();
+
+        repo.delete_job(job.id).unwrap();
+
+        let mut sprint_repo = SprintRepository {
+            connection: &mut conn,
+        };
+        let updated_sprint = sprint_repo.get_current_sprint("test-sprint").unwrap();
+        assert_eq!(updated_sprint.num_jobs, 0);
+    }
+
+    #[test]
+    fn test_list_jobs_returns_jobs_in_sprint() {
+        let mut conn = setup_test_db();
+        let sprint = create_sprint(&mut conn, "test-sprint");
+        let title = create_title(&mut conn, "SWE");
+        let status_id = get_status_id(&mut conn, "PENDING");
+
+        let mut repo = JobRepository {
+            connection: &mut conn,
+        };
+        repo.add_job(NewJob {
+            company_name: "Google",
+            created: "2025-01-15 10:00:00".to_string(),
+            title_id: title.id,
+            status_id,
+            link: None,
+            sprint_id: sprint.id,
+            source_id: None,
+            referrer: None,
+            resume_id: None,
+            cover_letter_id: None,
+        })
+        .unwrap();
+        repo.add_job(NewJob {
+            company_name: "Meta",
+            created: "2025-01-16 10:00:00".to_string(),
+            title_id: title.id,
+            status_id,
+            link: None,
+            sprint_id: sprint.id,
+            source_id: None,
+            referrer: None,
+            resume_id: None,
+            cover_letter_id: None,
+        })
+        .unwrap();
+
+        let query_args = QueryArgs::default();
+        let jobs = repo.list_jobs(&query_args, &sprint).unwrap();
+        assert_eq!(jobs.len(), 2);
+    }
+
+    #[test]
+    fn test_list_jobs_filters_by_company() {
+        let mut conn = setup_test_db();
+        let sprint = create_sprint(&mut conn, "test-sprint");
+        let title = create_title(&mut conn, "SWE");
+        let status_id = get_status_id(&mut conn, "PENDING");
+
+        let mut repo = JobRepository {
+            connection: &mut conn,
+        };
+        repo.add_job(NewJob {
+            company_name: "Google",
+            created: "2025-01-15 10:00:00".to_string(),
+            title_id: title.id,
+            status_id,
+            link: None,
+            sprint_id: sprint.id,
+            source_id: None,
+            referrer: None,
+            resume_id: None,
+            cover_letter_id: None,
+        })
+        .unwrap();
+        repo.add_job(NewJob {
+            company_name: "Meta",
+            created: "2025-01-16 10:00:00".to_string(),
+            title_id: title.id,
+            status_id,
+            link: None,
+            sprint_id: sprint.id,
+            source_id: None,
+            referrer: None,
+            resume_id: None,
+            cover_letter_id: None,
+        })
+        .unwrap();
+
+        let query_args = QueryArgs {
+            company: Some("Goo".to_string()),
+            ..Default::default()
+        };
+        let jobs = repo.list_jobs(&query_args, &sprint).unwrap();
+        assert_eq!(jobs.len(), 1);
+        assert_eq!(jobs[0].company_name, "Google");
+    }
+
+    #[test]
+    fn test_list_jobs_filters_by_status() {
+        let mut conn = setup_test_db();
+        let sprint = create_sprint(&mut conn, "test-sprint");
+        let title = create_title(&mut conn, "SWE");
+        let pending_id = get_status_id(&mut conn, "PENDING");
+        let rejected_id = get_status_id(&mut conn, "REJECTED");
+
+        let mut repo = JobRepository {
+            connection: &mut conn,
+        };
+        repo.add_job(NewJob {
+            company_name: "Google",
+            created: "2025-01-15 10:00:00".to_string(),
+            title_id: title.id,
+            status_id: pending_id,
+            link: None,
+            sprint_id: sprint.id,
+            source_id: None,
+            referrer: None,
+            resume_id: None,
+            cover_letter_id: None,
+        })
+        .unwrap();
+        repo.add_job(NewJob {
+            company_name: "Meta",
+            created: "2025-01-16 10:00:00".to_string(),
+            title_id: title.id,
+            status_id: rejected_id,
+            link: None,
+            sprint_id: sprint.id,
+            source_id: None,
+            referrer: None,
+            resume_id: None,
+            cover_letter_id: None,
+        })
+        .unwrap();
+
+        let query_args = QueryArgs {
+            status: Some("REJECTED".to_string()),
+            ..Default::default()
+        };
+        let jobs = repo.list_jobs(&query_args, &sprint).unwrap();
+        assert_eq!(jobs.len(), 1);
+        assert_eq!(jobs[0].company_name, "Meta");
+    }
+
+    #[test]
+    fn test_list_jobs_filters_by_source() {
+        let mut conn = setup_test_db();
+        let sprint = create_sprint(&mut conn, "test-sprint");
+        let title = create_title(&mut conn, "SWE");
+        let status_id = get_status_id(&mut conn, "PENDING");
+        let source = create_source(&mut conn, "REFERRAL");
+
         let mut repo = JobRepository {
             connection: &mut conn,
         };
@@ -480,8 +1530,11 @@ mod tests {
             title_id: title.id,
             status_id,
             link: None,
-            notes: None,
             sprint_id: sprint.id,
+            source_id: Some(source.id),
+            referrer: Some("Jane Doe"),
+            resume_id: None,
+            cover_letter_id: None,
         })
         .unwrap();
         repo.add_job(NewJob {
@@ -490,59 +1543,453 @@ mod tests {
             title_id: title.id,
             status_id,
             link: None,
-            notes: None,
             sprint_id: sprint.id,
+            source_id: None,
+            referrer: None,
+            resume_id: None,
+            cover_letter_id: None,
         })
         .unwrap();
 
-        let query_args = QueryArgs {
-            company: Some("Goo".to_string()),
+        let query_args = QueryArgs {
+            source: Some("refer".to_string()),
+            ..Default::default()
+        };
+        let jobs = repo.list_jobs(&query_args, &sprint).unwrap();
+        assert_eq!(jobs.len(), 1);
+        assert_eq!(jobs[0].company_name, "Google");
+        assert_eq!(jobs[0].source.as_deref(), Some("REFERRAL"));
+    }
+
+    #[test]
+    fn test_list_jobs_filters_by_tags() {
+        let mut conn = setup_test_db();
+        let sprint = create_sprint(&mut conn, "test-sprint");
+        let title = create_title(&mut conn, "SWE");
+        let status_id = get_status_id(&mut conn, "PENDING");
+
+        let mut job_ids = Vec::new();
+        let mut repo = JobRepository {
+            connection: &mut conn,
+        };
+        for company in ["Stripe", "Plaid", "Google"] {
+            let job = repo
+                .add_job(NewJob {
+                    company_name: company,
+                    created: "2025-01-15 10:00:00".to_string(),
+                    title_id: title.id,
+                    status_id,
+                    link: None,
+                    sprint_id: sprint.id,
+                    source_id: None,
+                    referrer: None,
+                    resume_id: None,
+                    cover_letter_id: None,
+                })
+                .unwrap();
+            job_ids.push(job.id);
+        }
+
+        let mut tag_repo = TagRepository {
+            connection: &mut conn,
+        };
+        let fintech = tag_repo.add_tag(NewTag { name: "fintech" }).unwrap();
+        let remote = tag_repo.add_tag(NewTag { name: "remote" }).unwrap();
+        tag_repo.tag_job(job_ids[0], fintech.id).unwrap();
+        tag_repo.tag_job(job_ids[0], remote.id).unwrap();
+        tag_repo.tag_job(job_ids[1], fintech.id).unwrap();
+        tag_repo.tag_job(job_ids[2], remote.id).unwrap();
+
+        let mut repo = JobRepository {
+            connection: &mut conn,
+        };
+
+        let any_args = QueryArgs {
+            tags: vec!["FinTech".to_string(), "remote".to_string()],
+            ..Default::default()
+        };
+        assert_eq!(repo.list_jobs(&any_args, &sprint).unwrap().len(), 3);
+
+        let all_args = QueryArgs {
+            tags: vec!["fintech".to_string(), "remote".to_string()],
+            all_tags: true,
+            ..Default::default()
+        };
+        let jobs = repo.list_jobs(&all_args, &sprint).unwrap();
+        assert_eq!(jobs.len(), 1);
+        assert_eq!(jobs[0].company_name, "Stripe");
+        assert_eq!(jobs[0].tags.as_deref(), Some("fintech, remote"));
+    }
+
+    #[test]
+    fn test_list_jobs_fills_and_filters_by_custom_fields() {
+        let mut conn = setup_test_db();
+        let sprint = create_sprint(&mut conn, "test-sprint");
+        let title = create_title(&mut conn, "SWE");
+        let status_id = get_status_id(&mut conn, "PENDING");
+
+        let mut job_ids = Vec::new();
+        let mut repo = JobRepository {
+            connection: &mut conn,
+        };
+        for company in ["Stripe", "Plaid"] {
+            let job = repo
+                .add_job(NewJob {
+                    company_name: company,
+                    created: "2025-01-15 10:00:00".to_string(),
+                    title_id: title.id,
+                    status_id,
+                    link: None,
+                    sprint_id: sprint.id,
+                    source_id: None,
+                    referrer: None,
+                    resume_id: None,
+                    cover_letter_id: None,
+                })
+                .unwrap();
+            job_ids.push(job.id);
+        }
+
+        let mut field_repo = CustomFieldRepository {
+            connection: &mut conn,
+        };
+        let team = field_repo
+            .add_field(NewCustomField {
+                name: "Team",
+                field_type: "TEXT",
+                options: None,
+            })
+            .unwrap();
+        let visa = field_repo
+            .add_field(NewCustomField {
+                name: "Visa Sponsorship",
+                field_type: "BOOL",
+                options: None,
+            })
+            .unwrap();
+        field_repo.set_value(job_ids[0], team.id, "Payments").unwrap();
+        field_repo.set_value(job_ids[0], visa.id, "true").unwrap();
+        field_repo.set_value(job_ids[1], visa.id, "false").unwrap();
+
+        let mut repo = JobRepository {
+            connection: &mut conn,
+        };
+
+        let jobs = repo.list_jobs(&QueryArgs::default(), &sprint).unwrap();
+        let plaid = jobs.iter().find(|job| job.company_name == "Plaid").unwrap();
+        assert_eq!(
+            plaid.custom_fields,
+            vec![
+                ("Team".to_string(), "".to_string()),
+                ("Visa Sponsorship".to_string(), "false".to_string()),
+            ]
+        );
+
+        let text_args = QueryArgs {
+            fields: vec![("Team".to_string(), "pay".to_string())],
+            ..Default::default()
+        };
+        let jobs = repo.list_jobs(&text_args, &sprint).unwrap();
+        assert_eq!(jobs.len(), 1);
+        assert_eq!(jobs[0].company_name, "Stripe");
+
+        let bool_args = QueryArgs {
+            fields: vec![("Visa Sponsorship".to_string(), "no".to_string())],
+            ..Default::default()
+        };
+        let jobs = repo.list_jobs(&bool_args, &sprint).unwrap();
+        assert_eq!(jobs.len(), 1);
+        assert_eq!(jobs[0].company_name, "Plaid");
+
+        let invalid_args = QueryArgs {
+            fields: vec![("Visa Sponsorship".to_string(), "maybe".to_string())],
+            ..Default::default()
+        };
+        assert!(matches!(
+            repo.list_jobs(&invalid_args, &sprint),
+            Err(FettersError::InvalidCustomFieldValue(_))
+        ));
+
+        let unknown_args = QueryArgs {
+            fields: vec![("Clearance".to_string(), "Secret".to_string())],
+            ..Default::default()
+        };
+        assert!(matches!(
+            repo.list_jobs(&unknown_args, &sprint),
+            Err(FettersError::UnknownCustomField(_))
+        ));
+    }
+
+    #[test]
+    fn test_list_jobs_filters_by_description() {
+        let mut conn = setup_test_db();
+        let sprint = create_sprint(&mut conn, "test-sprint");
+        let title = create_title(&mut conn, "SWE");
+        let status_id = get_status_id(&mut conn, "PENDING");
+
+        let mut job_ids = Vec::new();
+        let mut repo = JobRepository {
+            connection: &mut conn,
+        };
+        for company in ["Stripe", "Plaid"] {
+            let job = repo
+                .add_job(NewJob {
+                    company_name: company,
+                    created: "2025-01-15 10:00:00".to_string(),
+                    title_id: title.id,
+                    status_id,
+                    link: None,
+                    sprint_id: sprint.id,
+                    source_id: None,
+                    referrer: None,
+                    resume_id: None,
+                    cover_letter_id: None,
+                })
+                .unwrap();
+            job_ids.push(job.id);
+        }
+
+        let mut description_repo = DescriptionRepository {
+            connection: &mut conn,
+        };
+        description_repo
+            .set_description(NewJobDescription {
+                job_id: job_ids[1],
+                content: "You will run Kubernetes clusters for our bank integrations.",
+                captured: "2025-01-15 10:00:00",
+            })
+            .unwrap();
+
+        let mut repo = JobRepository {
+            connection: &mut conn,
+        };
+        let args = QueryArgs {
+            description: Some("kubernetes".to_string()),
+            ..Default::default()
+        };
+        let jobs = repo.list_jobs(&args, &sprint).unwrap();
+        assert_eq!(jobs.len(), 1);
+        assert_eq!(jobs[0].company_name, "Plaid");
+
+        repo.delete_job(job_ids[1]).unwrap();
+        let mut description_repo = DescriptionRepository {
+            connection: &mut conn,
+        };
+        assert!(description_repo.get_description(job_ids[1]).unwrap().is_none());
+    }
+
+    #[test]
+    fn test_list_jobs_filters_by_any_note_and_shows_latest_note() {
+        let mut conn = setup_test_db();
+        let sprint = create_sprint(&mut conn, "test-sprint");
+        let title = create_title(&mut conn, "SWE");
+        let status_id = get_status_id(&mut conn, "PENDING");
+
+        let mut job_ids = Vec::new();
+        let mut repo = JobRepository {
+            connection: &mut conn,
+        };
+        for company in ["Stripe", "Plaid"] {
+            let job = repo
+                .add_job(NewJob {
+                    company_name: company,
+                    created: "2025-01-15 10:00:00".to_string(),
+                    title_id: title.id,
+                    status_id,
+                    link: None,
+                    sprint_id: sprint.id,
+                    source_id: None,
+                    referrer: None,
+                    resume_id: None,
+                    cover_letter_id: None,
+                })
+                .unwrap();
+            job_ids.push(job.id);
+        }
+
+        let mut note_repo = NoteRepository {
+            connection: &mut conn,
+        };
+        for (content, created) in [
+            ("Recruiter mentioned a take-home", "2025-01-15 10:00:00"),
+            ("Sent thank you email", "2025-01-20 10:00:00"),
+        ] {
+            note_repo
+                .add_note(NewJobNote {
+                    job_id: job_ids[1],
+                    content,
+                    created,
+                })
+                .unwrap();
+        }
+
+        let mut repo = JobRepository {
+            connection: &mut conn,
+        };
+        let args = QueryArgs {
+            notes: Some("take-home".to_string()),
             ..Default::default()
         };
-        let jobs = repo.list_jobs(&query_args, &sprint).unwrap();
+        let jobs = repo.list_jobs(&args, &sprint).unwrap();
         assert_eq!(jobs.len(), 1);
-        assert_eq!(jobs[0].company_name, "Google");
+        assert_eq!(jobs[0].company_name, "Plaid");
+        assert_eq!(jobs[0].notes.as_deref(), Some("Sent thank you email"));
+
+        repo.delete_job(job_ids[1]).unwrap();
+        let mut note_repo = NoteRepository {
+            connection: &mut conn,
+        };
+        assert!(note_repo.get_notes_for_job(job_ids[1]).unwrap().is_empty());
     }
 
     #[test]
-    fn test_list_jobs_filters_by_status() {
+    fn test_get_tabled_job() {
         let mut conn = setup_test_db();
         let sprint = create_sprint(&mut conn, "test-sprint");
         let title = create_title(&mut conn, "SWE");
-        let pending_id = get_status_id(&mut conn, "PENDING");
-        let rejected_id = get_status_id(&mut conn, "REJECTED");
+        let status_id = get_status_id(&mut conn, "PENDING");
 
         let mut repo = JobRepository {
             connection: &mut conn,
         };
-        repo.add_job(NewJob {
-            company_name: "Google",
-            created: "2025-01-15 10:00:00".to_string(),
-            title_id: title.id,
-            status_id: pending_id,
-            link: None,
-            notes: None,
-            sprint_id: sprint.id,
-        })
-        .unwrap();
-        repo.add_job(NewJob {
-            company_name: "Meta",
-            created: "2025-01-16 10:00:00".to_string(),
-            title_id: title.id,
-            status_id: rejected_id,
-            link: None,
-            notes: None,
-            sprint_id: sprint.id,
-        })
-        .unwrap();
+        for company in ["Google", "Google Cloud"] {
+            repo.add_job(NewJob {
+                company_name: company,
+                created: "2025-01-15 10:00:00".to_string(),
+                title_id: title.id,
+                status_id,
+                link: None,
+                sprint_id: sprint.id,
+                source_id: None,
+                referrer: None,
+                resume_id: None,
+                cover_letter_id: None,
+            })
+            .unwrap();
+        }
 
-        let query_args = QueryArgs {
-            status: Some("REJECTED".to_string()),
-            ..Default::default()
+        let all_jobs = repo.list_jobs(&QueryArgs::default(), &sprint).unwrap();
+        let google = all_jobs
+            .iter()
+            .find(|job| job.company_name == "Google")
+            .unwrap();
+
+        let tabled_job = repo.get_tabled_job(google.id).unwrap();
+        assert_eq!(tabled_job.company_name, "Google");
+        assert_eq!(tabled_job.title.as_deref(), Some("SWE"));
+        assert_eq!(tabled_job.status.as_deref(), Some("PENDING"));
+
+        // Jobs in other sprints are found regardless of their company name.
+        let other_sprint = create_sprint(&mut conn, "test-sprint-2");
+        let mut repo = JobRepository {
+            connection: &mut conn,
         };
-        let jobs = repo.list_jobs(&query_args, &sprint).unwrap();
-        assert_eq!(jobs.len(), 1);
-        assert_eq!(jobs[0].company_name, "Meta");
+        let other_job = repo
+            .add_job(NewJob {
+                company_name: "100% Remote_Co",
+                created: "2025-01-16 10:00:00".to_string(),
+                title_id: title.id,
+                status_id,
+                link: None,
+                sprint_id: other_sprint.id,
+                source_id: None,
+                referrer: None,
+                resume_id: None,
+                cover_letter_id: None,
+            })
+            .unwrap();
+        assert_eq!(
+            repo.get_tabled_job(other_job.id).unwrap().company_name,
+            "100% Remote_Co"
+        );
+
+        assert!(matches!(
+            repo.get_tabled_job(999),
+            Err(FettersError::JobNotFound(999))
+        ));
+    }
+
+    #[test]
+    fn test_get_job_details() {
+        let mut conn = setup_test_db();
+        let sprint = create_sprint(&mut conn, "test-sprint");
+        let title = create_title(&mut conn, "SWE");
+        let status_id = get_status_id(&mut conn, "PENDING");
+
+        let mut repo = JobRepository {
+            connection: &mut conn,
+        };
+        let job = repo
+            .add_job(NewJob {
+                company_name: "Stripe",
+                created: "2025-01-15 10:00:00".to_string(),
+                title_id: title.id,
+                status_id,
+                link: Some("https://stripe.com/jobs"),
+                sprint_id: sprint.id,
+                source_id: None,
+                referrer: Some("Jane Doe"),
+                resume_id: None,
+                cover_letter_id: None,
+            })
+            .unwrap();
+
+        let mut tag_repo = TagRepository {
+            connection: &mut conn,
+        };
+        let tag = tag_repo.add_tag(NewTag { name: "fintech" }).unwrap();
+        tag_repo.tag_job(job.id, tag.id).unwrap();
+
+        let mut field_repo = CustomFieldRepository {
+            connection: &mut conn,
+        };
+        let team = field_repo
+            .add_field(NewCustomField {
+                name: "Team",
+                field_type: "TEXT",
+                options: None,
+            })
+            .unwrap();
+        field_repo
+            .add_field(NewCustomField {
+                name: "Visa",
+                field_type: "BOOL",
+                options: None,
+            })
+            .unwrap();
+        field_repo.set_value(job.id, team.id, "Payments").unwrap();
+
+        let mut note_repo = NoteRepository {
+            connection: &mut conn,
+        };
+        note_repo
+            .add_note(NewJobNote {
+                job_id: job.id,
+                content: "Line one\nLine two",
+                created: "2025-01-15 10:00:00",
+            })
+            .unwrap();
+
+        let mut repo = JobRepository {
+            connection: &mut conn,
+        };
+        let tabled_job = repo.get_tabled_job(job.id).unwrap();
+        let details = repo.get_job_details(&tabled_job).unwrap();
+        assert_eq!(details.sprint, "test-sprint");
+        assert_eq!(details.referrer.as_deref(), Some("Jane Doe"));
+        assert_eq!(details.tags, vec!["fintech"]);
+        assert_eq!(
+            details.custom_fields,
+            vec![("Team".to_string(), "Payments".to_string())]
+        );
+        assert!(details.stages.is_empty());
+        assert!(details.description.is_none());
+
+        let json: serde_json::Value = serde_json::to_value(&details).unwrap();
+        assert_eq!(json["company_name"], "Stripe");
+        assert_eq!(json["notes"][0]["content"], "Line one\nLine two");
+        assert_eq!(json["custom_fields"]["Team"], "Payments");
+        assert!(json["resume"].is_null());
     }
 
     #[test]
@@ -561,8 +2008,11 @@ mod tests {
             title_id: title.id,
             status_id,
             link: None,
-            notes: None,
             sprint_id: sprint.id,
+            source_id: None,
+            referrer: None,
+            resume_id: None,
+            cover_letter_id: None,
         })
         .unwrap();
 
@@ -591,8 +2041,11 @@ mod tests {
             title_id: title.id,
             status_id,
             link: None,
-            notes: None,
             sprint_id: sprint1.id,
+            source_id: None,
+            referrer: None,
+            resume_id: None,
+            cover_letter_id: None,
         })
         .unwrap();
         repo.add_job(NewJob {
@@ -601,8 +2054,11 @@ mod tests {
             title_id: title.id,
             status_id,
             link: None,
-            notes: None,
             sprint_id: sprint2.id,
+            source_id: None,
+            referrer: None,
+            resume_id: None,
+            cover_letter_id: None,
         })
         .unwrap();
 
@@ -615,6 +2071,245 @@ mod tests {
         assert_eq!(jobs.len(), 2);
     }
 
+    fn create_job(
+        conn: &mut SqliteConnection,
+        company_name: &str,
+        created: &str,
+        sprint_id: i32,
+    ) -> QueriedJob {
+        let title = create_title(conn, "SWE");
+        let status_id = get_status_id(conn, "PENDING");
+
+        let mut repo = JobRepository { connection: conn };
+        repo.add_job(NewJob {
+            company_name,
+            created: created.to_string(),
+            title_id: title.id,
+            status_id,
+            link: None,
+            sprint_id,
+            source_id: None,
+            referrer: None,
+            resume_id: None,
+            cover_letter_id: None,
+        })
+        .unwrap()
+    }
+
+    #[test]
+    fn test_list_jobs_all_sprints_and_by_id() {
+        let mut conn = setup_test_db();
+        let sprint1 = create_sprint(&mut conn, "sprint-1");
+        let sprint2 = create_sprint(&mut conn, "sprint-2");
+        create_job(&mut conn, "Google", "2025-01-15 10:00:00", sprint1.id);
+        let meta = create_job(&mut conn, "Meta", "2025-01-16 10:00:00", sprint2.id);
+
+        let mut repo = JobRepository {
+            connection: &mut conn,
+        };
+
+        let query_args = QueryArgs {
+            all_sprints: true,
+            ..Default::default()
+        };
+        assert_eq!(repo.list_jobs(&query_args, &sprint1).unwrap().len(), 2);
+
+        // A job in another sprint is found by ID unless a sprint filter is provided.
+        let query_args = QueryArgs {
+            id: Some(meta.id),
+            ..Default::default()
+        };
+        let jobs = repo.list_jobs(&query_args, &sprint1).unwrap();
+        assert_eq!(jobs.len(), 1);
+        assert_eq!(jobs[0].company_name, "Meta");
+
+        let query_args = QueryArgs {
+            id: Some(meta.id),
+            sprint: Some("sprint-1".to_string()),
+            ..Default::default()
+        };
+        assert!(repo.list_jobs(&query_args, &sprint1).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_list_jobs_filters_by_date_range() {
+        let mut conn = setup_test_db();
+        let sprint = create_sprint(&mut conn, "test-sprint");
+        create_job(&mut conn, "Google", "2025-01-10 09:00:00", sprint.id);
+        create_job(&mut conn, "Meta", "2025-01-15 23:59:59", sprint.id);
+        create_job(&mut conn, "Apple", "2025-01-20 08:00:00", sprint.id);
+
+        let mut repo = JobRepository {
+            connection: &mut conn,
+        };
+
+        let query_args = QueryArgs {
+            since: Some("2025-01-15".parse().unwrap()),
+            ..Default::default()
+        };
+        let companies: Vec<String> = repo
+            .list_jobs(&query_args, &sprint)
+            .unwrap()
+            .into_iter()
+            .map(|job| job.company_name)
+            .collect();
+        assert_eq!(companies, vec!["Meta", "Apple"]);
+
+        let query_args = QueryArgs {
+            since: Some("2025-01-11".parse().unwrap()),
+            until: Some("2025-01-15".parse().unwrap()),
+            ..Default::default()
+        };
+        let jobs = repo.list_jobs(&query_args, &sprint).unwrap();
+        assert_eq!(jobs.len(), 1);
+        assert_eq!(jobs[0].company_name, "Meta");
+
+        // Every job was created more than a week ago.
+        let query_args = QueryArgs {
+            since: Some("7d".parse().unwrap()),
+            ..Default::default()
+        };
+        assert!(repo.list_jobs(&query_args, &sprint).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_list_jobs_sorts_and_limits() {
+        let mut conn = setup_test_db();
+        let sprint = create_sprint(&mut conn, "test-sprint");
+        create_job(&mut conn, "Meta", "2025-01-10 09:00:00", sprint.id);
+        create_job(&mut conn, "Apple", "2025-01-12 09:00:00", sprint.id);
+        create_job(&mut conn, "Google", "2025-01-11 09:00:00", sprint.id);
+
+        let mut repo = JobRepository {
+            connection: &mut conn,
+        };
+        let mut list_companies = |sort: &str, limit: Option<i64>| -> Vec<String> {
+            let query_args = QueryArgs {
+                sort: Some(sort.parse().unwrap()),
+                limit,
+                ..Default::default()
+            };
+            repo.list_jobs(&query_args, &sprint)
+                .unwrap()
+                .into_iter()
+                .map(|job| job.company_name)
+                .collect()
+        };
+
+        assert_eq!(list_companies("company", None), vec!["Apple", "Google", "Meta"]);
+        assert_eq!(
+            list_companies("created:desc", None),
+            vec!["Apple", "Google", "Meta"]
+        );
+        assert_eq!(list_companies("id:desc", Some(2)), vec!["Google", "Apple"]);
+    }
+
+    #[test]
+    fn test_list_jobs_filters_by_stages_before_limit() {
+        let mut conn = setup_test_db();
+        let sprint = create_sprint(&mut conn, "test-sprint");
+        create_job(&mut conn, "Google", "2025-01-10 09:00:00", sprint.id);
+        let meta = create_job(&mut conn, "Meta", "2025-01-11 09:00:00", sprint.id);
+
+        let mut stage_repo = StageRepository {
+            connection: &mut conn,
+        };
+        stage_repo
+            .add_stage(NewInterviewStage {
+                job_id: meta.id,
+                stage_number: 1,
+                name: None,
+                status: "SCHEDULED".to_string(),
+                scheduled_date: "2025/01/20".to_string(),
+                notes: None,
+                created: "2025-01-15".to_string(),
+            })
+            .unwrap();
+
+        let mut repo = JobRepository {
+            connection: &mut conn,
+        };
+        for stages in [0, 1] {
+            let query_args = QueryArgs {
+                stages: Some(stages),
+                limit: Some(1),
+                ..Default::default()
+            };
+            let jobs = repo.list_jobs(&query_args, &sprint).unwrap();
+            assert_eq!(jobs.len(), 1);
+            assert_eq!(jobs[0].company_name, "Meta");
+        }
+
+        let query_args = QueryArgs {
+            stages: Some(2),
+            ..Default::default()
+        };
+        assert!(repo.list_jobs(&query_args, &sprint).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_list_jobs_filters_by_where_clause() {
+        let mut conn = setup_test_db();
+        let sprint = create_sprint(&mut conn, "test-sprint");
+        let backend = create_title(&mut conn, "Backend Engineer");
+        let rejected = get_status_id(&mut conn, "REJECTED");
+        let ghosted = get_status_id(&mut conn, "GHOSTED");
+        let pending = get_status_id(&mut conn, "PENDING");
+
+        let mut repo = JobRepository {
+            connection: &mut conn,
+        };
+        for (company_name, created, status_id) in [
+            ("Acme", "2026-01-05 10:00:00", rejected),
+            ("Globex", "2026-01-06 10:00:00", ghosted),
+            ("Initech", "2025-12-20 10:00:00", rejected),
+            ("Hooli", "2026-01-07 10:00:00", pending),
+        ] {
+            repo.add_job(NewJob {
+                company_name,
+                created: created.to_string(),
+                title_id: backend.id,
+                status_id,
+                link: None,
+                sprint_id: sprint.id,
+                source_id: None,
+                referrer: None,
+                resume_id: None,
+                cover_letter_id: None,
+            })
+            .unwrap();
+        }
+
+        let mut list_companies = |expression: &str| -> Vec<String> {
+            let query_args = QueryArgs {
+                where_clause: Some(expression.parse().unwrap()),
+                ..Default::default()
+            };
+            repo.list_jobs(&query_args, &sprint)
+                .unwrap()
+                .into_iter()
+                .map(|job| job.company_name)
+                .collect()
+        };
+
+        assert_eq!(
+            list_companies(
+                "status:(REJECTED|GHOSTED) AND title:backend AND created>2026-01-01 AND NOT company:acme"
+            ),
+            vec!["Globex"]
+        );
+        assert_eq!(
+            list_companies("company=initech OR status=pending"),
+            vec!["Initech", "Hooli"]
+        );
+        assert_eq!(
+            list_companies("created<=2026-01-05 NOT source:referral"),
+            vec!["Acme", "Initech"]
+        );
+        assert_eq!(list_companies("created:2026-01-06"), vec!["Globex"]);
+        assert!(list_companies("stages>0").is_empty());
+    }
+
     #[test]
     fn test_count_jobs_per_status() {
         let mut conn = setup_test_db();
@@ -632,8 +2327,11 @@ mod tests {
             title_id: title.id,
             status_id: pending_id,
             link: None,
-            notes: None,
             sprint_id: sprint.id,
+            source_id: None,
+            referrer: None,
+            resume_id: None,
+            cover_letter_id: None,
         })
         .unwrap();
         repo.add_job(NewJob {
@@ -642,8 +2340,11 @@ mod tests {
             title_id: title.id,
             status_id: rejected_id,
             link: None,
-            notes: None,
             sprint_id: sprint.id,
+            source_id: None,
+            referrer: None,
+            resume_id: None,
+            cover_letter_id: None,
         })
         .unwrap();
 
@@ -674,8 +2375,11 @@ mod tests {
             title_id: title.id,
             status_id,
             link: None,
-            notes: None,
             sprint_id: sprint1.id,
+            source_id: None,
+            referrer: None,
+            resume_id: None,
+            cover_letter_id: None,
         })
         .unwrap();
         repo.add_job(NewJob {
@@ -684,8 +2388,11 @@ mod tests {
             title_id: title.id,
             status_id,
             link: None,
-            notes: None,
             sprint_id: sprint2.id,
+            source_id: None,
+            referrer: None,
+            resume_id: None,
+            cover_letter_id: None,
         })
         .unwrap();
 
@@ -698,4 +2405,106 @@ mod tests {
         let s2 = insights.iter().find(|i| i.label == "sprint-2").unwrap();
         assert_eq!(s2.count, 1);
     }
+
+    #[test]
+    fn test_count_rates_per_source() {
+        let mut conn = setup_test_db();
+        let sprint = create_sprint(&mut conn, "test-sprint");
+        let title = create_title(&mut conn, "SWE");
+        let pending_id = get_status_id(&mut conn, "PENDING");
+        let offer_id = get_status_id(&mut conn, "OFFER RECEIVED");
+        let referral = create_source(&mut conn, "REFERRAL");
+
+        let mut repo = JobRepository {
+            connection: &mut conn,
+        };
+        for (company, status_id, source_id) in [
+            ("Google", offer_id, Some(referral.id)),
+            ("Meta", pending_id, Some(referral.id)),
+            ("Apple", pending_id, None),
+        ] {
+            repo.add_job(NewJob {
+                company_name: company,
+                created: "2025-01-15 10:00:00".to_string(),
+                title_id: title.id,
+                status_id,
+                link: None,
+                sprint_id: sprint.id,
+                source_id,
+                referrer: None,
+                resume_id: None,
+                cover_letter_id: None,
+            })
+            .unwrap();
+        }
+
+        let rates = repo.count_rates_per_source(&sprint).unwrap();
+        assert_eq!(rates.len(), 2);
+
+        let referral_rates = rates.iter().find(|r| r.jobs.label == "REFERRAL").unwrap();
+        assert_eq!(referral_rates.jobs.count, 2);
+        assert_eq!(referral_rates.offer_rate, "50.00%");
+
+        let no_source = rates.iter().find(|r| r.jobs.label == "N/A").unwrap();
+        assert_eq!(no_source.jobs.count, 1);
+        assert_eq!(no_source.response_rate, "0.00%");
+    }
+
+    #[test]
+    fn test_list_jobs_last_activity() {
+        let mut conn = setup_test_db();
+        let sprint = create_sprint(&mut conn, "sprint-1");
+        let job = create_job(&mut conn, "Google", "2025-01-15 10:00:00", sprint.id);
+
+        // Adding the job is recorded in the operation log with the current time.
+        let mut repo = JobRepository {
+            connection: &mut conn,
+        };
+        let jobs = repo.list_jobs(&QueryArgs::default(), &sprint).unwrap();
+        assert!(jobs[0].last_activity.as_str() > "2025-01-15 10:00:00");
+
+        delete(crate::schema::operation_changes::table)
+            .execute(&mut conn)
+            .unwrap();
+        let mut repo = JobRepository {
+            connection: &mut conn,
+        };
+        let jobs = repo.list_jobs(&QueryArgs::default(), &sprint).unwrap();
+        assert_eq!(jobs[0].last_activity, "2025-01-15 10:00:00");
+
+        let mut stage_repo = StageRepository {
+            connection: &mut conn,
+        };
+        stage_repo
+            .add_stage(NewInterviewStage {
+                job_id: job.id,
+                stage_number: 1,
+                name: None,
+                status: "SCHEDULED".to_string(),
+                scheduled_date: "2025/02/10".to_string(),
+                notes: None,
+                created: "2025-02-01".to_string(),
+            })
+            .unwrap();
+        delete(crate::schema::operation_changes::table)
+            .execute(&mut conn)
+            .unwrap();
+
+        let mut note_repo = NoteRepository {
+            connection: &mut conn,
+        };
+        note_repo
+            .add_note(NewJobNote {
+                job_id: job.id,
+                content: "Followed up",
+                created: "2025-01-20 09:00:00",
+            })
+            .unwrap();
+
+        let mut repo = JobRepository {
+            connection: &mut conn,
+        };
+        let jobs = repo.list_jobs(&QueryArgs::default(), &sprint).unwrap();
+        assert_eq!(jobs[0].last_activity, "2025-02-01");
+    }
 }
@@ -1,7 +1,16 @@
 //! Contains all repositories for `fetters`.
 
+pub mod attachment;
+pub mod custom_field;
+pub mod description;
+pub mod document;
 pub mod job;
+pub mod note;
+pub mod operation;
+pub mod search;
+pub mod source;
 pub mod sprint;
 pub mod stage;
 pub mod statuses;
+pub mod tag;
 pub mod title;
@@ -0,0 +1,88 @@
+//! Contains the job note repository abstraction class.
+
+use diesel::insert_into;
+use diesel::prelude::*;
+
+use crate::errors::FettersError;
+use crate::models::note::{NewJobNote, QueriedJobNote};
+use crate::schema::job_notes;
+
+/// Contains all methods pertaining to CRUD operations for the `job_notes` table.
+pub struct NoteRepository<'a> {
+    /// A mutable reference to the SQLite database connection.
+    pub connection: &'a mut SqliteConnection,
+}
+
+impl<'a> NoteRepository<'a> {
+    /// Appends a new note to a job's log.
+    pub fn add_note(&mut self, new_note: NewJobNote) -> Result<QueriedJobNote, FettersError> {
+        Ok(insert_into(job_notes::table)
+            .values(&new_note)
+            .returning(QueriedJobNote::as_returning())
+            .get_result(self.connection)?)
+    }
+
+    /// Retrieves all notes for a job in chronological order.
+    pub fn get_notes_for_job(
+        &mut self,
+        target_job_id: i32,
+    ) -> Result<Vec<QueriedJobNote>, FettersError> {
+        Ok(job_notes::table
+            .filter(job_notes::job_id.eq(target_job_id))
+            .select(QueriedJobNote::as_select())
+            .order((job_notes::created.asc(), job_notes::id.asc()))
+            .load(self.connection)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use diesel::Connection;
+
+    fn setup_test_db() -> SqliteConnection {
+        let mut connection = SqliteConnection::establish(":memory:")
+            .expect("Failed to create in-memory database");
+        crate::utils::migrations::run_migrations(&mut connection)
+            .expect("Failed to run migrations");
+        connection
+    }
+
+    #[test]
+    fn test_add_and_get_notes_in_chronological_order() {
+        let mut conn = setup_test_db();
+        let mut repo = NoteRepository {
+            connection: &mut conn,
+        };
+
+        for (job_id, content, created) in [
+            (1, "Recruiter call went well", "2026-05-04 09:00:00"),
+            (1, "Applied through referral", "2026-05-03 09:24:17"),
+            (2, "Other job", "2026-05-03 10:00:00"),
+            (1, "Sent thank you email", "2026-05-04 09:00:00"),
+        ] {
+            repo.add_note(NewJobNote {
+                job_id,
+                content,
+                created,
+            })
+            .unwrap();
+        }
+
+        let contents: Vec<String> = repo
+            .get_notes_for_job(1)
+            .unwrap()
+            .into_iter()
+            .map(|note| note.content)
+            .collect();
+        assert_eq!(
+            contents,
+            vec![
+                "Applied through referral",
+                "Recruiter call went well",
+                "Sent thank you email"
+            ]
+        );
+        assert!(repo.get_notes_for_job(3).unwrap().is_empty());
+    }
+}
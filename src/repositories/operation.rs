@@ -0,0 +1,630 @@
+//! Contains the operation log repository abstraction class.
+
+use std::collections::HashSet;
+
+use chrono::Local;
+use diesel::dsl::{count, exists, max};
+use diesel::prelude::*;
+use diesel::{delete, insert_into, replace_into, update};
+use serde::Serialize;
+
+use crate::errors::FettersError;
+use crate::models::{
+    job::QueriedJob,
+    operation::{NewOperation, NewOperationChange, QueriedOperation, QueriedOperationChange},
+    sprint::QueriedSprint,
+    stage::QueriedInterviewStage,
+};
+use crate::repositories::job::JobRepository;
+use crate::schema::{interview_stages, jobs, operation_changes, operations, sprints};
+
+/// Contains all methods pertaining to CRUD operations for the `operations` and
+/// `operation_changes` tables.
+pub struct OperationRepository<'a> {
+    /// A mutable reference to the SQLite database connection.
+    pub connection: &'a mut SqliteConnection,
+}
+
+impl<'a> OperationRepository<'a> {
+    /// Starts a new operation. All changes recorded afterwards belong to this operation until the
+    /// next operation is started.
+    pub fn begin_operation(
+        &mut self,
+        new_operation: NewOperation,
+    ) -> Result<QueriedOperation, FettersError> {
+        Ok(insert_into(operations::table)
+            .values(&new_operation)
+            .returning(QueriedOperation::as_returning())
+            .get_result(self.connection)?)
+    }
+
+    /// Finishes the current operation and starts a new one performed now. This is used when a
+    /// single process makes several independent changes, ie. in the shell or the terminal UI, so
+    /// each change can be undone on its own.
+    pub fn start_operation(&mut self, command: &str) -> Result<QueriedOperation, FettersError> {
+        self.finish_operation()?;
+
+        self.begin_operation(NewOperation {
+            performed: &Local::now().format("%Y-%m-%d %H:%M:%S").to_string(),
+            command,
+        })
+    }
+
+    /// Removes the current operation if no changes were recorded for it.
+    pub fn finish_operation(&mut self) -> Result<(), FettersError> {
+        if let Some(operation) = self.get_current_operation()?
+            && self.get_changes(operation.id)?.is_empty()
+        {
+            delete(operations::table.find(operation.id)).execute(self.connection)?;
+        }
+
+        Ok(())
+    }
+
+    /// Retrieves the most recently started operation, which changes are currently recorded for.
+    pub fn get_current_operation(&mut self) -> Result<Option<QueriedOperation>, FettersError> {
+        Ok(operations::table
+            .order(operations::id.desc())
+            .select(QueriedOperation::as_select())
+            .first(self.connection)
+            .optional()?)
+    }
+
+    /// Records a change to a row in the current operation. A new operation is started if none
+    /// exists yet.
+    pub fn record_change<T: Serialize>(
+        &mut self,
+        table_name: &str,
+        row_id: i32,
+        before: Option<&T>,
+        after: Option<&T>,
+    ) -> Result<(), FettersError> {
+        let current_operation_id: Option<i32> = operations::table
+            .select(max(operations::id))
+            .first(self.connection)?;
+        let operation_id = match current_operation_id {
+            Some(operation_id) => operation_id,
+            None => {
+                self.begin_operation(NewOperation {
+                    performed: &chrono::Local::now().format("%Y-%m-%d %H:%M:%S").to_string(),
+                    command: "fetters",
+                })?
+                .id
+            }
+        };
+
+        let before_snapshot = before.map(serde_json::to_string).transpose()?;
+        let after_snapshot = after.map(serde_json::to_string).transpose()?;
+
+        insert_into(operation_changes::table)
+            .values(&NewOperationChange {
+                operation_id,
+                table_name,
+                row_id,
+                before_snapshot: before_snapshot.as_deref(),
+                after_snapshot: after_snapshot.as_deref(),
+            })
+            .execute(self.connection)?;
+
+        Ok(())
+    }
+
+    /// Retrieves all changes recorded for an operation in the order they were made.
+    pub fn get_changes(
+        &mut self,
+        operation_id: i32,
+    ) -> Result<Vec<QueriedOperationChange>, FettersError> {
+        Ok(operation_changes::table
+            .filter(operation_changes::operation_id.eq(operation_id))
+            .order(operation_changes::id.asc())
+            .select(QueriedOperationChange::as_select())
+            .load(self.connection)?)
+    }
+
+    /// Retrieves the most recent operations that changed something, newest first.
+    pub fn get_operations(
+        &mut self,
+        limit: i64,
+    ) -> Result<Vec<(QueriedOperation, Vec<QueriedOperationChange>)>, FettersError> {
+        let queried_operations = operations::table
+            .filter(exists(
+                operation_changes::table.filter(operation_changes::operation_id.eq(operations::id)),
+            ))
+            .order(operations::id.desc())
+            .limit(limit)
+            .select(QueriedOperation::as_select())
+            .load(self.connection)?;
+
+        self.with_changes(queried_operations)
+    }
+
+    /// Retrieves up to `steps` operations that have not been undone yet, newest first. Operations
+    /// started at or after `current_operation_id` are excluded.
+    pub fn get_undoable_operations(
+        &mut self,
+        current_operation_id: i32,
+        steps: i64,
+    ) -> Result<Vec<(QueriedOperation, Vec<QueriedOperationChange>)>, FettersError> {
+        let queried_operations = operations::table
+            .filter(operations::id.lt(current_operation_id))
+            .filter(operations::undone_at.is_null())
+            .filter(exists(
+                operation_changes::table.filter(operation_changes::operation_id.eq(operations::id)),
+            ))
+            .order(operations::id.desc())
+            .limit(steps)
+            .select(QueriedOperation::as_select())
+            .load(self.connection)?;
+
+        self.with_changes(queried_operations)
+    }
+
+    /// Reverses every change recorded for an operation by restoring the row snapshots taken before
+    /// each change, then marks the operation as undone. The job counts of all affected sprints are
+    /// recalculated afterwards. Returns the IDs of jobs that were removed because adding them was
+    /// undone.
+    pub fn undo_operation(
+        &mut self,
+        operation_id: i32,
+        timestamp: &str,
+    ) -> Result<Vec<i32>, FettersError> {
+        let changes = self.get_changes(operation_id)?;
+
+        let mut removed_job_ids = Vec::new();
+        let mut sprint_ids = HashSet::new();
+
+        for change in changes.iter().rev() {
+            if change.table_name == "sprints" {
+                sprint_ids.insert(change.row_id);
+            }
+            if change.table_name == "jobs" {
+                for snapshot in [&change.before_snapshot, &change.after_snapshot]
+                    .into_iter()
+                    .flatten()
+                {
+                    sprint_ids.insert(serde_json::from_str::<QueriedJob>(snapshot)?.sprint_id);
+                }
+
+                if change.before_snapshot.is_none() {
+                    removed_job_ids.push(change.row_id);
+                }
+            }
+
+            self.restore_snapshot(
+                &change.table_name,
+                change.row_id,
+                change.before_snapshot.as_deref(),
+            )?;
+        }
+
+        for sprint_id in sprint_ids {
+            self.recount_num_jobs(sprint_id)?;
+        }
+
+        update(operations::table.find(operation_id))
+            .set(operations::undone_at.eq(timestamp))
+            .execute(self.connection)?;
+
+        Ok(removed_job_ids)
+    }
+
+    /// Removes every recorded change to a job or any of its interview stages. This is used when a
+    /// job is permanently deleted, since undoing those changes would bring back a job whose notes,
+    /// tags, and attachments no longer exist. Operations left without changes are no longer listed.
+    pub fn forget_job(&mut self, job_id: i32, stage_ids: &[i32]) -> Result<(), FettersError> {
+        delete(
+            operation_changes::table.filter(
+                operation_changes::table_name
+                    .eq("jobs")
+                    .and(operation_changes::row_id.eq(job_id))
+                    .or(operation_changes::table_name
+                        .eq("interview_stages")
+                        .and(operation_changes::row_id.eq_any(stage_ids))),
+            ),
+        )
+        .execute(self.connection)?;
+
+        Ok(())
+    }
+
+    /// Pair each operation with its changes.
+    fn with_changes(
+        &mut self,
+        queried_operations: Vec<QueriedOperation>,
+    ) -> Result<Vec<(QueriedOperation, Vec<QueriedOperationChange>)>, FettersError> {
+        let mut operations_with_changes = Vec::new();
+        for operation in queried_operations {
+            let changes = self.get_changes(operation.id)?;
+            operations_with_changes.push((operation, changes));
+        }
+
+        Ok(operations_with_changes)
+    }
+
+    /// Restore a row to a snapshot, or delete the row if there is no snapshot because the row was
+    /// inserted.
+    fn restore_snapshot(
+        &mut self,
+        table_name: &str,
+        row_id: i32,
+        snapshot: Option<&str>,
+    ) -> Result<(), FettersError> {
+        match (table_name, snapshot) {
+            ("jobs", Some(snapshot)) => {
+                let row: QueriedJob = serde_json::from_str(snapshot)?;
+                replace_into(jobs::table)
+                    .values(&row)
+                    .execute(self.connection)?;
+            }
+            ("jobs", None) => {
+                let mut job_repo = JobRepository {
+                    connection: self.connection,
+                };
+                job_repo.delete_related_rows(row_id)?;
+                delete(jobs::table.find(row_id)).execute(self.connection)?;
+            }
+            ("interview_stages", Some(snapshot)) => {
+                let row: QueriedInterviewStage = serde_json::from_str(snapshot)?;
+                replace_into(interview_stages::table)
+                    .values(&row)
+                    .execute(self.connection)?;
+            }
+            ("interview_stages", None) => {
+                delete(interview_stages::table.find(row_id)).execute(self.connection)?;
+            }
+            ("sprints", Some(snapshot)) => {
+                let row: QueriedSprint = serde_json::from_str(snapshot)?;
+                replace_into(sprints::table)
+                    .values(&row)
+                    .execute(self.connection)?;
+            }
+            ("sprints", None) => {
+                let sprint_in_use = diesel::select(exists(
+                    jobs::table.filter(jobs::sprint_id.eq(row_id)),
+                ))
+                .get_result::<bool>(self.connection)?;
+                if sprint_in_use {
+                    let sprint_name = sprints::table
+                        .find(row_id)
+                        .select(sprints::name)
+                        .first::<String>(self.connection)?;
+                    return Err(FettersError::SprintInUse(sprint_name));
+                }
+
+                delete(sprints::table.find(row_id)).execute(self.connection)?;
+            }
+            (table_name, _) => {
+                return Err(FettersError::UnknownError(format!(
+                    "Changes to the {table_name} table cannot be undone."
+                )));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Set a sprint's job count to the number of jobs in the sprint that are not in the trash.
+    fn recount_num_jobs(&mut self, sprint_id: i32) -> Result<(), FettersError> {
+        let num_jobs: i64 = jobs::table
+            .filter(jobs::sprint_id.eq(sprint_id))
+            .filter(jobs::deleted_at.is_null())
+            .select(count(jobs::id))
+            .first(self.connection)?;
+
+        update(sprints::table.find(sprint_id))
+            .set(sprints::num_jobs.eq(num_jobs as i32))
+            .execute(self.connection)?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use diesel::Connection;
+
+    use crate::models::job::NewJob;
+    use crate::models::sprint::NewSprint;
+    use crate::models::stage::NewInterviewStage;
+    use crate::models::title::NewTitle;
+    use crate::repositories::sprint::SprintRepository;
+    use crate::repositories::stage::StageRepository;
+    use crate::repositories::statuses::StatusRepository;
+    use crate::repositories::title::TitleRepository;
+
+    fn setup_test_db() -> SqliteConnection {
+        let mut connection =
+            SqliteConnection::establish(":memory:").expect("Failed to create in-memory database");
+        crate::utils::migrations::run_migrations(&mut connection)
+            .expect("Failed to run migrations");
+
+        let mut status_repo = StatusRepository {
+            connection: &mut connection,
+        };
+        status_repo
+            .seed_statuses()
+            .expect("Failed to seed statuses");
+
+        connection
+    }
+
+    fn begin(conn: &mut SqliteConnection, command: &str) -> i32 {
+        let mut repo = OperationRepository { connection: conn };
+        repo.begin_operation(NewOperation {
+            performed: "2025-01-15 10:00:00",
+            command,
+        })
+        .unwrap()
+        .id
+    }
+
+    fn create_sprint(conn: &mut SqliteConnection) -> QueriedSprint {
+        let mut repo = SprintRepository { connection: conn };
+        repo.add_job_sprint(NewSprint {
+            name: "test-sprint",
+            start_date: "2025-01-01",
+            end_date: None,
+            num_jobs: &0,
+        })
+        .unwrap()
+    }
+
+    fn create_job(conn: &mut SqliteConnection, sprint_id: i32) -> QueriedJob {
+        let mut title_repo = TitleRepository { connection: conn };
+        let title = title_repo.add_title(NewTitle { name: "SWE" }).unwrap();
+
+        let mut status_repo = StatusRepository { connection: conn };
+        let status_id = status_repo.get_all_statuses().unwrap()[0].id;
+
+        let mut job_repo = JobRepository { connection: conn };
+        job_repo
+            .add_job(NewJob {
+                company_name: "TestCo",
+                created: "2025-01-15 10:00:00".to_string(),
+                title_id: title.id,
+                status_id,
+                link: None,
+                sprint_id,
+                source_id: None,
+                referrer: None,
+                resume_id: None,
+                cover_letter_id: None,
+            })
+            .unwrap()
+    }
+
+    fn add_stage(
+        conn: &mut SqliteConnection,
+        job_id: i32,
+        stage_number: i32,
+    ) -> QueriedInterviewStage {
+        let mut repo = StageRepository { connection: conn };
+        repo.add_stage(NewInterviewStage {
+            job_id,
+            stage_number,
+            name: None,
+            status: "SCHEDULED".to_string(),
+            scheduled_date: "2025/01/20".to_string(),
+            notes: None,
+            created: "2025-01-15".to_string(),
+        })
+        .unwrap()
+    }
+
+    #[test]
+    fn test_finish_operation_removes_empty_operation() {
+        let mut conn = setup_test_db();
+        let operation_id = begin(&mut conn, "fetters list");
+
+        let mut repo = OperationRepository {
+            connection: &mut conn,
+        };
+        repo.finish_operation().unwrap();
+
+        let current = repo.get_current_operation().unwrap();
+        assert!(current.is_none_or(|operation| operation.id != operation_id));
+    }
+
+    #[test]
+    fn test_start_operation_replaces_empty_operation() {
+        let mut conn = setup_test_db();
+        let sprint = create_sprint(&mut conn);
+        let empty_id = begin(&mut conn, "fetters shell");
+
+        let mut repo = OperationRepository {
+            connection: &mut conn,
+        };
+        let first = repo.start_operation("fetters add").unwrap();
+        assert_ne!(first.id, empty_id);
+        create_job(&mut conn, sprint.id);
+
+        let mut repo = OperationRepository {
+            connection: &mut conn,
+        };
+        let second = repo.start_operation("fetters list").unwrap();
+        let operations = repo.get_operations(10).unwrap();
+
+        // The empty operation is removed, the one with a change is kept.
+        assert!(
+            operations
+                .iter()
+                .all(|(operation, _)| operation.id != empty_id)
+        );
+        assert!(
+            operations
+                .iter()
+                .any(|(operation, _)| operation.id == first.id)
+        );
+        assert_eq!(
+            repo.get_current_operation()
+                .unwrap()
+                .map(|operation| operation.id),
+            Some(second.id)
+        );
+    }
+
+    #[test]
+    fn test_get_operations_lists_newest_first() {
+        let mut conn = setup_test_db();
+        begin(&mut conn, "fetters sprint new");
+        let sprint = create_sprint(&mut conn);
+        begin(&mut conn, "fetters add");
+        create_job(&mut conn, sprint.id);
+        begin(&mut conn, "fetters list");
+
+        let mut repo = OperationRepository {
+            connection: &mut conn,
+        };
+        let operations = repo.get_operations(10).unwrap();
+
+        assert_eq!(operations.len(), 2);
+        assert_eq!(operations[0].0.command, "fetters add");
+        assert_eq!(operations[1].0.command, "fetters sprint new");
+        assert!(
+            operations[0]
+                .1
+                .iter()
+                .any(|change| change.table_name == "jobs" && change.before_snapshot.is_none())
+        );
+    }
+
+    #[test]
+    fn test_undo_add_job_removes_job_and_restores_num_jobs() {
+        let mut conn = setup_test_db();
+        begin(&mut conn, "fetters sprint new");
+        let sprint = create_sprint(&mut conn);
+        let add_operation_id = begin(&mut conn, "fetters add");
+        let job = create_job(&mut conn, sprint.id);
+        let undo_operation_id = begin(&mut conn, "fetters undo");
+
+        let mut repo = OperationRepository {
+            connection: &mut conn,
+        };
+        let operations = repo.get_undoable_operations(undo_operation_id, 1).unwrap();
+        assert_eq!(operations.len(), 1);
+        assert_eq!(operations[0].0.id, add_operation_id);
+
+        let removed = repo
+            .undo_operation(add_operation_id, "2025-01-16 10:00:00")
+            .unwrap();
+        assert_eq!(removed, vec![job.id]);
+
+        let mut job_repo = JobRepository {
+            connection: &mut conn,
+        };
+        assert!(job_repo.get_job(job.id).is_err());
+
+        let mut sprint_repo = SprintRepository {
+            connection: &mut conn,
+        };
+        assert_eq!(sprint_repo.get_sprint(sprint.id).unwrap().num_jobs, 0);
+
+        let mut repo = OperationRepository {
+            connection: &mut conn,
+        };
+        let operations = repo.get_undoable_operations(undo_operation_id, 1).unwrap();
+        assert_ne!(operations[0].0.id, add_operation_id);
+    }
+
+    #[test]
+    fn test_undo_sprint_new_refuses_while_jobs_belong_to_sprint() {
+        let mut conn = setup_test_db();
+        let sprint_operation_id = begin(&mut conn, "fetters sprint new");
+        let sprint = create_sprint(&mut conn);
+        begin(&mut conn, "fetters add");
+        create_job(&mut conn, sprint.id);
+
+        let mut repo = OperationRepository {
+            connection: &mut conn,
+        };
+        let result = repo.undo_operation(sprint_operation_id, "2025-01-16 10:00:00");
+        assert!(matches!(result, Err(FettersError::SprintInUse(name)) if name == "test-sprint"));
+
+        let mut sprint_repo = SprintRepository {
+            connection: &mut conn,
+        };
+        assert!(sprint_repo.get_sprint(sprint.id).is_ok());
+    }
+
+    #[test]
+    fn test_undo_delete_stage_restores_stage_numbering() {
+        let mut conn = setup_test_db();
+        let sprint = create_sprint(&mut conn);
+        let job = create_job(&mut conn, sprint.id);
+        let first = add_stage(&mut conn, job.id, 1);
+        let second = add_stage(&mut conn, job.id, 2);
+
+        let delete_operation_id = begin(&mut conn, "fetters stage delete");
+        let mut stage_repo = StageRepository {
+            connection: &mut conn,
+        };
+        stage_repo.delete_stage(first.id).unwrap();
+        stage_repo.renumber_stages(job.id).unwrap();
+        assert_eq!(stage_repo.get_stage(second.id).unwrap().stage_number, 1);
+
+        let mut repo = OperationRepository {
+            connection: &mut conn,
+        };
+        repo.undo_operation(delete_operation_id, "2025-01-16 10:00:00")
+            .unwrap();
+
+        let mut stage_repo = StageRepository {
+            connection: &mut conn,
+        };
+        let stages = stage_repo.get_stages_for_job(job.id).unwrap();
+        assert_eq!(stages.len(), 2);
+        assert_eq!(stages[0].id, first.id);
+        assert_eq!(stages[0].stage_number, 1);
+        assert_eq!(stages[1].id, second.id);
+        assert_eq!(stages[1].stage_number, 2);
+    }
+
+    #[test]
+    fn test_undo_trash_job_restores_job_and_num_jobs() {
+        let mut conn = setup_test_db();
+        let sprint = create_sprint(&mut conn);
+        let job = create_job(&mut conn, sprint.id);
+
+        let trash_operation_id = begin(&mut conn, "fetters delete");
+        let mut job_repo = JobRepository {
+            connection: &mut conn,
+        };
+        job_repo.trash_job(job.id, "2025-01-16 10:00:00").unwrap();
+
+        let mut repo = OperationRepository {
+            connection: &mut conn,
+        };
+        repo.undo_operation(trash_operation_id, "2025-01-16 11:00:00")
+            .unwrap();
+
+        let mut job_repo = JobRepository {
+            connection: &mut conn,
+        };
+        assert!(job_repo.get_job(job.id).unwrap().deleted_at.is_none());
+
+        let mut sprint_repo = SprintRepository {
+            connection: &mut conn,
+        };
+        assert_eq!(sprint_repo.get_sprint(sprint.id).unwrap().num_jobs, 1);
+    }
+
+    #[test]
+    fn test_forget_job_removes_recorded_changes() {
+        let mut conn = setup_test_db();
+        let sprint = create_sprint(&mut conn);
+        let add_operation_id = begin(&mut conn, "fetters add");
+        let job = create_job(&mut conn, sprint.id);
+
+        let mut job_repo = JobRepository {
+            connection: &mut conn,
+        };
+        job_repo.delete_job(job.id).unwrap();
+
+        let mut repo = OperationRepository {
+            connection: &mut conn,
+        };
+        let changes = repo.get_changes(add_operation_id).unwrap();
+        assert!(changes.iter().all(|change| change.table_name != "jobs"));
+    }
+}
@@ -0,0 +1,323 @@
+//! Contains the full-text search repository abstraction class.
+
+use diesel::prelude::*;
+use diesel::result::Error as DieselError;
+use diesel::sql_query;
+use diesel::sql_types::{BigInt, Nullable, Text};
+
+use crate::errors::FettersError;
+use crate::models::search::{MATCH_END, MATCH_START, SearchResult};
+
+/// Contains all methods pertaining to querying the `job_search` FTS5 index.
+pub struct SearchRepository<'a> {
+    /// A mutable reference to the SQLite database connection.
+    pub connection: &'a mut SqliteConnection,
+}
+
+impl<'a> SearchRepository<'a> {
+    /// Search company names, job titles, notes, interview stage notes, and job descriptions with
+    /// an FTS5 query. Jobs in the trash are left out. All sprints are searched unless a sprint
+    /// name is provided. Results are ordered by relevance, with matches in company names and job
+    /// titles ranked above matches in longer text.
+    pub fn search_jobs(
+        &mut self,
+        query: &str,
+        sprint: Option<&str>,
+        limit: i64,
+    ) -> Result<Vec<SearchResult>, FettersError> {
+        sql_query(
+            "SELECT jobs.id AS id, sprints.name AS sprint, jobs.company_name AS company_name, \
+                titles.name AS title, statuses.name AS status, \
+                snippet(job_search, -1, ?, ?, '...', 12) AS snippet \
+            FROM job_search \
+            INNER JOIN jobs ON jobs.id = job_search.rowid \
+            LEFT JOIN titles ON titles.id = jobs.title_id \
+            LEFT JOIN statuses ON statuses.id = jobs.status_id \
+            LEFT JOIN sprints ON sprints.id = jobs.sprint_id \
+            WHERE job_search MATCH ? \
+                AND jobs.deleted_at IS NULL \
+                AND (? IS NULL OR sprints.name LIKE '%' || ? || '%') \
+            ORDER BY bm25(job_search, 10.0, 5.0, 1.0, 1.0, 1.0) \
+            LIMIT ?",
+        )
+        .bind::<Text, _>(MATCH_START)
+        .bind::<Text, _>(MATCH_END)
+        .bind::<Text, _>(query)
+        .bind::<Nullable<Text>, _>(sprint)
+        .bind::<Nullable<Text>, _>(sprint)
+        .bind::<BigInt, _>(limit)
+        .load::<SearchResult>(self.connection)
+        .map_err(|error| match error {
+            // FTS5 reports malformed queries and unknown column filters as database errors.
+            DieselError::DatabaseError(_, info)
+                if info.message().starts_with("fts5:")
+                    || info.message().starts_with("no such column") =>
+            {
+                FettersError::InvalidSearchQuery(info.message().to_string())
+            }
+            error => FettersError::DieselResultError(error),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use diesel::Connection;
+
+    use crate::models::description::NewJobDescription;
+    use crate::models::job::{JobUpdate, NewJob};
+    use crate::models::note::NewJobNote;
+    use crate::models::sprint::NewSprint;
+    use crate::models::stage::NewInterviewStage;
+    use crate::models::title::NewTitle;
+    use crate::repositories::description::DescriptionRepository;
+    use crate::repositories::job::JobRepository;
+    use crate::repositories::note::NoteRepository;
+    use crate::repositories::sprint::SprintRepository;
+    use crate::repositories::stage::StageRepository;
+    use crate::repositories::statuses::StatusRepository;
+    use crate::repositories::title::TitleRepository;
+
+    fn setup_test_db() -> SqliteConnection {
+        let mut connection =
+            SqliteConnection::establish(":memory:").expect("Failed to create in-memory database");
+        crate::utils::migrations::run_migrations(&mut connection)
+            .expect("Failed to run migrations");
+
+        let mut status_repo = StatusRepository {
+            connection: &mut connection,
+        };
+        status_repo
+            .seed_statuses()
+            .expect("Failed to seed statuses");
+
+        connection
+    }
+
+    fn create_job(conn: &mut SqliteConnection, company_name: &str, sprint_name: &str) -> i32 {
+        let mut sprint_repo = SprintRepository { connection: conn };
+        let sprint = sprint_repo
+            .add_job_sprint(NewSprint {
+                name: sprint_name,
+                start_date: "2025-01-01",
+                end_date: None,
+                num_jobs: &0,
+            })
+            .unwrap();
+
+        let mut title_repo = TitleRepository { connection: conn };
+        let title = title_repo
+            .add_title(NewTitle {
+                name: "Backend Engineer",
+            })
+            .unwrap();
+
+        let mut status_repo = StatusRepository { connection: conn };
+        let status_id = status_repo.get_all_statuses().unwrap()[0].id;
+
+        let mut job_repo = JobRepository { connection: conn };
+        job_repo
+            .add_job(NewJob {
+                company_name,
+                created: "2025-01-15 10:00:00".to_string(),
+                title_id: title.id,
+                status_id,
+                link: None,
+                sprint_id: sprint.id,
+                source_id: None,
+                referrer: None,
+                resume_id: None,
+                cover_letter_id: None,
+            })
+            .unwrap()
+            .id
+    }
+
+    fn search(conn: &mut SqliteConnection, query: &str, sprint: Option<&str>) -> Vec<i32> {
+        let mut repo = SearchRepository { connection: conn };
+        repo.search_jobs(query, sprint, 20)
+            .unwrap()
+            .into_iter()
+            .map(|result| result.id)
+            .collect()
+    }
+
+    #[test]
+    fn test_search_company_and_title() {
+        let mut conn = setup_test_db();
+        let job_id = create_job(&mut conn, "Initech", "sprint-1");
+
+        assert_eq!(search(&mut conn, "initech", None), vec![job_id]);
+        assert_eq!(search(&mut conn, "backend", None), vec![job_id]);
+        assert!(search(&mut conn, "frontend", None).is_empty());
+    }
+
+    #[test]
+    fn test_search_notes_stage_notes_and_description() {
+        let mut conn = setup_test_db();
+        let job_id = create_job(&mut conn, "Initech", "sprint-1");
+
+        let mut note_repo = NoteRepository {
+            connection: &mut conn,
+        };
+        note_repo
+            .add_note(NewJobNote {
+                job_id,
+                content: "Recruiter mentioned relocation",
+                created: "2025-01-16 10:00:00",
+            })
+            .unwrap();
+
+        let mut stage_repo = StageRepository {
+            connection: &mut conn,
+        };
+        stage_repo
+            .add_stage(NewInterviewStage {
+                job_id,
+                stage_number: 1,
+                name: None,
+                status: "SCHEDULED".to_string(),
+                scheduled_date: "2025/01/20".to_string(),
+                notes: Some("Whiteboard system design".to_string()),
+                created: "2025-01-16".to_string(),
+            })
+            .unwrap();
+
+        let mut description_repo = DescriptionRepository {
+            connection: &mut conn,
+        };
+        description_repo
+            .set_description(NewJobDescription {
+                job_id,
+                content: "Experience with Kubernetes required",
+                captured: "2025-01-15 10:00:00",
+            })
+            .unwrap();
+
+        assert_eq!(search(&mut conn, "relocation", None), vec![job_id]);
+        assert_eq!(search(&mut conn, "whiteboard", None), vec![job_id]);
+        assert_eq!(search(&mut conn, "kubernetes", None), vec![job_id]);
+    }
+
+    #[test]
+    fn test_search_phrase_prefix_and_boolean_syntax() {
+        let mut conn = setup_test_db();
+        let initech_id = create_job(&mut conn, "Initech Systems", "sprint-1");
+        let globex_id = create_job(&mut conn, "Globex", "sprint-2");
+
+        assert_eq!(
+            search(&mut conn, "\"initech systems\"", None),
+            vec![initech_id]
+        );
+        assert_eq!(search(&mut conn, "glob*", None), vec![globex_id]);
+        assert_eq!(
+            search(&mut conn, "backend NOT globex", None),
+            vec![initech_id]
+        );
+
+        let mut both = search(&mut conn, "initech OR globex", None);
+        both.sort();
+        assert_eq!(both, vec![initech_id, globex_id]);
+    }
+
+    #[test]
+    fn test_search_ranks_company_matches_first() {
+        let mut conn = setup_test_db();
+        let note_job_id = create_job(&mut conn, "Initech", "sprint-1");
+        let company_job_id = create_job(&mut conn, "Globex", "sprint-2");
+
+        let mut note_repo = NoteRepository {
+            connection: &mut conn,
+        };
+        note_repo
+            .add_note(NewJobNote {
+                job_id: note_job_id,
+                content: "Their competitor Globex also reached out after the phone screen",
+                created: "2025-01-16 10:00:00",
+            })
+            .unwrap();
+
+        assert_eq!(
+            search(&mut conn, "globex", None),
+            vec![company_job_id, note_job_id]
+        );
+    }
+
+    #[test]
+    fn test_search_filters_by_sprint() {
+        let mut conn = setup_test_db();
+        create_job(&mut conn, "Initech", "2025-01-01");
+        let second_id = create_job(&mut conn, "Initech", "2025-02-01");
+
+        assert_eq!(search(&mut conn, "initech", None).len(), 2);
+        assert_eq!(
+            search(&mut conn, "initech", Some("2025-02")),
+            vec![second_id]
+        );
+    }
+
+    #[test]
+    fn test_search_reflects_updates_and_trash() {
+        let mut conn = setup_test_db();
+        let job_id = create_job(&mut conn, "Initech", "sprint-1");
+
+        let mut job_repo = JobRepository {
+            connection: &mut conn,
+        };
+        job_repo
+            .update_job(
+                job_id,
+                JobUpdate {
+                    company_name: Some("Initrode"),
+                    ..Default::default()
+                },
+            )
+            .unwrap();
+
+        assert!(search(&mut conn, "initech", None).is_empty());
+        assert_eq!(search(&mut conn, "initrode", None), vec![job_id]);
+
+        let mut job_repo = JobRepository {
+            connection: &mut conn,
+        };
+        job_repo.trash_job(job_id, "2025-01-20 10:00:00").unwrap();
+
+        assert!(search(&mut conn, "initrode", None).is_empty());
+    }
+
+    #[test]
+    fn test_search_highlights_snippet() {
+        let mut conn = setup_test_db();
+        create_job(&mut conn, "Initech", "sprint-1");
+
+        let mut repo = SearchRepository {
+            connection: &mut conn,
+        };
+        let results = repo.search_jobs("initech", None, 20).unwrap();
+
+        assert_eq!(
+            results[0].snippet,
+            format!("{MATCH_START}Initech{MATCH_END}")
+        );
+    }
+
+    #[test]
+    fn test_search_invalid_query() {
+        let mut conn = setup_test_db();
+        create_job(&mut conn, "Initech", "sprint-1");
+
+        let mut repo = SearchRepository {
+            connection: &mut conn,
+        };
+
+        assert!(matches!(
+            repo.search_jobs("initech AND", None, 20),
+            Err(FettersError::InvalidSearchQuery(_))
+        ));
+        assert!(matches!(
+            repo.search_jobs("salary:high", None, 20),
+            Err(FettersError::InvalidSearchQuery(_))
+        ));
+    }
+}
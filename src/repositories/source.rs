@@ -0,0 +1,127 @@
+//! Contains the application source repository abstraction class.
+
+use diesel::insert_into;
+use diesel::prelude::*;
+
+use crate::errors::FettersError;
+use crate::models::source::{NewSource, QueriedSource};
+
+/// Contains all methods pertaining to CRUD operations for the `sources` table.
+pub struct SourceRepository<'a> {
+    pub connection: &'a mut SqliteConnection,
+}
+
+impl<'a> SourceRepository<'a> {
+    /// Adds a new application source into the `sources` table.
+    pub fn add_source(&mut self, new_source: NewSource) -> Result<QueriedSource, FettersError> {
+        use crate::schema::sources::dsl::*;
+
+        insert_into(sources)
+            .values(&new_source)
+            .on_conflict(name)
+            .do_nothing()
+            .execute(self.connection)?;
+
+        Ok(sources
+            .filter(name.eq(new_source.name))
+            .first(self.connection)?)
+    }
+
+    /// Retrieves an existing application source by ID.
+    pub fn get_source(&mut self, source_id: i32) -> Result<QueriedSource, FettersError> {
+        use crate::schema::sources::dsl::*;
+
+        Ok(sources
+            .find(source_id)
+            .select(QueriedSource::as_select())
+            .first(self.connection)?)
+    }
+
+    /// Retrieves all application sources.
+    pub fn get_all_sources(&mut self) -> Result<Vec<QueriedSource>, FettersError> {
+        use crate::schema::sources::dsl::*;
+
+        Ok(sources
+            .select(QueriedSource::as_select())
+            .load(self.connection)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use diesel::Connection;
+
+    fn setup_test_db() -> SqliteConnection {
+        let mut connection = SqliteConnection::establish(":memory:")
+            .expect("Failed to create in-memory database");
+        crate::utils::migrations::run_migrations(&mut connection)
+            .expect("Failed to run migrations");
+        connection
+    }
+
+    #[test]
+    fn test_add_source() {
+        let mut conn = setup_test_db();
+        let mut repo = SourceRepository {
+            connection: &mut conn,
+        };
+
+        let source = repo.add_source(NewSource { name: "REFERRAL" }).unwrap();
+        assert_eq!(source.name, "REFERRAL");
+        assert!(source.id > 0);
+    }
+
+    #[test]
+    fn test_add_duplicate_source_returns_existing() {
+        let mut conn = setup_test_db();
+        let mut repo = SourceRepository {
+            connection: &mut conn,
+        };
+
+        let first = repo.add_source(NewSource { name: "LINKEDIN" }).unwrap();
+        let second = repo.add_source(NewSource { name: "LINKEDIN" }).unwrap();
+        assert_eq!(first.id, second.id);
+    }
+
+    #[test]
+    fn test_get_source() {
+        let mut conn = setup_test_db();
+        let mut repo = SourceRepository {
+            connection: &mut conn,
+        };
+
+        let added = repo
+            .add_source(NewSource {
+                name: "RECRUITER INBOUND",
+            })
+            .unwrap();
+        let fetched = repo.get_source(added.id).unwrap();
+        assert_eq!(fetched.id, added.id);
+        assert_eq!(fetched.name, "RECRUITER INBOUND");
+    }
+
+    #[test]
+    fn test_get_source_not_found() {
+        let mut conn = setup_test_db();
+        let mut repo = SourceRepository {
+            connection: &mut conn,
+        };
+
+        assert!(repo.get_source(999).is_err());
+    }
+
+    #[test]
+    fn test_get_all_sources() {
+        let mut conn = setup_test_db();
+        let mut repo = SourceRepository {
+            connection: &mut conn,
+        };
+
+        repo.add_source(NewSource { name: "REFERRAL" }).unwrap();
+        repo.add_source(NewSource { name: "JOB BOARD" }).unwrap();
+
+        let sources = repo.get_all_sources().unwrap();
+        assert_eq!(sources.len(), 2);
+    }
+}
@@ -7,6 +7,7 @@ use diesel::prelude::*;
 
 use crate::errors::FettersError;
 use crate::models::sprint::{NewSprint, QueriedSprint, SprintUpdate};
+use crate::repositories::operation::OperationRepository;
 use crate::schema::sprints;
 
 /// Contains all methods pertaining to CRUD operations for the `sprints` table.
@@ -19,10 +20,27 @@ impl<'a> SprintRepository<'a> {
     pub fn add_job_sprint(&mut self, new_sprint: NewSprint) -> Result<QueriedSprint, FettersError> {
         use crate::schema::sprints::dsl::*;
 
-        Ok(insert_into(sprints)
+        let queried_sprint = insert_into(sprints)
             .values(&new_sprint)
             .returning(QueriedSprint::as_returning())
-            .get_result(self.connection)?)
+            .get_result(self.connection)?;
+
+        let mut operation_repo = OperationRepository {
+            connection: self.connection,
+        };
+        operation_repo.record_change("sprints", queried_sprint.id, None, Some(&queried_sprint))?;
+
+        Ok(queried_sprint)
+    }
+
+    /// Retrieves an existing sprint by ID.
+    pub fn get_sprint(&mut self, sprint_id: i32) -> Result<QueriedSprint, FettersError> {
+        use crate::schema::sprints::dsl::*;
+
+        Ok(sprints
+            .find(sprint_id)
+            .select(QueriedSprint::as_select())
+            .first(self.connection)?)
     }
 
     /// Retrieves the current sprint's ID.
@@ -42,7 +60,12 @@ impl<'a> SprintRepository<'a> {
                         end_date: None,
                         num_jobs: &0,
                     };
-                    self.add_job_sprint(new_sprint)
+                    // The current sprint comes from the configuration rather than from a
+                    // command, so creating it is not recorded as a change that can be undone.
+                    Ok(insert_into(sprints)
+                        .values(&new_sprint)
+                        .returning(QueriedSprint::as_returning())
+                        .get_result(self.connection)?)
                 },
                 Ok,
             )
@@ -56,10 +79,24 @@ impl<'a> SprintRepository<'a> {
     ) -> Result<QueriedSprint, FettersError> {
         use crate::schema::sprints::dsl::*;
 
-        Ok(update(sprints.find(sprint_id))
+        let previous_sprint = self.get_sprint(sprint_id)?;
+
+        let queried_sprint = update(sprints.find(sprint_id))
             .set(&changes)
             .returning(QueriedSprint::as_returning())
-            .get_result(self.connection)?)
+            .get_result(self.connection)?;
+
+        let mut operation_repo = OperationRepository {
+            connection: self.connection,
+        };
+        operation_repo.record_change(
+            "sprints",
+            sprint_id,
+            Some(&previous_sprint),
+            Some(&queried_sprint),
+        )?;
+
+        Ok(queried_sprint)
     }
 
     /// Retrieves all job sprints.
@@ -73,18 +110,39 @@ impl<'a> SprintRepository<'a> {
 
     /// Increment the `num_jobs` count for a particular sprint.
     pub fn increment_num_jobs(&mut self, sprint_id: i32) -> Result<(), FettersError> {
-        update(sprints::table.filter(sprints::id.eq(sprint_id)))
-            .set(sprints::num_jobs.eq(sprints::num_jobs + 1))
-            .execute(self.connection)?;
-
-        Ok(())
+        self.adjust_num_jobs(sprint_id, 1)
     }
 
     /// Decrement the `num_jobs` count for a particular sprint.
     pub fn decrement_num_jobs(&mut self, sprint_id: i32) -> Result<(), FettersError> {
-        update(sprints::table.filter(sprints::id.eq(sprint_id)))
-            .set(sprints::num_jobs.eq(sprints::num_jobs - 1))
-            .execute(self.connection)?;
+        self.adjust_num_jobs(sprint_id, -1)
+    }
+
+    /// Add a difference to the `num_jobs` count for a particular sprint. Nothing happens if the
+    /// sprint does not exist.
+    fn adjust_num_jobs(&mut self, sprint_id: i32, difference: i32) -> Result<(), FettersError> {
+        let previous_sprint = sprints::table
+            .find(sprint_id)
+            .select(QueriedSprint::as_select())
+            .first(self.connection)
+            .optional()?;
+
+        if let Some(previous_sprint) = previous_sprint {
+            let queried_sprint = update(sprints::table.find(sprint_id))
+                .set(sprints::num_jobs.eq(sprints::num_jobs + difference))
+                .returning(QueriedSprint::as_returning())
+                .get_result(self.connection)?;
+
+            let mut operation_repo = OperationRepository {
+                connection: self.connection,
+            };
+            operation_repo.record_change(
+                "sprints",
+                sprint_id,
+                Some(&previous_sprint),
+                Some(&queried_sprint),
+            )?;
+        }
 
         Ok(())
     }
@@ -133,6 +191,10 @@ mod tests {
         let sprint = repo.get_current_sprint("new-sprint").unwrap();
         assert_eq!(sprint.name, "new-sprint");
         assert_eq!(sprint.num_jobs, 0);
+        let mut operation_repo = OperationRepository {
+            connection: &mut conn,
+        };
+        assert!(operation_repo.get_current_operation().unwrap().is_none());
     }
 
     #[test]
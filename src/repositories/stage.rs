@@ -6,6 +6,7 @@ use diesel::{delete, insert_into, update};
 
 use crate::errors::FettersError;
 use crate::models::stage::{InterviewStageUpdate, NewInterviewStage, QueriedInterviewStage};
+use crate::repositories::operation::OperationRepository;
 use crate::schema::interview_stages;
 
 /// Contains all methods pertaining to CRUD operations for the `interview_stages` table.
@@ -20,10 +21,22 @@ impl<'a> StageRepository<'a> {
         &mut self,
         new_stage: NewInterviewStage,
     ) -> Result<QueriedInterviewStage, FettersError> {
-        Ok(insert_into(interview_stages::table)
+        let queried_stage = insert_into(interview_stages::table)
             .values(&new_stage)
             .returning(QueriedInterviewStage::as_returning())
-            .get_result(self.connection)?)
+            .get_result(self.connection)?;
+
+        self.record_change(queried_stage.id, None, Some(&queried_stage))?;
+
+        Ok(queried_stage)
+    }
+
+    /// Retrieves an existing interview stage by ID.
+    pub fn get_stage(&mut self, stage_id: i32) -> Result<QueriedInterviewStage, FettersError> {
+        Ok(interview_stages::table
+            .find(stage_id)
+            .select(QueriedInterviewStage::as_select())
+            .first(self.connection)?)
     }
 
     /// Gets all interview stages for a given job, ordered by stage number.
@@ -54,12 +67,16 @@ impl<'a> StageRepository<'a> {
         stage_id: i32,
         changes: InterviewStageUpdate,
     ) -> Result<QueriedInterviewStage, FettersError> {
-        Ok(
-            update(interview_stages::table.find(stage_id))
-                .set(&changes)
-                .returning(QueriedInterviewStage::as_returning())
-                .get_result(self.connection)?,
-        )
+        let previous_stage = self.get_stage(stage_id)?;
+
+        let queried_stage = update(interview_stages::table.find(stage_id))
+            .set(&changes)
+            .returning(QueriedInterviewStage::as_returning())
+            .get_result(self.connection)?;
+
+        self.record_change(stage_id, Some(&previous_stage), Some(&queried_stage))?;
+
+        Ok(queried_stage)
     }
 
     /// Deletes an interview stage.
@@ -67,11 +84,27 @@ impl<'a> StageRepository<'a> {
         &mut self,
         stage_id: i32,
     ) -> Result<QueriedInterviewStage, FettersError> {
-        Ok(
-            delete(interview_stages::table.find(stage_id))
-                .returning(QueriedInterviewStage::as_returning())
-                .get_result(self.connection)?,
-        )
+        let queried_stage = delete(interview_stages::table.find(stage_id))
+            .returning(QueriedInterviewStage::as_returning())
+            .get_result(self.connection)?;
+
+        self.record_change(stage_id, Some(&queried_stage), None)?;
+
+        Ok(queried_stage)
+    }
+
+    /// Sets the stage number of an existing interview stage.
+    pub fn set_stage_number(&mut self, stage_id: i32, number: i32) -> Result<(), FettersError> {
+        let previous_stage = self.get_stage(stage_id)?;
+
+        let queried_stage = update(interview_stages::table.find(stage_id))
+            .set(interview_stages::stage_number.eq(number))
+            .returning(QueriedInterviewStage::as_returning())
+            .get_result(self.connection)?;
+
+        self.record_change(stage_id, Some(&previous_stage), Some(&queried_stage))?;
+
+        Ok(())
     }
 
     /// Renumber stages for a given job after deletion so they are sequential (1, 2, 3...).
@@ -81,14 +114,25 @@ impl<'a> StageRepository<'a> {
         for (index, stage) in stages.iter().enumerate() {
             let new_number = (index + 1) as i32;
             if stage.stage_number != new_number {
-                update(interview_stages::table.find(stage.id))
-                    .set(interview_stages::stage_number.eq(new_number))
-                    .execute(self.connection)?;
+                self.set_stage_number(stage.id, new_number)?;
             }
         }
 
         Ok(())
     }
+
+    /// Record a change to an interview stage in the operation log.
+    fn record_change(
+        &mut self,
+        stage_id: i32,
+        before: Option<&QueriedInterviewStage>,
+        after: Option<&QueriedInterviewStage>,
+    ) -> Result<(), FettersError> {
+        let mut operation_repo = OperationRepository {
+            connection: self.connection,
+        };
+        operation_repo.record_change("interview_stages", stage_id, before, after)
+    }
 }
 
 #[cfg(test)]
@@ -146,8 +190,11 @@ mod tests {
                 title_id: title.id,
                 status_id,
                 link: None,
-                notes: None,
                 sprint_id: sprint.id,
+                source_id: None,
+                referrer: None,
+                resume_id: None,
+                cover_letter_id: None,
             })
             .unwrap()
     }
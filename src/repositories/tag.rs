@@ -0,0 +1,251 @@
+//! Contains the tag repository abstraction class.
+
+use diesel::dsl::count;
+use diesel::prelude::*;
+use diesel::{delete, insert_into};
+
+use crate::errors::FettersError;
+use crate::models::tag::{NewJobTag, NewTag, QueriedTag, TabledTag};
+use crate::schema::{job_tags, jobs, tags};
+
+/// Contains all methods pertaining to CRUD operations for the `tags` and `job_tags` tables.
+pub struct TagRepository<'a> {
+    /// A mutable reference to the SQLite database connection.
+    pub connection: &'a mut SqliteConnection,
+}
+
+impl<'a> TagRepository<'a> {
+    /// Adds a new tag into the `tags` table, or returns the existing tag with the same name.
+    pub fn add_tag(&mut self, new_tag: NewTag) -> Result<QueriedTag, FettersError> {
+        insert_into(tags::table)
+            .values(&new_tag)
+            .on_conflict(tags::name)
+            .do_nothing()
+            .execute(self.connection)?;
+
+        Ok(tags::table
+            .filter(tags::name.eq(new_tag.name))
+            .select(QueriedTag::as_select())
+            .first(self.connection)?)
+    }
+
+    /// Retrieves all tags, ordered by name.
+    pub fn get_all_tags(&mut self) -> Result<Vec<QueriedTag>, FettersError> {
+        Ok(tags::table
+            .order(tags::name.asc())
+            .select(QueriedTag::as_select())
+            .load(self.connection)?)
+    }
+
+    /// Retrieves all tags applied to a given job, ordered by name.
+    pub fn get_tags_for_job(
+        &mut self,
+        target_job_id: i32,
+    ) -> Result<Vec<QueriedTag>, FettersError> {
+        Ok(job_tags::table
+            .inner_join(tags::table)
+            .filter(job_tags::job_id.eq(target_job_id))
+            .order(tags::name.asc())
+            .select(QueriedTag::as_select())
+            .load(self.connection)?)
+    }
+
+    /// Applies a tag to a job. Applying a tag that is already on the job is a no-op.
+    pub fn tag_job(&mut self, target_job_id: i32, target_tag_id: i32) -> Result<(), FettersError> {
+        insert_into(job_tags::table)
+            .values(&NewJobTag {
+                job_id: target_job_id,
+                tag_id: target_tag_id,
+            })
+            .on_conflict_do_nothing()
+            .execute(self.connection)?;
+
+        Ok(())
+    }
+
+    /// Removes a tag from a job.
+    pub fn untag_job(
+        &mut self,
+        target_job_id: i32,
+        target_tag_id: i32,
+    ) -> Result<(), FettersError> {
+        delete(job_tags::table.find((target_job_id, target_tag_id))).execute(self.connection)?;
+
+        Ok(())
+    }
+
+    /// Get every tag along with the number of job applications it is applied to. Jobs in the trash
+    /// are not counted.
+    pub fn count_jobs_per_tag(&mut self) -> Result<Vec<TabledTag>, FettersError> {
+        Ok(tags::table
+            .left_join(
+                job_tags::table.on(job_tags::tag_id.eq(tags::id).and(
+                    job_tags::job_id.eq_any(
+                        jobs::table
+                            .filter(jobs::deleted_at.is_null())
+                            .select(jobs::id),
+                    ),
+                )),
+            )
+            .group_by(tags::name)
+            .select((tags::name, count(job_tags::job_id.nullable())))
+            .order(tags::name.asc())
+            .load::<TabledTag>(self.connection)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use diesel::Connection;
+
+    use crate::models::job::{NewJob, QueriedJob};
+    use crate::models::title::NewTitle;
+    use crate::repositories::job::JobRepository;
+    use crate::repositories::sprint::SprintRepository;
+    use crate::repositories::statuses::StatusRepository;
+    use crate::repositories::title::TitleRepository;
+
+    fn setup_test_db() -> SqliteConnection {
+        let mut connection = SqliteConnection::establish(":memory:")
+            .expect("Failed to create in-memory database");
+        crate::utils::migrations::run_migrations(&mut connection)
+            .expect("Failed to run migrations");
+
+        let mut status_repo = StatusRepository {
+            connection: &mut connection,
+        };
+        status_repo
+            .seed_statuses()
+            .expect("Failed to seed statuses");
+
+        connection
+    }
+
+    fn create_test_job(conn: &mut SqliteConnection, company_name: &str) -> QueriedJob {
+        let mut sprint_repo = SprintRepository { connection: conn };
+        let sprint = sprint_repo.get_current_sprint("test-sprint").unwrap();
+
+        let mut title_repo = TitleRepository { connection: conn };
+        let title = title_repo.add_title(NewTitle { name: "SWE" }).unwrap();
+
+        let mut status_repo = StatusRepository { connection: conn };
+        let status_id = status_repo.get_all_statuses().unwrap()[0].id;
+
+        let mut job_repo = JobRepository { connection: conn };
+        job_repo
+            .add_job(NewJob {
+                company_name,
+                created: "2025-01-15 10:00:00".to_string(),
+                title_id: title.id,
+                status_id,
+                link: None,
+                sprint_id: sprint.id,
+                source_id: None,
+                referrer: None,
+                resume_id: None,
+                cover_letter_id: None,
+            })
+            .unwrap()
+    }
+
+    #[test]
+    fn test_add_tag() {
+        let mut conn = setup_test_db();
+        let mut repo = TagRepository {
+            connection: &mut conn,
+        };
+
+        let tag = repo.add_tag(NewTag { name: "fintech" }).unwrap();
+        assert_eq!(tag.name, "fintech");
+        assert!(tag.id > 0);
+    }
+
+    #[test]
+    fn test_add_duplicate_tag_returns_existing() {
+        let mut conn = setup_test_db();
+        let mut repo = TagRepository {
+            connection: &mut conn,
+        };
+
+        let first = repo.add_tag(NewTag { name: "fintech" }).unwrap();
+        let second = repo.add_tag(NewTag { name: "fintech" }).unwrap();
+        assert_eq!(first.id, second.id);
+    }
+
+    #[test]
+    fn test_get_all_tags_sorted() {
+        let mut conn = setup_test_db();
+        let mut repo = TagRepository {
+            connection: &mut conn,
+        };
+
+        repo.add_tag(NewTag { name: "needs-visa" }).unwrap();
+        repo.add_tag(NewTag { name: "dream-company" }).unwrap();
+
+        let tags = repo.get_all_tags().unwrap();
+        assert_eq!(tags.len(), 2);
+        assert_eq!(tags[0].name, "dream-company");
+        assert_eq!(tags[1].name, "needs-visa");
+    }
+
+    #[test]
+    fn test_tag_and_untag_job() {
+        let mut conn = setup_test_db();
+        let job = create_test_job(&mut conn, "Stripe");
+
+        let mut repo = TagRepository {
+            connection: &mut conn,
+        };
+        let fintech = repo.add_tag(NewTag { name: "fintech" }).unwrap();
+        let remote = repo.add_tag(NewTag { name: "remote" }).unwrap();
+
+        repo.tag_job(job.id, fintech.id).unwrap();
+        repo.tag_job(job.id, remote.id).unwrap();
+        // Tagging twice should not fail or create a duplicate.
+        repo.tag_job(job.id, fintech.id).unwrap();
+
+        let tags = repo.get_tags_for_job(job.id).unwrap();
+        assert_eq!(tags.len(), 2);
+
+        repo.untag_job(job.id, fintech.id).unwrap();
+
+        let tags = repo.get_tags_for_job(job.id).unwrap();
+        assert_eq!(tags.len(), 1);
+        assert_eq!(tags[0].name, "remote");
+    }
+
+    #[test]
+    fn test_count_jobs_per_tag() {
+        let mut conn = setup_test_db();
+        let stripe = create_test_job(&mut conn, "Stripe");
+        let plaid = create_test_job(&mut conn, "Plaid");
+
+        let mut repo = TagRepository {
+            connection: &mut conn,
+        };
+        let fintech = repo.add_tag(NewTag { name: "fintech" }).unwrap();
+        repo.add_tag(NewTag { name: "unused" }).unwrap();
+
+        repo.tag_job(stripe.id, fintech.id).unwrap();
+        repo.tag_job(plaid.id, fintech.id).unwrap();
+
+        let counts = repo.count_jobs_per_tag().unwrap();
+        assert_eq!(counts.len(), 2);
+        assert_eq!(counts[0].name, "fintech");
+        assert_eq!(counts[0].num_jobs, 2);
+        assert_eq!(counts[1].name, "unused");
+        assert_eq!(counts[1].num_jobs, 0);
+
+        let mut job_repo = JobRepository {
+            connection: &mut conn,
+        };
+        job_repo.trash_job(plaid.id, "2025-01-20 09:00:00").unwrap();
+
+        let mut repo = TagRepository {
+            connection: &mut conn,
+        };
+        let counts = repo.count_jobs_per_tag().unwrap();
+        assert_eq!(counts[0].num_jobs, 1);
+    }
+}
@@ -1,5 +1,43 @@
 // @generated automatically by Diesel CLI.
 
+diesel::table! {
+    attachments (id) {
+        id -> Integer,
+        job_id -> Integer,
+        file_name -> Text,
+        file_path -> Text,
+        added -> Text,
+    }
+}
+
+diesel::table! {
+    custom_field_values (job_id, field_id) {
+        job_id -> Integer,
+        field_id -> Integer,
+        value -> Text,
+    }
+}
+
+diesel::table! {
+    custom_fields (id) {
+        id -> Integer,
+        name -> Text,
+        field_type -> Text,
+        options -> Nullable<Text>,
+    }
+}
+
+diesel::table! {
+    documents (id) {
+        id -> Integer,
+        kind -> Text,
+        label -> Text,
+        file_path -> Text,
+        checksum -> Text,
+        created -> Text,
+    }
+}
+
 diesel::table! {
     interview_stages (id) {
         id -> Integer,
@@ -13,6 +51,30 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    job_descriptions (job_id) {
+        job_id -> Integer,
+        content -> Text,
+        captured -> Text,
+    }
+}
+
+diesel::table! {
+    job_notes (id) {
+        id -> Integer,
+        job_id -> Integer,
+        content -> Text,
+        created -> Text,
+    }
+}
+
+diesel::table! {
+    job_tags (job_id, tag_id) {
+        job_id -> Integer,
+        tag_id -> Integer,
+    }
+}
+
 diesel::table! {
     jobs (id) {
         id -> Integer,
@@ -21,8 +83,39 @@ diesel::table! {
         title_id -> Integer,
         status_id -> Integer,
         link -> Nullable<Text>,
-        notes -> Nullable<Text>,
         sprint_id -> Integer,
+        source_id -> Nullable<Integer>,
+        referrer -> Nullable<Text>,
+        resume_id -> Nullable<Integer>,
+        cover_letter_id -> Nullable<Integer>,
+        deleted_at -> Nullable<Text>,
+    }
+}
+
+diesel::table! {
+    operation_changes (id) {
+        id -> Integer,
+        operation_id -> Integer,
+        table_name -> Text,
+        row_id -> Integer,
+        before_snapshot -> Nullable<Text>,
+        after_snapshot -> Nullable<Text>,
+    }
+}
+
+diesel::table! {
+    operations (id) {
+        id -> Integer,
+        performed -> Text,
+        command -> Text,
+        undone_at -> Nullable<Text>,
+    }
+}
+
+diesel::table! {
+    sources (id) {
+        id -> Integer,
+        name -> Text,
     }
 }
 
@@ -43,6 +136,13 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    tags (id) {
+        id -> Integer,
+        name -> Text,
+    }
+}
+
 diesel::table! {
     titles (id) {
         id -> Integer,
@@ -50,15 +150,35 @@ diesel::table! {
     }
 }
 
+diesel::joinable!(attachments -> jobs (job_id));
+diesel::joinable!(custom_field_values -> custom_fields (field_id));
+diesel::joinable!(custom_field_values -> jobs (job_id));
 diesel::joinable!(interview_stages -> jobs (job_id));
+diesel::joinable!(job_descriptions -> jobs (job_id));
+diesel::joinable!(job_notes -> jobs (job_id));
+diesel::joinable!(job_tags -> jobs (job_id));
+diesel::joinable!(job_tags -> tags (tag_id));
+diesel::joinable!(jobs -> sources (source_id));
 diesel::joinable!(jobs -> sprints (sprint_id));
 diesel::joinable!(jobs -> statuses (status_id));
 diesel::joinable!(jobs -> titles (title_id));
+diesel::joinable!(operation_changes -> operations (operation_id));
 
 diesel::allow_tables_to_appear_in_same_query!(
+    attachments,
+    custom_field_values,
+    custom_fields,
+    documents,
     interview_stages,
+    job_descriptions,
+    job_notes,
+    job_tags,
     jobs,
+    operation_changes,
+    operations,
+    sources,
     sprints,
     statuses,
+    tags,
     titles,
 );
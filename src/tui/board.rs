@@ -0,0 +1,452 @@
+//! Contains the interactive job board opened with `fetters board --interactive`.
+
+use std::io::Stdout;
+
+use crossterm::cursor::MoveTo;
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use crossterm::queue;
+use crossterm::style::{Attribute, Print, SetAttribute};
+use diesel::SqliteConnection;
+
+use crate::cli::QueryArgs;
+use crate::errors::FettersError;
+use crate::models::job::{JobUpdate, TabledJob, colorize_by_status};
+use crate::models::sprint::QueriedSprint;
+use crate::models::status::QueriedStatus;
+use crate::repositories::{
+    job::JobRepository, operation::OperationRepository, sprint::SprintRepository,
+    statuses::StatusRepository,
+};
+use crate::tui::{Screen, fit, scroll_offset};
+
+/// The key bindings displayed at the bottom of the board.
+const HELP: &str = "h/l column  j/k move  H/L or </> move job  o open link  r reload  q quit";
+
+/// The number of rows each card takes up, including the blank row below it.
+const CARD_HEIGHT: usize = 3;
+
+/// A board with a column of job applications per application status.
+pub struct Board {
+    /// The sprint the job applications are in.
+    sprint: QueriedSprint,
+    /// All application statuses, in the order their columns are displayed.
+    statuses: Vec<QueriedStatus>,
+    /// The job applications in each status column.
+    columns: Vec<Vec<TabledJob>>,
+    /// The index of the selected column.
+    column: usize,
+    /// The index of the selected job application in the selected column.
+    row: usize,
+    /// The result of the last action.
+    message: Option<String>,
+    /// Whether the board should be closed.
+    quit: bool,
+}
+
+impl Board {
+    /// Load the board for the current sprint. The first column containing a job application is
+    /// selected.
+    pub fn new(
+        connection: &mut SqliteConnection,
+        current_sprint: &QueriedSprint,
+    ) -> Result<Board, FettersError> {
+        let mut sprint_repo = SprintRepository { connection };
+        let sprint = sprint_repo.get_sprint(current_sprint.id)?;
+        let mut status_repo = StatusRepository { connection };
+        let statuses = status_repo.get_all_statuses()?;
+
+        let mut board = Board {
+            sprint,
+            statuses,
+            columns: Vec::new(),
+            column: 0,
+            row: 0,
+            message: None,
+            quit: false,
+        };
+        board.reload(connection, None)?;
+        board.column = board
+            .columns
+            .iter()
+            .position(|column| !column.is_empty())
+            .unwrap_or_default();
+
+        Ok(board)
+    }
+
+    /// Query the job applications again and select the job application with the given ID if it is
+    /// on the board.
+    fn reload(
+        &mut self,
+        connection: &mut SqliteConnection,
+        select_id: Option<i32>,
+    ) -> Result<(), FettersError> {
+        let mut job_repo = JobRepository { connection };
+        let jobs = job_repo.list_jobs(&QueryArgs::default(), &self.sprint)?;
+
+        self.columns = self
+            .statuses
+            .iter()
+            .map(|status| {
+                jobs.iter()
+                    .filter(|job| job.status.as_deref() == Some(status.name.as_str()))
+                    .cloned()
+                    .collect()
+            })
+            .collect();
+
+        if let Some((column, row)) = select_id.and_then(|job_id| self.find_job(job_id)) {
+            self.column = column;
+            self.row = row;
+        }
+        self.clamp_row();
+
+        Ok(())
+    }
+
+    /// The column and row of a job application on the board.
+    fn find_job(&self, job_id: i32) -> Option<(usize, usize)> {
+        self.columns.iter().enumerate().find_map(|(column, jobs)| {
+            jobs.iter()
+                .position(|job| job.id == job_id)
+                .map(|row| (column, row))
+        })
+    }
+
+    /// Keep the selected row within the selected column.
+    fn clamp_row(&mut self) {
+        let length = self.columns.get(self.column).map(Vec::len).unwrap_or(0);
+        self.row = self.row.min(length.saturating_sub(1));
+    }
+
+    /// The selected job application, if the selected column is not empty.
+    fn selected_job(&self) -> Option<&TabledJob> {
+        self.columns
+            .get(self.column)
+            .and_then(|jobs| jobs.get(self.row))
+    }
+
+    /// Move the selected job application to the column `offset` columns away, changing its status.
+    fn move_job(
+        &mut self,
+        connection: &mut SqliteConnection,
+        offset: isize,
+    ) -> Result<(), FettersError> {
+        let Some(target) = self
+            .column
+            .checked_add_signed(offset)
+            .filter(|target| *target < self.statuses.len())
+        else {
+            return Ok(());
+        };
+        let Some(job) = self.selected_job() else {
+            return Ok(());
+        };
+        let (job_id, company_name) = (job.id, job.company_name.clone());
+        let status = &self.statuses[target];
+
+        let mut operation_repo = OperationRepository { connection };
+        operation_repo.start_operation(&format!(
+            "fetters board (move job {job_id} to {})",
+            status.name
+        ))?;
+        let mut job_repo = JobRepository { connection };
+        job_repo.update_job(
+            job_id,
+            JobUpdate {
+                status_id: Some(status.id),
+                ..Default::default()
+            },
+        )?;
+
+        self.message = Some(format!("Moved {company_name} to {}.", status.name));
+        self.reload(connection, Some(job_id))
+    }
+}
+
+impl Screen for Board {
+    fn draw(&self, out: &mut Stdout, width: u16, height: u16) -> Result<(), FettersError> {
+        let full_width = width as usize;
+        let column_width = full_width / self.statuses.len().max(1);
+        let card_width = column_width.saturating_sub(1);
+        let visible_cards = (height as usize).saturating_sub(4) / CARD_HEIGHT;
+
+        queue!(
+            out,
+            MoveTo(0, 0),
+            SetAttribute(Attribute::Reverse),
+            Print(fit(
+                &format!(
+                    " fetters board | sprint: {} | {} job applications",
+                    self.sprint.name,
+                    self.columns.iter().map(Vec::len).sum::<usize>()
+                ),
+                full_width
+            )),
+            SetAttribute(Attribute::Reset),
+        )?;
+
+        // Clear everything below the title before drawing the columns on top.
+        for row in 1..height.saturating_sub(1) {
+            queue!(out, MoveTo(0, row), Print(" ".repeat(full_width)))?;
+        }
+
+        for (index, (status, jobs)) in self.statuses.iter().zip(&self.columns).enumerate() {
+            let left = (index * column_width) as u16;
+
+            queue!(
+                out,
+                MoveTo(left, 1),
+                Print(colorize_by_status(
+                    &status.name,
+                    &fit(&format!("{} ({})", status.name, jobs.len()), card_width)
+                )),
+                MoveTo(left, 2),
+                Print("─".repeat(card_width)),
+            )?;
+
+            let first_card = if index == self.column {
+                scroll_offset(self.row, visible_cards)
+            } else {
+                0
+            };
+            for (slot, job) in jobs.iter().skip(first_card).take(visible_cards).enumerate() {
+                let selected = index == self.column && first_card + slot == self.row;
+                let marker = if selected { ">" } else { " " };
+                let top = 3 + (slot * CARD_HEIGHT) as u16;
+
+                if selected {
+                    queue!(out, SetAttribute(Attribute::Reverse))?;
+                }
+                queue!(
+                    out,
+                    MoveTo(left, top),
+                    Print(job.colorize_field(&fit(
+                        &format!("{marker}#{} {}", job.id, job.company_name),
+                        card_width
+                    ))),
+                    MoveTo(left, top + 1),
+                    Print(job.colorize_field(&fit(
+                        &format!("{marker}{}", job.title.as_deref().unwrap_or("N/A")),
+                        card_width
+                    ))),
+                    SetAttribute(Attribute::Reset),
+                )?;
+            }
+        }
+
+        queue!(
+            out,
+            MoveTo(0, height.saturating_sub(1)),
+            SetAttribute(Attribute::Dim),
+            Print(fit(self.message.as_deref().unwrap_or(HELP), full_width)),
+            SetAttribute(Attribute::Reset),
+        )?;
+
+        Ok(())
+    }
+
+    fn handle_key(
+        &mut self,
+        connection: &mut SqliteConnection,
+        key: KeyEvent,
+    ) -> Result<(), FettersError> {
+        self.message = None;
+        let shift = key.modifiers.contains(KeyModifiers::SHIFT);
+
+        match key.code {
+            KeyCode::Char('q') | KeyCode::Esc => self.quit = true,
+            KeyCode::Left if shift => self.move_job(connection, -1)?,
+            KeyCode::Right if shift => self.move_job(connection, 1)?,
+            KeyCode::Char('H') | KeyCode::Char('<') => self.move_job(connection, -1)?,
+            KeyCode::Char('L') | KeyCode::Char('>') => self.move_job(connection, 1)?,
+            KeyCode::Left | KeyCode::Char('h') => {
+                self.column = self.column.saturating_sub(1);
+                self.clamp_row();
+            }
+            KeyCode::Right | KeyCode::Char('l') => {
+                self.column = (self.column + 1).min(self.statuses.len().saturating_sub(1));
+                self.clamp_row();
+            }
+            KeyCode::Up | KeyCode::Char('k') => self.row = self.row.saturating_sub(1),
+            KeyCode::Down | KeyCode::Char('j') => {
+                self.row += 1;
+                self.clamp_row();
+            }
+            KeyCode::Char('o') => {
+                if let Some(job) = self.selected_job() {
+                    match job.link.as_deref().filter(|link| !link.is_empty()) {
+                        Some(link) => {
+                            open::that(link)?;
+                            self.message = Some(format!("Opened {link}"));
+                        }
+                        None => {
+                            self.message =
+                                Some(format!("{} has no link to open.", job.company_name))
+                        }
+                    }
+                }
+            }
+            KeyCode::Char('r') => {
+                let selected_id = self.selected_job().map(|job| job.id);
+                self.reload(connection, selected_id)?;
+                self.message = Some("Reloaded job applications.".to_string());
+            }
+            _ => {}
+        }
+
+        Ok(())
+    }
+
+    fn set_message(&mut self, message: String) {
+        self.message = Some(message);
+    }
+
+    fn should_quit(&self) -> bool {
+        self.quit
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use diesel::Connection;
+
+    use crate::models::job::NewJob;
+    use crate::models::sprint::NewSprint;
+    use crate::models::title::NewTitle;
+    use crate::repositories::title::TitleRepository;
+
+    fn setup_test_db() -> SqliteConnection {
+        let mut connection =
+            SqliteConnection::establish(":memory:").expect("Failed to create in-memory database");
+        crate::utils::migrations::run_migrations(&mut connection)
+            .expect("Failed to run migrations");
+
+        let mut status_repo = StatusRepository {
+            connection: &mut connection,
+        };
+        status_repo
+            .seed_statuses()
+            .expect("Failed to seed statuses");
+
+        connection
+    }
+
+    fn create_sprint(conn: &mut SqliteConnection, name: &str) -> QueriedSprint {
+        let mut repo = SprintRepository { connection: conn };
+        repo.add_job_sprint(NewSprint {
+            name,
+            start_date: "2025-01-01",
+            end_date: None,
+            num_jobs: &0,
+        })
+        .unwrap()
+    }
+
+    fn create_job(conn: &mut SqliteConnection, company_name: &str, sprint_id: i32) -> i32 {
+        let mut title_repo = TitleRepository { connection: conn };
+        let title = title_repo.add_title(NewTitle { name: "SWE" }).unwrap();
+        let mut status_repo = StatusRepository { connection: conn };
+        let status_id = status_repo
+            .get_all_statuses()
+            .unwrap()
+            .into_iter()
+            .find(|status| status.name == "PENDING")
+            .unwrap()
+            .id;
+
+        let mut job_repo = JobRepository { connection: conn };
+        job_repo
+            .add_job(NewJob {
+                company_name,
+                created: "2025-01-15 10:00:00".to_string(),
+                title_id: title.id,
+                status_id,
+                link: None,
+                sprint_id,
+                source_id: None,
+                referrer: None,
+                resume_id: None,
+                cover_letter_id: None,
+            })
+            .unwrap()
+            .id
+    }
+
+    fn press(board: &mut Board, conn: &mut SqliteConnection, code: KeyCode) {
+        board
+            .handle_key(conn, KeyEvent::new(code, KeyModifiers::NONE))
+            .unwrap();
+    }
+
+    fn status_of(board: &Board, job_id: i32) -> String {
+        let (column, _) = board.find_job(job_id).unwrap();
+        board.statuses[column].name.clone()
+    }
+
+    #[test]
+    fn test_board_groups_jobs_by_status() {
+        let mut conn = setup_test_db();
+        let sprint = create_sprint(&mut conn, "test-sprint");
+        create_job(&mut conn, "Initech", sprint.id);
+        create_job(&mut conn, "Globex", sprint.id);
+
+        let board = Board::new(&mut conn, &sprint).unwrap();
+        assert_eq!(board.columns.len(), board.statuses.len());
+        assert_eq!(board.statuses[board.column].name, "PENDING");
+        assert_eq!(board.columns[board.column].len(), 2);
+        assert_eq!(board.columns.iter().map(Vec::len).sum::<usize>(), 2);
+    }
+
+    #[test]
+    fn test_move_job_between_columns() {
+        let mut conn = setup_test_db();
+        let sprint = create_sprint(&mut conn, "test-sprint");
+        create_job(&mut conn, "Initech", sprint.id);
+        let job_id = create_job(&mut conn, "Globex", sprint.id);
+
+        let mut board = Board::new(&mut conn, &sprint).unwrap();
+        let pending = board.column;
+        press(&mut board, &mut conn, KeyCode::Char('j'));
+        assert_eq!(board.selected_job().map(|job| job.id), Some(job_id));
+
+        press(&mut board, &mut conn, KeyCode::Char('L'));
+        assert_eq!(board.column, pending + 1);
+        assert_eq!(board.selected_job().map(|job| job.id), Some(job_id));
+        assert_eq!(status_of(&board, job_id), board.statuses[pending + 1].name);
+
+        // The new status is stored, not just displayed.
+        let mut job_repo = JobRepository {
+            connection: &mut conn,
+        };
+        assert_eq!(
+            job_repo.get_job(job_id).unwrap().status_id,
+            board.statuses[pending + 1].id
+        );
+
+        press(&mut board, &mut conn, KeyCode::Char('<'));
+        assert_eq!(board.column, pending);
+        assert_eq!(status_of(&board, job_id), "PENDING");
+    }
+
+    #[test]
+    fn test_move_job_stops_at_edges() {
+        let mut conn = setup_test_db();
+        let sprint = create_sprint(&mut conn, "test-sprint");
+        let job_id = create_job(&mut conn, "Initech", sprint.id);
+
+        let mut board = Board::new(&mut conn, &sprint).unwrap();
+        for _ in 0..board.statuses.len() {
+            press(&mut board, &mut conn, KeyCode::Char('H'));
+        }
+        assert_eq!(board.column, 0);
+        assert_eq!(status_of(&board, job_id), board.statuses[0].name);
+
+        // Selecting an empty column leaves nothing to move.
+        press(&mut board, &mut conn, KeyCode::Char('l'));
+        assert!(board.selected_job().is_none());
+        press(&mut board, &mut conn, KeyCode::Char('L'));
+        assert_eq!(status_of(&board, job_id), board.statuses[0].name);
+    }
+}
@@ -0,0 +1,866 @@
+//! Contains the full-screen dashboard opened with `fetters tui`.
+
+use std::io::Stdout;
+
+use chrono::{Local, NaiveDate};
+use crossterm::cursor::MoveTo;
+use crossterm::event::{KeyCode, KeyEvent};
+use crossterm::queue;
+use crossterm::style::{Attribute, Color, Print, ResetColor, SetAttribute, SetForegroundColor};
+use diesel::SqliteConnection;
+
+use crate::cli::QueryArgs;
+use crate::commands::stage::stage_tree_lines;
+use crate::errors::FettersError;
+use crate::models::job::{JobUpdate, TabledJob};
+use crate::models::sprint::QueriedSprint;
+use crate::models::stage::{NewInterviewStage, QueriedInterviewStage, StageStatus};
+use crate::models::status::QueriedStatus;
+use crate::repositories::{
+    job::JobRepository, operation::OperationRepository, sprint::SprintRepository,
+    stage::StageRepository, statuses::StatusRepository,
+};
+use crate::tui::{Input, Picker, Screen, fit, scroll_offset, status_color, wrap_text};
+
+/// The key bindings displayed at the bottom of the dashboard.
+const HELP: &str =
+    "j/k move  / filter  s status  a add stage  o open link  p sprint  r reload  q quit";
+
+/// The width of the ID column in the job table.
+const ID_WIDTH: usize = 5;
+
+/// The width of the status column in the job table, which fits the longest default status.
+const STATUS_WIDTH: usize = 18;
+
+/// What the dashboard is currently doing with key presses.
+#[derive(Debug)]
+enum Mode {
+    /// Moving through the job table.
+    Browse,
+    /// Typing a filter for the job table.
+    Filter,
+    /// Choosing a new status for the selected job application.
+    PickStatus(Picker),
+    /// Choosing the sprint to display.
+    PickSprint(Picker),
+    /// Entering the name of a new interview stage.
+    StageName(Input),
+    /// Choosing the status of a new interview stage.
+    StageStatus { name: String, picker: Picker },
+    /// Entering the date of a new interview stage.
+    StageDate {
+        name: String,
+        status: StageStatus,
+        input: Input,
+    },
+}
+
+/// A job table with a detail pane for the selected job application.
+pub struct Dashboard {
+    /// All sprints that can be switched to.
+    sprints: Vec<QueriedSprint>,
+    /// The index of the displayed sprint in `sprints`.
+    sprint: usize,
+    /// All application statuses.
+    statuses: Vec<QueriedStatus>,
+    /// All job applications in the displayed sprint.
+    jobs: Vec<TabledJob>,
+    /// The text job applications are filtered by.
+    filter: String,
+    /// Indices into `jobs` of the job applications matching the filter.
+    visible: Vec<usize>,
+    /// The index into `visible` of the selected job application.
+    selected: usize,
+    /// The interview stages of the selected job application.
+    stages: Vec<QueriedInterviewStage>,
+    /// What key presses currently do.
+    mode: Mode,
+    /// The result of the last action.
+    message: Option<String>,
+    /// Whether the dashboard should be closed.
+    quit: bool,
+}
+
+impl Dashboard {
+    /// Load the dashboard, starting with the current sprint.
+    pub fn new(
+        connection: &mut SqliteConnection,
+        current_sprint: &QueriedSprint,
+    ) -> Result<Dashboard, FettersError> {
+        let mut sprint_repo = SprintRepository { connection };
+        let sprints = sprint_repo.get_all_sprints()?;
+        let mut status_repo = StatusRepository { connection };
+        let statuses = status_repo.get_all_statuses()?;
+
+        let mut dashboard = Dashboard {
+            sprint: sprints
+                .iter()
+                .position(|sprint| sprint.id == current_sprint.id)
+                .unwrap_or_default(),
+            sprints,
+            statuses,
+            jobs: Vec::new(),
+            filter: String::new(),
+            visible: Vec::new(),
+            selected: 0,
+            stages: Vec::new(),
+            mode: Mode::Browse,
+            message: None,
+            quit: false,
+        };
+        dashboard.reload(connection)?;
+
+        Ok(dashboard)
+    }
+
+    /// Query the job applications in the displayed sprint again, keeping the same job application
+    /// selected if it is still there.
+    fn reload(&mut self, connection: &mut SqliteConnection) -> Result<(), FettersError> {
+        let selected_id = self.selected_job().map(|job| job.id);
+
+        self.jobs = match self.sprints.get(self.sprint) {
+            Some(sprint) => {
+                let mut job_repo = JobRepository { connection };
+                job_repo.list_jobs(&QueryArgs::default(), sprint)?
+            }
+            None => Vec::new(),
+        };
+        self.apply_filter();
+
+        if let Some(position) = self
+            .visible
+            .iter()
+            .position(|index| Some(self.jobs[*index].id) == selected_id)
+        {
+            self.selected = position;
+        }
+
+        self.load_stages(connection)
+    }
+
+    /// Update the visible job applications after the filter has changed.
+    fn apply_filter(&mut self) {
+        let filter = self.filter.to_lowercase();
+
+        self.visible = self
+            .jobs
+            .iter()
+            .enumerate()
+            .filter(|(_, job)| filter.is_empty() || matches_filter(job, &filter))
+            .map(|(index, _)| index)
+            .collect();
+        self.selected = self.selected.min(self.visible.len().saturating_sub(1));
+    }
+
+    /// Load the interview stages of the selected job application.
+    fn load_stages(&mut self, connection: &mut SqliteConnection) -> Result<(), FettersError> {
+        self.stages = match self.selected_job().map(|job| job.id) {
+            Some(job_id) => {
+                let mut stage_repo = StageRepository { connection };
+                stage_repo.get_stages_for_job(job_id)?
+            }
+            None => Vec::new(),
+        };
+
+        Ok(())
+    }
+
+    /// The selected job application, if any match the filter.
+    fn selected_job(&self) -> Option<&TabledJob> {
+        self.visible
+            .get(self.selected)
+            .and_then(|index| self.jobs.get(*index))
+    }
+
+    /// Move the selection by `offset` rows.
+    fn move_selection(
+        &mut self,
+        connection: &mut SqliteConnection,
+        offset: isize,
+    ) -> Result<(), FettersError> {
+        let last = self.visible.len().saturating_sub(1);
+        self.selected = self.selected.saturating_add_signed(offset).min(last);
+
+        self.load_stages(connection)
+    }
+
+    /// Handle a key press while browsing the job table.
+    fn handle_browse_key(
+        &mut self,
+        connection: &mut SqliteConnection,
+        key: KeyEvent,
+    ) -> Result<(), FettersError> {
+        match key.code {
+            KeyCode::Char('q') | KeyCode::Esc => self.quit = true,
+            KeyCode::Down | KeyCode::Char('j') => self.move_selection(connection, 1)?,
+            KeyCode::Up | KeyCode::Char('k') => self.move_selection(connection, -1)?,
+            KeyCode::PageDown => self.move_selection(connection, 10)?,
+            KeyCode::PageUp => self.move_selection(connection, -10)?,
+            KeyCode::Home | KeyCode::Char('g') => {
+                self.move_selection(connection, -(self.selected as isize))?
+            }
+            KeyCode::End | KeyCode::Char('G') => {
+                self.move_selection(connection, self.visible.len() as isize)?
+            }
+            KeyCode::Char('/') => self.mode = Mode::Filter,
+            KeyCode::Char('r') => {
+                self.reload(connection)?;
+                self.message = Some("Reloaded job applications.".to_string());
+            }
+            KeyCode::Char('p') => {
+                self.mode = Mode::PickSprint(Picker::new(
+                    "Switch sprint",
+                    self.sprints
+                        .iter()
+                        .map(|sprint| sprint.name.clone())
+                        .collect(),
+                    self.sprints
+                        .get(self.sprint)
+                        .map(|sprint| sprint.name.as_str()),
+                ));
+            }
+            KeyCode::Char('s') => {
+                if let Some(job) = self.selected_job() {
+                    self.mode = Mode::PickStatus(Picker::new(
+                        &format!("Status for {}", job.company_name),
+                        self.statuses
+                            .iter()
+                            .map(|status| status.name.clone())
+                            .collect(),
+                        job.status.as_deref(),
+                    ));
+                }
+            }
+            KeyCode::Char('a') => {
+                if let Some(job) = self.selected_job() {
+                    self.mode = Mode::StageName(Input::new(
+                        &format!("Stage name for {} (optional)", job.company_name),
+                        "",
+                    ));
+                }
+            }
+            KeyCode::Char('o') => {
+                if let Some(job) = self.selected_job() {
+                    match job.link.as_deref().filter(|link| !link.is_empty()) {
+                        Some(link) => {
+                            open::that(link)?;
+                            self.message = Some(format!("Opened {link}"));
+                        }
+                        None => {
+                            self.message =
+                                Some(format!("{} has no link to open.", job.company_name))
+                        }
+                    }
+                }
+            }
+            _ => {}
+        }
+
+        Ok(())
+    }
+
+    /// Set the status of the selected job application.
+    fn set_status(
+        &mut self,
+        connection: &mut SqliteConnection,
+        status_index: usize,
+    ) -> Result<(), FettersError> {
+        let (Some(job), Some(status)) = (self.selected_job(), self.statuses.get(status_index))
+        else {
+            return Ok(());
+        };
+        let (job_id, company_name) = (job.id, job.company_name.clone());
+
+        let mut operation_repo = OperationRepository { connection };
+        operation_repo.start_operation(&format!(
+            "fetters tui (set status of job {job_id} to {})",
+            status.name
+        ))?;
+        let mut job_repo = JobRepository { connection };
+        job_repo.update_job(
+            job_id,
+            JobUpdate {
+                status_id: Some(status.id),
+                ..Default::default()
+            },
+        )?;
+
+        self.message = Some(format!("Set {company_name} to {}.", status.name));
+        self.reload(connection)
+    }
+
+    /// Add an interview stage to the selected job application.
+    fn add_stage(
+        &mut self,
+        connection: &mut SqliteConnection,
+        name: String,
+        status: StageStatus,
+        date: NaiveDate,
+    ) -> Result<(), FettersError> {
+        let Some(job) = self.selected_job() else {
+            return Ok(());
+        };
+        let (job_id, company_name) = (job.id, job.company_name.clone());
+
+        let mut operation_repo = OperationRepository { connection };
+        operation_repo.start_operation(&format!("fetters tui (add stage to job {job_id})"))?;
+        let mut stage_repo = StageRepository { connection };
+        let stage_number = stage_repo.get_next_stage_number(job_id)?;
+        stage_repo.add_stage(NewInterviewStage {
+            job_id,
+            stage_number,
+            name: Some(name).filter(|name| !name.is_empty()),
+            status: status.to_string(),
+            scheduled_date: date.format("%Y/%m/%d").to_string(),
+            notes: None,
+            created: Local::now().format("%Y-%m-%d %H:%M:%S").to_string(),
+        })?;
+
+        self.message = Some(format!("Added stage {stage_number} to {company_name}."));
+        self.reload(connection)
+    }
+
+    /// The lines displayed in the detail pane for the selected job application.
+    fn detail_lines(&self, width: usize) -> Vec<String> {
+        let Some(job) = self.selected_job() else {
+            return vec!["No job applications match.".to_string()];
+        };
+        let or_na = |value: &Option<String>| value.clone().unwrap_or("N/A".to_string());
+
+        let mut lines = vec![
+            format!("Company:       {}", job.company_name),
+            format!("Title:         {}", or_na(&job.title)),
+            format!("Status:        {}", or_na(&job.status)),
+            format!("Source:        {}", or_na(&job.source)),
+            format!("Tags:          {}", job.tags.clone().unwrap_or_default()),
+            format!("Link:          {}", or_na(&job.link)),
+            format!("Created:       {}", job.created),
+            format!("Last activity: {}", job.last_activity),
+        ];
+        lines.extend(
+            job.custom_fields
+                .iter()
+                .filter(|(_, value)| !value.is_empty())
+                .map(|(name, value)| format!("{name}: {value}")),
+        );
+
+        if let Some(note) = &job.notes {
+            lines.push(String::new());
+            lines.push("Latest note:".to_string());
+            lines.extend(wrap_text(note, width));
+        }
+
+        lines.push(String::new());
+        if self.stages.is_empty() {
+            lines.push("No interview stages.".to_string());
+        } else {
+            lines.extend(stage_tree_lines(job, &self.stages));
+        }
+
+        lines
+    }
+
+    /// Draw the job table on the left side of the screen.
+    fn draw_table(
+        &self,
+        out: &mut Stdout,
+        width: usize,
+        top: u16,
+        rows: usize,
+    ) -> Result<(), FettersError> {
+        let text_width = width.saturating_sub(ID_WIDTH + STATUS_WIDTH + 3);
+        let company_width = text_width / 2;
+        let title_width = text_width - company_width;
+
+        queue!(
+            out,
+            MoveTo(0, top),
+            SetAttribute(Attribute::Bold),
+            Print(fit(
+                &format!(
+                    "{} {} {} {}",
+                    fit("ID", ID_WIDTH),
+                    fit("Company", company_width),
+                    fit("Title", title_width),
+                    fit("Status", STATUS_WIDTH),
+                ),
+                width
+            )),
+            SetAttribute(Attribute::Reset),
+        )?;
+
+        let first_row = scroll_offset(self.selected, rows);
+        for row in 0..rows {
+            queue!(out, MoveTo(0, top + 1 + row as u16))?;
+
+            let Some(job) = self
+                .visible
+                .get(first_row + row)
+                .and_then(|index| self.jobs.get(*index))
+            else {
+                queue!(out, Print(" ".repeat(width)))?;
+                continue;
+            };
+
+            if first_row + row == self.selected {
+                queue!(out, SetAttribute(Attribute::Reverse))?;
+            }
+            let status = job.status.as_deref().unwrap_or("N/A");
+            queue!(
+                out,
+                Print(fit(
+                    &format!(
+                        "{} {} {} ",
+                        fit(&job.id.to_string(), ID_WIDTH),
+                        fit(&job.company_name, company_width),
+                        fit(job.title.as_deref().unwrap_or("N/A"), title_width),
+                    ),
+                    width.saturating_sub(STATUS_WIDTH)
+                )),
+                SetForegroundColor(status_color(status)),
+                Print(fit(status, STATUS_WIDTH.min(width))),
+                SetAttribute(Attribute::Reset),
+                ResetColor,
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Screen for Dashboard {
+    fn draw(&self, out: &mut Stdout, width: u16, height: u16) -> Result<(), FettersError> {
+        let full_width = width as usize;
+        let table_width = full_width * 55 / 100;
+        let detail_width = full_width.saturating_sub(table_width + 3);
+        let body_rows = (height as usize).saturating_sub(4);
+
+        let sprint_name = self
+            .sprints
+            .get(self.sprint)
+            .map(|sprint| sprint.name.as_str())
+            .unwrap_or("N/A");
+        queue!(
+            out,
+            MoveTo(0, 0),
+            SetAttribute(Attribute::Reverse),
+            Print(fit(
+                &format!(
+                    " fetters | sprint: {sprint_name} | {} of {} job applications",
+                    self.visible.len(),
+                    self.jobs.len()
+                ),
+                full_width
+            )),
+            SetAttribute(Attribute::Reset),
+        )?;
+
+        let filter_line = match self.mode {
+            Mode::Filter => format!("Filter: {}_", self.filter),
+            _ if self.filter.is_empty() => "Press / to filter job applications.".to_string(),
+            _ => format!("Filter: {}", self.filter),
+        };
+        queue!(
+            out,
+            MoveTo(0, 1),
+            SetForegroundColor(Color::Cyan),
+            Print(fit(&filter_line, full_width)),
+            ResetColor,
+        )?;
+
+        self.draw_table(out, table_width, 2, body_rows.saturating_sub(1))?;
+
+        let detail_lines = self.detail_lines(detail_width);
+        for row in 0..body_rows {
+            queue!(
+                out,
+                MoveTo(table_width as u16, 2 + row as u16),
+                SetForegroundColor(Color::DarkGrey),
+                Print(" │ "),
+                ResetColor,
+                Print(fit(
+                    detail_lines.get(row).map(String::as_str).unwrap_or(""),
+                    detail_width
+                )),
+            )?;
+        }
+
+        let status_line = self.message.as_deref().unwrap_or(HELP);
+        queue!(
+            out,
+            MoveTo(0, height.saturating_sub(1)),
+            SetAttribute(Attribute::Dim),
+            Print(fit(status_line, full_width)),
+            SetAttribute(Attribute::Reset),
+        )?;
+
+        match &self.mode {
+            Mode::PickStatus(picker)
+            | Mode::PickSprint(picker)
+            | Mode::StageStatus { picker, .. } => picker.draw(out, width, height)?,
+            Mode::StageName(input) | Mode::StageDate { input, .. } => {
+                input.draw(out, width, height)?
+            }
+            Mode::Browse | Mode::Filter => {}
+        }
+
+        Ok(())
+    }
+
+    fn handle_key(
+        &mut self,
+        connection: &mut SqliteConnection,
+        key: KeyEvent,
+    ) -> Result<(), FettersError> {
+        self.message = None;
+
+        // Esc closes any picker or input without making changes.
+        if key.code == KeyCode::Esc && !matches!(self.mode, Mode::Browse | Mode::Filter) {
+            self.mode = Mode::Browse;
+            return Ok(());
+        }
+
+        match std::mem::replace(&mut self.mode, Mode::Browse) {
+            Mode::Browse => self.handle_browse_key(connection, key)?,
+            Mode::Filter => match key.code {
+                KeyCode::Enter => {}
+                KeyCode::Esc => {
+                    self.filter.clear();
+                    self.apply_filter();
+                    self.load_stages(connection)?;
+                }
+                KeyCode::Backspace => {
+                    self.filter.pop();
+                    self.apply_filter();
+                    self.load_stages(connection)?;
+                    self.mode = Mode::Filter;
+                }
+                KeyCode::Char(character) => {
+                    self.filter.push(character);
+                    self.apply_filter();
+                    self.load_stages(connection)?;
+                    self.mode = Mode::Filter;
+                }
+                _ => self.mode = Mode::Filter,
+            },
+            Mode::PickStatus(mut picker) => match picker.handle_key(key) {
+                Some(index) => self.set_status(connection, index)?,
+                None => self.mode = Mode::PickStatus(picker),
+            },
+            Mode::PickSprint(mut picker) => match picker.handle_key(key) {
+                Some(index) => {
+                    self.sprint = index;
+                    self.selected = 0;
+                    self.reload(connection)?;
+                }
+                None => self.mode = Mode::PickSprint(picker),
+            },
+            Mode::StageName(mut input) => {
+                self.mode = if input.handle_key(key) {
+                    Mode::StageStatus {
+                        name: input.value.trim().to_string(),
+                        picker: Picker::new(
+                            "Stage status",
+                            StageStatus::variants()
+                                .iter()
+                                .map(|status| status.to_string())
+                                .collect(),
+                            None,
+                        ),
+                    }
+                } else {
+                    Mode::StageName(input)
+                };
+            }
+            Mode::StageStatus { name, mut picker } => match picker.handle_key(key) {
+                Some(index) => {
+                    let status = StageStatus::variants().swap_remove(index);
+                    let input = Input::new(
+                        "Stage date (YYYY/MM/DD)",
+                        &Local::now().format("%Y/%m/%d").to_string(),
+                    );
+                    self.mode = Mode::StageDate {
+                        name,
+                        status,
+                        input,
+                    };
+                }
+                None => self.mode = Mode::StageStatus { name, picker },
+            },
+            Mode::StageDate {
+                name,
+                status,
+                mut input,
+            } => {
+                if !input.handle_key(key) {
+                    self.mode = Mode::StageDate {
+                        name,
+                        status,
+                        input,
+                    };
+                } else {
+                    match NaiveDate::parse_from_str(input.value.trim(), "%Y/%m/%d") {
+                        Ok(date) => self.add_stage(connection, name, status, date)?,
+                        Err(_) => {
+                            self.message =
+                                Some(format!("{} is not a YYYY/MM/DD date.", input.value.trim()));
+                            self.mode = Mode::StageDate {
+                                name,
+                                status,
+                                input,
+                            };
+                        }
+                    }
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    fn set_message(&mut self, message: String) {
+        self.message = Some(message);
+    }
+
+    fn should_quit(&self) -> bool {
+        self.quit
+    }
+}
+
+/// Whether a job application matches a lowercase filter by its ID, company, title, status, source,
+/// or tags.
+fn matches_filter(job: &TabledJob, filter: &str) -> bool {
+    let id = job.id.to_string();
+
+    [
+        Some(id.as_str()),
+        Some(job.company_name.as_str()),
+        job.title.as_deref(),
+        job.status.as_deref(),
+        job.source.as_deref(),
+        job.tags.as_deref(),
+    ]
+    .into_iter()
+    .flatten()
+    .any(|value| value.to_lowercase().contains(filter))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crossterm::event::KeyModifiers;
+    use diesel::Connection;
+
+    use crate::models::job::NewJob;
+    use crate::models::sprint::NewSprint;
+    use crate::models::title::NewTitle;
+    use crate::repositories::title::TitleRepository;
+
+    fn setup_test_db() -> SqliteConnection {
+        let mut connection =
+            SqliteConnection::establish(":memory:").expect("Failed to create in-memory database");
+        crate::utils::migrations::run_migrations(&mut connection)
+            .expect("Failed to run migrations");
+
+        let mut status_repo = StatusRepository {
+            connection: &mut connection,
+        };
+        status_repo
+            .seed_statuses()
+            .expect("Failed to seed statuses");
+
+        connection
+    }
+
+    fn create_sprint(conn: &mut SqliteConnection, name: &str) -> QueriedSprint {
+        let mut repo = SprintRepository { connection: conn };
+        repo.add_job_sprint(NewSprint {
+            name,
+            start_date: "2025-01-01",
+            end_date: None,
+            num_jobs: &0,
+        })
+        .unwrap()
+    }
+
+    fn create_job(conn: &mut SqliteConnection, company_name: &str, sprint_id: i32) -> i32 {
+        let mut title_repo = TitleRepository { connection: conn };
+        let title = title_repo.add_title(NewTitle { name: "SWE" }).unwrap();
+        let mut status_repo = StatusRepository { connection: conn };
+        let status_id = status_repo
+            .get_all_statuses()
+            .unwrap()
+            .into_iter()
+            .find(|status| status.name == "PENDING")
+            .unwrap()
+            .id;
+
+        let mut job_repo = JobRepository { connection: conn };
+        job_repo
+            .add_job(NewJob {
+                company_name,
+                created: "2025-01-15 10:00:00".to_string(),
+                title_id: title.id,
+                status_id,
+                link: None,
+                sprint_id,
+                source_id: None,
+                referrer: None,
+                resume_id: None,
+                cover_letter_id: None,
+            })
+            .unwrap()
+            .id
+    }
+
+    fn press(dashboard: &mut Dashboard, conn: &mut SqliteConnection, code: KeyCode) {
+        dashboard
+            .handle_key(conn, KeyEvent::new(code, KeyModifiers::NONE))
+            .unwrap();
+    }
+
+    fn type_text(dashboard: &mut Dashboard, conn: &mut SqliteConnection, text: &str) {
+        for character in text.chars() {
+            press(dashboard, conn, KeyCode::Char(character));
+        }
+    }
+
+    #[test]
+    fn test_filter_and_navigation() {
+        let mut conn = setup_test_db();
+        let sprint = create_sprint(&mut conn, "test-sprint");
+        create_job(&mut conn, "Initech", sprint.id);
+        create_job(&mut conn, "Globex", sprint.id);
+        create_job(&mut conn, "Initrode", sprint.id);
+
+        let mut dashboard = Dashboard::new(&mut conn, &sprint).unwrap();
+        assert_eq!(dashboard.visible.len(), 3);
+
+        press(&mut dashboard, &mut conn, KeyCode::Char('G'));
+        assert_eq!(dashboard.selected, 2);
+        press(&mut dashboard, &mut conn, KeyCode::Char('j'));
+        assert_eq!(dashboard.selected, 2);
+
+        press(&mut dashboard, &mut conn, KeyCode::Char('/'));
+        type_text(&mut dashboard, &mut conn, "INIT");
+        assert_eq!(dashboard.visible.len(), 2);
+        assert_eq!(dashboard.selected, 1);
+        assert_eq!(
+            dashboard
+                .selected_job()
+                .map(|job| job.company_name.as_str()),
+            Some("Initrode")
+        );
+
+        // Enter keeps the filter while browsing, Esc in filter mode clears it.
+        press(&mut dashboard, &mut conn, KeyCode::Enter);
+        press(&mut dashboard, &mut conn, KeyCode::Char('k'));
+        assert_eq!(dashboard.visible.len(), 2);
+        assert_eq!(dashboard.selected, 0);
+
+        press(&mut dashboard, &mut conn, KeyCode::Char('/'));
+        press(&mut dashboard, &mut conn, KeyCode::Esc);
+        assert_eq!(dashboard.filter, "");
+        assert_eq!(dashboard.visible.len(), 3);
+
+        press(&mut dashboard, &mut conn, KeyCode::Char('q'));
+        assert!(dashboard.should_quit());
+    }
+
+    #[test]
+    fn test_set_status() {
+        let mut conn = setup_test_db();
+        let sprint = create_sprint(&mut conn, "test-sprint");
+        let job_id = create_job(&mut conn, "Initech", sprint.id);
+
+        let mut dashboard = Dashboard::new(&mut conn, &sprint).unwrap();
+        press(&mut dashboard, &mut conn, KeyCode::Char('s'));
+        let Mode::PickStatus(picker) = &mut dashboard.mode else {
+            panic!("Expected the status picker to be open");
+        };
+        picker.selected = picker
+            .options
+            .iter()
+            .position(|option| option == "REJECTED")
+            .unwrap();
+        press(&mut dashboard, &mut conn, KeyCode::Enter);
+
+        assert!(matches!(dashboard.mode, Mode::Browse));
+        assert_eq!(
+            dashboard.selected_job().and_then(|job| job.status.clone()),
+            Some("REJECTED".to_string())
+        );
+
+        // The change is recorded in its own operation so it can be undone.
+        let mut operation_repo = OperationRepository {
+            connection: &mut conn,
+        };
+        let operation = operation_repo.get_current_operation().unwrap().unwrap();
+        assert_eq!(
+            operation.command,
+            format!("fetters tui (set status of job {job_id} to REJECTED)")
+        );
+    }
+
+    #[test]
+    fn test_add_stage() {
+        let mut conn = setup_test_db();
+        let sprint = create_sprint(&mut conn, "test-sprint");
+        create_job(&mut conn, "Initech", sprint.id);
+
+        let mut dashboard = Dashboard::new(&mut conn, &sprint).unwrap();
+        press(&mut dashboard, &mut conn, KeyCode::Char('a'));
+        type_text(&mut dashboard, &mut conn, "Phone Screen");
+        press(&mut dashboard, &mut conn, KeyCode::Enter);
+        press(&mut dashboard, &mut conn, KeyCode::Char('j'));
+        press(&mut dashboard, &mut conn, KeyCode::Enter);
+
+        let Mode::StageDate { input, .. } = &mut dashboard.mode else {
+            panic!("Expected the stage date input to be open");
+        };
+        input.value = "2025/02/03".to_string();
+        press(&mut dashboard, &mut conn, KeyCode::Enter);
+
+        assert_eq!(dashboard.stages.len(), 1);
+        assert_eq!(dashboard.stages[0].name.as_deref(), Some("Phone Screen"));
+        assert_eq!(dashboard.stages[0].status, "PASSED");
+        assert_eq!(dashboard.stages[0].scheduled_date, "2025/02/03");
+
+        let lines = dashboard.detail_lines(40);
+        assert!(lines.contains(&"Initech - SWE".to_string()));
+        assert!(
+            lines
+                .iter()
+                .any(|line| line.ends_with("Stage 1: Phone Screen"))
+        );
+        assert!(
+            lines
+                .iter()
+                .any(|line| line.ends_with("[PASSED] 2025/02/03"))
+        );
+    }
+
+    #[test]
+    fn test_invalid_stage_date() {
+        let mut conn = setup_test_db();
+        let sprint = create_sprint(&mut conn, "test-sprint");
+        create_job(&mut conn, "Initech", sprint.id);
+
+        let mut dashboard = Dashboard::new(&mut conn, &sprint).unwrap();
+        press(&mut dashboard, &mut conn, KeyCode::Char('a'));
+        press(&mut dashboard, &mut conn, KeyCode::Enter);
+        press(&mut dashboard, &mut conn, KeyCode::Enter);
+        let Mode::StageDate { input, .. } = &mut dashboard.mode else {
+            panic!("Expected the stage date input to be open");
+        };
+        input.value = "tomorrow".to_string();
+        press(&mut dashboard, &mut conn, KeyCode::Enter);
+
+        assert!(matches!(dashboard.mode, Mode::StageDate { .. }));
+        assert!(dashboard.stages.is_empty());
+        assert_eq!(
+            dashboard.message.as_deref(),
+            Some("tomorrow is not a YYYY/MM/DD date.")
+        );
+    }
+}
@@ -0,0 +1,409 @@
+//! Contains the building blocks shared by the full-screen terminal interfaces: restoring the
+//! terminal, the event loop, list pickers, text inputs, and text layout helpers.
+
+pub mod board;
+pub mod dashboard;
+
+use std::io::{Stdout, Write, stdout};
+
+use crossterm::cursor::{Hide, MoveTo, Show};
+use crossterm::event::{self, Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers};
+use crossterm::style::{Attribute, Color, Print, ResetColor, SetAttribute, SetForegroundColor};
+use crossterm::terminal::{
+    self, Clear, ClearType, EnterAlternateScreen, LeaveAlternateScreen, disable_raw_mode,
+    enable_raw_mode,
+};
+use crossterm::{execute, queue};
+use diesel::SqliteConnection;
+
+use crate::errors::FettersError;
+
+/// A full-screen interface driven by key presses.
+pub trait Screen {
+    /// Draw the whole screen. Every row should be filled so nothing from the previous frame is
+    /// left behind.
+    fn draw(&self, out: &mut Stdout, width: u16, height: u16) -> Result<(), FettersError>;
+
+    /// Handle a key press. Errors are displayed in the screen's message line instead of closing
+    /// the interface.
+    fn handle_key(
+        &mut self,
+        connection: &mut SqliteConnection,
+        key: KeyEvent,
+    ) -> Result<(), FettersError>;
+
+    /// Display a message, ie. the result of an action or an error.
+    fn set_message(&mut self, message: String);
+
+    /// Whether the interface should be closed.
+    fn should_quit(&self) -> bool;
+}
+
+/// Switches the terminal into raw mode on the alternate screen and restores it when dropped, so
+/// the terminal is usable again even if the interface exits with an error.
+pub struct TerminalGuard;
+
+impl TerminalGuard {
+    /// Enter raw mode and the alternate screen.
+    pub fn enter() -> Result<TerminalGuard, FettersError> {
+        enable_raw_mode()?;
+        execute!(stdout(), EnterAlternateScreen, Hide)?;
+
+        Ok(TerminalGuard)
+    }
+}
+
+impl Drop for TerminalGuard {
+    fn drop(&mut self) {
+        let _ = execute!(stdout(), Show, LeaveAlternateScreen);
+        let _ = disable_raw_mode();
+    }
+}
+
+/// Run a screen until it asks to quit or Ctrl-C is pressed.
+pub fn run_screen<S: Screen>(
+    connection: &mut SqliteConnection,
+    screen: &mut S,
+) -> Result<(), FettersError> {
+    let _guard = TerminalGuard::enter()?;
+    let mut out = stdout();
+
+    while !screen.should_quit() {
+        let (width, height) = terminal::size()?;
+        screen.draw(&mut out, width, height)?;
+        out.flush()?;
+
+        match event::read()? {
+            // Only presses are handled. Terminals that report key releases would otherwise
+            // trigger every action twice.
+            Event::Key(key) if key.kind != KeyEventKind::Release => {
+                if key.code == KeyCode::Char('c') && key.modifiers.contains(KeyModifiers::CONTROL) {
+                    break;
+                }
+                if let Err(error) = screen.handle_key(connection, key) {
+                    screen.set_message(error.to_string());
+                }
+            }
+            Event::Resize(_, _) => queue!(out, Clear(ClearType::All))?,
+            _ => {}
+        }
+    }
+
+    Ok(())
+}
+
+/// A list of options to pick from, ie. the statuses a job application can be moved to.
+#[derive(Debug)]
+pub struct Picker {
+    /// The title displayed above the options.
+    pub title: String,
+    /// The options.
+    pub options: Vec<String>,
+    /// The index of the highlighted option.
+    pub selected: usize,
+}
+
+impl Picker {
+    /// Create a picker with the option matching `current` highlighted, or the first option if none
+    /// match.
+    pub fn new(title: &str, options: Vec<String>, current: Option<&str>) -> Picker {
+        let selected = current
+            .and_then(|current| options.iter().position(|option| option == current))
+            .unwrap_or_default();
+
+        Picker {
+            title: title.to_string(),
+            options,
+            selected,
+        }
+    }
+
+    /// Move the highlight with the arrow keys or `j` and `k`. Returns the index of the chosen
+    /// option once Enter is pressed.
+    pub fn handle_key(&mut self, key: KeyEvent) -> Option<usize> {
+        match key.code {
+            KeyCode::Up | KeyCode::Char('k') => self.selected = self.selected.saturating_sub(1),
+            KeyCode::Down | KeyCode::Char('j') => {
+                self.selected = (self.selected + 1).min(self.options.len().saturating_sub(1));
+            }
+            KeyCode::Enter if !self.options.is_empty() => return Some(self.selected),
+            _ => {}
+        }
+
+        None
+    }
+
+    /// Draw the picker in a box in the middle of the screen.
+    pub fn draw(&self, out: &mut Stdout, width: u16, height: u16) -> Result<(), FettersError> {
+        let lines = self
+            .options
+            .iter()
+            .enumerate()
+            .map(|(index, option)| {
+                let marker = if index == self.selected { ">" } else { " " };
+                (format!("{marker} {option}"), index == self.selected)
+            })
+            .collect::<Vec<(String, bool)>>();
+
+        draw_box(out, &self.title, &lines, width, height)
+    }
+}
+
+/// A single line of text input, ie. the name of a new interview stage.
+#[derive(Debug)]
+pub struct Input {
+    /// The prompt displayed above the input.
+    pub prompt: String,
+    /// The text entered so far.
+    pub value: String,
+}
+
+impl Input {
+    /// Create an input prefilled with `value`.
+    pub fn new(prompt: &str, value: &str) -> Input {
+        Input {
+            prompt: prompt.to_string(),
+            value: value.to_string(),
+        }
+    }
+
+    /// Edit the text. Returns true once Enter is pressed.
+    pub fn handle_key(&mut self, key: KeyEvent) -> bool {
+        match key.code {
+            KeyCode::Char(character) => self.value.push(character),
+            KeyCode::Backspace => {
+                self.value.pop();
+            }
+            KeyCode::Enter => return true,
+            _ => {}
+        }
+
+        false
+    }
+
+    /// Draw the input in a box in the middle of the screen.
+    pub fn draw(&self, out: &mut Stdout, width: u16, height: u16) -> Result<(), FettersError> {
+        draw_box(
+            out,
+            &self.prompt,
+            &[(format!("{}_", self.value), false)],
+            width,
+            height,
+        )
+    }
+}
+
+/// Draw a bordered box in the middle of the screen. Highlighted lines are drawn in reverse video.
+fn draw_box(
+    out: &mut Stdout,
+    title: &str,
+    lines: &[(String, bool)],
+    width: u16,
+    height: u16,
+) -> Result<(), FettersError> {
+    let inner_width = lines
+        .iter()
+        .map(|(line, _)| line.chars().count())
+        .chain(std::iter::once(title.chars().count()))
+        .max()
+        .unwrap_or_default()
+        .clamp(20, (width as usize).saturating_sub(6).max(1));
+    let visible_lines = lines.len().min((height as usize).saturating_sub(4).max(1));
+    let highlighted = lines
+        .iter()
+        .position(|(_, highlighted)| *highlighted)
+        .unwrap_or_default();
+    let first_line = scroll_offset(highlighted, visible_lines);
+
+    let left = ((width as usize).saturating_sub(inner_width + 4) / 2) as u16;
+    let top = ((height as usize).saturating_sub(visible_lines + 2) / 2) as u16;
+
+    queue!(
+        out,
+        MoveTo(left, top),
+        SetForegroundColor(Color::Cyan),
+        Print(format!("┌ {} ┐", fit(title, inner_width))),
+    )?;
+    for (row, (line, highlighted)) in lines
+        .iter()
+        .skip(first_line)
+        .take(visible_lines)
+        .enumerate()
+    {
+        queue!(
+            out,
+            MoveTo(left, top + 1 + row as u16),
+            SetForegroundColor(Color::Cyan),
+            Print("│ "),
+            ResetColor,
+        )?;
+        if *highlighted {
+            queue!(out, SetAttribute(Attribute::Reverse))?;
+        }
+        queue!(
+            out,
+            Print(fit(line, inner_width)),
+            SetAttribute(Attribute::Reset),
+            SetForegroundColor(Color::Cyan),
+            Print(" │"),
+        )?;
+    }
+    queue!(
+        out,
+        MoveTo(left, top + 1 + visible_lines as u16),
+        Print(format!("└{}┘", "─".repeat(inner_width + 2))),
+        ResetColor,
+    )?;
+
+    Ok(())
+}
+
+/// The first row to display so the row at `selected` is visible in a list that is `rows` tall.
+pub fn scroll_offset(selected: usize, rows: usize) -> usize {
+    if rows == 0 {
+        selected
+    } else {
+        (selected + 1).saturating_sub(rows)
+    }
+}
+
+/// Cut `text` to `width` characters, ending with "…" if it was cut, and pad it with spaces to
+/// exactly `width` characters.
+pub fn fit(text: &str, width: usize) -> String {
+    let length = text.chars().count();
+
+    if length > width {
+        let mut cut = text
+            .chars()
+            .take(width.saturating_sub(1))
+            .collect::<String>();
+        if width > 0 {
+            cut.push('…');
+        }
+        cut
+    } else {
+        format!("{text}{}", " ".repeat(width - length))
+    }
+}
+
+/// Wrap `text` into lines of at most `width` characters, breaking between words where possible.
+/// Line breaks in the text are kept.
+pub fn wrap_text(text: &str, width: usize) -> Vec<String> {
+    let width = width.max(1);
+    let mut lines = Vec::new();
+
+    for paragraph in text.lines() {
+        let mut line = String::new();
+
+        for word in paragraph.split_whitespace() {
+            let mut word = word.to_string();
+
+            // Words longer than a whole line are split across lines.
+            while word.chars().count() > width {
+                if !line.is_empty() {
+                    lines.push(std::mem::take(&mut line));
+                }
+                let rest = word.chars().skip(width).collect::<String>();
+                lines.push(word.chars().take(width).collect());
+                word = rest;
+            }
+
+            if line.is_empty() {
+                line = word;
+            } else if line.chars().count() + 1 + word.chars().count() <= width {
+                line.push(' ');
+                line.push_str(&word);
+            } else {
+                lines.push(std::mem::replace(&mut line, word));
+            }
+        }
+
+        lines.push(line);
+    }
+
+    lines
+}
+
+/// The color used for an application status, matching the colors used in job tables.
+pub fn status_color(status: &str) -> Color {
+    match status {
+        "GHOSTED" => Color::Rgb {
+            r: 133,
+            g: 133,
+            b: 133,
+        },
+        "HIRED" => Color::Green,
+        "IN PROGRESS" => Color::Yellow,
+        "NOT HIRING ANYMORE" => Color::Rgb {
+            r: 117,
+            g: 117,
+            b: 117,
+        },
+        "OFFER RECEIVED" => Color::Magenta,
+        "PENDING" => Color::Blue,
+        "REJECTED" => Color::Red,
+        _ => Color::Reset,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn key(code: KeyCode) -> KeyEvent {
+        KeyEvent::new(code, KeyModifiers::NONE)
+    }
+
+    #[test]
+    fn test_fit() {
+        assert_eq!(fit("Initech", 10), "Initech   ");
+        assert_eq!(fit("Initech Systems", 10), "Initech S…");
+        assert_eq!(fit("Initech", 7), "Initech");
+        assert_eq!(fit("Initech", 0), "");
+    }
+
+    #[test]
+    fn test_wrap_text() {
+        assert_eq!(
+            wrap_text("Recruiter said the team is hiring", 14),
+            vec!["Recruiter said", "the team is", "hiring"]
+        );
+        assert_eq!(wrap_text("first\nsecond", 20), vec!["first", "second"]);
+        assert_eq!(
+            wrap_text("https://initech.example.com", 10),
+            vec!["https://in", "itech.exam", "ple.com"]
+        );
+    }
+
+    #[test]
+    fn test_scroll_offset() {
+        assert_eq!(scroll_offset(0, 5), 0);
+        assert_eq!(scroll_offset(4, 5), 0);
+        assert_eq!(scroll_offset(7, 5), 3);
+    }
+
+    #[test]
+    fn test_picker_handle_key() {
+        let mut picker = Picker::new(
+            "Status",
+            vec!["PENDING".to_string(), "REJECTED".to_string()],
+            Some("REJECTED"),
+        );
+        assert_eq!(picker.selected, 1);
+
+        assert_eq!(picker.handle_key(key(KeyCode::Down)), None);
+        assert_eq!(picker.selected, 1);
+        assert_eq!(picker.handle_key(key(KeyCode::Char('k'))), None);
+        assert_eq!(picker.handle_key(key(KeyCode::Enter)), Some(0));
+    }
+
+    #[test]
+    fn test_input_handle_key() {
+        let mut input = Input::new("Name", "Phone");
+        assert!(!input.handle_key(key(KeyCode::Char('s'))));
+        assert!(!input.handle_key(key(KeyCode::Backspace)));
+        assert!(!input.handle_key(key(KeyCode::Char(' '))));
+        assert!(input.handle_key(key(KeyCode::Enter)));
+        assert_eq!(input.value, "Phone ");
+    }
+}
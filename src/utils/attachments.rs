@@ -0,0 +1,115 @@
+//! Contains utility functions for storing files attached to job applications.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::{config::configuration::Config, errors::FettersError};
+
+/// Get the directory that holds the files attached to a job.
+pub fn get_attachment_dir(job_id: i32) -> Result<PathBuf, FettersError> {
+    Ok(Config::get_data_dir_path()?
+        .join("attachments")
+        .join(job_id.to_string()))
+}
+
+/// Copy a file into an attachment directory, creating the directory if it does not exist. A
+/// number is appended to the file name if the directory already contains a file with that name.
+/// Returns the path to the copy.
+pub fn copy_attachment(source: &Path, attachment_dir: &Path) -> Result<PathBuf, FettersError> {
+    if !source.is_file() {
+        return Err(FettersError::UnknownError(format!(
+            "{} is not a file!",
+            source.display()
+        )));
+    }
+
+    fs::create_dir_all(attachment_dir)?;
+
+    let destination = unique_destination(attachment_dir, source);
+    fs::copy(source, &destination)?;
+
+    Ok(destination)
+}
+
+/// Remove the directory holding the files attached to a job, if it exists.
+pub fn remove_attachment_dir(job_id: i32) -> Result<(), FettersError> {
+    let attachment_dir = get_attachment_dir(job_id)?;
+
+    if attachment_dir.exists() {
+        fs::remove_dir_all(attachment_dir)?;
+    }
+
+    Ok(())
+}
+
+/// Find a path in the attachment directory that does not already exist for the source file (ie.
+/// `offer.pdf`, then `offer (1).pdf`, then `offer (2).pdf`).
+fn unique_destination(attachment_dir: &Path, source: &Path) -> PathBuf {
+    let file_name = source
+        .file_name()
+        .map(|name| name.to_string_lossy().to_string())
+        .unwrap_or_else(|| "attachment".to_string());
+    let mut destination = attachment_dir.join(&file_name);
+
+    let stem = source
+        .file_stem()
+        .map(|stem| stem.to_string_lossy().to_string())
+        .unwrap_or_else(|| file_name.clone());
+    let extension = source
+        .extension()
+        .map(|extension| format!(".{}", extension.to_string_lossy()))
+        .unwrap_or_default();
+
+    let mut copy_number = 1;
+    while destination.exists() {
+        destination = attachment_dir.join(format!("{stem} ({copy_number}){extension}"));
+        copy_number += 1;
+    }
+
+    destination
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_get_attachment_dir() {
+        let attachment_dir = get_attachment_dir(42).unwrap();
+        assert!(attachment_dir.ends_with(Path::new("attachments").join("42")));
+    }
+
+    #[test]
+    fn test_copy_attachment() {
+        let dir = tempfile::tempdir().unwrap();
+        let source = dir.path().join("offer.pdf");
+        fs::write(&source, "offer").unwrap();
+        let attachment_dir = dir.path().join("attachments").join("1");
+
+        let copy = copy_attachment(&source, &attachment_dir).unwrap();
+        assert_eq!(copy, attachment_dir.join("offer.pdf"));
+        assert_eq!(fs::read_to_string(&copy).unwrap(), "offer");
+        assert!(source.exists());
+    }
+
+    #[test]
+    fn test_copy_attachment_does_not_overwrite() {
+        let dir = tempfile::tempdir().unwrap();
+        let source = dir.path().join("offer.pdf");
+        fs::write(&source, "offer").unwrap();
+        let attachment_dir = dir.path().join("attachments");
+
+        copy_attachment(&source, &attachment_dir).unwrap();
+        let second_copy = copy_attachment(&source, &attachment_dir).unwrap();
+        let third_copy = copy_attachment(&source, &attachment_dir).unwrap();
+
+        assert_eq!(second_copy, attachment_dir.join("offer (1).pdf"));
+        assert_eq!(third_copy, attachment_dir.join("offer (2).pdf"));
+    }
+
+    #[test]
+    fn test_copy_attachment_rejects_directories() {
+        let dir = tempfile::tempdir().unwrap();
+        assert!(copy_attachment(dir.path(), &dir.path().join("attachments")).is_err());
+    }
+}
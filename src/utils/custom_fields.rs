@@ -0,0 +1,97 @@
+//! Contains utility functions for entering values for user-defined custom fields.
+
+use chrono::NaiveDate;
+use diesel::SqliteConnection;
+use inquire::{Confirm, DateSelect, Select, Text, validator::Validation};
+
+use crate::{
+    errors::FettersError,
+    models::custom_field::{CustomFieldType, QueriedCustomField},
+    repositories::custom_field::CustomFieldRepository,
+    utils::prompt::get_inquire_config,
+};
+
+/// Prompt for a value for every defined custom field. Custom fields are optional, so fields the
+/// user skips are not included in the returned `Vec`.
+pub fn input_custom_field_values(
+    connection: &mut SqliteConnection,
+) -> Result<Vec<(QueriedCustomField, String)>, FettersError> {
+    let mut field_repo = CustomFieldRepository { connection };
+    let mut values = Vec::new();
+
+    for field in field_repo.get_all_fields()? {
+        if let Some(value) = input_custom_field_value(&field, None)? {
+            values.push((field, value));
+        }
+    }
+
+    Ok(values)
+}
+
+/// Prompt for a value for a single custom field. The prompt depends on the field type. `None` is
+/// returned if the user skips the prompt or enters an empty value.
+pub fn input_custom_field_value(
+    field: &QueriedCustomField,
+    previous_value: Option<&str>,
+) -> Result<Option<String>, FettersError> {
+    let previous_value = previous_value.filter(|value| !value.is_empty());
+
+    let value = match field.parsed_type() {
+        CustomFieldType::Date => {
+            DateSelect::new(&format!("[OPTIONAL] Select a date for {}:", field.name))
+                .with_starting_date(
+                    previous_value
+                        .and_then(|value| NaiveDate::parse_from_str(value, "%Y-%m-%d").ok())
+                        .unwrap_or_else(|| chrono::Local::now().date_naive()),
+                )
+                .with_render_config(get_inquire_config())
+                .prompt_skippable()?
+                .map(|date| date.format("%Y-%m-%d").to_string())
+        }
+        CustomFieldType::Bool => Confirm::new(&format!("[OPTIONAL] {}?", field.name))
+            .with_default(previous_value == Some("true"))
+            .with_render_config(get_inquire_config())
+            .prompt_skippable()?
+            .map(|answer| answer.to_string()),
+        CustomFieldType::Enum => {
+            let options = field.option_list();
+            let starting_cursor = previous_value
+                .and_then(|value| options.iter().position(|option| option == value))
+                .unwrap_or(0);
+
+            Select::new(
+                &format!("[OPTIONAL] Select a value for {}:", field.name),
+                options,
+            )
+            .with_starting_cursor(starting_cursor)
+            .with_render_config(get_inquire_config())
+            .prompt_skippable()?
+        }
+        field_type @ (CustomFieldType::Text | CustomFieldType::Number) => {
+            let validator_type = field_type.clone();
+
+            Text::new(&format!("[OPTIONAL] Enter a value for {}:", field.name))
+                .with_initial_value(previous_value.unwrap_or(""))
+                .with_validator(move |input: &str| {
+                    if input.trim().is_empty() {
+                        return Ok(Validation::Valid);
+                    }
+
+                    Ok(match validator_type.validate(input, &[]) {
+                        Ok(_) => Validation::Valid,
+                        Err(message) => Validation::Invalid(message.into()),
+                    })
+                })
+                .with_render_config(get_inquire_config())
+                .prompt_skippable()?
+                .filter(|value| !value.trim().is_empty())
+                .map(|value| {
+                    field_type
+                        .validate(&value, &[])
+                        .unwrap_or_else(|_| value.trim().to_string())
+                })
+        }
+    };
+
+    Ok(value)
+}
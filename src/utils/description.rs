@@ -0,0 +1,112 @@
+//! Contains utility functions for entering the full text of a job posting.
+
+use std::fs;
+use std::io::{self, Read};
+
+use inquire::{Confirm, Select};
+
+use crate::{errors::FettersError, utils::prompt::get_inquire_config};
+
+/// Read a job description from a file, or from stdin if the path is `-`. `None` is returned if
+/// the file is empty.
+pub fn read_description_file(path: &str) -> Result<Option<String>, FettersError> {
+    let content = if path == "-" {
+        let mut buffer = String::new();
+        io::stdin().read_to_string(&mut buffer)?;
+        buffer
+    } else {
+        fs::read_to_string(path)?
+    };
+
+    Ok(normalize_description(&content))
+}
+
+/// Open the job description in `$EDITOR`. The editor is prefilled with the previous description,
+/// if any. `None` is returned if the description is left empty.
+pub fn edit_description(previous_description: &str) -> Result<Option<String>, FettersError> {
+    let content = edit::edit(previous_description)?;
+
+    Ok(normalize_description(&content))
+}
+
+/// Get the job description for a new job application. The description is read from the given
+/// file if one was provided. If a description was extracted from a job posting, the user may
+/// save it as-is, edit it first, or skip it. Otherwise, the user is asked whether they want to
+/// paste it in `$EDITOR`.
+pub fn input_description(
+    description_file: Option<&str>,
+    extracted_description: Option<&str>,
+) -> Result<Option<String>, FettersError> {
+    if let Some(path) = description_file {
+        return read_description_file(path);
+    }
+
+    if let Some(extracted_description) = extracted_description {
+        return match Select::new(
+            "[OPTIONAL] Do you want to save the job description from the posting?",
+            vec!["Save", "Edit in $EDITOR", "Skip"],
+        )
+        .with_render_config(get_inquire_config())
+        .prompt_skippable()?
+        {
+            Some("Save") => Ok(normalize_description(extracted_description)),
+            Some("Edit in $EDITOR") => edit_description(extracted_description),
+            _ => Ok(None),
+        };
+    }
+
+    match Confirm::new("[OPTIONAL] Do you want to paste the job description in your $EDITOR?")
+        .with_default(false)
+        .with_render_config(get_inquire_config())
+        .prompt_skippable()?
+    {
+        Some(true) => edit_description(""),
+        _ => Ok(None),
+    }
+}
+
+/// Trim surrounding whitespace from a job description. Empty descriptions are discarded.
+fn normalize_description(content: &str) -> Option<String> {
+    let content = content.trim();
+
+    if content.is_empty() {
+        None
+    } else {
+        Some(content.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_read_description_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("posting.txt");
+        fs::write(
+            &path,
+            "\n  Senior Engineer, Payments\n\nBuild payment rails.\n\n",
+        )
+        .unwrap();
+
+        assert_eq!(
+            read_description_file(path.to_str().unwrap()).unwrap(),
+            Some("Senior Engineer, Payments\n\nBuild payment rails.".to_string())
+        );
+    }
+
+    #[test]
+    fn test_read_empty_description_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("posting.txt");
+        fs::write(&path, "   \n").unwrap();
+
+        assert_eq!(read_description_file(path.to_str().unwrap()).unwrap(), None);
+    }
+
+    #[test]
+    fn test_read_missing_description_file_fails() {
+        assert!(read_description_file("/nonexistent/posting.txt").is_err());
+    }
+}
@@ -1,8 +1,15 @@
 //! Contains utilities for displaying job applications.
 
+use std::collections::BTreeMap;
+use std::io::{IsTerminal, stdout};
+
+use chrono::Local;
+use crossterm::terminal;
 use owo_colors::OwoColorize;
 use tabled::{
     Table, Tabled,
+    builder::Builder,
+    grid::util::string::get_text_width,
     settings::{
         Alignment, Color, Modify, Panel, Remove, Style, Width,
         location::Locator,
@@ -10,11 +17,91 @@ use tabled::{
     },
 };
 
-use crate::models::{insight::CountAndPercentage, job::TabledJob, sprint::QueriedSprint};
+use crate::cli::JobColumn;
+use crate::config::configuration::SavedView;
+use crate::models::{
+    attachment::QueriedAttachment,
+    custom_field::QueriedCustomField,
+    document::QueriedDocument,
+    job::{JobDetails, TabledJob, TabledJobChange, TabledTrashedJob, colorize_by_status},
+    note::QueriedJobNote,
+    operation::TabledOperation,
+    search::SearchResult,
+    sprint::QueriedSprint,
+    tag::TabledTag,
+};
+
+/// How a long job table column is shortened: truncated to a single line or wrapped across lines.
+#[derive(Clone, Copy)]
+enum Shorten {
+    Truncate,
+    Wrap,
+}
+
+/// The widest a job table column may be, the narrowest it may be shrunk to when fitting the table
+/// to the terminal, and how it is shortened. Columns that are not listed are never shortened.
+fn column_limits(column: &JobColumn) -> Option<(usize, usize, Shorten)> {
+    match column {
+        JobColumn::Company => Some((22, 12, Shorten::Truncate)),
+        JobColumn::Title => Some((35, 12, Shorten::Truncate)),
+        JobColumn::Tags => Some((30, 10, Shorten::Wrap)),
+        JobColumn::Link => Some((40, 12, Shorten::Truncate)),
+        JobColumn::Notes => Some((40, 16, Shorten::Wrap)),
+        _ => None,
+    }
+}
+
+/// The order in which columns are shrunk when a job table is wider than the terminal.
+const SHRINK_ORDER: [JobColumn; 5] = [
+    JobColumn::Notes,
+    JobColumn::Link,
+    JobColumn::Title,
+    JobColumn::Tags,
+    JobColumn::Company,
+];
+
+/// Display jobs in a table with the given columns. The default columns followed by every custom
+/// field are displayed if no columns are provided. Long values are shortened to fit the terminal.
+pub fn display_jobs(jobs: &[TabledJob], sprint_name: &str, columns: &[JobColumn]) {
+    // All jobs returned from the job repository contain the same custom fields, so the first job
+    // determines the custom field columns.
+    let columns = if columns.is_empty() {
+        let custom_fields = jobs
+            .first()
+            .map(|job| {
+                job.custom_fields
+                    .iter()
+                    .map(|(field_name, _)| JobColumn::Field(field_name.clone()))
+                    .collect::<Vec<JobColumn>>()
+            })
+            .unwrap_or_default();
 
-/// Display jobs in a table.
-pub fn display_jobs(jobs: &Vec<TabledJob>, sprint_name: &str) {
-    let mut table = Table::new(jobs);
+        JobColumn::defaults()
+            .into_iter()
+            .chain(custom_fields)
+            .collect()
+    } else {
+        columns.to_vec()
+    };
+
+    let today = Local::now().date_naive();
+    let rows = jobs
+        .iter()
+        .map(|job| {
+            columns
+                .iter()
+                .map(|column| job.column_value(column, today))
+                .collect::<Vec<String>>()
+        })
+        .collect::<Vec<Vec<String>>>();
+
+    let mut builder = Builder::default();
+    builder.push_record(columns.iter().map(JobColumn::header));
+    for row in &rows {
+        builder.push_record(row.clone());
+    }
+
+    let mut table = builder.build();
 
     table
         .with(Style::blank())
@@ -26,11 +113,50 @@ pub fn display_jobs(jobs: &Vec<TabledJob>, sprint_name: &str) {
         ))
         .with(Modify::list(Rows::first(), Alignment::center()))
         .with(Modify::list(Rows::one(1), Color::FG_BRIGHT_CYAN))
-        .modify(Rows::first(), Color::FG_BRIGHT_WHITE)
-        .modify(Columns::one(2), Width::truncate(22).suffix("..."))
-        .modify(Columns::one(3), Width::truncate(35).suffix("..."))
-        .modify(Columns::one(6), Width::truncate(23).suffix("..."))
-        .modify(Columns::one(7), Width::wrap(40).keep_words(true))
+        .modify(Rows::first(), Color::FG_BRIGHT_WHITE);
+
+    // Every column that may be shortened is shortened once, to the width it is given here.
+    let natural_widths = columns
+        .iter()
+        .enumerate()
+        .map(|(index, column)| {
+            rows.iter()
+                .map(|row| get_text_width(&row[index]))
+                .chain(std::iter::once(get_text_width(&column.header())))
+                .max()
+                .unwrap_or_default()
+        })
+        .collect::<Vec<usize>>();
+    let widths = fit_widths(
+        &columns,
+        &natural_widths,
+        table.total_width(),
+        terminal_width(),
+    );
+
+    for (index, column) in columns.iter().enumerate() {
+        if widths[index] >= natural_widths[index] {
+            continue;
+        }
+
+        match column_limits(column) {
+            Some((_, _, Shorten::Truncate)) => {
+                table.modify(
+                    Columns::one(index),
+                    Width::truncate(widths[index]).suffix("..."),
+                );
+            }
+            Some((_, _, Shorten::Wrap)) => {
+                table.modify(
+                    Columns::one(index),
+                    Width::wrap(widths[index]).keep_words(true),
+                );
+            }
+            None => {}
+        }
+    }
+
+    table
         .modify(Locator::content("GHOSTED"), Color::rgb_fg(133, 133, 133))
         .modify(Locator::content("HIRED"), Color::FG_BRIGHT_GREEN)
         .modify(Locator::content("IN PROGRESS"), Color::FG_BRIGHT_YELLOW)
@@ -45,6 +171,141 @@ pub fn display_jobs(jobs: &Vec<TabledJob>, sprint_name: &str) {
     println!("\n{table}\n");
 }
 
+/// The narrowest a column on the job board is made to fit the terminal.
+const MIN_CARD_WIDTH: usize = 12;
+
+/// Display job applications as a board with a column per application status. Each job application
+/// is displayed as a card colored by its status. Columns are narrowed to fit the terminal.
+pub fn display_board(statuses: &[String], jobs: &[TabledJob], sprint_name: &str) {
+    let columns = statuses
+        .iter()
+        .map(|status| {
+            jobs.iter()
+                .filter(|job| job.status.as_deref() == Some(status.as_str()))
+                .collect::<Vec<&TabledJob>>()
+        })
+        .collect::<Vec<Vec<&TabledJob>>>();
+
+    // Each column takes up its card width plus a border and padding on both sides.
+    let card_width = terminal_width().map(|width| {
+        (width.saturating_sub(1) / statuses.len().max(1))
+            .saturating_sub(3)
+            .max(MIN_CARD_WIDTH)
+    });
+
+    let mut builder = Builder::default();
+    builder.push_record(statuses.iter().zip(&columns).map(|(status, column)| {
+        colorize_by_status(
+            status,
+            &shorten(&format!("{status} ({})", column.len()), card_width),
+        )
+    }));
+    for row in 0..columns.iter().map(Vec::len).max().unwrap_or_default() {
+        builder.push_record(columns.iter().map(|column| match column.get(row) {
+            Some(job) => [
+                format!("#{} {}", job.id, job.company_name),
+                job.title.clone().unwrap_or("N/A".to_string()),
+            ]
+            .iter()
+            .map(|line| job.colorize_field(&shorten(line, card_width)))
+            .collect::<Vec<String>>()
+            .join("\n"),
+            None => String::new(),
+        }));
+    }
+
+    let mut table = builder.build();
+    table
+        .with(Style::modern_rounded())
+        .with(Panel::header(
+            format!("{sprint_name} SPRINT BOARD [{} JOBS LISTED]", jobs.len())
+                .green()
+                .bold()
+                .to_string(),
+        ))
+        .with(Modify::list(Rows::first(), Alignment::center()))
+        .with(Modify::list(Rows::one(1), Alignment::center()));
+
+    println!("\n{table}\n");
+}
+
+/// Cut `text` to `width` characters, ending with "..." if it was cut.
+fn shorten(text: &str, width: Option<usize>) -> String {
+    match width {
+        Some(width) if text.chars().count() > width => format!(
+            "{}...",
+            text.chars()
+                .take(width.saturating_sub(3))
+                .collect::<String>()
+        ),
+        _ => text.to_string(),
+    }
+}
+
+/// Decide how wide each job table column is. Long columns are capped to their maximum width, and
+/// if the table is still wider than the terminal, columns are shrunk in `SHRINK_ORDER` down to
+/// their minimum width. `total_width` is the width of the table before any column is shortened.
+fn fit_widths(
+    columns: &[JobColumn],
+    natural_widths: &[usize],
+    total_width: usize,
+    terminal_width: Option<usize>,
+) -> Vec<usize> {
+    let mut widths = columns
+        .iter()
+        .zip(natural_widths)
+        .map(|(column, natural_width)| match column_limits(column) {
+            Some((max_width, _, _)) => (*natural_width).min(max_width),
+            None => *natural_width,
+        })
+        .collect::<Vec<usize>>();
+
+    let Some(terminal_width) = terminal_width else {
+        return widths;
+    };
+
+    let capped_width = total_width
+        - natural_widths
+            .iter()
+            .zip(&widths)
+            .map(|(natural_width, width)| natural_width - width)
+            .sum::<usize>();
+    let mut excess = capped_width.saturating_sub(terminal_width);
+
+    for shrink_column in SHRINK_ORDER.iter() {
+        if excess == 0 {
+            break;
+        }
+
+        if let Some(index) = columns.iter().position(|column| column == shrink_column)
+            && let Some((_, min_width, _)) = column_limits(shrink_column)
+        {
+            let reduction = widths[index].saturating_sub(min_width).min(excess);
+            widths[index] -= reduction;
+            excess -= reduction;
+        }
+    }
+
+    widths
+}
+
+/// Detect the width of the terminal. `$COLUMNS` takes precedence if it is set. Output that is not
+/// written to a terminal is not fit to any width.
+fn terminal_width() -> Option<usize> {
+    if let Some(columns) = std::env::var("COLUMNS")
+        .ok()
+        .and_then(|columns| columns.parse::<usize>().ok())
+    {
+        return Some(columns);
+    }
+
+    if !stdout().is_terminal() {
+        return None;
+    }
+
+    terminal::size().ok().map(|(width, _)| width as usize)
+}
+
 /// Display a single job. This generic function works with any struct that implements `Tabled`.
 pub fn display_single_job<T: Tabled>(job: T) {
     let mut table = Table::new([job]);
@@ -54,8 +315,9 @@ pub fn display_single_job<T: Tabled>(job: T) {
         .with(Modify::list(Rows::first(), Color::FG_BRIGHT_CYAN))
         .modify(Columns::one(1), Width::truncate(22).suffix("..."))
         .modify(Columns::one(2), Width::truncate(35).suffix("..."))
-        .modify(Columns::one(5), Width::truncate(23).suffix("..."))
-        .modify(Columns::one(6), Width::wrap(40).keep_words(true))
+        .modify(Columns::one(6), Width::wrap(30).keep_words(true))
+        .modify(Columns::one(7), Width::truncate(23).suffix("..."))
+        .modify(Columns::one(8), Width::wrap(40).keep_words(true))
         .modify(Locator::content("GHOSTED"), Color::rgb_fg(133, 133, 133))
         .modify(Locator::content("HIRED"), Color::FG_BRIGHT_GREEN)
         .modify(Locator::content("IN PROGRESS"), Color::FG_BRIGHT_YELLOW)
@@ -70,6 +332,141 @@ pub fn display_single_job<T: Tabled>(job: T) {
     println!("\n{table}\n");
 }
 
+/// Display every attribute of a single job application, one attribute per row. Custom fields are
+/// listed after the built-in attributes.
+pub fn display_job_details(details: &JobDetails) {
+    let or_na = |value: &Option<String>| value.clone().unwrap_or("N/A".to_string());
+
+    let mut builder = Builder::default();
+    builder.push_record(["ID".to_string(), details.id.to_string()]);
+    builder.push_record(["Created".to_string(), details.created.clone()]);
+    builder.push_record(["Status".to_string(), or_na(&details.status)]);
+    builder.push_record(["Sprint".to_string(), details.sprint.clone()]);
+    builder.push_record(["Source".to_string(), or_na(&details.source)]);
+    builder.push_record(["Referrer".to_string(), or_na(&details.referrer)]);
+    builder.push_record(["Link".to_string(), or_na(&details.link)]);
+    builder.push_record(["Tags".to_string(), details.tags.join(", ")]);
+    builder.push_record(["Resume".to_string(), or_na(&details.resume)]);
+    builder.push_record(["Cover Letter".to_string(), or_na(&details.cover_letter)]);
+    for (field_name, value) in &details.custom_fields {
+        builder.push_record([field_name.clone(), value.clone()]);
+    }
+
+    let mut table = builder.build();
+    table
+        .with(Style::blank())
+        .modify(Columns::first(), Color::FG_BRIGHT_CYAN)
+        .modify(Columns::one(1), Width::wrap(80).keep_words(true))
+        .modify(Locator::content("GHOSTED"), Color::rgb_fg(133, 133, 133))
+        .modify(Locator::content("HIRED"), Color::FG_BRIGHT_GREEN)
+        .modify(Locator::content("IN PROGRESS"), Color::FG_BRIGHT_YELLOW)
+        .modify(
+            Locator::content("NOT HIRING ANYMORE"),
+            Color::rgb_fg(117, 117, 117),
+        )
+        .modify(Locator::content("OFFER RECEIVED"), Color::FG_BRIGHT_MAGENTA)
+        .modify(Locator::content("PENDING"), Color::FG_BRIGHT_BLUE)
+        .modify(Locator::content("REJECTED"), Color::FG_BRIGHT_RED)
+        .with(Panel::header(
+            format!(
+                "{} - {}",
+                details.company_name,
+                details.title.as_deref().unwrap_or("N/A")
+            )
+            .green()
+            .bold()
+            .to_string(),
+        ));
+
+    println!("\n{table}\n");
+}
+
+/// Display a summary of the job applications changed by a bulk update.
+pub fn display_job_changes(changes: &Vec<TabledJobChange>) {
+    let mut table = Table::new(changes);
+
+    table
+        .with(Style::blank())
+        .with(Panel::header(
+            format!("UPDATED {} JOBS", changes.len())
+                .green()
+                .bold()
+                .to_string(),
+        ))
+        .with(Modify::list(Rows::first(), Alignment::center()))
+        .with(Modify::list(Rows::one(1), Color::FG_BRIGHT_CYAN))
+        .modify(Columns::one(1), Width::truncate(22).suffix("..."))
+        .modify(Columns::one(4), Width::wrap(40).keep_words(true));
+
+    println!("\n{table}\n");
+}
+
+/// Display the job applications in the trash.
+pub fn display_trashed_jobs(trashed_jobs: &Vec<TabledTrashedJob>) {
+    let mut table = Table::new(trashed_jobs);
+
+    table
+        .with(Style::blank())
+        .with(Panel::header(
+            format!("TRASH [{} JOBS]", trashed_jobs.len())
+                .red()
+                .bold()
+                .to_string(),
+        ))
+        .with(Modify::list(Rows::first(), Alignment::center()))
+        .with(Modify::list(Rows::one(1), Color::FG_BRIGHT_CYAN))
+        .modify(Columns::one(2), Width::truncate(22).suffix("..."))
+        .modify(Columns::one(3), Width::truncate(35).suffix("..."));
+
+    println!("\n{table}\n");
+}
+
+/// Display full-text search results in order of relevance.
+pub fn display_search_results(results: &Vec<SearchResult>, query: &str) {
+    let mut table = Table::new(results);
+
+    table
+        .with(Style::blank())
+        .with(Panel::header(
+            format!("SEARCH RESULTS FOR \"{query}\" [{} JOBS]", results.len())
+                .green()
+                .bold()
+                .to_string(),
+        ))
+        .with(Modify::list(Rows::first(), Alignment::center()))
+        .with(Modify::list(Rows::one(1), Color::FG_BRIGHT_CYAN))
+        .modify(Columns::one(2), Width::truncate(22).suffix("..."))
+        .modify(Columns::one(3), Width::truncate(35).suffix("..."))
+        .modify(Columns::one(5), Width::wrap(60).keep_words(true))
+        .modify(Locator::content("GHOSTED"), Color::rgb_fg(133, 133, 133))
+        .modify(Locator::content("HIRED"), Color::FG_BRIGHT_GREEN)
+        .modify(Locator::content("IN PROGRESS"), Color::FG_BRIGHT_YELLOW)
+        .modify(
+            Locator::content("NOT HIRING ANYMORE"),
+            Color::rgb_fg(117, 117, 117),
+        )
+        .modify(Locator::content("OFFER RECEIVED"), Color::FG_BRIGHT_MAGENTA)
+        .modify(Locator::content("PENDING"), Color::FG_BRIGHT_BLUE)
+        .modify(Locator::content("REJECTED"), Color::FG_BRIGHT_RED);
+
+    println!("\n{table}\n");
+}
+
+/// Display operations from the operation log.
+pub fn display_operations(operations: &Vec<TabledOperation>, table_header: &str) {
+    let mut table = Table::new(operations);
+
+    table
+        .with(Style::blank())
+        .with(Panel::header(table_header.green().bold().to_string()))
+        .with(Modify::list(Rows::first(), Alignment::center()))
+        .with(Modify::list(Rows::one(1), Color::FG_BRIGHT_CYAN))
+        .modify(Columns::one(2), Width::truncate(30).suffix("..."))
+        .modify(Columns::one(3), Width::wrap(50));
+
+    println!("\n{table}\n");
+}
+
 /// Display sprint metadata.
 pub fn display_sprint(queried_sprints: &Vec<QueriedSprint>, table_header: &str) {
     let mut table = Table::new(queried_sprints);
@@ -83,13 +480,107 @@ pub fn display_sprint(queried_sprints: &Vec<QueriedSprint>, table_header: &str)
     println!("\n{table}\n");
 }
 
-/// Display insights information.
-pub fn display_insights(
-    count_and_percentages: Vec<CountAndPercentage>,
+/// Display all tags and the number of job applications they are applied to.
+pub fn display_tags(tabled_tags: &Vec<TabledTag>) {
+    let mut table = Table::new(tabled_tags);
+
+    table
+        .with(Panel::header("ALL TAGS".green().bold().to_string()))
+        .with(Modify::list(Rows::first(), Alignment::center()))
+        .with(Modify::list(Rows::one(1), Color::FG_BRIGHT_BLUE))
+        .with(Style::blank());
+
+    println!("\n{table}\n");
+}
+
+/// Display all saved views and their query options.
+pub fn display_views(views: &BTreeMap<String, SavedView>) {
+    let mut builder = Builder::default();
+    builder.push_record(["Name", "Query", "Format"]);
+    for (name, view) in views {
+        builder.push_record([
+            format!("@{name}"),
+            view.describe_query(),
+            view.format.to_string(),
+        ]);
+    }
+
+    let mut table = builder.build();
+    table
+        .with(Panel::header("ALL SAVED VIEWS".green().bold().to_string()))
+        .with(Modify::list(Rows::first(), Alignment::center()))
+        .with(Modify::list(Rows::one(1), Color::FG_BRIGHT_BLUE))
+        .with(Style::blank())
+        .modify(Columns::one(1), Width::wrap(80).keep_words(true));
+
+    println!("\n{table}\n");
+}
+
+/// Display all custom field definitions.
+pub fn display_custom_fields(custom_fields: &Vec<QueriedCustomField>) {
+    let mut table = Table::new(custom_fields);
+
+    table
+        .with(Panel::header(
+            "ALL CUSTOM FIELDS".green().bold().to_string(),
+        ))
+        .with(Modify::list(Rows::first(), Alignment::center()))
+        .with(Modify::list(Rows::one(1), Color::FG_BRIGHT_BLUE))
+        .with(Style::blank());
+
+    println!("\n{table}\n");
+}
+
+/// Display all resume and cover letter versions.
+pub fn display_documents(documents: &Vec<QueriedDocument>) {
+    let mut table = Table::new(documents);
+
+    table
+        .with(Panel::header(
+            "ALL RESUMES AND COVER LETTERS".green().bold().to_string(),
+        ))
+        .with(Modify::list(Rows::first(), Alignment::center()))
+        .with(Modify::list(Rows::one(1), Color::FG_BRIGHT_BLUE))
+        .with(Style::blank());
+
+    println!("\n{table}\n");
+}
+
+/// Display the notes logged for a job application in chronological order.
+pub fn display_notes(notes: &Vec<QueriedJobNote>) {
+    let mut table = Table::new(notes);
+
+    table
+        .with(Panel::header("NOTES".green().bold().to_string()))
+        .with(Modify::list(Rows::first(), Alignment::center()))
+        .with(Modify::list(Rows::one(1), Color::FG_BRIGHT_BLUE))
+        .with(Style::blank())
+        .modify(Columns::one(1), Width::wrap(80).keep_words(true));
+
+    println!("\n{table}\n");
+}
+
+/// Display the files attached to a job application.
+pub fn display_attachments(attachments: &Vec<QueriedAttachment>) {
+    let mut table = Table::new(attachments);
+
+    table
+        .with(Panel::header("ATTACHMENTS".green().bold().to_string()))
+        .with(Modify::list(Rows::first(), Alignment::center()))
+        .with(Modify::list(Rows::one(1), Color::FG_BRIGHT_BLUE))
+        .with(Style::blank());
+
+    println!("\n{table}\n");
+}
+
+/// Display insights information. This generic function works with any insight struct that
+/// implements `Tabled`.
+pub fn display_insights<T: Tabled>(
+    insights: Vec<T>,
     table_header: &str,
     remove_current_sprint: bool,
 ) {
-    let mut table = Table::new(count_and_percentages);
+    let mut table = Table::new(insights);
 
     if remove_current_sprint {
         table.with(Remove::column(Columns::one(2)));
@@ -103,3 +594,40 @@ pub fn display_insights(
 
     println!("\n{table}\n");
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_shorten() {
+        assert_eq!(shorten("Initech Systems", Some(10)), "Initech...");
+        assert_eq!(shorten("Initech", Some(10)), "Initech");
+        assert_eq!(shorten("Initech Systems", None), "Initech Systems");
+    }
+
+    #[test]
+    fn test_fit_widths_caps_long_columns() {
+        let columns = vec![JobColumn::Id, JobColumn::Link, JobColumn::Notes];
+        let widths = fit_widths(&columns, &[2, 80, 10], 100, None);
+        assert_eq!(widths, vec![2, 40, 10]);
+    }
+
+    #[test]
+    fn test_fit_widths_shrinks_notes_then_link() {
+        let columns = vec![JobColumn::Id, JobColumn::Link, JobColumn::Notes];
+
+        // Capping links and notes makes the table 140 - 40 - 10 = 90 wide, so notes are shrunk by
+        // 10 to fit 80 columns.
+        let widths = fit_widths(&columns, &[2, 80, 50], 140, Some(80));
+        assert_eq!(widths, vec![2, 40, 30]);
+
+        // Notes stop at their minimum width before links are shrunk.
+        let widths = fit_widths(&columns, &[2, 80, 50], 140, Some(60));
+        assert_eq!(widths, vec![2, 34, 16]);
+
+        // Columns that cannot be shrunk are left as they are.
+        let widths = fit_widths(&columns, &[2, 80, 50], 140, Some(10));
+        assert_eq!(widths, vec![2, 12, 16]);
+    }
+}
@@ -0,0 +1,67 @@
+//! Contains utility functions for hashing and selecting resume and cover letter versions.
+
+use std::fs;
+
+use diesel::SqliteConnection;
+use inquire::Select;
+use sha2::{Digest, Sha256};
+
+use crate::{
+    errors::FettersError,
+    models::document::{DocumentKind, QueriedDocument},
+    repositories::document::DocumentRepository,
+    utils::prompt::get_inquire_config,
+};
+
+/// Compute the SHA-256 checksum of a file as a lowercase hex string.
+pub fn compute_checksum(path: &str) -> Result<String, FettersError> {
+    let contents = fs::read(path)?;
+
+    Ok(format!("{:x}", Sha256::digest(contents)))
+}
+
+/// Select the version of a document submitted with a job application. Documents are optional, so
+/// `None` is returned if the user skips this step or no documents of this kind have been added.
+pub fn select_document(
+    connection: &mut SqliteConnection,
+    kind: DocumentKind,
+) -> Result<Option<QueriedDocument>, FettersError> {
+    let mut document_repo = DocumentRepository { connection };
+    let documents = document_repo.get_all_documents(Some(&kind.to_string()))?;
+
+    if documents.is_empty() {
+        return Ok(None);
+    }
+
+    Ok(Select::new(
+        &format!(
+            "[OPTIONAL] Select the {} version submitted with this application:",
+            kind.to_string().to_lowercase()
+        ),
+        documents,
+    )
+    .with_render_config(get_inquire_config())
+    .prompt_skippable()?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_compute_checksum() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("resume.txt");
+        fs::write(&path, "test").unwrap();
+
+        assert_eq!(
+            compute_checksum(path.to_str().unwrap()).unwrap(),
+            "9f86d081884c7d659a2feaa0c55ad015a3bf4f1b2b0b822cd15d6c15b0f00a08"
+        );
+    }
+
+    #[test]
+    fn test_compute_checksum_missing_file_fails() {
+        assert!(compute_checksum("/nonexistent/resume.pdf").is_err());
+    }
+}
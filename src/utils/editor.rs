@@ -0,0 +1,441 @@
+//! Contains utilities for editing a job application as a TOML document.
+
+use std::collections::HashSet;
+use std::fmt::{self, Display, Formatter};
+use std::str::FromStr;
+
+use chrono::NaiveDate;
+use owo_colors::OwoColorize;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    errors::FettersError,
+    models::{job::JobDetails, note::QueriedJobNote, stage::StageStatus},
+};
+
+/// A job application as it is written to and read from the TOML document. Optional attributes are
+/// represented by empty strings since TOML has no null value.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct EditableJob {
+    /// The name of the company.
+    pub company_name: String,
+    /// The job title. A new title is created if it does not exist yet.
+    pub title: String,
+    /// The application status. Must be one of the stored statuses.
+    pub status: String,
+    /// The name of the sprint. Must be one of the stored sprints.
+    pub sprint: String,
+    /// The link to the job application.
+    #[serde(default)]
+    pub link: String,
+    /// A new note to add to the notes log. Existing notes cannot be edited.
+    #[serde(default)]
+    pub new_note: String,
+    /// The interview stages in order. Stages are numbered by their position.
+    #[serde(default, rename = "stage")]
+    pub stages: Vec<EditableStage>,
+}
+
+/// An interview stage as it is written to and read from the TOML document.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct EditableStage {
+    /// The SQLite ID of an existing stage. New stages do not have an ID.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<i32>,
+    /// The name of the stage.
+    #[serde(default)]
+    pub name: String,
+    /// The stage status ("SCHEDULED", "PASSED", or "REJECTED").
+    pub status: String,
+    /// The date associated with this stage (formatted as YYYY/MM/DD).
+    pub date: String,
+    /// Any notes about this stage.
+    #[serde(default)]
+    pub notes: String,
+}
+
+impl Display for EditableStage {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        if !self.name.is_empty() {
+            write!(f, "{} ", self.name)?;
+        }
+        write!(f, "[{}] {}", self.status, self.date)?;
+        if !self.notes.is_empty() {
+            write!(f, " - {}", self.notes)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl EditableJob {
+    /// Create an editable job from everything stored about a job application.
+    pub fn from_details(details: &JobDetails) -> Self {
+        EditableJob {
+            company_name: details.company_name.clone(),
+            title: details.title.clone().unwrap_or_default(),
+            status: details.status.clone().unwrap_or_default(),
+            sprint: details.sprint.clone(),
+            link: details.link.clone().unwrap_or_default(),
+            new_note: String::new(),
+            stages: details
+                .stages
+                .iter()
+                .map(|stage| EditableStage {
+                    id: Some(stage.id),
+                    name: stage.name.clone().unwrap_or_default(),
+                    status: stage.status.clone(),
+                    date: stage.scheduled_date.clone(),
+                    notes: stage.notes.clone().unwrap_or_default(),
+                })
+                .collect(),
+        }
+    }
+
+    /// Trim every value and uppercase the statuses, then check that the job is valid. `statuses`
+    /// and `sprints` contain the names of every stored status and sprint. `stage_ids` contains the
+    /// IDs of the stages that currently belong to this job.
+    pub fn normalize_and_validate(
+        &mut self,
+        statuses: &[String],
+        sprints: &[String],
+        stage_ids: &[i32],
+    ) -> Result<(), FettersError> {
+        self.company_name = self.company_name.trim().to_string();
+        self.title = self.title.trim().to_string();
+        self.status = self.status.trim().to_uppercase();
+        self.sprint = self.sprint.trim().to_string();
+        self.link = self.link.trim().to_string();
+        self.new_note = self.new_note.trim().to_string();
+
+        if self.company_name.is_empty() {
+            return Err(FettersError::InvalidEdit(
+                "company_name cannot be empty.".to_string(),
+            ));
+        }
+        if self.title.is_empty() {
+            return Err(FettersError::InvalidEdit(
+                "title cannot be empty.".to_string(),
+            ));
+        }
+        if !statuses.contains(&self.status) {
+            return Err(FettersError::InvalidEdit(format!(
+                "status must be one of {}.",
+                statuses.join(", ")
+            )));
+        }
+        if !sprints.contains(&self.sprint) {
+            return Err(FettersError::InvalidEdit(format!(
+                "There is no sprint named {}.",
+                self.sprint
+            )));
+        }
+
+        let mut seen_ids = HashSet::new();
+        for (index, stage) in self.stages.iter_mut().enumerate() {
+            let stage_number = index + 1;
+
+            if let Some(id) = stage.id
+                && (!stage_ids.contains(&id) || !seen_ids.insert(id))
+            {
+                return Err(FettersError::InvalidEdit(format!(
+                    "Stage {stage_number} has an unknown or duplicate id {id}. Remove the id to add a new stage."
+                )));
+            }
+
+            stage.name = stage.name.trim().to_string();
+            stage.notes = stage.notes.trim().to_string();
+            stage.date = stage.date.trim().to_string();
+            stage.status = StageStatus::from_str(stage.status.trim())
+                .map_err(|error| {
+                    FettersError::InvalidEdit(format!("Stage {stage_number}: {error}"))
+                })?
+                .to_string();
+
+            if NaiveDate::parse_from_str(&stage.date, "%Y/%m/%d").is_err() {
+                return Err(FettersError::InvalidEdit(format!(
+                    "Stage {stage_number}: the date must be formatted as YYYY/MM/DD."
+                )));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Render the TOML document that is opened in `$EDITOR`. The existing notes are included as
+/// comments for reference.
+pub fn to_toml_document(
+    job: &EditableJob,
+    notes: &[QueriedJobNote],
+) -> Result<String, FettersError> {
+    let mut document = String::from(
+        "# Edit this job application and save the file to preview your changes.\n\
+         # - status must be one of the stored statuses (ie. PENDING or REJECTED).\n\
+         # - sprint must be the name of an existing sprint.\n\
+         # - new_note is added to the notes log. Existing notes cannot be edited.\n\
+         # - Stages are numbered in order. Remove a [[stage]] to delete it, or add one without an\n\
+         #   id to create it. Stage statuses are SCHEDULED, PASSED, or REJECTED.\n",
+    );
+
+    if !notes.is_empty() {
+        document.push_str("#\n# Notes:\n");
+        for note in notes {
+            for (index, line) in note.content.lines().enumerate() {
+                if index == 0 {
+                    document.push_str(&format!("#   [{}] {line}\n", note.created));
+                } else {
+                    document.push_str(&format!("#   {line}\n"));
+                }
+            }
+        }
+    }
+
+    document.push('\n');
+    document.push_str(&toml::to_string_pretty(job)?);
+
+    Ok(document)
+}
+
+/// Parse the TOML document saved in `$EDITOR`. Comments are ignored.
+pub fn parse_toml_document(document: &str) -> Result<EditableJob, FettersError> {
+    Ok(toml::from_str(document)?)
+}
+
+/// Describe the changes between two versions of a job application as colored lines. Stages are
+/// matched by ID, so new, removed, moved, and modified stages are listed separately.
+pub fn diff_jobs(before: &EditableJob, after: &EditableJob) -> Vec<String> {
+    let mut changes = Vec::new();
+
+    for (label, old_value, new_value) in [
+        ("Company Name", &before.company_name, &after.company_name),
+        ("Title", &before.title, &after.title),
+        ("Status", &before.status, &after.status),
+        ("Sprint", &before.sprint, &after.sprint),
+        ("Link", &before.link, &after.link),
+    ] {
+        if old_value != new_value {
+            changes.push(format!(
+                "{label}: {} -> {}",
+                display_value(old_value).red(),
+                display_value(new_value).green()
+            ));
+        }
+    }
+
+    if !after.new_note.is_empty() {
+        changes.push(format!("New Note: {}", after.new_note.green()));
+    }
+
+    for (index, stage) in before.stages.iter().enumerate() {
+        if !after
+            .stages
+            .iter()
+            .any(|new_stage| new_stage.id == stage.id)
+        {
+            changes.push(format!("- Stage {}: {stage}", index + 1).red().to_string());
+        }
+    }
+
+    for (index, stage) in after.stages.iter().enumerate() {
+        let stage_number = index + 1;
+
+        match before
+            .stages
+            .iter()
+            .enumerate()
+            .find(|(_, old_stage)| stage.id.is_some() && old_stage.id == stage.id)
+        {
+            None => changes.push(
+                format!("+ Stage {stage_number}: {stage}")
+                    .green()
+                    .to_string(),
+            ),
+            Some((old_index, old_stage)) if old_index != index || old_stage != stage => {
+                changes.push(format!(
+                    "~ Stage {} -> {stage_number}: {} -> {}",
+                    old_index + 1,
+                    old_stage.to_string().red(),
+                    stage.to_string().green()
+                ));
+            }
+            Some(_) => {}
+        }
+    }
+
+    changes
+}
+
+/// Empty values are displayed as "N/A".
+fn display_value(value: &str) -> &str {
+    if value.is_empty() { "N/A" } else { value }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn stage(id: Option<i32>, name: &str, status: &str) -> EditableStage {
+        EditableStage {
+            id,
+            name: name.to_string(),
+            status: status.to_string(),
+            date: "2026/05/10".to_string(),
+            notes: String::new(),
+        }
+    }
+
+    fn editable_job() -> EditableJob {
+        EditableJob {
+            company_name: "Stripe".to_string(),
+            title: "Software Engineer".to_string(),
+            status: "IN PROGRESS".to_string(),
+            sprint: "2026-05-01".to_string(),
+            link: "https://stripe.com/jobs".to_string(),
+            new_note: String::new(),
+            stages: vec![
+                stage(Some(1), "Phone Screen", "PASSED"),
+                stage(Some(2), "Onsite", "SCHEDULED"),
+            ],
+        }
+    }
+
+    fn statuses() -> Vec<String> {
+        vec![
+            "PENDING".to_string(),
+            "IN PROGRESS".to_string(),
+            "REJECTED".to_string(),
+        ]
+    }
+
+    fn sprints() -> Vec<String> {
+        vec!["2026-05-01".to_string()]
+    }
+
+    #[test]
+    fn test_toml_document_roundtrip() {
+        let job = editable_job();
+        let notes = vec![QueriedJobNote {
+            created: "2026-05-03 09:24:17".to_string(),
+            content: "Referred by Jane\nFollow up next week".to_string(),
+        }];
+
+        let document = to_toml_document(&job, &notes).unwrap();
+        assert!(document.contains("#   [2026-05-03 09:24:17] Referred by Jane"));
+        assert!(document.contains("#   Follow up next week"));
+        assert!(document.contains("[[stage]]"));
+
+        assert_eq!(parse_toml_document(&document).unwrap(), job);
+    }
+
+    #[test]
+    fn test_parse_toml_document_defaults_optional_values() {
+        let job = parse_toml_document(
+            r#"
+company_name = "Plaid"
+title = "SWE"
+status = "pending"
+sprint = "2026-05-01"
+
+[[stage]]
+status = "scheduled"
+date = "2026/05/12"
+"#,
+        )
+        .unwrap();
+
+        assert_eq!(job.link, "");
+        assert_eq!(job.stages.len(), 1);
+        assert_eq!(job.stages[0].id, None);
+        assert!(parse_toml_document("company_name = \"Plaid\"").is_err());
+    }
+
+    #[test]
+    fn test_normalize_and_validate() {
+        let mut job = editable_job();
+        job.status = " rejected ".to_string();
+        job.stages[1].status = "passed".to_string();
+        job.stages.push(stage(None, "Offer Call", "scheduled"));
+
+        job.normalize_and_validate(&statuses(), &sprints(), &[1, 2])
+            .unwrap();
+        assert_eq!(job.status, "REJECTED");
+        assert_eq!(job.stages[1].status, "PASSED");
+        assert_eq!(job.stages[2].status, "SCHEDULED");
+    }
+
+    #[test]
+    fn test_normalize_and_validate_rejects_invalid_values() {
+        let mut job = editable_job();
+        job.company_name = "  ".to_string();
+        assert!(
+            job.normalize_and_validate(&statuses(), &sprints(), &[1, 2])
+                .is_err()
+        );
+
+        let mut job = editable_job();
+        job.status = "HIRED".to_string();
+        assert!(
+            job.normalize_and_validate(&statuses(), &sprints(), &[1, 2])
+                .is_err()
+        );
+
+        let mut job = editable_job();
+        job.sprint = "missing".to_string();
+        assert!(
+            job.normalize_and_validate(&statuses(), &sprints(), &[1, 2])
+                .is_err()
+        );
+
+        let mut job = editable_job();
+        job.stages[0].date = "2026-05-10".to_string();
+        assert!(
+            job.normalize_and_validate(&statuses(), &sprints(), &[1, 2])
+                .is_err()
+        );
+
+        let mut job = editable_job();
+        job.stages[0].status = "GHOSTED".to_string();
+        assert!(
+            job.normalize_and_validate(&statuses(), &sprints(), &[1, 2])
+                .is_err()
+        );
+
+        let mut job = editable_job();
+        job.stages[1].id = Some(1);
+        assert!(
+            job.normalize_and_validate(&statuses(), &sprints(), &[1, 2])
+                .is_err()
+        );
+
+        let mut job = editable_job();
+        job.stages[1].id = Some(99);
+        assert!(
+            job.normalize_and_validate(&statuses(), &sprints(), &[1, 2])
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_diff_jobs() {
+        let before = editable_job();
+        assert!(diff_jobs(&before, &before).is_empty());
+
+        let mut after = before.clone();
+        after.status = "REJECTED".to_string();
+        after.link = String::new();
+        after.new_note = "Rejected after the onsite".to_string();
+        after.stages.remove(0);
+        after.stages[0].status = "REJECTED".to_string();
+        after.stages.push(stage(None, "Feedback Call", "SCHEDULED"));
+
+        let changes = diff_jobs(&before, &after);
+        assert_eq!(changes.len(), 6);
+        assert!(changes[0].starts_with("Status: "));
+        assert!(changes[1].contains("N/A"));
+        assert!(changes[2].contains("Rejected after the onsite"));
+        assert!(changes[3].contains("- Stage 1: Phone Screen [PASSED]"));
+        assert!(changes[4].contains("~ Stage 2 -> 1: "));
+        assert!(changes[5].contains("+ Stage 2: Feedback Call [SCHEDULED] 2026/05/10"));
+    }
+}
@@ -0,0 +1,755 @@
+//! Contains the parser and compiler for `--where` filter expressions.
+//!
+//! A filter expression combines conditions with `AND`, `OR`, `NOT`, and parentheses, ie.
+//! `status:(REJECTED|GHOSTED) AND title:backend AND created>2026-01-01 AND NOT company:acme`.
+//! Conditions next to each other without an operator are ANDed together.
+
+use std::fmt::{self, Display, Formatter};
+use std::str::FromStr;
+
+use chrono::{Duration, NaiveDate};
+use diesel::BoxableExpression;
+use diesel::dsl::{not, sql};
+use diesel::expression::is_aggregate;
+use diesel::prelude::*;
+use diesel::sql_types::{Bool, Integer, Text};
+use diesel::sqlite::Sqlite;
+use serde::de::Error as DeError;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use strum::{Display as StrumDisplay, EnumIter, EnumString, IntoEnumIterator};
+
+use crate::cli::DateBound;
+use crate::models::tag::normalize_tag_name;
+
+/// A compiled filter expression that can be applied to the job listing query.
+pub type JobFilter<QS> =
+    Box<dyn BoxableExpression<QS, Sqlite, (), is_aggregate::Never, SqlType = Bool>>;
+
+/// The job attributes that may be used in a filter expression.
+#[derive(Clone, Copy, Debug, EnumIter, EnumString, PartialEq, StrumDisplay)]
+#[strum(serialize_all = "lowercase", ascii_case_insensitive)]
+pub enum WhereField {
+    /// The name of the company.
+    Company,
+    /// The timestamp at which the job application was created.
+    Created,
+    /// The stored job description.
+    Description,
+    /// The SQLite ID.
+    Id,
+    /// The link to the job application.
+    Link,
+    /// Any note logged for the job application.
+    Notes,
+    /// The contact who referred you.
+    Referrer,
+    /// The application source.
+    Source,
+    /// The sprint name.
+    Sprint,
+    /// The number of interview stages.
+    Stages,
+    /// The application status.
+    Status,
+    /// A tag applied to the job application.
+    Tag,
+    /// The job title.
+    Title,
+}
+
+impl WhereField {
+    /// Whether this field holds text, which only supports the `:` and `=` operators.
+    fn is_text(&self) -> bool {
+        !matches!(
+            self,
+            WhereField::Created | WhereField::Id | WhereField::Stages
+        )
+    }
+}
+
+/// The operators that compare a field to a value.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum WhereOperator {
+    /// `:` matches partial text for text fields and equal values for everything else.
+    Matches,
+    /// `=` matches the whole value.
+    Equals,
+    /// `>`
+    GreaterThan,
+    /// `>=`
+    GreaterOrEqual,
+    /// `<`
+    LessThan,
+    /// `<=`
+    LessOrEqual,
+}
+
+/// A value compared against a field.
+#[derive(Clone, Debug, PartialEq)]
+pub enum WhereValue {
+    /// Text compared against text fields.
+    Text(String),
+    /// A date compared against the created timestamp.
+    Date(DateBound),
+    /// A number compared against IDs and stage counts.
+    Number(i32),
+}
+
+/// A parsed filter expression.
+#[derive(Clone, Debug, PartialEq)]
+pub enum WhereExpr {
+    /// Both expressions must match.
+    And(Box<WhereExpr>, Box<WhereExpr>),
+    /// Either expression must match.
+    Or(Box<WhereExpr>, Box<WhereExpr>),
+    /// The expression must not match.
+    Not(Box<WhereExpr>),
+    /// A field must match any of the values, ie. `status:(REJECTED|GHOSTED)`.
+    Condition {
+        /// The field to compare.
+        field: WhereField,
+        /// How the field is compared to the values.
+        operator: WhereOperator,
+        /// The values to compare the field against.
+        values: Vec<WhereValue>,
+    },
+}
+
+impl WhereExpr {
+    /// Whether any condition in this expression uses the given field.
+    pub fn uses_field(&self, target: WhereField) -> bool {
+        match self {
+            WhereExpr::And(left, right) | WhereExpr::Or(left, right) => {
+                left.uses_field(target) || right.uses_field(target)
+            }
+            WhereExpr::Not(expr) => expr.uses_field(target),
+            WhereExpr::Condition { field, .. } => *field == target,
+        }
+    }
+
+    /// Compile this expression into a filter for the job listing query. Relative dates are
+    /// counted back from `today`. Table names match the joins made in `JobRepository::list_jobs`.
+    pub fn compile<QS: 'static>(&self, today: NaiveDate) -> JobFilter<QS> {
+        match self {
+            WhereExpr::And(left, right) => {
+                Box::new(left.compile::<QS>(today).and(right.compile::<QS>(today)))
+            }
+            WhereExpr::Or(left, right) => {
+                Box::new(left.compile::<QS>(today).or(right.compile::<QS>(today)))
+            }
+            WhereExpr::Not(expr) => Box::new(not(expr.compile::<QS>(today))),
+            WhereExpr::Condition {
+                field,
+                operator,
+                values,
+            } => values
+                .iter()
+                .map(|value| compile_condition::<QS>(*field, *operator, value, today))
+                .reduce(|left, right| Box::new(left.or(right)))
+                .unwrap_or_else(|| Box::new(sql::<Bool>("0"))),
+        }
+    }
+}
+
+/// Compile a single `field operator value` comparison.
+fn compile_condition<QS: 'static>(
+    field: WhereField,
+    operator: WhereOperator,
+    value: &WhereValue,
+    today: NaiveDate,
+) -> JobFilter<QS> {
+    match value {
+        WhereValue::Text(text) => {
+            // `COALESCE` keeps missing values from turning `NOT` conditions into NULL.
+            let column = match field {
+                WhereField::Company => "jobs.company_name",
+                WhereField::Link => "COALESCE(jobs.link, '')",
+                WhereField::Referrer => "COALESCE(jobs.referrer, '')",
+                WhereField::Source => "COALESCE(sources.name, '')",
+                WhereField::Sprint => "COALESCE(sprints.name, '')",
+                WhereField::Status => "COALESCE(statuses.name, '')",
+                WhereField::Title => "COALESCE(titles.name, '')",
+                WhereField::Notes => {
+                    return text_subquery(
+                        "EXISTS (SELECT 1 FROM job_notes WHERE job_notes.job_id = jobs.id AND job_notes.content",
+                        operator,
+                        text,
+                    );
+                }
+                WhereField::Description => {
+                    return text_subquery(
+                        "EXISTS (SELECT 1 FROM job_descriptions WHERE job_descriptions.job_id = jobs.id AND job_descriptions.content",
+                        operator,
+                        text,
+                    );
+                }
+                WhereField::Tag => {
+                    // Tags are matched by their whole name.
+                    return Box::new(
+                        sql::<Bool>(
+                            "EXISTS (SELECT 1 FROM job_tags INNER JOIN tags ON tags.id = job_tags.tag_id WHERE job_tags.job_id = jobs.id AND tags.name = ",
+                        )
+                        .bind::<Text, _>(normalize_tag_name(text))
+                        .sql(")"),
+                    );
+                }
+                WhereField::Created | WhereField::Id | WhereField::Stages => {
+                    unreachable!("{field} does not hold text")
+                }
+            };
+
+            match operator {
+                WhereOperator::Equals => Box::new(
+                    sql::<Bool>(&format!("{column} = "))
+                        .bind::<Text, _>(text.to_string())
+                        .sql(" COLLATE NOCASE"),
+                ),
+                _ => Box::new(
+                    sql::<Bool>(&format!("{column} LIKE ")).bind::<Text, _>(format!("%{text}%")),
+                ),
+            }
+        }
+        WhereValue::Date(date) => {
+            let date = date.resolve(today);
+            let start = date.format("%Y-%m-%d").to_string();
+            let next_day = (date + Duration::days(1)).format("%Y-%m-%d").to_string();
+
+            // Created timestamps are `YYYY-MM-DD HH:MM:SS`, so a date covers every timestamp from
+            // the start of that day up to the start of the next day.
+            let compare = |sql_operator: &str, bound: String| -> JobFilter<QS> {
+                Box::new(
+                    sql::<Bool>(&format!("jobs.created {sql_operator} ")).bind::<Text, _>(bound),
+                )
+            };
+
+            match operator {
+                WhereOperator::Matches | WhereOperator::Equals => {
+                    Box::new(compare(">=", start).and(compare("<", next_day)))
+                }
+                WhereOperator::GreaterThan => compare(">=", next_day),
+                WhereOperator::GreaterOrEqual => compare(">=", start),
+                WhereOperator::LessThan => compare("<", start),
+                WhereOperator::LessOrEqual => compare("<", next_day),
+            }
+        }
+        WhereValue::Number(number) => {
+            let column = match field {
+                WhereField::Stages => {
+                    "(SELECT COUNT(*) FROM interview_stages WHERE interview_stages.job_id = jobs.id)"
+                }
+                _ => "jobs.id",
+            };
+            let sql_operator = match operator {
+                WhereOperator::Matches | WhereOperator::Equals => "=",
+                WhereOperator::GreaterThan => ">",
+                WhereOperator::GreaterOrEqual => ">=",
+                WhereOperator::LessThan => "<",
+                WhereOperator::LessOrEqual => "<=",
+            };
+
+            Box::new(
+                sql::<Bool>(&format!("{column} {sql_operator} ")).bind::<Integer, _>(*number),
+            )
+        }
+    }
+}
+
+/// Compile a text comparison against rows in another table that reference the job.
+fn text_subquery<QS: 'static>(
+    subquery: &str,
+    operator: WhereOperator,
+    text: &str,
+) -> JobFilter<QS> {
+    match operator {
+        WhereOperator::Equals => Box::new(
+            sql::<Bool>(&format!("{subquery} = "))
+                .bind::<Text, _>(text.to_string())
+                .sql(" COLLATE NOCASE)"),
+        ),
+        _ => Box::new(
+            sql::<Bool>(&format!("{subquery} LIKE "))
+                .bind::<Text, _>(format!("%{text}%"))
+                .sql(")"),
+        ),
+    }
+}
+
+/// A filter expression passed to `--where`. The original text is kept so the expression can be
+/// displayed and saved as it was written.
+#[derive(Clone, Debug, PartialEq)]
+pub struct WhereClause {
+    /// The expression as it was written.
+    pub source: String,
+    /// The parsed expression.
+    pub expr: WhereExpr,
+}
+
+impl WhereClause {
+    /// Combine two expressions so that jobs have to match both of them.
+    pub fn and(self, other: WhereClause) -> WhereClause {
+        WhereClause {
+            source: format!("({}) AND ({})", self.source, other.source),
+            expr: WhereExpr::And(Box::new(self.expr), Box::new(other.expr)),
+        }
+    }
+}
+
+impl FromStr for WhereClause {
+    type Err = String;
+
+    fn from_str(source: &str) -> Result<Self, Self::Err> {
+        let mut parser = Parser {
+            chars: source.chars().collect(),
+            position: 0,
+        };
+
+        let expr = parser
+            .parse_expression()
+            .map_err(|error| error.render(source))?;
+
+        Ok(WhereClause {
+            source: source.to_string(),
+            expr,
+        })
+    }
+}
+
+impl Display for WhereClause {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.source)
+    }
+}
+
+/// Expressions are saved as they were written and parsed again when a saved view is loaded.
+impl Serialize for WhereClause {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.source)
+    }
+}
+
+impl<'de> Deserialize<'de> for WhereClause {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(DeError::custom)
+    }
+}
+
+/// An error raised while parsing a filter expression, pointing at the offending character.
+#[derive(Debug, PartialEq)]
+struct ParseError {
+    /// What went wrong.
+    message: String,
+    /// The index of the offending character.
+    position: usize,
+}
+
+impl ParseError {
+    /// Render the error with the expression and a caret under the offending character.
+    fn render(&self, source: &str) -> String {
+        format!(
+            "{} (at character {})\n  {source}\n  {}^",
+            self.message,
+            self.position + 1,
+            " ".repeat(self.position)
+        )
+    }
+}
+
+/// A recursive descent parser for filter expressions.
+struct Parser {
+    /// The characters of the expression.
+    chars: Vec<char>,
+    /// The index of the next character to read.
+    position: usize,
+}
+
+impl Parser {
+    /// Parse the whole expression. Any trailing characters are an error.
+    fn parse_expression(&mut self) -> Result<WhereExpr, ParseError> {
+        let expr = self.parse_or()?;
+
+        self.skip_whitespace();
+        match self.peek() {
+            None => Ok(expr),
+            Some(')') => Err(self.error("unexpected ')' without a matching '('")),
+            Some(_) => Err(self.error("expected AND, OR, or the end of the expression")),
+        }
+    }
+
+    /// `and_expr (OR and_expr)*`
+    fn parse_or(&mut self) -> Result<WhereExpr, ParseError> {
+        let mut expr = self.parse_and()?;
+
+        while self.consume_keyword("OR") {
+            let right = self.parse_and()?;
+            expr = WhereExpr::Or(Box::new(expr), Box::new(right));
+        }
+
+        Ok(expr)
+    }
+
+    /// `not_expr ([AND] not_expr)*`
+    fn parse_and(&mut self) -> Result<WhereExpr, ParseError> {
+        let mut expr = self.parse_not()?;
+
+        loop {
+            self.skip_whitespace();
+            if self.peek().is_none() || self.peek() == Some(')') || self.at_keyword("OR") {
+                break;
+            }
+
+            self.consume_keyword("AND");
+            let right = self.parse_not()?;
+            expr = WhereExpr::And(Box::new(expr), Box::new(right));
+        }
+
+        Ok(expr)
+    }
+
+    /// `NOT not_expr | primary`
+    fn parse_not(&mut self) -> Result<WhereExpr, ParseError> {
+        if self.consume_keyword("NOT") {
+            Ok(WhereExpr::Not(Box::new(self.parse_not()?)))
+        } else {
+            self.parse_primary()
+        }
+    }
+
+    /// `( or_expr ) | condition`
+    fn parse_primary(&mut self) -> Result<WhereExpr, ParseError> {
+        self.skip_whitespace();
+
+        if self.peek() == Some('(') {
+            let open_position = self.position;
+            self.position += 1;
+
+            let expr = self.parse_or()?;
+
+            self.skip_whitespace();
+            match self.peek() {
+                Some(')') => {
+                    self.position += 1;
+                    Ok(expr)
+                }
+                None => Err(ParseError {
+                    message: "unclosed '('".to_string(),
+                    position: open_position,
+                }),
+                Some(_) => Err(self.error("expected ')'")),
+            }
+        } else {
+            self.parse_condition()
+        }
+    }
+
+    /// `field operator (value | ( value (| value)* ))`
+    fn parse_condition(&mut self) -> Result<WhereExpr, ParseError> {
+        let field_position = self.position;
+        let name: String = self.take_while(|c| c.is_ascii_alphanumeric() || c == '_');
+
+        if name.is_empty() {
+            return Err(match self.peek() {
+                None => self.error("expected a condition (ie. status:PENDING)"),
+                Some(c) => self.error(&format!("unexpected '{c}', expected a field name")),
+            });
+        }
+
+        let field = WhereField::from_str(&name).map_err(|_| ParseError {
+            message: format!(
+                "unknown field \"{name}\", expected one of: {}",
+                WhereField::iter()
+                    .map(|field| field.to_string())
+                    .collect::<Vec<String>>()
+                    .join(", ")
+            ),
+            position: field_position,
+        })?;
+
+        let operator_position = self.position;
+        let operator = match (self.peek(), self.chars.get(self.position + 1)) {
+            (Some(':'), _) => Some((WhereOperator::Matches, 1)),
+            (Some('='), _) => Some((WhereOperator::Equals, 1)),
+            (Some('>'), Some('=')) => Some((WhereOperator::GreaterOrEqual, 2)),
+            (Some('>'), _) => Some((WhereOperator::GreaterThan, 1)),
+            (Some('<'), Some('=')) => Some((WhereOperator::LessOrEqual, 2)),
+            (Some('<'), _) => Some((WhereOperator::LessThan, 1)),
+            _ => None,
+        };
+        let Some((operator, length)) = operator else {
+            return Err(self.error(&format!(
+                "expected an operator (:, =, >, >=, <, <=) after \"{name}\""
+            )));
+        };
+
+        if field.is_text()
+            && !matches!(operator, WhereOperator::Matches | WhereOperator::Equals)
+        {
+            return Err(ParseError {
+                message: format!("\"{name}\" only supports the ':' and '=' operators"),
+                position: operator_position,
+            });
+        }
+        self.position += length;
+
+        let mut values = Vec::new();
+        if self.peek() == Some('(') {
+            let open_position = self.position;
+            self.position += 1;
+
+            loop {
+                values.push(self.parse_value(field)?);
+
+                match self.peek() {
+                    Some('|') => self.position += 1,
+                    Some(')') => {
+                        self.position += 1;
+                        break;
+                    }
+                    None => {
+                        return Err(ParseError {
+                            message: "unclosed '('".to_string(),
+                            position: open_position,
+                        });
+                    }
+                    Some(_) => return Err(self.error("expected '|' or ')'")),
+                }
+            }
+        } else {
+            values.push(self.parse_value(field)?);
+        }
+
+        Ok(WhereExpr::Condition {
+            field,
+            operator,
+            values,
+        })
+    }
+
+    /// Parse a bare or double-quoted value and convert it to the type held by the field.
+    fn parse_value(&mut self, field: WhereField) -> Result<WhereValue, ParseError> {
+        let value_position = self.position;
+
+        let text = if self.peek() == Some('"') {
+            self.position += 1;
+            let text = self.take_while(|c| c != '"');
+
+            if self.peek() != Some('"') {
+                return Err(ParseError {
+                    message: "unterminated quote".to_string(),
+                    position: value_position,
+                });
+            }
+            self.position += 1;
+
+            text
+        } else {
+            self.take_while(|c| !c.is_whitespace() && !matches!(c, '(' | ')' | '|' | '"'))
+        };
+
+        if text.is_empty() {
+            return Err(self.error("expected a value"));
+        }
+
+        let invalid = |message: String| ParseError {
+            message,
+            position: value_position,
+        };
+
+        match field {
+            WhereField::Created => text
+                .parse::<DateBound>()
+                .map(WhereValue::Date)
+                .map_err(invalid),
+            WhereField::Id | WhereField::Stages => text
+                .parse::<i32>()
+                .map(WhereValue::Number)
+                .map_err(|_| invalid(format!("invalid number \"{text}\""))),
+            _ => Ok(WhereValue::Text(text)),
+        }
+    }
+
+    /// Consume a keyword (case-insensitive) if it is the next word.
+    fn consume_keyword(&mut self, keyword: &str) -> bool {
+        if self.at_keyword(keyword) {
+            self.position += keyword.len();
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Whether the next word is the keyword (case-insensitive). Leading whitespace is skipped.
+    fn at_keyword(&mut self, keyword: &str) -> bool {
+        self.skip_whitespace();
+
+        let end = self.position + keyword.len();
+        if end > self.chars.len() {
+            return false;
+        }
+
+        let word: String = self.chars[self.position..end].iter().collect();
+        let at_boundary = self
+            .chars
+            .get(end)
+            .is_none_or(|c| c.is_whitespace() || *c == '(');
+
+        word.eq_ignore_ascii_case(keyword) && at_boundary
+    }
+
+    /// Read characters while they match the predicate.
+    fn take_while(&mut self, predicate: impl Fn(char) -> bool) -> String {
+        let start = self.position;
+        while self.peek().is_some_and(&predicate) {
+            self.position += 1;
+        }
+
+        self.chars[start..self.position].iter().collect()
+    }
+
+    /// Skip whitespace characters.
+    fn skip_whitespace(&mut self) {
+        self.take_while(char::is_whitespace);
+    }
+
+    /// Look at the next character without reading it.
+    fn peek(&self) -> Option<char> {
+        self.chars.get(self.position).copied()
+    }
+
+    /// Create an error pointing at the next character.
+    fn error(&self, message: &str) -> ParseError {
+        ParseError {
+            message: message.to_string(),
+            position: self.position,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn condition(field: WhereField, operator: WhereOperator, values: &[&str]) -> WhereExpr {
+        WhereExpr::Condition {
+            field,
+            operator,
+            values: values
+                .iter()
+                .map(|value| WhereValue::Text(value.to_string()))
+                .collect(),
+        }
+    }
+
+    fn parse(source: &str) -> WhereExpr {
+        source.parse::<WhereClause>().unwrap().expr
+    }
+
+    fn parse_error(source: &str) -> ParseError {
+        let mut parser = Parser {
+            chars: source.chars().collect(),
+            position: 0,
+        };
+        parser.parse_expression().unwrap_err()
+    }
+
+    #[test]
+    fn test_parse_condition_with_alternatives() {
+        assert_eq!(
+            parse("status:(REJECTED|GHOSTED)"),
+            condition(
+                WhereField::Status,
+                WhereOperator::Matches,
+                &["REJECTED", "GHOSTED"]
+            )
+        );
+    }
+
+    #[test]
+    fn test_parse_precedence() {
+        // NOT binds tighter than AND, which binds tighter than OR.
+        assert_eq!(
+            parse("company:a OR NOT company:b AND title:c"),
+            WhereExpr::Or(
+                Box::new(condition(WhereField::Company, WhereOperator::Matches, &["a"])),
+                Box::new(WhereExpr::And(
+                    Box::new(WhereExpr::Not(Box::new(condition(
+                        WhereField::Company,
+                        WhereOperator::Matches,
+                        &["b"]
+                    )))),
+                    Box::new(condition(WhereField::Title, WhereOperator::Matches, &["c"])),
+                )),
+            )
+        );
+    }
+
+    #[test]
+    fn test_parse_implicit_and_parentheses_and_quotes() {
+        assert_eq!(
+            parse("(company:a or company:b) title:\"staff engineer\""),
+            WhereExpr::And(
+                Box::new(WhereExpr::Or(
+                    Box::new(condition(WhereField::Company, WhereOperator::Matches, &["a"])),
+                    Box::new(condition(WhereField::Company, WhereOperator::Matches, &["b"])),
+                )),
+                Box::new(condition(
+                    WhereField::Title,
+                    WhereOperator::Matches,
+                    &["staff engineer"]
+                )),
+            )
+        );
+    }
+
+    #[test]
+    fn test_parse_typed_values() {
+        assert_eq!(
+            parse("created>2026-01-01"),
+            WhereExpr::Condition {
+                field: WhereField::Created,
+                operator: WhereOperator::GreaterThan,
+                values: vec![WhereValue::Date(DateBound::Absolute(
+                    NaiveDate::from_ymd_opt(2026, 1, 1).unwrap()
+                ))],
+            }
+        );
+        assert_eq!(
+            parse("stages>=2"),
+            WhereExpr::Condition {
+                field: WhereField::Stages,
+                operator: WhereOperator::GreaterOrEqual,
+                values: vec![WhereValue::Number(2)],
+            }
+        );
+    }
+
+    #[test]
+    fn test_parse_errors_point_at_offending_character() {
+        assert_eq!(parse_error("salary:high").position, 0);
+        assert_eq!(parse_error("status>PENDING").position, 6);
+        assert_eq!(parse_error("created>yesterday").position, 8);
+        assert_eq!(parse_error("status:(REJECTED|GHOSTED").position, 7);
+        assert_eq!(parse_error("status:PENDING AND").position, 18);
+        assert_eq!(parse_error("status:PENDING)").position, 14);
+        assert_eq!(parse_error("title:\"staff").position, 6);
+        assert_eq!(parse_error("company").position, 7);
+    }
+
+    #[test]
+    fn test_parse_error_rendering() {
+        let error = "title:backend AND salary:high"
+            .parse::<WhereClause>()
+            .unwrap_err();
+
+        assert!(error.starts_with("unknown field \"salary\""));
+        assert!(error.ends_with("  title:backend AND salary:high\n                    ^"));
+    }
+
+    #[test]
+    fn test_uses_field() {
+        let expr = parse("status:PENDING AND NOT sprint:2025");
+        assert!(expr.uses_field(WhereField::Sprint));
+        assert!(!expr.uses_field(WhereField::Title));
+    }
+}
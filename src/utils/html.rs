@@ -0,0 +1,483 @@
+//! Contains utilities for rendering job applications as self-contained HTML reports. Pages embed
+//! their styles and draw charts as inline SVG, so they can be opened without a network connection
+//! or any JavaScript.
+
+use std::collections::HashMap;
+use std::fmt::Write;
+
+use crate::{
+    commands::stage::stage_tree_lines,
+    models::{
+        insight::CountAndPercentage, job::TabledJob, sprint::QueriedSprint,
+        stage::QueriedInterviewStage,
+    },
+};
+
+/// The styles embedded in every page.
+const STYLES: &str = r#"
+body { font-family: -apple-system, "Segoe UI", Helvetica, Arial, sans-serif; margin: 2rem auto; max-width: 72rem; padding: 0 1rem; color: #1f2328; }
+h1 { margin-bottom: 0.25rem; }
+h2 { border-bottom: 1px solid #d0d7de; padding-bottom: 0.25rem; margin-top: 2.5rem; }
+nav a { margin-right: 0.75rem; }
+a { color: #0969da; }
+.meta { color: #59636e; margin-top: 0; }
+.summary { display: flex; flex-wrap: wrap; gap: 2rem; align-items: flex-start; }
+table { border-collapse: collapse; font-size: 0.9rem; }
+th, td { border: 1px solid #d0d7de; padding: 0.35rem 0.6rem; text-align: left; vertical-align: top; }
+th { background: #f6f8fa; }
+td.number { text-align: right; }
+.status { border-radius: 1rem; color: #ffffff; font-size: 0.8rem; font-weight: 600; padding: 0.1rem 0.6rem; white-space: nowrap; }
+pre { background: #f6f8fa; border-radius: 0.4rem; padding: 0.75rem; overflow-x: auto; }
+.current { font-weight: 600; }
+"#;
+
+/// The width of the label column in bar charts.
+const CHART_LABEL_WIDTH: usize = 170;
+/// The width of the longest bar in bar charts.
+const CHART_BAR_WIDTH: usize = 360;
+/// The height of each row in bar charts.
+const CHART_ROW_HEIGHT: usize = 28;
+
+/// Everything displayed on the report page of a single sprint.
+pub struct SprintReport {
+    /// The sprint.
+    pub sprint: QueriedSprint,
+    /// The job applications tracked in the sprint.
+    pub jobs: Vec<TabledJob>,
+    /// The interview stages of each job application, keyed by job ID.
+    pub stages: HashMap<i32, Vec<QueriedInterviewStage>>,
+    /// The number of job applications per status in the sprint.
+    pub status_counts: Vec<CountAndPercentage>,
+}
+
+/// The name of the file a sprint's report page is written to. Characters that are not allowed in
+/// file names on every platform are replaced, and the sprint ID keeps names that only differ in
+/// those characters apart.
+pub fn report_filename(sprint_id: i32, sprint_name: &str) -> String {
+    let name = sprint_name
+        .chars()
+        .map(|character| {
+            if character.is_alphanumeric() || matches!(character, '-' | '_' | '.') {
+                character
+            } else {
+                '-'
+            }
+        })
+        .collect::<String>();
+
+    format!("sprint-{sprint_id}-{name}.html")
+}
+
+/// Render the report page of a sprint. `sprint_counts` contains the ID and number of job
+/// applications of every sprint, which are linked to each other.
+pub fn render_sprint_page(
+    report: &SprintReport,
+    sprint_counts: &[(i32, CountAndPercentage)],
+    generated: &str,
+) -> String {
+    let sprint = &report.sprint;
+    let mut body = String::new();
+
+    let _ = write!(
+        body,
+        "<nav><a href=\"index.html\">All sprints</a></nav>\n<h1>{} Sprint</h1>\n<p class=\"meta\">{} • {} job applications • Generated {}</p>\n",
+        escape(&sprint.name),
+        escape(&sprint_dates(sprint)),
+        report.jobs.len(),
+        escape(generated)
+    );
+
+    body.push_str("<h2>Status Counts</h2>\n<div class=\"summary\">\n");
+    if report.status_counts.is_empty() {
+        body.push_str("<p>No job applications are tracked in this sprint.</p>\n");
+    } else {
+        body.push_str(&bar_chart(
+            &report
+                .status_counts
+                .iter()
+                .map(|count| (count.label.clone(), count.count, status_color(&count.label)))
+                .collect::<Vec<(String, i64, &str)>>(),
+        ));
+        body.push_str(
+            "<table>\n<tr><th>Status</th><th># of Jobs</th><th>% in Sprint</th><th>% Overall</th></tr>\n",
+        );
+        for count in &report.status_counts {
+            let _ = writeln!(
+                body,
+                "<tr><td>{}</td><td class=\"number\">{}</td><td class=\"number\">{}</td><td class=\"number\">{}</td></tr>",
+                status_badge(&count.label),
+                count.count,
+                escape(&count.sprint_percentage),
+                escape(&count.overall_percentage)
+            );
+        }
+        body.push_str("</table>\n");
+    }
+    body.push_str("</div>\n");
+
+    body.push_str("<h2>Job Applications</h2>\n");
+    if report.jobs.is_empty() {
+        body.push_str("<p>No job applications are tracked in this sprint.</p>\n");
+    } else {
+        body.push_str(&jobs_table(&report.jobs));
+    }
+
+    let jobs_with_stages = report
+        .jobs
+        .iter()
+        .filter_map(|job| {
+            report
+                .stages
+                .get(&job.id)
+                .filter(|stages| !stages.is_empty())
+                .map(|stages| (job, stages))
+        })
+        .collect::<Vec<(&TabledJob, &Vec<QueriedInterviewStage>)>>();
+    if !jobs_with_stages.is_empty() {
+        body.push_str("<h2>Interview Stages</h2>\n");
+        for (job, stages) in jobs_with_stages {
+            let _ = writeln!(
+                body,
+                "<pre>{}</pre>",
+                escape(&stage_tree_lines(job, stages).join("\n"))
+            );
+        }
+    }
+
+    body.push_str("<h2>Job Applications per Sprint</h2>\n<div class=\"summary\">\n");
+    body.push_str(&sprint_counts_section(sprint_counts, Some(sprint.id)));
+    body.push_str("</div>\n");
+
+    page(&format!("{} Sprint", sprint.name), &body)
+}
+
+/// Render the index page linking to the report page of every sprint.
+pub fn render_index_page(sprint_counts: &[(i32, CountAndPercentage)], generated: &str) -> String {
+    let mut body = String::new();
+
+    let _ = write!(
+        body,
+        "<h1>Job Applications</h1>\n<p class=\"meta\">{} job applications in {} sprints • Generated {}</p>\n<h2>Sprints</h2>\n<div class=\"summary\">\n",
+        sprint_counts
+            .iter()
+            .map(|(_, count)| count.count)
+            .sum::<i64>(),
+        sprint_counts.len(),
+        escape(generated)
+    );
+    body.push_str(&sprint_counts_section(sprint_counts, None));
+    body.push_str("</div>\n");
+
+    page("Job Applications", &body)
+}
+
+/// Render the chart and table of the number of job applications per sprint. The sprint with the ID
+/// `current` is highlighted.
+fn sprint_counts_section(
+    sprint_counts: &[(i32, CountAndPercentage)],
+    current: Option<i32>,
+) -> String {
+    let mut section = bar_chart(
+        &sprint_counts
+            .iter()
+            .map(|(sprint_id, count)| {
+                let color = if Some(*sprint_id) == current {
+                    "#0969da"
+                } else {
+                    "#8fb8ed"
+                };
+                (count.label.clone(), count.count, color)
+            })
+            .collect::<Vec<(String, i64, &str)>>(),
+    );
+
+    section.push_str("<table>\n<tr><th>Sprint</th><th># of Jobs</th><th>% Overall</th></tr>\n");
+    for (sprint_id, count) in sprint_counts {
+        let class = if Some(*sprint_id) == current {
+            " class=\"current\""
+        } else {
+            ""
+        };
+        let _ = writeln!(
+            section,
+            "<tr{class}><td><a href=\"{}\">{}</a></td><td class=\"number\">{}</td><td class=\"number\">{}</td></tr>",
+            escape(&report_filename(*sprint_id, &count.label)),
+            escape(&count.label),
+            count.count,
+            escape(&count.overall_percentage)
+        );
+    }
+    section.push_str("</table>\n");
+
+    section
+}
+
+/// Render the table of job applications.
+fn jobs_table(jobs: &[TabledJob]) -> String {
+    let mut table = String::from(
+        "<table>\n<tr><th>ID</th><th>Created</th><th>Company</th><th>Title</th><th>Status</th><th>Source</th><th>Stages</th><th>Tags</th><th>Link</th><th>Latest Note</th></tr>\n",
+    );
+
+    for job in jobs {
+        let link = job
+            .link
+            .as_deref()
+            .filter(|link| !link.is_empty())
+            .map(|link| {
+                if is_web_link(link) {
+                    format!("<a href=\"{0}\">{0}</a>", escape(link))
+                } else {
+                    escape(link)
+                }
+            })
+            .unwrap_or_default();
+
+        let _ = writeln!(
+            table,
+            "<tr><td class=\"number\">{}</td><td>{}</td><td>{}</td><td>{}</td><td>{}</td><td>{}</td><td class=\"number\">{}</td><td>{}</td><td>{link}</td><td>{}</td></tr>",
+            job.id,
+            escape(&job.created),
+            escape(&job.company_name),
+            escape(job.title.as_deref().unwrap_or("N/A")),
+            job.status.as_deref().map(status_badge).unwrap_or_default(),
+            escape(job.source.as_deref().unwrap_or("")),
+            job.stages.unwrap_or_default(),
+            escape(job.tags.as_deref().unwrap_or("")),
+            escape(job.notes.as_deref().unwrap_or(""))
+        );
+    }
+    table.push_str("</table>\n");
+
+    table
+}
+
+/// Draw a horizontal bar chart of `(label, count, color)` bars as inline SVG.
+fn bar_chart(bars: &[(String, i64, &str)]) -> String {
+    let max_count = bars
+        .iter()
+        .map(|(_, count, _)| *count)
+        .max()
+        .unwrap_or(0)
+        .max(1);
+    let width = CHART_LABEL_WIDTH + CHART_BAR_WIDTH + 50;
+    let height = CHART_ROW_HEIGHT * bars.len().max(1);
+
+    let mut chart = format!(
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{width}\" height=\"{height}\" viewBox=\"0 0 {width} {height}\" role=\"img\" font-size=\"13\">\n"
+    );
+    for (row, (label, count, color)) in bars.iter().enumerate() {
+        let y = row * CHART_ROW_HEIGHT;
+        let bar_width = (*count as usize * CHART_BAR_WIDTH / max_count as usize).max(1);
+
+        let _ = writeln!(
+            chart,
+            "<text x=\"{}\" y=\"{}\" text-anchor=\"end\">{}</text><rect x=\"{CHART_LABEL_WIDTH}\" y=\"{}\" width=\"{bar_width}\" height=\"{}\" rx=\"3\" fill=\"{color}\"><title>{}: {count}</title></rect><text x=\"{}\" y=\"{}\">{count}</text>",
+            CHART_LABEL_WIDTH - 8,
+            y + 18,
+            escape(label),
+            y + 5,
+            CHART_ROW_HEIGHT - 10,
+            escape(label),
+            CHART_LABEL_WIDTH + bar_width + 6,
+            y + 18
+        );
+    }
+    chart.push_str("</svg>\n");
+
+    chart
+}
+
+/// A colored badge displaying an application status.
+fn status_badge(status: &str) -> String {
+    format!(
+        "<span class=\"status\" style=\"background: {}\">{}</span>",
+        status_color(status),
+        escape(status)
+    )
+}
+
+/// The color used for an application status, matching the colors used in job tables.
+fn status_color(status: &str) -> &'static str {
+    match status {
+        "GHOSTED" => "#858585",
+        "HIRED" => "#1a7f37",
+        "IN PROGRESS" => "#bf8700",
+        "NOT HIRING ANYMORE" => "#757575",
+        "OFFER RECEIVED" => "#8250df",
+        "PENDING" => "#0969da",
+        "REJECTED" => "#cf222e",
+        _ => "#59636e",
+    }
+}
+
+/// The start and end dates of a sprint.
+fn sprint_dates(sprint: &QueriedSprint) -> String {
+    match sprint.end_date.as_deref() {
+        Some(end_date) => format!("{} to {end_date}", sprint.start_date),
+        None => format!("Started {}", sprint.start_date),
+    }
+}
+
+/// Wrap the body of a page in a complete HTML document.
+fn page(title: &str, body: &str) -> String {
+    format!(
+        "<!DOCTYPE html>\n<html lang=\"en\">\n<head>\n<meta charset=\"utf-8\">\n<meta name=\"viewport\" content=\"width=device-width, initial-scale=1\">\n<title>{} | fetters</title>\n<style>{STYLES}</style>\n</head>\n<body>\n{body}</body>\n</html>\n",
+        escape(title)
+    )
+}
+
+/// Whether a job link is a web page. Other links, ie. local file paths or `javascript:` URLs, are
+/// shown as text instead of being linked.
+fn is_web_link(link: &str) -> bool {
+    let link = link.to_ascii_lowercase();
+
+    link.starts_with("http://") || link.starts_with("https://")
+}
+
+/// Escape text for use in HTML elements and attribute values.
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&#39;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sprint() -> QueriedSprint {
+        QueriedSprint {
+            id: 1,
+            name: "2025-01-01".to_string(),
+            start_date: "2025-01-01".to_string(),
+            end_date: None,
+            num_jobs: 1,
+        }
+    }
+
+    fn count(label: &str, count: i64) -> CountAndPercentage {
+        CountAndPercentage {
+            label: label.to_string(),
+            count,
+            sprint_percentage: "100.00%".to_string(),
+            overall_percentage: "50.00%".to_string(),
+        }
+    }
+
+    fn job() -> TabledJob {
+        TabledJob {
+            id: 3,
+            created: "2025-01-02 10:00:00".to_string(),
+            company_name: "Initech <Austin>".to_string(),
+            title: Some("Backend Engineer".to_string()),
+            status: Some("IN PROGRESS".to_string()),
+            source: None,
+            stages: Some(1),
+            tags: None,
+            link: Some("https://initech.example.com/jobs?id=1&ref=2".to_string()),
+            notes: None,
+            last_activity: "2025-01-02 10:00:00".to_string(),
+            custom_fields: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_report_filename() {
+        assert_eq!(report_filename(1, "2025-01-01"), "sprint-1-2025-01-01.html");
+        assert_eq!(
+            report_filename(2, "Q1 / spring"),
+            "sprint-2-Q1---spring.html"
+        );
+        assert_ne!(
+            report_filename(3, "Q1/spring"),
+            report_filename(4, "Q1:spring")
+        );
+    }
+
+    #[test]
+    fn test_is_web_link() {
+        assert!(is_web_link("https://initech.example.com/jobs/42"));
+        assert!(is_web_link("HTTP://initech.example.com"));
+        assert!(!is_web_link("javascript:alert(1)"));
+        assert!(!is_web_link("/home/me/offers/initech.pdf"));
+
+        let mut local_job = job();
+        local_job.link = Some("javascript:alert(1)".to_string());
+        let table = jobs_table(&[job(), local_job]);
+        assert_eq!(table.matches("<a href").count(), 1);
+        assert!(table.contains("<td>javascript:alert(1)</td>"));
+    }
+
+    #[test]
+    fn test_escape() {
+        assert_eq!(
+            escape(r#"<a href="x">Tom & Jerry's</a>"#),
+            "&lt;a href=&quot;x&quot;&gt;Tom &amp; Jerry&#39;s&lt;/a&gt;"
+        );
+    }
+
+    #[test]
+    fn test_bar_chart() {
+        let chart = bar_chart(&[
+            ("PENDING".to_string(), 4, "#0969da"),
+            ("REJECTED".to_string(), 2, "#cf222e"),
+        ]);
+
+        assert!(chart.starts_with("<svg"));
+        assert!(chart.contains(&format!("width=\"{CHART_BAR_WIDTH}\"")));
+        assert!(chart.contains(&format!("width=\"{}\"", CHART_BAR_WIDTH / 2)));
+        assert!(chart.contains("<title>REJECTED: 2</title>"));
+    }
+
+    #[test]
+    fn test_render_sprint_page() {
+        let report = SprintReport {
+            sprint: sprint(),
+            jobs: vec![job()],
+            stages: HashMap::from([(
+                3,
+                vec![QueriedInterviewStage {
+                    id: 1,
+                    job_id: 3,
+                    stage_number: 1,
+                    name: Some("Phone Screen".to_string()),
+                    status: "PASSED".to_string(),
+                    scheduled_date: "2025/01/05".to_string(),
+                    notes: None,
+                    created: "2025-01-02 10:00:00".to_string(),
+                }],
+            )]),
+            status_counts: vec![count("IN PROGRESS", 1)],
+        };
+        let page = render_sprint_page(
+            &report,
+            &[(1, count("2025-01-01", 1)), (2, count("2024-12-01", 1))],
+            "2025-01-10 09:00",
+        );
+
+        assert!(page.starts_with("<!DOCTYPE html>"));
+        assert!(!page.contains("<script"));
+        assert!(page.contains("<h1>2025-01-01 Sprint</h1>"));
+        assert!(page.contains("Initech &lt;Austin&gt;"));
+        assert!(page.contains("<a href=\"https://initech.example.com/jobs?id=1&amp;ref=2\">"));
+        assert!(page.contains("Stage 1: Phone Screen"));
+        assert!(page.contains("[PASSED] 2025/01/05"));
+        assert!(page.contains("<a href=\"sprint-2-2024-12-01.html\">2024-12-01</a>"));
+        assert!(page.contains("<tr class=\"current\"><td><a href=\"sprint-1-2025-01-01.html\">"));
+        assert_eq!(page.matches("<svg").count(), 2);
+    }
+
+    #[test]
+    fn test_render_index_page() {
+        let page = render_index_page(
+            &[(1, count("2025-01-01", 3)), (2, count("2024-12-01", 2))],
+            "2025-01-10 09:00",
+        );
+
+        assert!(page.contains("5 job applications in 2 sprints"));
+        assert!(page.contains("<a href=\"sprint-1-2025-01-01.html\">2025-01-01</a>"));
+        assert!(!page.contains("class=\"current\""));
+    }
+}
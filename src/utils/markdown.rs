@@ -0,0 +1,408 @@
+//! Contains utilities for exporting job applications as Markdown notes with YAML frontmatter,
+//! ie. for an Obsidian vault. Exported files are identified by a `fetters_id` frontmatter property
+//! so they are updated in place when exporting again, even if they were renamed.
+
+use std::collections::HashMap;
+use std::fmt::Write;
+use std::fs::{read_dir, read_to_string};
+use std::path::{Path, PathBuf};
+
+use crate::{
+    errors::FettersError,
+    models::{
+        job::TabledJob, note::QueriedJobNote, sprint::QueriedSprint, stage::QueriedInterviewStage,
+    },
+};
+
+/// Everything written to the Markdown export is above this line. Anything below it is written by
+/// hand and kept when exporting again.
+pub const USER_CONTENT_MARKER: &str =
+    "%% fetters: anything below this line is kept when exporting again %%";
+
+/// A job application along with everything exported with it.
+#[derive(Debug)]
+pub struct MarkdownJob {
+    /// The job application.
+    pub job: TabledJob,
+    /// The name of the sprint containing the job application.
+    pub sprint: String,
+    /// The names of the tags applied to the job application.
+    pub tags: Vec<String>,
+    /// All notes logged for the job application in chronological order.
+    pub notes: Vec<QueriedJobNote>,
+    /// The interview stages of the job application.
+    pub stages: Vec<QueriedInterviewStage>,
+}
+
+/// The `fetters_id` frontmatter property of a job note.
+pub fn job_note_id(job_id: i32) -> String {
+    format!("job-{job_id}")
+}
+
+/// The `fetters_id` frontmatter property of a sprint note.
+pub fn sprint_note_id(sprint_name: &str) -> String {
+    format!("sprint-{sprint_name}")
+}
+
+/// The filename of a new job note, ie. `Initech - Backend Engineer (3).md`.
+pub fn job_note_filename(job: &TabledJob) -> String {
+    format!(
+        "{} - {} ({}).md",
+        sanitize_filename(&job.company_name),
+        sanitize_filename(job.title.as_deref().unwrap_or("N/A")),
+        job.id
+    )
+}
+
+/// The filename of a new sprint note, ie. `Sprint 2026-10-18.md`.
+pub fn sprint_note_filename(sprint_name: &str) -> String {
+    format!("Sprint {}.md", sanitize_filename(sprint_name))
+}
+
+/// Render the note of a single job application.
+pub fn render_job_note(markdown_job: &MarkdownJob) -> String {
+    let job = &markdown_job.job;
+    let mut output = String::from("---\n");
+
+    let _ = writeln!(output, "fetters_id: {}", job_note_id(job.id));
+    output.push_str(&job_properties(markdown_job));
+    output.push_str("---\n\n");
+
+    let _ = writeln!(
+        output,
+        "# {} - {}\n",
+        job.company_name,
+        job.title.as_deref().unwrap_or("N/A")
+    );
+    if let Some(link) = job.link.as_deref().filter(|link| !link.is_empty()) {
+        let _ = writeln!(output, "[Job posting]({link})\n");
+    }
+    output.push_str(&job_sections(markdown_job, "##"));
+    output.push_str(USER_CONTENT_MARKER);
+    output.push('\n');
+
+    output
+}
+
+/// Render the note of a sprint with a section for each of its job applications.
+pub fn render_sprint_note(sprint: &QueriedSprint, markdown_jobs: &[MarkdownJob]) -> String {
+    let mut output = String::from("---\n");
+
+    let _ = writeln!(output, "fetters_id: {}", sprint_note_id(&sprint.name));
+    let _ = writeln!(output, "sprint: {}", yaml_string(&sprint.name));
+    let _ = writeln!(output, "start: {}", yaml_string(&sprint.start_date));
+    match sprint.end_date.as_deref() {
+        Some(end_date) => {
+            let _ = writeln!(output, "end: {}", yaml_string(end_date));
+        }
+        None => output.push_str("end:\n"),
+    }
+    let _ = writeln!(output, "jobs: {}", markdown_jobs.len());
+    output.push_str("---\n\n");
+
+    let _ = writeln!(output, "# {} Sprint\n", sprint.name);
+    for markdown_job in markdown_jobs {
+        let job = &markdown_job.job;
+        let _ = writeln!(
+            output,
+            "## {} - {}\n",
+            job.company_name,
+            job.title.as_deref().unwrap_or("N/A")
+        );
+
+        // Only the note itself can have frontmatter, so the properties are listed instead.
+        for line in job_properties(markdown_job).lines() {
+            let _ = writeln!(output, "- {line}");
+        }
+        output.push('\n');
+        output.push_str(&job_sections(markdown_job, "###"));
+    }
+    output.push_str(USER_CONTENT_MARKER);
+    output.push('\n');
+
+    output
+}
+
+/// Keep everything below the marker in the existing file when it is exported again.
+pub fn merge_user_content(generated: &str, existing: Option<&str>) -> String {
+    match existing.and_then(|existing| existing.split_once(USER_CONTENT_MARKER)) {
+        Some((_, user_content)) => {
+            format!("{}{}", generated.trim_end_matches('\n'), user_content)
+        }
+        None => generated.to_string(),
+    }
+}
+
+/// Find the Markdown files in `directory` that were previously exported, keyed by their
+/// `fetters_id` frontmatter property.
+pub fn find_exported_notes(directory: &Path) -> Result<HashMap<String, PathBuf>, FettersError> {
+    let mut notes = HashMap::new();
+
+    for entry in read_dir(directory)? {
+        let path = entry?.path();
+        if path.extension().is_none_or(|extension| extension != "md") {
+            continue;
+        }
+
+        if let Some(id) = read_to_string(&path)
+            .ok()
+            .and_then(|content| frontmatter_id(&content))
+        {
+            notes.insert(id, path);
+        }
+    }
+
+    Ok(notes)
+}
+
+/// Read the `fetters_id` property from a note's frontmatter.
+fn frontmatter_id(content: &str) -> Option<String> {
+    let mut lines = content.lines();
+    if lines.next()?.trim_end() != "---" {
+        return None;
+    }
+
+    lines
+        .take_while(|line| line.trim_end() != "---")
+        .find_map(|line| line.strip_prefix("fetters_id:"))
+        .map(|id| id.trim().trim_matches('"').to_string())
+}
+
+/// The frontmatter properties of a job application, one per line.
+fn job_properties(markdown_job: &MarkdownJob) -> String {
+    let job = &markdown_job.job;
+    let optional = |value: Option<&str>| value.map(yaml_string).unwrap_or_default();
+
+    let mut output = String::new();
+    let _ = writeln!(output, "company: {}", yaml_string(&job.company_name));
+    let _ = writeln!(output, "title: {}", optional(job.title.as_deref()));
+    let _ = writeln!(output, "status: {}", optional(job.status.as_deref()));
+    let _ = writeln!(output, "sprint: {}", yaml_string(&markdown_job.sprint));
+    let _ = writeln!(output, "link: {}", optional(job.link.as_deref()));
+    let _ = writeln!(output, "created: {}", yaml_string(&job.created));
+    let _ = writeln!(
+        output,
+        "tags: [{}]",
+        markdown_job
+            .tags
+            .iter()
+            .map(|tag| yaml_string(tag))
+            .collect::<Vec<String>>()
+            .join(", ")
+    );
+
+    output
+}
+
+/// The notes and the interview stage checklist of a job application, under headings of the given
+/// level.
+fn job_sections(markdown_job: &MarkdownJob, heading: &str) -> String {
+    let mut output = String::new();
+
+    let _ = writeln!(output, "{heading} Notes\n");
+    if markdown_job.notes.is_empty() {
+        output.push_str("No notes yet.\n\n");
+    }
+    for note in &markdown_job.notes {
+        let _ = writeln!(output, "**{}**\n\n{}\n", note.created, note.content.trim());
+    }
+
+    let _ = writeln!(output, "{heading} Interview Stages\n");
+    if markdown_job.stages.is_empty() {
+        output.push_str("No interview stages yet.\n\n");
+    }
+    for stage in &markdown_job.stages {
+        output.push_str(&stage_checklist_item(stage));
+    }
+    if !markdown_job.stages.is_empty() {
+        output.push('\n');
+    }
+
+    output
+}
+
+/// Render an interview stage as a checklist item. Stages that are no longer scheduled are checked
+/// off.
+fn stage_checklist_item(stage: &QueriedInterviewStage) -> String {
+    let checkbox = if stage.status == "SCHEDULED" {
+        "[ ]"
+    } else {
+        "[x]"
+    };
+    let name = match stage.name.as_deref() {
+        Some(name) if !name.is_empty() => format!("Stage {}: {name}", stage.stage_number),
+        _ => format!("Stage {}", stage.stage_number),
+    };
+
+    let mut output = format!(
+        "- {checkbox} {name} ({}, {})\n",
+        stage.status, stage.scheduled_date
+    );
+    if let Some(notes) = stage.notes.as_deref().filter(|notes| !notes.is_empty()) {
+        for line in notes.lines() {
+            let _ = writeln!(output, "    {line}");
+        }
+    }
+
+    output
+}
+
+/// Quote a string for YAML.
+fn yaml_string(value: &str) -> String {
+    format!(
+        "\"{}\"",
+        value
+            .replace('\\', "\\\\")
+            .replace('"', "\\\"")
+            .replace('\n', "\\n")
+    )
+}
+
+/// Replace characters that are not allowed in filenames or in Obsidian links.
+fn sanitize_filename(name: &str) -> String {
+    name.chars()
+        .map(|character| {
+            if matches!(
+                character,
+                '/' | '\\' | ':' | '*' | '?' | '"' | '<' | '>' | '|' | '#' | '^' | '[' | ']'
+            ) || character.is_control()
+            {
+                '-'
+            } else {
+                character
+            }
+        })
+        .collect::<String>()
+        .trim()
+        .to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn job() -> TabledJob {
+        TabledJob {
+            id: 3,
+            created: "2026-10-01 10:00:00".to_string(),
+            company_name: "Initech".to_string(),
+            title: Some("Backend Engineer".to_string()),
+            status: Some("IN PROGRESS".to_string()),
+            source: None,
+            stages: Some(2),
+            tags: Some("remote, rust".to_string()),
+            link: Some("https://example.com/jobs/3".to_string()),
+            notes: Some("Recruiter called".to_string()),
+            last_activity: "2026-10-05 09:00:00".to_string(),
+            custom_fields: Vec::new(),
+        }
+    }
+
+    fn stage(stage_number: i32, status: &str, notes: Option<&str>) -> QueriedInterviewStage {
+        QueriedInterviewStage {
+            id: stage_number,
+            job_id: 3,
+            stage_number,
+            name: Some(format!("Round {stage_number}")),
+            status: status.to_string(),
+            scheduled_date: "2026/10/05".to_string(),
+            notes: notes.map(str::to_string),
+            created: "2026-10-02 10:00:00".to_string(),
+        }
+    }
+
+    fn markdown_job() -> MarkdownJob {
+        MarkdownJob {
+            job: job(),
+            sprint: "2026-10-01".to_string(),
+            tags: vec!["remote".to_string(), "rust".to_string()],
+            notes: vec![QueriedJobNote {
+                created: "2026-10-03 12:00:00".to_string(),
+                content: "Recruiter called".to_string(),
+            }],
+            stages: vec![
+                stage(1, "PASSED", Some("Went well")),
+                stage(2, "SCHEDULED", None),
+            ],
+        }
+    }
+
+    #[test]
+    fn test_render_job_note() {
+        let note = render_job_note(&markdown_job());
+
+        assert!(note.starts_with("---\nfetters_id: job-3\ncompany: \"Initech\"\n"));
+        assert!(note.contains("status: \"IN PROGRESS\"\nsprint: \"2026-10-01\"\n"));
+        assert!(note.contains("tags: [\"remote\", \"rust\"]\n---\n"));
+        assert!(note.contains("# Initech - Backend Engineer\n"));
+        assert!(note.contains("**2026-10-03 12:00:00**\n\nRecruiter called\n"));
+        assert!(note.contains("- [x] Stage 1: Round 1 (PASSED, 2026/10/05)\n    Went well\n"));
+        assert!(note.contains("- [ ] Stage 2: Round 2 (SCHEDULED, 2026/10/05)\n"));
+        assert!(note.ends_with(&format!("{USER_CONTENT_MARKER}\n")));
+        assert_eq!(frontmatter_id(&note), Some("job-3".to_string()));
+    }
+
+    #[test]
+    fn test_render_sprint_note() {
+        let sprint = QueriedSprint {
+            id: 1,
+            name: "2026-10-01".to_string(),
+            start_date: "2026-10-01".to_string(),
+            end_date: None,
+            num_jobs: 1,
+        };
+        let note = render_sprint_note(&sprint, &[markdown_job()]);
+
+        assert!(note.starts_with("---\nfetters_id: sprint-2026-10-01\nsprint: \"2026-10-01\"\n"));
+        assert!(note.contains("end:\njobs: 1\n---\n"));
+        assert!(note.contains("## Initech - Backend Engineer\n\n- company: \"Initech\"\n"));
+        assert!(note.contains("### Notes\n"));
+        assert!(note.contains("### Interview Stages\n"));
+        assert_eq!(frontmatter_id(&note), Some("sprint-2026-10-01".to_string()));
+    }
+
+    #[test]
+    fn test_merge_user_content() {
+        let generated = format!("new\n{USER_CONTENT_MARKER}\n");
+
+        assert_eq!(merge_user_content(&generated, None), generated);
+        assert_eq!(merge_user_content(&generated, Some("no marker")), generated);
+        assert_eq!(
+            merge_user_content(
+                &generated,
+                Some(&format!("old\n{USER_CONTENT_MARKER}\nMy own notes\n"))
+            ),
+            format!("new\n{USER_CONTENT_MARKER}\nMy own notes\n")
+        );
+    }
+
+    #[test]
+    fn test_find_exported_notes() {
+        let directory =
+            std::env::temp_dir().join(format!("fetters-markdown-test-{}", std::process::id()));
+        std::fs::create_dir_all(&directory).unwrap();
+        std::fs::write(
+            directory.join("Renamed by hand.md"),
+            render_job_note(&markdown_job()),
+        )
+        .unwrap();
+        std::fs::write(directory.join("Unrelated.md"), "# No frontmatter\n").unwrap();
+        std::fs::write(directory.join("notes.txt"), "---\nfetters_id: job-9\n---\n").unwrap();
+
+        let notes = find_exported_notes(&directory).unwrap();
+        std::fs::remove_dir_all(&directory).unwrap();
+
+        assert_eq!(notes.len(), 1);
+        assert_eq!(notes["job-3"], directory.join("Renamed by hand.md"));
+    }
+
+    #[test]
+    fn test_yaml_string_and_filenames() {
+        assert_eq!(yaml_string(r#"Say "hi"\"#), r#""Say \"hi\"\\""#);
+        assert_eq!(
+            job_note_filename(&job()),
+            "Initech - Backend Engineer (3).md"
+        );
+        assert_eq!(sprint_note_filename("Q1/2026"), "Sprint Q1-2026.md");
+    }
+}
@@ -12,7 +12,7 @@ const MIGRATIONS: EmbeddedMigrations = embed_migrations!("./migrations");
 /// Run the SQLite migrations specified in the `migrations/` directory.
 pub fn run_migrations(connection: &mut SqliteConnection) -> Result<(), FettersError> {
     if let Err(error) = connection.run_pending_migrations(MIGRATIONS) {
-        println!(
+        eprintln!(
             "{}",
             format!("FAILED TO RUN SQLITE MIGRATIONS: {}", error)
                 .red()
@@ -1,7 +1,18 @@
 //! Contains all utils for `fetters`.
 
+pub mod attachments;
+pub mod custom_fields;
+pub mod description;
 pub mod display;
+pub mod documents;
+pub mod editor;
+pub mod filter;
+pub mod html;
+pub mod markdown;
 pub mod migrations;
+pub mod posting;
 pub mod prompt;
+pub mod select;
+pub mod sources;
 pub mod spreadsheet;
 pub mod titles;
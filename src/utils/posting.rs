@@ -0,0 +1,504 @@
+//! Contains utilities for extracting job details from a saved job posting HTML page.
+//!
+//! Most job boards embed a schema.org `JobPosting` object as JSON-LD. If the page does not contain
+//! one, the `<title>` tag and OpenGraph metadata are used instead.
+
+use std::fs;
+
+use regex::Regex;
+use serde_json::Value;
+
+use crate::errors::FettersError;
+
+/// Contains the job details extracted from a job posting page. Every attribute is optional since
+/// job boards do not consistently fill them in.
+#[derive(Debug, Default, PartialEq)]
+pub struct JobPosting {
+    /// The name of the hiring organization.
+    pub company: Option<String>,
+    /// The job title.
+    pub title: Option<String>,
+    /// The base salary (ie. "USD 120000-150000/YEAR").
+    pub salary: Option<String>,
+    /// The job location(s).
+    pub location: Option<String>,
+    /// The date the job was posted.
+    pub date_posted: Option<String>,
+    /// The date the posting expires.
+    pub valid_through: Option<String>,
+    /// The canonical URL of the posting.
+    pub url: Option<String>,
+    /// The plain text job description.
+    pub description: Option<String>,
+}
+
+impl JobPosting {
+    /// Summarize the salary, location, and posting dates for use as the application's notes.
+    pub fn summary_notes(&self) -> Option<String> {
+        let summary: Vec<String> = [
+            ("Salary", &self.salary),
+            ("Location", &self.location),
+            ("Posted", &self.date_posted),
+            ("Apply by", &self.valid_through),
+        ]
+        .into_iter()
+        .filter_map(|(label, value)| value.as_ref().map(|value| format!("{label}: {value}")))
+        .collect();
+
+        if summary.is_empty() {
+            None
+        } else {
+            Some(summary.join(" | "))
+        }
+    }
+}
+
+/// Read a saved job posting HTML file and extract its job details.
+pub fn read_job_posting(path: &str) -> Result<JobPosting, FettersError> {
+    let html = fs::read_to_string(path)?;
+
+    Ok(parse_job_posting(&html))
+}
+
+/// Extract job details from a job posting HTML page. Attributes missing from the JSON-LD
+/// `JobPosting` object are filled in from the `<title>` tag and OpenGraph metadata.
+pub fn parse_job_posting(html: &str) -> JobPosting {
+    let mut posting = find_json_ld_posting(html)
+        .map(|json_ld| posting_from_json_ld(&json_ld))
+        .unwrap_or_default();
+
+    if posting.company.is_none() {
+        posting.company = meta_content(html, "og:site_name");
+    }
+    if posting.title.is_none() {
+        posting.title = meta_content(html, "og:title").or_else(|| title_tag(html));
+    }
+    if posting.url.is_none() {
+        posting.url = meta_content(html, "og:url");
+    }
+    if posting.description.is_none() {
+        posting.description = meta_content(html, "og:description");
+    }
+
+    posting
+}
+
+/// Guess the company and job title from a browser page title, ie. "Backend Engineer at Initech" or
+/// "Initech hiring Backend Engineer in Austin, TX | LinkedIn". Site names after a `|` are ignored,
+/// and the whole title is used as the job title if no company can be found.
+pub fn parse_page_title(page_title: &str) -> JobPosting {
+    let page_title = decode_entities(page_title);
+    let page_title = page_title.split(" | ").next().unwrap_or_default().trim();
+
+    let (company, title) = if let Some((company, title)) = page_title.split_once(" hiring ") {
+        (Some(company), title.split(" in ").next().unwrap_or(title))
+    } else if let Some((title, company)) = page_title
+        .rsplit_once(" at ")
+        .or_else(|| page_title.rsplit_once(" @ "))
+    {
+        (Some(company), title.trim_start_matches("Job Application for "))
+    } else if let Some((title, company)) = page_title.split_once(" - ") {
+        (company.split(" - ").next(), title)
+    } else {
+        (None, page_title)
+    };
+
+    let non_empty = |value: &str| Some(value.trim().to_string()).filter(|value| !value.is_empty());
+
+    JobPosting {
+        company: company.and_then(non_empty),
+        title: non_empty(title),
+        ..Default::default()
+    }
+}
+
+/// Find the first schema.org `JobPosting` object embedded in a JSON-LD `<script>` tag.
+fn find_json_ld_posting(html: &str) -> Option<Value> {
+    let script_regex = Regex::new(
+        r#"(?is)<script[^>]*type\s*=\s*["']application/ld\+json["'][^>]*>(.*?)</script>"#,
+    )
+    .expect("Invalid JSON-LD regex");
+
+    script_regex
+        .captures_iter(html)
+        .filter_map(|captures| serde_json::from_str::<Value>(captures[1].trim()).ok())
+        .find_map(find_job_posting_object)
+}
+
+/// Recursively search a JSON-LD document for an object whose `@type` is `JobPosting`. Documents
+/// may contain a single object, an array of objects, or an `@graph` array.
+fn find_job_posting_object(value: Value) -> Option<Value> {
+    match value {
+        Value::Array(values) => values.into_iter().find_map(find_job_posting_object),
+        Value::Object(mut object) => {
+            let is_job_posting = match object.get("@type") {
+                Some(Value::String(type_name)) => type_name == "JobPosting",
+                Some(Value::Array(type_names)) => type_names
+                    .iter()
+                    .any(|type_name| type_name.as_str() == Some("JobPosting")),
+                _ => false,
+            };
+
+            if is_job_posting {
+                Some(Value::Object(object))
+            } else {
+                object.remove("@graph").and_then(find_job_posting_object)
+            }
+        }
+        _ => None,
+    }
+}
+
+/// Convert a JSON-LD `JobPosting` object into a `JobPosting`.
+fn posting_from_json_ld(json_ld: &Value) -> JobPosting {
+    JobPosting {
+        company: json_ld.get("hiringOrganization").and_then(name_or_string),
+        title: json_ld.get("title").and_then(non_empty_string),
+        salary: json_ld.get("baseSalary").and_then(format_salary),
+        location: format_location(json_ld),
+        date_posted: json_ld.get("datePosted").and_then(format_date),
+        valid_through: json_ld.get("validThrough").and_then(format_date),
+        url: json_ld.get("url").and_then(non_empty_string),
+        description: json_ld
+            .get("description")
+            .and_then(Value::as_str)
+            .map(html_to_text)
+            .filter(|description| !description.is_empty()),
+    }
+}
+
+/// Returns the trimmed string if the value is a non-empty string or a number.
+fn non_empty_string(value: &Value) -> Option<String> {
+    let text = match value {
+        Value::String(text) => decode_entities(text.trim()),
+        Value::Number(number) => number.to_string(),
+        _ => return None,
+    };
+
+    if text.is_empty() { None } else { Some(text) }
+}
+
+/// Returns the `name` of an object (ie. an `Organization` or `Country`) or the value itself if
+/// it is a plain string.
+fn name_or_string(value: &Value) -> Option<String> {
+    match value {
+        Value::Object(object) => object.get("name").and_then(non_empty_string),
+        value => non_empty_string(value),
+    }
+}
+
+/// Returns the date portion of an ISO 8601 timestamp.
+fn format_date(value: &Value) -> Option<String> {
+    non_empty_string(value).map(|date| date.chars().take(10).collect())
+}
+
+/// Format a `MonetaryAmount` salary, such as "USD 120000-150000/YEAR".
+fn format_salary(value: &Value) -> Option<String> {
+    let (currency, amount) = match value {
+        Value::Object(object) => (
+            object.get("currency").and_then(non_empty_string),
+            object.get("value"),
+        ),
+        value => (None, Some(value)),
+    };
+
+    let (range, unit) = match amount? {
+        Value::Object(quantitative_value) => {
+            let range = match (
+                quantitative_value
+                    .get("minValue")
+                    .and_then(non_empty_string),
+                quantitative_value
+                    .get("maxValue")
+                    .and_then(non_empty_string),
+            ) {
+                (Some(min_value), Some(max_value)) => Some(format!("{min_value}-{max_value}")),
+                (min_value, max_value) => min_value
+                    .or(max_value)
+                    .or_else(|| quantitative_value.get("value").and_then(non_empty_string)),
+            };
+
+            (
+                range,
+                quantitative_value
+                    .get("unitText")
+                    .and_then(non_empty_string),
+            )
+        }
+        value => (non_empty_string(value), None),
+    };
+
+    let mut salary = range?;
+    if let Some(currency) = currency {
+        salary = format!("{currency} {salary}");
+    }
+    if let Some(unit) = unit {
+        salary = format!("{salary}/{unit}");
+    }
+
+    Some(salary)
+}
+
+/// Format the `jobLocation` place(s) as a list of "city, region, country" entries. Remote
+/// postings are marked with `jobLocationType: TELECOMMUTE`.
+fn format_location(json_ld: &Value) -> Option<String> {
+    let places = match json_ld.get("jobLocation") {
+        Some(Value::Array(places)) => places.iter().collect(),
+        Some(place) => vec![place],
+        None => Vec::new(),
+    };
+
+    let mut locations: Vec<String> = places
+        .into_iter()
+        .filter_map(|place| match place.get("address") {
+            Some(Value::Object(address)) => {
+                let parts: Vec<String> = ["addressLocality", "addressRegion", "addressCountry"]
+                    .iter()
+                    .filter_map(|key| address.get(*key).and_then(name_or_string))
+                    .collect();
+
+                if parts.is_empty() {
+                    None
+                } else {
+                    Some(parts.join(", "))
+                }
+            }
+            Some(address) => non_empty_string(address),
+            None => name_or_string(place),
+        })
+        .collect();
+
+    if json_ld
+        .get("jobLocationType")
+        .and_then(Value::as_str)
+        .is_some_and(|location_type| location_type.eq_ignore_ascii_case("TELECOMMUTE"))
+    {
+        locations.push("Remote".to_string());
+    }
+
+    if locations.is_empty() {
+        None
+    } else {
+        Some(locations.join("; "))
+    }
+}
+
+/// Returns the `content` of an OpenGraph `<meta>` tag.
+fn meta_content(html: &str, property: &str) -> Option<String> {
+    let meta_regex = Regex::new(r"(?is)<meta\s[^>]*>").expect("Invalid meta regex");
+    let attribute_regex = Regex::new(r#"(?is)([a-z:_-]+)\s*=\s*(?:"([^"]*)"|'([^']*)')"#)
+        .expect("Invalid attribute regex");
+
+    meta_regex.find_iter(html).find_map(|meta_tag| {
+        let mut matches_property = false;
+        let mut content = None;
+
+        for captures in attribute_regex.captures_iter(meta_tag.as_str()) {
+            let value = captures.get(2).or(captures.get(3))?.as_str();
+
+            match captures[1].to_lowercase().as_str() {
+                "property" | "name" if value.eq_ignore_ascii_case(property) => {
+                    matches_property = true;
+                }
+                "content" => content = Some(decode_entities(value.trim())),
+                _ => {}
+            }
+        }
+
+        if matches_property {
+            content.filter(|content| !content.is_empty())
+        } else {
+            None
+        }
+    })
+}
+
+/// Returns the text inside the `<title>` tag.
+fn title_tag(html: &str) -> Option<String> {
+    let title_regex = Regex::new(r"(?is)<title[^>]*>(.*?)</title>").expect("Invalid title regex");
+
+    title_regex
+        .captures(html)
+        .map(|captures| decode_entities(captures[1].trim()))
+        .filter(|title| !title.is_empty())
+}
+
+/// Convert an HTML fragment into plain text. Block-level tags are converted into line breaks and
+/// list items are prefixed with a dash.
+fn html_to_text(html: &str) -> String {
+    let html = decode_entities(html);
+
+    let line_break_regex =
+        Regex::new(r"(?i)<br\s*/?>|</(p|div|h[1-6]|ul|ol)>").expect("Invalid line break regex");
+    let list_item_regex = Regex::new(r"(?i)<li[^>]*>").expect("Invalid list item regex");
+    let tag_regex = Regex::new(r"(?s)<[^>]+>").expect("Invalid tag regex");
+    let blank_lines_regex = Regex::new(r"\n\s*\n\s*\n+").expect("Invalid blank lines regex");
+
+    let text = line_break_regex.replace_all(&html, "\n");
+    let text = list_item_regex.replace_all(&text, "\n- ");
+    let text = tag_regex.replace_all(&text, "");
+    let text: Vec<&str> = text.lines().map(|line| line.trim()).collect();
+
+    blank_lines_regex
+        .replace_all(&text.join("\n"), "\n\n")
+        .trim()
+        .to_string()
+}
+
+/// Decode the most common HTML entities.
+fn decode_entities(text: &str) -> String {
+    text.replace("&nbsp;", " ")
+        .replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&#x27;", "'")
+        .replace("&apos;", "'")
+        .replace("&amp;", "&")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const JSON_LD_POSTING: &str = r#"
+<html>
+<head>
+    <title>Senior Engineer | Careers</title>
+    <meta property="og:site_name" content="Job Board">
+    <script type="application/ld+json">
+    {
+        "@context": "https://schema.org/",
+        "@type": "JobPosting",
+        "title": "Senior Backend Engineer",
+        "description": "&lt;p&gt;Build payment rails.&lt;/p&gt;&lt;ul&gt;&lt;li&gt;Rust&lt;/li&gt;&lt;li&gt;SQL&lt;/li&gt;&lt;/ul&gt;",
+        "datePosted": "2026-03-01T09:00:00Z",
+        "validThrough": "2026-04-15",
+        "hiringOrganization": {"@type": "Organization", "name": "Stripe"},
+        "jobLocation": [
+            {"@type": "Place", "address": {"@type": "PostalAddress", "addressLocality": "Seattle", "addressRegion": "WA", "addressCountry": {"@type": "Country", "name": "US"}}},
+            {"@type": "Place", "address": {"@type": "PostalAddress", "addressLocality": "Dublin", "addressCountry": "IE"}}
+        ],
+        "jobLocationType": "TELECOMMUTE",
+        "baseSalary": {
+            "@type": "MonetaryAmount",
+            "currency": "USD",
+            "value": {"@type": "QuantitativeValue", "minValue": 180000, "maxValue": 220000, "unitText": "YEAR"}
+        },
+        "url": "https://stripe.com/jobs/123"
+    }
+    </script>
+</head>
+</html>
+"#;
+
+    #[test]
+    fn test_parse_page_title() {
+        let posting = parse_page_title("Initech hiring Backend Engineer in Austin, TX | LinkedIn");
+        assert_eq!(posting.company.as_deref(), Some("Initech"));
+        assert_eq!(posting.title.as_deref(), Some("Backend Engineer"));
+
+        let posting = parse_page_title("Job Application for Staff Engineer at Globex");
+        assert_eq!(posting.company.as_deref(), Some("Globex"));
+        assert_eq!(posting.title.as_deref(), Some("Staff Engineer"));
+
+        let posting = parse_page_title("Site Reliability Engineer - Hooli - Careers");
+        assert_eq!(posting.company.as_deref(), Some("Hooli"));
+        assert_eq!(posting.title.as_deref(), Some("Site Reliability Engineer"));
+
+        let posting = parse_page_title("Careers &amp; Openings");
+        assert_eq!(posting.company, None);
+        assert_eq!(posting.title.as_deref(), Some("Careers & Openings"));
+    }
+
+    #[test]
+    fn test_parse_json_ld_posting() {
+        let posting = parse_job_posting(JSON_LD_POSTING);
+
+        assert_eq!(posting.company.as_deref(), Some("Stripe"));
+        assert_eq!(posting.title.as_deref(), Some("Senior Backend Engineer"));
+        assert_eq!(posting.salary.as_deref(), Some("USD 180000-220000/YEAR"));
+        assert_eq!(
+            posting.location.as_deref(),
+            Some("Seattle, WA, US; Dublin, IE; Remote")
+        );
+        assert_eq!(posting.date_posted.as_deref(), Some("2026-03-01"));
+        assert_eq!(posting.valid_through.as_deref(), Some("2026-04-15"));
+        assert_eq!(posting.url.as_deref(), Some("https://stripe.com/jobs/123"));
+        assert_eq!(
+            posting.description.as_deref(),
+            Some("Build payment rails.\n\n- Rust\n- SQL")
+        );
+    }
+
+    #[test]
+    fn test_parse_json_ld_posting_inside_graph() {
+        let html = r#"<script type='application/ld+json'>
+            {"@graph": [
+                {"@type": "WebPage", "name": "Careers"},
+                {"@type": "JobPosting", "title": "Data Engineer", "hiringOrganization": "Plaid",
+                 "baseSalary": {"currency": "USD", "value": {"value": 95, "unitText": "HOUR"}}}
+            ]}
+        </script>"#;
+
+        let posting = parse_job_posting(html);
+        assert_eq!(posting.company.as_deref(), Some("Plaid"));
+        assert_eq!(posting.title.as_deref(), Some("Data Engineer"));
+        assert_eq!(posting.salary.as_deref(), Some("USD 95/HOUR"));
+        assert!(posting.location.is_none());
+    }
+
+    #[test]
+    fn test_parse_falls_back_to_open_graph_and_title() {
+        let html = r#"
+            <html><head>
+                <title>Staff Engineer - Acme &amp; Co</title>
+                <meta property="og:site_name" content="Acme &amp; Co" />
+                <meta content="https://acme.example/jobs/9" property="og:url">
+                <meta name="og:description" content="Own our billing platform.">
+            </head></html>
+        "#;
+
+        let posting = parse_job_posting(html);
+        assert_eq!(posting.company.as_deref(), Some("Acme & Co"));
+        assert_eq!(posting.title.as_deref(), Some("Staff Engineer - Acme & Co"));
+        assert_eq!(posting.url.as_deref(), Some("https://acme.example/jobs/9"));
+        assert_eq!(
+            posting.description.as_deref(),
+            Some("Own our billing platform.")
+        );
+        assert!(posting.salary.is_none());
+    }
+
+    #[test]
+    fn test_parse_prefers_og_title_over_title_tag() {
+        let html =
+            r#"<title>Careers</title><meta property="og:title" content="Platform Engineer">"#;
+        assert_eq!(
+            parse_job_posting(html).title.as_deref(),
+            Some("Platform Engineer")
+        );
+    }
+
+    #[test]
+    fn test_parse_ignores_invalid_json_ld() {
+        let html = r#"<script type="application/ld+json">{not json</script><title>Careers</title>"#;
+        let posting = parse_job_posting(html);
+        assert_eq!(posting.title.as_deref(), Some("Careers"));
+        assert!(posting.company.is_none());
+    }
+
+    #[test]
+    fn test_summary_notes() {
+        let posting = JobPosting {
+            salary: Some("USD 180000-220000/YEAR".to_string()),
+            date_posted: Some("2026-03-01".to_string()),
+            ..Default::default()
+        };
+        assert_eq!(
+            posting.summary_notes().as_deref(),
+            Some("Salary: USD 180000-220000/YEAR | Posted: 2026-03-01")
+        );
+        assert!(JobPosting::default().summary_notes().is_none());
+    }
+}
@@ -0,0 +1,38 @@
+//! Contains a utility function for selecting a single job application from query results.
+
+use diesel::SqliteConnection;
+use inquire::Select;
+
+use crate::{
+    cli::QueryArgs,
+    errors::FettersError,
+    models::{job::TabledJob, sprint::QueriedSprint},
+    repositories::job::JobRepository,
+    utils::{display::display_jobs, prompt::get_inquire_config},
+};
+
+/// Shared helper to select a job from query results.
+pub fn select_job(
+    connection: &mut SqliteConnection,
+    query_args: &mut QueryArgs,
+    current_sprint: &QueriedSprint,
+) -> Result<Option<TabledJob>, FettersError> {
+    let mut job_repo = JobRepository { connection };
+    let matched_jobs = job_repo.list_jobs(query_args, current_sprint)?;
+
+    if matched_jobs.is_empty() {
+        return Err(FettersError::NoJobsAvailable(
+            query_args.sprint_label(&current_sprint.name),
+        ));
+    }
+
+    display_jobs(
+        &matched_jobs,
+        &query_args.sprint_label(&current_sprint.name),
+        &[],
+    );
+
+    Ok(Select::new("Select a job application:", matched_jobs)
+        .with_render_config(get_inquire_config())
+        .prompt_skippable()?)
+}
@@ -0,0 +1,90 @@
+//! Contains utility functions for creating a new application source or selecting an existing one.
+
+use diesel::SqliteConnection;
+use inquire::{Select, Text};
+
+use crate::{
+    errors::FettersError, models::source::QueriedSource, repositories::source::SourceRepository,
+    utils::prompt::get_inquire_config,
+};
+
+/// Contains all variants that may be returned from the create_or_use_source() function.
+pub enum SourceType {
+    /// The user has created a new source.
+    NewSource(String),
+    /// The user has selected an existing source.
+    QueriedSource(QueriedSource),
+}
+
+impl SourceType {
+    /// Returns the name of the source.
+    pub fn name(&self) -> &str {
+        match self {
+            SourceType::NewSource(name) => name,
+            SourceType::QueriedSource(queried_source) => &queried_source.name,
+        }
+    }
+}
+
+/// Display the `Select` menu for existing application sources or create a new source. Sources
+/// are optional, so `None` is returned if the user skips this step.
+pub fn create_or_use_source(
+    connection: &mut SqliteConnection,
+) -> Result<Option<SourceType>, FettersError> {
+    let mut source_repo = SourceRepository { connection };
+    let existing_sources = source_repo.get_all_sources()?;
+
+    if existing_sources.is_empty() {
+        create_new_source()
+    } else {
+        get_existing_or_create_source(&mut source_repo, existing_sources)
+    }
+}
+
+/// Create a new application source.
+fn create_new_source() -> Result<Option<SourceType>, FettersError> {
+    match Text::new(
+        "[OPTIONAL] Enter where this application came from (ie. REFERRAL, JOB BOARD, RECRUITER INBOUND):",
+    )
+    .with_render_config(get_inquire_config())
+    .prompt_skippable()?
+    {
+        Some(name) if !name.trim().is_empty() => Ok(Some(SourceType::NewSource(
+            name.trim().to_uppercase(),
+        ))),
+        Some(_) | None => Ok(None),
+    }
+}
+
+/// Select an existing application source, create a new one, or skip.
+fn get_existing_or_create_source(
+    source_repo: &mut SourceRepository,
+    existing_sources: Vec<QueriedSource>,
+) -> Result<Option<SourceType>, FettersError> {
+    let existing_or_new = Select::new(
+        "Do you want to choose an existing application source or create a new one?",
+        vec!["Existing", "New", "Skip"],
+    )
+    .with_render_config(get_inquire_config())
+    .prompt_skippable()?;
+
+    match existing_or_new {
+        Some("Existing") => {
+            let source_selection = Select::new("Select a source:", existing_sources)
+                .with_render_config(get_inquire_config())
+                .prompt_skippable()?;
+
+            if let Some(source) = source_selection {
+                Ok(Some(SourceType::QueriedSource(
+                    source_repo.get_source(source.id)?,
+                )))
+            } else {
+                Err(FettersError::UnknownError(
+                    "No selection was provided.".to_string(),
+                ))
+            }
+        }
+        Some("New") => create_new_source(),
+        _ => Ok(None),
+    }
+}
@@ -21,14 +21,18 @@ pub fn create_spreadsheet(sprint: &Option<String>) -> Result<(Spreadsheet, Strin
 pub fn write_jobs(spreadsheet: &mut Spreadsheet, sheet_name: &str, jobs: Vec<TabledJob>) {
     let worksheet = spreadsheet.get_sheet_by_name_mut(sheet_name).unwrap();
 
-    let headers = vec![
+    let mut headers = vec![
         "Timestamp",
         "Company Name",
         "Title",
         "Status",
         "Link",
         "Notes",
+        "Tags",
     ];
+    if let Some(job) = jobs.first() {
+        headers.extend(job.custom_fields.iter().map(|(name, _)| name.as_str()));
+    }
     for (col, header) in headers.into_iter().enumerate() {
         let coordinates = ((col + 1) as u32, 1);
 
@@ -122,9 +126,13 @@ mod tests {
                 company_name: "Acme".to_string(),
                 title: Some("SWE".to_string()),
                 status: Some("PENDING".to_string()),
+                source: None,
                 stages: None,
+                tags: Some("fintech".to_string()),
                 link: Some("https://example.com".to_string()),
                 notes: Some("Notes here".to_string()),
+                last_activity: "2025-01-15".to_string(),
+                custom_fields: vec![("Team".to_string(), "Payments".to_string())],
             },
             TabledJob {
                 id: 2,
@@ -132,9 +140,13 @@ mod tests {
                 company_name: "Globex".to_string(),
                 title: None,
                 status: None,
+                source: None,
                 stages: None,
+                tags: None,
                 link: None,
                 notes: None,
+                last_activity: "2025-01-16".to_string(),
+                custom_fields: vec![("Team".to_string(), "".to_string())],
             },
         ];
 
@@ -155,6 +167,8 @@ mod tests {
         assert_eq!(worksheet.get_cell((4, 1)).unwrap().get_value(), "Status");
         assert_eq!(worksheet.get_cell((5, 1)).unwrap().get_value(), "Link");
         assert_eq!(worksheet.get_cell((6, 1)).unwrap().get_value(), "Notes");
+        assert_eq!(worksheet.get_cell((7, 1)).unwrap().get_value(), "Tags");
+        assert_eq!(worksheet.get_cell((8, 1)).unwrap().get_value(), "Team");
 
         // Verify first data row
         assert_eq!(
@@ -164,6 +178,8 @@ mod tests {
         assert_eq!(worksheet.get_cell((2, 2)).unwrap().get_value(), "Acme");
         assert_eq!(worksheet.get_cell((3, 2)).unwrap().get_value(), "SWE");
         assert_eq!(worksheet.get_cell((4, 2)).unwrap().get_value(), "PENDING");
+        assert_eq!(worksheet.get_cell((7, 2)).unwrap().get_value(), "fintech");
+        assert_eq!(worksheet.get_cell((8, 2)).unwrap().get_value(), "Payments");
 
         // Verify second data row
         assert_eq!(
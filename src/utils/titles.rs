@@ -17,8 +17,13 @@ pub enum TitleType {
     QueriedTitle(QueriedTitle),
 }
 
-/// Display the `Select` menu for existing job titles or create a new title.
-pub fn create_or_use_title(connection: &mut SqliteConnection) -> Result<TitleType, FettersError> {
+/// Display the `Select` menu for existing job titles or create a new title. If a title is
+/// suggested (ie. extracted from a job posting), the matching existing title is preselected, or
+/// the new title prompt is prefilled with it.
+pub fn create_or_use_title(
+    connection: &mut SqliteConnection,
+    suggested_title: Option<&str>,
+) -> Result<TitleType, FettersError> {
     let mut title_repo = TitleRepository { connection };
     let existing_titles = title_repo.get_all_titles()?;
 
@@ -27,18 +32,19 @@ pub fn create_or_use_title(connection: &mut SqliteConnection) -> Result<TitleTyp
             "{}",
             "There are currently no stored job titles!".yellow().bold()
         );
-        create_new_title()?
+        create_new_title(suggested_title)?
     } else {
-        get_existing_or_create_title(&mut title_repo, existing_titles)?
+        get_existing_or_create_title(&mut title_repo, existing_titles, suggested_title)?
     };
 
     Ok(queried_title)
 }
 
 /// Create a new job title.
-fn create_new_title() -> Result<TitleType, FettersError> {
+fn create_new_title(suggested_title: Option<&str>) -> Result<TitleType, FettersError> {
     loop {
         match Text::new("Enter a new job title:")
+            .with_initial_value(suggested_title.unwrap_or(""))
             .with_render_config(get_inquire_config())
             .prompt_skippable()?
         {
@@ -54,17 +60,30 @@ fn create_new_title() -> Result<TitleType, FettersError> {
 fn get_existing_or_create_title(
     title_repo: &mut TitleRepository,
     existing_titles: Vec<QueriedTitle>,
+    suggested_title: Option<&str>,
 ) -> Result<TitleType, FettersError> {
+    let suggested_index = suggested_title.and_then(|suggested_title| {
+        existing_titles
+            .iter()
+            .position(|title| title.name.eq_ignore_ascii_case(suggested_title.trim()))
+    });
+    let default_choice = match (suggested_title, suggested_index) {
+        (Some(_), None) => 1,
+        _ => 0,
+    };
+
     let existing_or_new = Select::new(
         "Do you want to choose an existing job title or create a new one?",
         vec!["Existing", "New"],
     )
+    .with_starting_cursor(default_choice)
     .with_render_config(get_inquire_config())
     .prompt_skippable()?;
 
     if let Some(selection) = existing_or_new {
         if selection == "Existing" {
             let title_selection = Select::new("Select a title:", existing_titles)
+                .with_starting_cursor(suggested_index.unwrap_or(0))
                 .with_render_config(get_inquire_config())
                 .prompt_skippable()?;
 
@@ -76,7 +95,7 @@ fn get_existing_or_create_title(
                 ))
             }
         } else {
-            Ok(create_new_title()?)
+            Ok(create_new_title(suggested_title)?)
         }
     } else {
         Err(FettersError::UnknownError(